@@ -0,0 +1,112 @@
+//! Shell completions. `bioimg completions <shell>` prints a static script that only knows the subcommands
+//! and flags, while registering with `source <(COMPLETE=bash bioimg)` (or the equivalent for other shells)
+//! makes the shell ask `bioimg` itself on every TAB, which can also complete zoo ids and rdf fields.
+
+use std::ffi::{OsStr, OsString};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use bioimg_runtime::download_cache::DownloadCache;
+use bioimg_runtime::zip_archive_ext::SharedZipArchive;
+use bioimg_spec::rdf::HttpUrl;
+use bioimg_zoo::collection::CollectionJson;
+use clap_complete::engine::CompletionCandidate;
+use clap_complete::Shell;
+use serde_yaml::Value;
+
+/// How long the zoo collection is reused for completions before it is downloaded again
+const COLLECTION_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(clap::Args, Debug)]
+pub struct CompletionsArgs {
+    /// The shell to generate the completion script for
+    #[arg(value_enum)]
+    pub shell: Shell,
+}
+
+/// Prints the completion script of `command`, with all of its subcommands and their flags, to stdout
+pub fn run(args: CompletionsArgs, command: &mut clap::Command) -> anyhow::Result<()> {
+    let bin_name = command.get_name().to_owned();
+    clap_complete::generate(args.shell, command, bin_name, &mut std::io::stdout());
+    Ok(())
+}
+
+fn candidates_with_prefix(current: &OsStr, values: impl IntoIterator<Item = (String, Option<String>)>) -> Vec<CompletionCandidate> {
+    let current = current.to_string_lossy();
+    values.into_iter()
+        .filter(|(value, _)| value.starts_with(current.as_ref()))
+        .map(|(value, help)| CompletionCandidate::new(value).help(help.map(Into::into)))
+        .collect()
+}
+
+fn cached_collection() -> anyhow::Result<CollectionJson> {
+    let url = HttpUrl::try_from(CollectionJson::request().uri().to_string())?;
+    let path = DownloadCache::global().with_max_age(Some(COLLECTION_MAX_AGE)).fetch(&url, None)?;
+    Ok(CollectionJson::parse_response(&http::Response::new(std::fs::read(path)?))?)
+}
+
+/// Completes the ids and nicknames of the resources in the zoo
+pub fn complete_zoo_ids(current: &OsStr) -> Vec<CompletionCandidate> {
+    // completions run on every TAB, so there's nowhere to report a failure to
+    let Ok(collection) = cached_collection() else {
+        return vec![];
+    };
+    let values = collection.items().iter().flat_map(|item| {
+        let help = item.name().map(str::to_owned);
+        let nickname = item.nickname().map(|nickname| (nickname.to_string(), help.clone()));
+        item.id().map(|id| (id.to_owned(), help)).into_iter().chain(nickname)
+    });
+    candidates_with_prefix(current, values)
+}
+
+/// The words of the command line being completed, which the shell passes after a `--`
+fn completed_words() -> Vec<OsString> {
+    std::env::args_os().skip_while(|arg| arg != "--").skip(1).collect()
+}
+
+/// The dotted paths of the fields of `value`, e.g. `name` and `config.my_tool.threshold`
+fn field_paths(value: &Value, prefix: &str, paths: &mut Vec<String>) {
+    let Value::Mapping(mapping) = value else {
+        return;
+    };
+    for (key, child) in mapping {
+        let Some(key) = key.as_str() else {
+            continue;
+        };
+        let path = if prefix.is_empty() { key.to_owned() } else { format!("{prefix}.{key}") };
+        field_paths(child, &path, paths);
+        paths.push(path);
+    }
+}
+
+/// Completes the keys of `bioimg set` assignments with the fields of the rdf of the model on the command line
+pub fn complete_assignment_keys(current: &OsStr) -> Vec<CompletionCandidate> {
+    // the model is the first positional argument after the subcommand
+    let words = completed_words();
+    let model_path = words.iter()
+        .skip_while(|word| *word != "set")
+        .skip(1)
+        .find(|word| !word.to_string_lossy().starts_with('-'))
+        .map(PathBuf::from);
+    let rdf = model_path.and_then(|path| {
+        let archive = SharedZipArchive::open(&path).ok()?;
+        let rdf_bytes = archive.read_full_entry(archive.rdf_entry_name()?).ok()?;
+        serde_yaml::from_slice::<Value>(&rdf_bytes).ok()
+    });
+    let Some(rdf) = rdf else {
+        return vec![];
+    };
+    let mut paths = vec![];
+    field_paths(&rdf, "", &mut paths);
+    paths.sort();
+    candidates_with_prefix(current, paths.into_iter().map(|path| (path, None)))
+}
+
+#[test]
+fn test_field_paths() {
+    let rdf: Value = serde_yaml::from_str("name: foo\nconfig:\n  tool:\n    threshold: 0.5\ntags: [a, b]\n").unwrap();
+    let mut paths = vec![];
+    field_paths(&rdf, "", &mut paths);
+    paths.sort();
+    assert_eq!(paths, vec!["config", "config.tool", "config.tool.threshold", "name", "tags"]);
+}
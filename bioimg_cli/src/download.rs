@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use bioimg_runtime::zip_archive_ext::SharedZipArchive;
+use bioimg_zoo::collection::CollectionJson;
+use clap_complete::engine::ArgValueCompleter;
+use serde_yaml::Value;
+use sha2::Digest;
+
+use crate::request::{download, send_bytes};
+
+#[derive(clap::Args, Debug)]
+pub struct DownloadArgs {
+    /// Nickname or id of the resource in the zoo, e.g. `affable-shark`
+    #[arg(add = ArgValueCompleter::new(crate::completions::complete_zoo_ids))]
+    pub resource: String,
+    /// Version to download instead of the latest one
+    #[arg(long)]
+    pub version: Option<String>,
+    /// Where to write the packaged resource. Defaults to `<id>.zip` in the current directory
+    #[arg(short, long, value_hint = clap::ValueHint::FilePath)]
+    pub output: Option<PathBuf>,
+}
+
+/// Adds `value` to `files` if it is a path relative to the rdf rather than a URL
+fn collect_path(value: Option<&Value>, sha256: Option<&Value>, files: &mut BTreeMap<String, Option<String>>) {
+    let Some(Value::String(path)) = value else {
+        return;
+    };
+    if path.contains("://") {
+        return;
+    }
+    let sha256 = match sha256 {
+        Some(Value::String(sha256)) => Some(sha256.to_lowercase()),
+        _ => None,
+    };
+    files.insert(path.clone(), sha256);
+}
+
+/// Every file referenced by the rdf `doc` with a relative path, alongside its declared sha256 if there is one
+fn referenced_files(doc: &Value, files: &mut BTreeMap<String, Option<String>>) {
+    match doc {
+        Value::Mapping(mapping) => {
+            collect_path(mapping.get("source"), mapping.get("sha256"), files);
+            collect_path(mapping.get("documentation"), None, files);
+            // the icon can also be an emoji
+            if let Some(Value::String(icon)) = mapping.get("icon") {
+                if icon.contains('.') {
+                    collect_path(mapping.get("icon"), None, files);
+                }
+            }
+            if let Some(Value::Sequence(covers)) = mapping.get("covers") {
+                for cover in covers {
+                    collect_path(Some(cover), None, files);
+                }
+            }
+            for value in mapping.values() {
+                referenced_files(value, files);
+            }
+        }
+        Value::Sequence(values) => values.iter().for_each(|value| referenced_files(value, files)),
+        _ => (),
+    }
+}
+
+/// Checks every file of the package that its rdf declares a sha256 for, and that none of the files the rdf
+/// references are missing. Returns how many files had a sha256 to check, and how many files are referenced
+fn verify_package(archive: &SharedZipArchive) -> anyhow::Result<(usize, usize)> {
+    let Some(rdf_entry_name) = archive.rdf_entry_name() else {
+        bail!("The package has no rdf.yaml");
+    };
+    let doc: Value = serde_yaml::from_slice(&archive.read_full_entry(rdf_entry_name)?).context("Parsing rdf")?;
+    let mut files = BTreeMap::new();
+    referenced_files(&doc, &mut files);
+
+    let mut num_verified = 0;
+    for (path, expected_sha256) in &files {
+        let entry_name = path.trim_start_matches("./");
+        if !archive.has_entry(entry_name) {
+            bail!("The package is missing {path}, which its rdf references");
+        }
+        let Some(expected) = expected_sha256 else {
+            continue;
+        };
+        let actual_sha256 = archive.with_entry(entry_name, |entry| -> std::io::Result<String> {
+            let mut hasher = sha2::Sha256::new();
+            std::io::copy(entry, &mut hasher)?;
+            Ok(hasher.finalize().iter().map(|byte| format!("{byte:02x}")).collect())
+        })??;
+        if *expected != actual_sha256 {
+            bail!("Hash mismatch for {path}: the rdf declares {expected} but the packaged file has {actual_sha256}");
+        }
+        num_verified += 1;
+    }
+    Ok((num_verified, files.len()))
+}
+
+pub fn run(args: DownloadArgs) -> anyhow::Result<()> {
+    let collection = CollectionJson::parse_response(&send_bytes(CollectionJson::request())?)
+        .context("Fetching the collection")?;
+    let Some(item) = collection.find_resource(&args.resource) else {
+        bail!("No resource with nickname or id '{}' in the zoo", args.resource);
+    };
+    if let Some(version) = &args.version {
+        if !item.versions().is_empty() && !item.versions().contains(version) {
+            bail!("'{}' has no version {version}. Available versions: {}", args.resource, item.versions().join(", "));
+        }
+    }
+    let Some(package_url) = item.package_url(args.version.as_deref()) else {
+        bail!("The zoo doesn't list an id for '{}'", args.resource);
+    };
+
+    let output = args.output.unwrap_or_else(|| {
+        let name = item.id().unwrap_or(&args.resource).replace('/', "_");
+        PathBuf::from(format!("{name}.zip"))
+    });
+    let fetch_and_verify = || -> anyhow::Result<(usize, usize)> {
+        let out_file = std::fs::File::create(&output).with_context(|| format!("Creating {}", output.to_string_lossy()))?;
+        eprintln!("Fetching {package_url}");
+        download(&package_url, out_file)?;
+        let archive = SharedZipArchive::open(&output).context("Opening the downloaded package")?;
+        verify_package(&archive)
+    };
+    let (num_verified, num_files) = match fetch_and_verify() {
+        Ok(counts) => counts,
+        Err(err) => {
+            // a package that failed verification would look like a valid download
+            _ = std::fs::remove_file(&output);
+            return Err(err);
+        }
+    };
+    eprintln!("Verified the hashes of {num_verified} of {num_files} files");
+    println!("Wrote {}", output.to_string_lossy());
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use bioimg_runtime::size_report::format_size;
+use bioimg_runtime::zoo_model::ZooModel;
+
+#[derive(clap::Args, Debug)]
+pub struct InfoArgs {
+    /// Path to the model .zip file
+    #[arg(value_hint = clap::ValueHint::FilePath)]
+    pub model: PathBuf,
+}
+
+pub fn run(args: InfoArgs) -> anyhow::Result<()> {
+    let model = ZooModel::try_load(&args.model)
+        .with_context(|| format!("Loading model from {}", args.model.to_string_lossy()))?;
+
+    println!("Name: {}", model.name);
+    if let Some(version) = &model.version {
+        println!("Version: {version}");
+    }
+    println!("License: {}", model.license);
+    println!("Inputs:");
+    for input in model.interface.inputs().iter() {
+        println!("    {input} {:?}", input.test_tensor.shape());
+    }
+    println!("Outputs:");
+    for output in model.interface.outputs().iter() {
+        println!("    {output} {:?}", output.test_tensor.shape());
+    }
+
+    let report = model.size_report();
+    println!("Sizes:");
+    let name_width = report.entries.iter()
+        .map(|entry| format!("{} ({})", entry.kind, entry.name).len())
+        .max()
+        .unwrap_or(0);
+    for entry in &report.entries {
+        let label = format!("{} ({})", entry.kind, entry.name);
+        let size = entry.size_bytes.map(format_size).unwrap_or_else(|| "unknown".to_owned());
+        println!("    {label:<name_width$}  {size:>9}");
+    }
+    println!("    {:<name_width$}  {:>9}", "Total", format_size(report.total_bytes()));
+    Ok(())
+}
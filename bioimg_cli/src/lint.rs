@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use bioimg_runtime::ilastik_compat::{check_ilastik_compatibility, IlastikIssueSeverity};
+use bioimg_runtime::lint::lint;
+use bioimg_runtime::zoo_model::ZooModel;
+
+use crate::report::{Finding, Report, ReportFormat, Severity};
+
+#[derive(clap::Args, Debug)]
+pub struct LintArgs {
+    /// Path to the model .zip file
+    #[arg(value_hint = clap::ValueHint::FilePath)]
+    pub model: PathBuf,
+    /// Also check whether the model can be used in ilastik
+    #[arg(long)]
+    pub ilastik: bool,
+    #[arg(long, value_enum, default_value_t)]
+    pub format: ReportFormat,
+}
+
+pub fn run(args: LintArgs) -> anyhow::Result<ExitCode> {
+    let model = match ZooModel::try_load(&args.model) {
+        Ok(model) => model,
+        Err(err) => {
+            let finding = Finding::new(Severity::Error, "load", format!("Could not load model: {err}"));
+            return Report::new("lint", &args.model, vec![finding]).emit(args.format);
+        }
+    };
+
+    let mut findings: Vec<Finding> = lint(&model)
+        .into_iter()
+        .map(|warning| Finding::new(Severity::Warning, warning.rule.code(), warning.message))
+        .collect();
+    if args.ilastik {
+        for issue in check_ilastik_compatibility(&model.interface, &model.weights) {
+            let severity = match issue.severity {
+                IlastikIssueSeverity::Error => Severity::Error,
+                IlastikIssueSeverity::Warning => Severity::Warning,
+            };
+            findings.push(Finding::new(severity, "ilastik", issue.to_string()));
+        }
+    }
+    Report::new("lint", &args.model, findings).emit(args.format)
+}
@@ -0,0 +1,104 @@
+pub mod completions;
+pub mod download;
+pub mod info;
+pub mod lint;
+pub mod pack;
+pub mod report;
+pub mod request;
+pub mod rewrite;
+pub mod sarif;
+pub mod set;
+pub mod stats;
+pub mod strip;
+pub mod test;
+pub mod upload;
+pub mod validate;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{CommandFactory, Parser, Subcommand};
+
+/// Command line tools for inspecting and manipulating bioimage.io models
+#[derive(Parser, Debug)]
+#[command(name = "bioimg", version, about, long_about = None)]
+struct Cli {
+    /// Where to keep files downloaded from URLs. Defaults to $BIOIMG_CACHE_DIR or the platform's cache directory
+    #[arg(long, global = true, value_hint = clap::ValueHint::DirPath)]
+    cache_dir: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Prints a summary of a packaged model, including how much space each of its files takes
+    Info(info::InfoArgs),
+    /// Changes fields of the rdf of a packaged model, leaving all other files in the archive untouched
+    Set(set::SetArgs),
+    /// Writes a copy of a packaged model without its weights, for sharing its description for review
+    Strip(strip::StripArgs),
+    /// Prints shape, dtype and value statistics of each test tensor of a packaged model
+    Stats(stats::StatsArgs),
+    /// Warns about best practices a packaged model doesn't follow, beyond what the spec requires.
+    ///
+    /// Exits with 0 if there are no findings, 2 if there are only warnings and 3 if there are errors
+    Lint(lint::LintArgs),
+    /// Checks that a packaged model can be loaded, optionally running the full test suite of bioimageio.core.
+    ///
+    /// Exits with 0 if there are no findings, 2 if there are only warnings and 3 if there are errors
+    Validate(validate::ValidateArgs),
+    /// Runs the test suite of bioimageio.core on a model, including inference on its test tensors.
+    ///
+    /// Requires a Python interpreter with bioimageio.core installed, either in the PATH or pointed to by
+    /// $BIOIMAGEIO_PYTHON. Exits with 0 if there are no findings, 2 if there are only warnings and 3 if there are errors
+    Test(test::TestArgs),
+    /// Packages a model from its rdf.yaml and the files next to it, or repackages a model .zip file.
+    ///
+    /// With --dry-run, only lists every file the package would contain, its size and where it comes from
+    Pack(pack::PackArgs),
+    /// Uploads a packaged model to the bioimage.io zoo for review and prints the nickname it was given.
+    ///
+    /// The API token can also be passed via $BIOIMAGEIO_API_TOKEN, e.g. from a CI secret
+    Upload(upload::UploadArgs),
+    /// Downloads a model (or any other resource) from the bioimage.io zoo by nickname or id, checking
+    /// every file against the sha256 declared in its rdf
+    Download(download::DownloadArgs),
+    /// Prints a completion script for the given shell.
+    ///
+    /// For example, for bash: `bioimg completions bash > ~/.local/share/bash-completion/completions/bioimg`.
+    /// To also complete zoo ids and rdf fields, register `bioimg` itself instead, e.g. with
+    /// `source <(COMPLETE=bash bioimg)` in ~/.bashrc
+    Completions(completions::CompletionsArgs),
+}
+
+fn run(command: Command) -> anyhow::Result<ExitCode> {
+    match command {
+        Command::Info(args) => info::run(args)?,
+        Command::Set(args) => set::run(args)?,
+        Command::Strip(args) => strip::run(args)?,
+        Command::Stats(args) => stats::run(args)?,
+        Command::Lint(args) => return lint::run(args),
+        Command::Validate(args) => return validate::run(args),
+        Command::Test(args) => return test::run(args),
+        Command::Pack(args) => pack::run(args)?,
+        Command::Upload(args) => upload::run(args)?,
+        Command::Download(args) => download::run(args)?,
+        Command::Completions(args) => completions::run(args, &mut Cli::command())?,
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn main() -> ExitCode {
+    // answers the shell and exits if `bioimg` was invoked for completions (i.e. with $COMPLETE set)
+    clap_complete::CompleteEnv::with_factory(Cli::command).complete();
+    let cli = Cli::parse();
+    bioimg_runtime::download_cache::DownloadCache::configure(cli.cache_dir);
+    match run(cli.command) {
+        Ok(exit_code) => exit_code,
+        Err(err) => {
+            eprintln!("Error: {err:?}");
+            ExitCode::from(report::EXIT_FAILURE)
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use bioimg_runtime::size_report::format_size;
+use bioimg_runtime::zoo_model::PackingOptions;
+use bioimg_runtime::TransferProgress;
+use iso8601_timestamp::Timestamp;
+
+use crate::validate::{load_model, parse_model_path};
+
+#[derive(clap::Args, Debug)]
+pub struct PackArgs {
+    /// Path to the rdf.yaml of an unpacked model, or to a model .zip file to repack
+    #[arg(value_parser = parse_model_path, value_hint = clap::ValueHint::AnyPath)]
+    pub model: PathBuf,
+    /// Where to write the packaged model
+    #[arg(short, long, required_unless_present = "dry_run", value_hint = clap::ValueHint::FilePath)]
+    pub output: Option<PathBuf>,
+    /// Only list the files the package would contain, without writing it
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Timestamp to write to the packed rdf instead of the model's own, e.g. `2024-01-01T00:00:00Z`.
+    /// Together with identical inputs, this makes the package byte-for-byte reproducible
+    #[arg(long, value_parser = parse_timestamp)]
+    pub timestamp: Option<Timestamp>,
+}
+
+/// Files smaller than this are copied quickly enough that reporting their progress would only be noise
+const PROGRESS_MIN_BYTES: u64 = 64 * 1024 * 1024;
+
+fn parse_timestamp(raw: &str) -> Result<Timestamp, String> {
+    Timestamp::parse(raw).ok_or_else(|| format!("Not an ISO 8601 timestamp: {raw}"))
+}
+
+pub fn run(args: PackArgs) -> anyhow::Result<()> {
+    let model = load_model(&args.model)
+        .with_context(|| format!("Loading model from {}", args.model.to_string_lossy()))?;
+    let options = PackingOptions { timestamp: args.timestamp };
+
+    let Some(output) = args.output.filter(|_| !args.dry_run) else {
+        let dry_run = model.pack_dry_run(&options);
+        let path_width = dry_run.entries.iter().map(|entry| entry.path.to_string().len()).max().unwrap_or(0);
+        for entry in &dry_run.entries {
+            let path = entry.path.to_string();
+            let source = entry.source.as_deref().unwrap_or("(generated)");
+            println!("{path:<path_width$}  {:>9}  {source}", format_size(entry.size_bytes));
+        }
+        println!("{:<path_width$}  {:>9}", "Total", format_size(dry_run.total_bytes()));
+        if let Some(err) = dry_run.error {
+            bail!("Packing would fail: {err}");
+        }
+        return Ok(());
+    };
+
+    let file = std::fs::File::create(&output)
+        .with_context(|| format!("Creating {}", output.to_string_lossy()))?;
+    let mut last_percent = None;
+    let mut on_progress = |progress: TransferProgress| {
+        let (Some(total_bytes), Some(fraction)) = (progress.total_bytes, progress.fraction()) else {
+            return;
+        };
+        if total_bytes < PROGRESS_MIN_BYTES {
+            return;
+        }
+        let percent = (fraction * 100.0) as u32;
+        if last_percent.replace(percent) == Some(percent) {
+            return;
+        }
+        eprint!("\rCopying {}: {percent:>3}%", format_size(total_bytes));
+        if percent == 100 {
+            eprintln!();
+        }
+    };
+    model.pack_into_with_progress(file, &options, &mut on_progress)?;
+    println!("Wrote {}", output.to_string_lossy());
+    Ok(())
+}
@@ -0,0 +1,155 @@
+//! Findings of the checking commands (`lint`, `validate`, `test`), printed either for humans, as JSON with a
+//! stable schema or as SARIF, plus the exit code that summarizes them so that CI pipelines can gate on it.
+
+use std::path::Path;
+use std::process::ExitCode;
+
+/// Version of the JSON report layout. Bumped whenever a field is removed or changes meaning
+pub const REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// No errors and no warnings
+pub const EXIT_OK: u8 = 0;
+/// The command itself failed (e.g. bad arguments or a missing tool), so nothing was checked
+pub const EXIT_FAILURE: u8 = 1;
+/// Only warnings were found
+pub const EXIT_WARNINGS: u8 = 2;
+/// At least one error was found
+pub const EXIT_ERRORS: u8 = 3;
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReportFormat {
+    #[default]
+    Text,
+    Json,
+    /// Static Analysis Results Interchange Format, for annotating repositories via GitHub code scanning
+    Sarif,
+}
+
+#[derive(serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Warning => write!(f, "warning"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+
+#[derive(serde::Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct Finding {
+    pub severity: Severity,
+    /// Which check produced this finding, e.g. a lint rule code or `ilastik`
+    pub code: String,
+    pub message: String,
+    /// Dotted path to the offending field of the rdf, if known, e.g. `inputs.0.axes`
+    pub location: Option<String>,
+    /// 1-based line of the rdf where the problem is, if known
+    pub line: Option<usize>,
+    /// 1-based column of the rdf where the problem is, if known
+    pub column: Option<usize>,
+}
+
+impl Finding {
+    pub fn new(severity: Severity, code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { severity, code: code.into(), message: message.into(), location: None, line: None, column: None }
+    }
+
+    pub fn at(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    pub fn at_position(mut self, line: usize, column: usize) -> Self {
+        self.line = Some(line);
+        self.column = Some(column);
+        self
+    }
+}
+
+#[derive(serde::Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportStatus {
+    Ok,
+    Warnings,
+    Errors,
+}
+
+#[derive(serde::Serialize, Debug)]
+pub struct Report {
+    pub schema_version: u32,
+    /// The command that produced this report, e.g. `lint`
+    pub command: &'static str,
+    pub model: String,
+    pub status: ReportStatus,
+    pub findings: Vec<Finding>,
+}
+
+impl Report {
+    pub fn new(command: &'static str, model: &Path, findings: Vec<Finding>) -> Self {
+        let status = match findings.iter().map(|finding| finding.severity).max() {
+            None => ReportStatus::Ok,
+            Some(Severity::Warning) => ReportStatus::Warnings,
+            Some(Severity::Error) => ReportStatus::Errors,
+        };
+        Self {
+            schema_version: REPORT_SCHEMA_VERSION,
+            command,
+            model: model.to_string_lossy().into_owned(),
+            status,
+            findings,
+        }
+    }
+
+    pub fn count(&self, severity: Severity) -> usize {
+        self.findings.iter().filter(|finding| finding.severity == severity).count()
+    }
+
+    pub fn exit_code(&self) -> ExitCode {
+        ExitCode::from(match self.status {
+            ReportStatus::Ok => EXIT_OK,
+            ReportStatus::Warnings => EXIT_WARNINGS,
+            ReportStatus::Errors => EXIT_ERRORS,
+        })
+    }
+
+    fn print_text(&self) {
+        for finding in &self.findings {
+            let position = match (finding.line, finding.column) {
+                (Some(line), Some(column)) => format!("{line}:{column}: "),
+                _ => String::new(),
+            };
+            match &finding.location {
+                Some(location) => println!("{}[{}]: {position}{location}: {}", finding.severity, finding.code, finding.message),
+                None => println!("{}[{}]: {position}{}", finding.severity, finding.code, finding.message),
+            }
+        }
+        let plural = |count: usize, noun: &str| match count {
+            1 => format!("1 {noun}"),
+            count => format!("{count} {noun}s"),
+        };
+        match self.status {
+            ReportStatus::Ok => println!("No problems found"),
+            _ => println!(
+                "{}, {}",
+                plural(self.count(Severity::Error), "error"),
+                plural(self.count(Severity::Warning), "warning")
+            ),
+        }
+    }
+
+    /// Prints the report and returns the exit code the process should end with
+    pub fn emit(&self, format: ReportFormat) -> anyhow::Result<ExitCode> {
+        match format {
+            ReportFormat::Text => self.print_text(),
+            ReportFormat::Json => println!("{}", serde_json::to_string_pretty(self)?),
+            ReportFormat::Sarif => println!("{}", serde_json::to_string_pretty(&crate::sarif::to_sarif(self))?),
+        }
+        Ok(self.exit_code())
+    }
+}
@@ -0,0 +1,36 @@
+//! Sends the requests built by `bioimg_zoo`, which doesn't do any IO itself
+
+use std::io::Read;
+
+use anyhow::Context;
+
+pub fn send_bytes<T: AsRef<[u8]>>(req: http::Request<T>) -> anyhow::Result<http::Response<Vec<u8>>> {
+    let (http_parts, body) = req.into_parts();
+    send_reader(http::Request::from_parts(http_parts, std::io::Cursor::new(body)))
+}
+
+/// Sends the requests of `bioimg_zoo`'s multi-step flows through [send_reader]
+pub struct UreqTransport;
+
+impl bioimg_zoo::upload::Transport for UreqTransport {
+    type Error = anyhow::Error;
+
+    fn send_reader<R: Read>(&self, req: http::Request<R>) -> anyhow::Result<http::Response<Vec<u8>>> {
+        send_reader(req)
+    }
+}
+
+/// Streams the body of `url` into `out`, so that large files don't have to fit in memory
+pub fn download<W: std::io::Write>(url: &str, mut out: W) -> anyhow::Result<u64> {
+    let response = ureq::get(url).call().with_context(|| format!("Requesting {url}"))?;
+    let num_bytes = std::io::copy(&mut response.into_reader(), &mut out).with_context(|| format!("Downloading {url}"))?;
+    Ok(num_bytes)
+}
+
+pub fn send_reader<R: Read>(req: http::Request<R>) -> anyhow::Result<http::Response<Vec<u8>>> {
+    let uri = req.uri().to_string();
+    let (http_parts, body) = req.into_parts();
+    let request: ureq::Request = http_parts.into();
+    let response = request.send(body).with_context(|| format!("Requesting {uri}"))?;
+    Ok(response.into())
+}
@@ -0,0 +1,44 @@
+use std::path::Path;
+
+use anyhow::Context;
+use bioimg_runtime::zip_archive_ext::SharedZipArchive;
+
+/// Writes a new archive to `output` with `rdf_bytes` as its rdf entry and a raw copy of every entry
+/// of `archive` for which `keep` returns true.
+///
+/// The archive is first written next to `output` and only then moved into place, so that `output`
+/// can be the very file `archive` was opened from.
+pub fn rewrite_archive(
+    archive: SharedZipArchive,
+    rdf_entry_name: &str,
+    rdf_bytes: &[u8],
+    mut keep: impl FnMut(&str) -> bool,
+    output: &Path,
+) -> anyhow::Result<()> {
+    let mut tmp_path = output.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    let tmp_path = std::path::PathBuf::from(tmp_path);
+
+    let result = (|| -> anyhow::Result<()> {
+        let tmp_file = std::fs::File::create(&tmp_path)
+            .with_context(|| format!("Creating {}", tmp_path.to_string_lossy()))?;
+        let mut writer = zip::ZipWriter::new(tmp_file);
+        archive.raw_copy_entries_into(&mut writer, |name| name != rdf_entry_name && keep(name))
+            .context("Copying archive entries")?;
+        let file_options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+        writer.start_file(rdf_entry_name, file_options)?;
+        std::io::Write::write_all(&mut writer, rdf_bytes)?;
+        writer.finish()?;
+        Ok(())
+    })();
+    if let Err(err) = result {
+        _ = std::fs::remove_file(&tmp_path);
+        return Err(err);
+    }
+
+    // release the source file before replacing it, which some platforms require
+    drop(archive);
+    std::fs::rename(&tmp_path, output)
+        .with_context(|| format!("Moving {} to {}", tmp_path.to_string_lossy(), output.to_string_lossy()))?;
+    Ok(())
+}
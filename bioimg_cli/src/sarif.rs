@@ -0,0 +1,85 @@
+//! Converts a [Report] into a SARIF 2.1.0 log, so that GitHub code scanning can show findings inline
+//! in model repositories
+
+use std::path::{Component, Path};
+
+use crate::report::{Report, Severity};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Whether findings can point at lines of the model's rdf, which is only possible for an unpacked
+/// rdf.yaml, since the rdf of a packaged model is hidden inside the .zip
+fn is_unpacked(model: &Path) -> bool {
+    model.extension().is_some_and(|ext| ext == "yaml" || ext == "yml")
+}
+
+/// `model` as a uri relative to the current directory, which is the repository root when run in CI,
+/// since that's what code scanning resolves artifact locations against
+fn relative_uri(model: &Path) -> String {
+    let relative = std::env::current_dir().ok()
+        .and_then(|cwd| model.strip_prefix(cwd).ok())
+        .unwrap_or(model);
+    let parts: Vec<String> = relative.components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part.to_string_lossy().into_owned()),
+            Component::ParentDir => Some("..".to_owned()),
+            _ => None,
+        })
+        .collect();
+    parts.join("/")
+}
+
+pub fn to_sarif(report: &Report) -> serde_json::Value {
+    let model = Path::new(&report.model);
+    let uri = relative_uri(model);
+    let has_regions = is_unpacked(model);
+    let mut rule_ids: Vec<&str> = report.findings.iter().map(|finding| finding.code.as_str()).collect();
+    rule_ids.sort();
+    rule_ids.dedup();
+    let rules: Vec<serde_json::Value> = rule_ids.iter().map(|id| serde_json::json!({"id": id})).collect();
+
+    let results: Vec<serde_json::Value> = report.findings.iter()
+        .map(|finding| {
+            let level = match finding.severity {
+                Severity::Warning => "warning",
+                Severity::Error => "error",
+            };
+            let mut physical_location = serde_json::json!({
+                "artifactLocation": {"uri": uri, "uriBaseId": "%SRCROOT%"},
+            });
+            if let (Some(line), true) = (finding.line, has_regions) {
+                physical_location["region"] = serde_json::json!({
+                    "startLine": line,
+                    "startColumn": finding.column.unwrap_or(1),
+                });
+            }
+            let mut location = serde_json::json!({"physicalLocation": physical_location});
+            if let Some(field_path) = &finding.location {
+                location["logicalLocations"] = serde_json::json!([{"fullyQualifiedName": field_path}]);
+            }
+            serde_json::json!({
+                "ruleId": finding.code,
+                "level": level,
+                "message": {"text": finding.message},
+                "locations": [location],
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "bioimg",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    })
+}
@@ -0,0 +1,171 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::{bail, Context};
+use bioimg_runtime::zip_archive_ext::SharedZipArchive;
+use bioimg_spec::rdf::model::ModelRdfV0_5;
+use clap_complete::engine::ArgValueCompleter;
+use serde_yaml::Value;
+
+use crate::rewrite::rewrite_archive;
+
+#[derive(clap::Args, Debug)]
+pub struct SetArgs {
+    /// Path to the model .zip file
+    #[arg(value_hint = clap::ValueHint::FilePath)]
+    pub model: PathBuf,
+    /// Changes to make to the rdf, e.g. `version=0.2.0`, `tags+=nucleus`, `tags-=draft` or
+    /// `config.my_tool.threshold=0.5`
+    #[arg(required = true, add = ArgValueCompleter::new(crate::completions::complete_assignment_keys))]
+    pub assignments: Vec<Assignment>,
+    /// Where to write the modified model. Defaults to overwriting the input file
+    #[arg(short, long, value_hint = clap::ValueHint::FilePath)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssignmentOp {
+    /// `key=value`
+    Set,
+    /// `key+=value`, appends to a list
+    Append,
+    /// `key-=value`, removes all matching items from a list
+    Remove,
+}
+
+#[derive(Clone, Debug)]
+pub struct Assignment {
+    pub path: Vec<String>,
+    pub op: AssignmentOp,
+    pub value: String,
+}
+
+impl FromStr for Assignment {
+    type Err = String;
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let Some((key, value)) = raw.split_once('=') else {
+            return Err(format!("Expected key=value, key+=value or key-=value, found '{raw}'"));
+        };
+        let (key, op) = if let Some(key) = key.strip_suffix('+') {
+            (key, AssignmentOp::Append)
+        } else if let Some(key) = key.strip_suffix('-') {
+            (key, AssignmentOp::Remove)
+        } else {
+            (key, AssignmentOp::Set)
+        };
+        let path: Vec<String> = key.split('.').map(|component| component.to_owned()).collect();
+        if path.iter().any(|component| component.is_empty()) {
+            return Err(format!("Bad key: '{key}'"));
+        }
+        Ok(Self { path, op, value: value.to_owned() })
+    }
+}
+
+/// Interprets `raw` the same way as `like`, so that e.g. `version=1.0` stays a string but
+/// `config.threshold=0.5` is a number if the field was already a number. Values of new fields are
+/// numbers or booleans if they look like one, and strings otherwise
+fn typed_value(raw: &str, like: Option<&Value>) -> anyhow::Result<Value> {
+    match like {
+        Some(Value::String(_)) => Ok(Value::String(raw.to_owned())),
+        None => match serde_yaml::from_str(raw) {
+            Ok(scalar @ (Value::Number(_) | Value::Bool(_))) => Ok(scalar),
+            _ => Ok(Value::String(raw.to_owned())),
+        },
+        Some(_) => serde_yaml::from_str(raw).with_context(|| format!("Parsing '{raw}' as yaml")),
+    }
+}
+
+impl Assignment {
+    fn key(&self) -> String {
+        self.path.join(".")
+    }
+
+    pub fn apply(&self, doc: &mut Value) -> anyhow::Result<()> {
+        let (field_name, parent_path) = self.path.split_last().expect("Empty paths are rejected on parsing");
+        let mut parent = doc;
+        for component in parent_path {
+            let Value::Mapping(mapping) = parent else {
+                bail!("Can't set {}: a parent field is not a mapping", self.key());
+            };
+            parent = mapping.entry(Value::String(component.clone()))
+                .or_insert_with(|| Value::Mapping(Default::default()));
+        }
+        let Value::Mapping(parent) = parent else {
+            bail!("Can't set {}: a parent field is not a mapping", self.key());
+        };
+        let field_key = Value::String(field_name.clone());
+
+        match self.op {
+            AssignmentOp::Set => {
+                let value = typed_value(&self.value, parent.get(&field_key))?;
+                parent.insert(field_key, value);
+            }
+            AssignmentOp::Append => {
+                let field = parent.entry(field_key).or_insert_with(|| Value::Sequence(vec![]));
+                let Value::Sequence(items) = field else {
+                    bail!("Can't append to {}: it is not a list", self.key());
+                };
+                let value = typed_value(&self.value, items.first())?;
+                if !items.contains(&value) {
+                    items.push(value);
+                }
+            }
+            AssignmentOp::Remove => {
+                let Some(field) = parent.get_mut(&field_key) else {
+                    return Ok(());
+                };
+                let Value::Sequence(items) = field else {
+                    bail!("Can't remove from {}: it is not a list", self.key());
+                };
+                let value = typed_value(&self.value, items.first())?;
+                items.retain(|item| *item != value);
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn run(args: SetArgs) -> anyhow::Result<()> {
+    let archive = SharedZipArchive::open(&args.model)
+        .with_context(|| format!("Opening {}", args.model.to_string_lossy()))?;
+    let Some(rdf_entry_name) = archive.rdf_entry_name() else {
+        bail!("No rdf.yaml found in {}", args.model.to_string_lossy());
+    };
+    let rdf_bytes = archive.read_full_entry(rdf_entry_name)?;
+    let mut doc: Value = serde_yaml::from_slice(&rdf_bytes).context("Parsing rdf")?;
+
+    for assignment in &args.assignments {
+        assignment.apply(&mut doc)?;
+    }
+    serde_yaml::from_value::<ModelRdfV0_5>(doc.clone()).context("The modified rdf is not a valid model")?;
+    let new_rdf_bytes = serde_yaml::to_string(&doc)?.into_bytes();
+
+    let output = args.output.as_ref().unwrap_or(&args.model);
+    rewrite_archive(archive, rdf_entry_name, &new_rdf_bytes, |_| true, output)?;
+    println!("Wrote {}", output.to_string_lossy());
+    Ok(())
+}
+
+#[test]
+fn test_assignments() {
+    let assignment: Assignment = "config.my_tool.threshold=0.5".parse().unwrap();
+    assert_eq!(assignment.path, ["config", "my_tool", "threshold"]);
+    assert_eq!((assignment.op, assignment.value.as_str()), (AssignmentOp::Set, "0.5"));
+    let assignment: Assignment = "tags+=nucleus".parse().unwrap();
+    assert_eq!((assignment.path.as_slice(), assignment.op), (["tags".to_owned()].as_slice(), AssignmentOp::Append));
+    assert_eq!("tags-=draft".parse::<Assignment>().unwrap().op, AssignmentOp::Remove);
+    assert_eq!("description=a=b".parse::<Assignment>().unwrap().value, "a=b");
+    assert!("version".parse::<Assignment>().is_err());
+    assert!("config..threshold=1".parse::<Assignment>().is_err());
+
+    let mut doc: Value = serde_yaml::from_str("version: '0.1'\ntags: [draft, cells]\nconfig: {}").unwrap();
+    for raw in ["version=1.0", "tags+=nucleus", "tags-=draft", "config.threshold=0.5", "config.enabled=true", "config.name=unet"] {
+        raw.parse::<Assignment>().unwrap().apply(&mut doc).unwrap();
+    }
+    let expected: Value = serde_yaml::from_str(
+        "version: '1.0'\ntags: [cells, nucleus]\nconfig: {threshold: 0.5, enabled: true, name: unet}",
+    )
+    .unwrap();
+    assert_eq!(doc, expected);
+    assert!("tags.first=x".parse::<Assignment>().unwrap().apply(&mut doc).is_err());
+}
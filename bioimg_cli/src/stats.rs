@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use bioimg_runtime::npy_array::NpyArray;
+use bioimg_runtime::tensor_stats::TensorStats;
+use bioimg_runtime::zoo_model::ZooModel;
+
+#[derive(clap::Args, Debug)]
+pub struct StatsArgs {
+    /// Path to the model .zip file
+    #[arg(value_hint = clap::ValueHint::FilePath)]
+    pub model: PathBuf,
+}
+
+fn print_tensor_stats(name: &str, array: &NpyArray) {
+    let stats = TensorStats::compute(array);
+    println!("    {name}");
+    println!("        shape: {:?}", array.shape());
+    println!("        dtype: {}", array.dtype_name());
+    println!("        min: {}  max: {}", stats.min, stats.max);
+    println!("        mean: {:.6}  std: {:.6}", stats.mean, stats.std);
+    println!("        NaNs: {}", stats.nan_count);
+}
+
+pub fn run(args: StatsArgs) -> anyhow::Result<()> {
+    let model = ZooModel::try_load(&args.model)
+        .with_context(|| format!("Loading model from {}", args.model.to_string_lossy()))?;
+
+    println!("Input test tensors:");
+    for input in model.interface.inputs().iter() {
+        print_tensor_stats(&input.tensor_meta.id.to_string(), &input.test_tensor);
+    }
+    println!("Output test tensors:");
+    for output in model.interface.outputs().iter() {
+        print_tensor_stats(&output.tensor_meta.id.to_string(), &output.test_tensor);
+    }
+    Ok(())
+}
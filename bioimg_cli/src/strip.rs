@@ -0,0 +1,148 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use bioimg_runtime::zip_archive_ext::SharedZipArchive;
+use bioimg_spec::rdf::model::ModelRdfV0_5;
+use serde_yaml::Value;
+
+use crate::rewrite::rewrite_archive;
+
+#[derive(clap::Args, Debug)]
+pub struct StripArgs {
+    /// Path to the model .zip file
+    #[arg(value_hint = clap::ValueHint::FilePath)]
+    pub model: PathBuf,
+    /// Where to write the stripped model
+    #[arg(short, long, value_hint = clap::ValueHint::FilePath)]
+    pub output: PathBuf,
+    /// URL of a directory where the weights of the model are hosted. If set, the weights (and their
+    /// architecture and environment files) are referenced by URL. Otherwise, weights whose files are
+    /// in the archive are dropped from the rdf, which fails if no other weights are left
+    #[arg(long)]
+    pub weights_url: Option<String>,
+}
+
+/// Adds `value` to `paths` if it is a reference to a file inside the archive
+fn collect_path(value: Option<&Value>, archive: &SharedZipArchive, paths: &mut HashSet<String>) {
+    if let Some(Value::String(path)) = value {
+        if archive.has_entry(path) {
+            paths.insert(path.clone());
+        }
+    }
+}
+
+/// The archive entries describing the model: covers, icon, documentation and test and sample tensors
+fn metadata_paths(doc: &Value, archive: &SharedZipArchive) -> HashSet<String> {
+    let mut paths = HashSet::new();
+    if let Some(Value::Sequence(covers)) = doc.get("covers") {
+        for cover in covers {
+            collect_path(Some(cover), archive, &mut paths);
+        }
+    }
+    collect_path(doc.get("icon"), archive, &mut paths);
+    collect_path(doc.get("documentation"), archive, &mut paths);
+    for tensors_key in ["inputs", "outputs"] {
+        let Some(Value::Sequence(tensors)) = doc.get(tensors_key) else {
+            continue;
+        };
+        for tensor in tensors {
+            for file_key in ["test_tensor", "sample_tensor"] {
+                let source = tensor.get(file_key).and_then(|file_descr| file_descr.get("source"));
+                collect_path(source, archive, &mut paths);
+            }
+        }
+    }
+    paths
+}
+
+/// Replaces `source` with a URL under `weights_url` if it is a reference to a file inside the archive
+fn point_to_url(source: Option<&mut Value>, archive: &SharedZipArchive, weights_url: &str) {
+    if let Some(Value::String(path)) = source {
+        if archive.has_entry(path) {
+            *path = format!("{}/{path}", weights_url.trim_end_matches('/'));
+        }
+    }
+}
+
+/// Points every file reference of the weights that lives in the archive to `weights_url` instead
+fn point_weights_to_url(doc: &mut Value, archive: &SharedZipArchive, weights_url: &str) {
+    let Some(Value::Mapping(weights)) = doc.get_mut("weights") else {
+        return;
+    };
+    for (_, weights_descr) in weights.iter_mut() {
+        point_to_url(weights_descr.get_mut("source"), archive, weights_url);
+        for nested_key in ["architecture", "dependencies"] {
+            let source = weights_descr.get_mut(nested_key).and_then(|nested| nested.get_mut("source"));
+            point_to_url(source, archive, weights_url);
+        }
+    }
+}
+
+/// Whether any file of `weights_descr` (its source, architecture or environment) is inside the archive
+fn references_archive(weights_descr: &Value, archive: &SharedZipArchive) -> bool {
+    let is_packed = |source: Option<&Value>| matches!(source, Some(Value::String(path)) if archive.has_entry(path));
+    is_packed(weights_descr.get("source"))
+        || ["architecture", "dependencies"]
+            .into_iter()
+            .any(|nested_key| is_packed(weights_descr.get(nested_key).and_then(|nested| nested.get("source"))))
+}
+
+/// Removes the weights whose files are inside the archive, returning their formats
+fn drop_packed_weights(doc: &mut Value, archive: &SharedZipArchive) -> Vec<Value> {
+    let Some(Value::Mapping(weights)) = doc.get_mut("weights") else {
+        return vec![];
+    };
+    let dropped: Vec<Value> = weights
+        .iter()
+        .filter(|(_, weights_descr)| references_archive(weights_descr, archive))
+        .map(|(format, _)| format.clone())
+        .collect();
+    for format in &dropped {
+        weights.remove(format);
+    }
+    // weights converted from dropped ones can't name them as their parent anymore
+    for (_, weights_descr) in weights.iter_mut() {
+        if let Value::Mapping(weights_descr) = weights_descr {
+            if weights_descr.get("parent").is_some_and(|parent| dropped.contains(parent)) {
+                weights_descr.remove("parent");
+            }
+        }
+    }
+    dropped
+}
+
+pub fn run(args: StripArgs) -> anyhow::Result<()> {
+    let archive = SharedZipArchive::open(&args.model)
+        .with_context(|| format!("Opening {}", args.model.to_string_lossy()))?;
+    let Some(rdf_entry_name) = archive.rdf_entry_name() else {
+        bail!("No rdf.yaml found in {}", args.model.to_string_lossy());
+    };
+    let rdf_bytes = archive.read_full_entry(rdf_entry_name)?;
+    let mut doc: Value = serde_yaml::from_slice(&rdf_bytes).context("Parsing rdf")?;
+    serde_yaml::from_value::<ModelRdfV0_5>(doc.clone()).context("Not a valid model")?;
+
+    let kept_paths = metadata_paths(&doc, &archive);
+    match &args.weights_url {
+        Some(weights_url) => point_weights_to_url(&mut doc, &archive, weights_url),
+        None => {
+            let dropped = drop_packed_weights(&mut doc, &archive);
+            if !matches!(doc.get("weights"), Some(Value::Mapping(weights)) if !weights.is_empty()) {
+                bail!("All weights of the model are files inside the archive. Use --weights-url to point to where they are hosted");
+            }
+            if !dropped.is_empty() {
+                let formats: Vec<&str> = dropped.iter().filter_map(Value::as_str).collect();
+                eprintln!(
+                    "Warning: dropped the {} weights, whose files are not kept. Use --weights-url to point to where they are hosted",
+                    formats.join(", ")
+                );
+            }
+        }
+    }
+    serde_yaml::from_value::<ModelRdfV0_5>(doc.clone()).context("The stripped rdf is not a valid model")?;
+    let new_rdf_bytes = serde_yaml::to_string(&doc)?.into_bytes();
+
+    rewrite_archive(archive, rdf_entry_name, &new_rdf_bytes, |name| kept_paths.contains(name), &args.output)?;
+    println!("Wrote {}", args.output.to_string_lossy());
+    Ok(())
+}
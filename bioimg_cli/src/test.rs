@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use anyhow::Context;
+use bioimg_runtime::core_validator::{CoreValidator, PYTHON_ENV_VAR};
+
+use crate::report::{Finding, Report, ReportFormat, Severity};
+use crate::validate::{core_finding, load_model, parse_model_path};
+
+#[derive(clap::Args, Debug)]
+pub struct TestArgs {
+    /// Path to the model .zip file, or to the rdf.yaml of an unpacked model
+    #[arg(value_parser = parse_model_path, value_hint = clap::ValueHint::AnyPath)]
+    pub model: PathBuf,
+    #[arg(long, value_enum, default_value_t)]
+    pub format: ReportFormat,
+}
+
+pub fn run(args: TestArgs) -> anyhow::Result<ExitCode> {
+    let validator = CoreValidator::discover().with_context(|| {
+        format!("Could not find a Python interpreter with bioimageio.core installed. Set ${PYTHON_ENV_VAR} to point to one")
+    })?;
+    if args.format == ReportFormat::Text {
+        println!("Testing with bioimageio.core {} ({})...", validator.core_version, validator.python().to_string_lossy());
+    }
+    let model = match load_model(&args.model) {
+        Ok(model) => model,
+        Err(err) => {
+            let finding = Finding::new(Severity::Error, "load", format!("Could not load model: {err}"));
+            return Report::new("test", &args.model, vec![finding]).emit(args.format);
+        }
+    };
+    let test_run = validator.test_model(model)?;
+    let summary = &test_run.summary;
+    let mut findings: Vec<Finding> = summary.errors().map(|entry| core_finding(Severity::Error, entry)).collect();
+    findings.extend(summary.warnings().map(|entry| core_finding(Severity::Warning, entry)));
+    if !summary.passed() && summary.errors().next().is_none() {
+        let message = format!("bioimageio.core reported status '{}'", summary.status);
+        findings.push(Finding::new(Severity::Error, "core", message));
+    }
+    let exit_code = Report::new("test", &args.model, findings).emit(args.format)?;
+    if let (Some(record), ReportFormat::Text) = (&test_run.record, args.format) {
+        println!(
+            "Ran with {} weights in {:.1}ms per tile on {}",
+            record.backend,
+            record.duration_per_tile.as_secs_f64() * 1000.0,
+            record.hardware
+        );
+    }
+    Ok(exit_code)
+}
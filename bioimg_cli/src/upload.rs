@@ -0,0 +1,39 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use bioimg_runtime::zip_archive_ext::SharedZipArchive;
+use bioimg_spec::rdf::model::ModelRdfV0_5;
+use bioimg_zoo::auth::UserToken;
+
+use crate::request::UreqTransport;
+
+#[derive(clap::Args, Debug)]
+pub struct UploadArgs {
+    /// Path to the model .zip file
+    #[arg(value_hint = clap::ValueHint::FilePath)]
+    pub model: PathBuf,
+    /// API token of the uploading user, as shown by the bioimage.io website after logging in
+    #[arg(long, env = "BIOIMAGEIO_API_TOKEN", hide_env_values = true)]
+    pub token: String,
+}
+
+pub fn run(args: UploadArgs) -> anyhow::Result<()> {
+    let user_token = UserToken::try_from(args.token).context("Parsing API token")?;
+
+    let archive = SharedZipArchive::open(&args.model)
+        .with_context(|| format!("Opening {}", args.model.to_string_lossy()))?;
+    let Some(rdf_entry_name) = archive.rdf_entry_name() else {
+        bail!("No rdf.yaml found in {}", args.model.to_string_lossy());
+    };
+    let rdf_bytes = archive.read_full_entry(rdf_entry_name)?;
+    serde_yaml::from_slice::<ModelRdfV0_5>(&rdf_bytes).context("Not a valid model")?;
+
+    let file = std::fs::File::open(&args.model)
+        .with_context(|| format!("Opening {}", args.model.to_string_lossy()))?;
+    let nickname = bioimg_zoo::upload::upload_model(&UreqTransport, user_token, file, |msg| eprintln!("{msg}"))
+        .context("Uploading the model")?;
+
+    // the nickname is the only thing on stdout, so that scripts can capture it
+    println!("{nickname}");
+    Ok(())
+}
@@ -0,0 +1,252 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime};
+
+use anyhow::Context;
+use bioimg_runtime::core_validator::{CoreValidationEntry, CoreValidator, PYTHON_ENV_VAR};
+use bioimg_runtime::zip_archive_ext::SharedZipArchive;
+use bioimg_runtime::zoo_model::{ModelLoadingError, ZooModel};
+use bioimg_spec::rdf::model::{ModelRdfV0_5, ParsingWarning};
+
+use crate::report::{Finding, Report, ReportFormat, Severity};
+
+#[derive(clap::Args, Debug)]
+pub struct ValidateArgs {
+    /// Path to the model .zip file, or to the rdf.yaml of an unpacked model
+    #[arg(value_parser = parse_model_path, value_hint = clap::ValueHint::AnyPath)]
+    pub model: PathBuf,
+    /// Also run the validation and test suite of bioimageio.core. Requires a Python interpreter with
+    /// bioimageio.core installed, either in the PATH or pointed to by $BIOIMAGEIO_PYTHON
+    #[arg(long)]
+    pub core: bool,
+    #[arg(long, value_enum, default_value_t)]
+    pub format: ReportFormat,
+    /// Keep running, validating again whenever the model (or any local file an unpacked rdf.yaml mentions) changes
+    #[arg(long)]
+    pub watch: bool,
+    /// Also load models declaring a newer format version than supported, reporting the fields that had to be
+    /// ignored as warnings
+    #[arg(long)]
+    pub best_effort: bool,
+}
+
+/// How often the watched files are checked for changes
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The names an rdf can have, both inside a package and next to the files of an unpacked model
+const RDF_FILE_NAMES: &[&str] = &["rdf.yaml", "bioimageio.yaml"];
+
+fn is_unpacked(model_path: &Path) -> bool {
+    model_path.extension().is_some_and(|ext| ext == "yaml" || ext == "yml")
+}
+
+/// Rejects yaml files that aren't an rdf, since the whole directory of an unpacked model is loaded
+/// and the rdf inside it would be picked instead of the file the user pointed to
+pub(crate) fn parse_model_path(raw: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(raw);
+    let is_rdf = path.file_name().is_some_and(|name| RDF_FILE_NAMES.iter().any(|rdf_name| name == *rdf_name));
+    if is_unpacked(&path) && !is_rdf {
+        return Err(format!("Unpacked models must be given by the path to their {}", RDF_FILE_NAMES.join(" or ")));
+    }
+    Ok(path)
+}
+
+/// The directory of an unpacked model, i.e. the one containing its rdf.yaml
+fn model_dir(rdf_path: &Path) -> &Path {
+    match rdf_path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    }
+}
+
+pub(crate) fn load_model(model_path: &Path) -> Result<ZooModel, ModelLoadingError> {
+    if !is_unpacked(model_path) {
+        return ZooModel::try_load(model_path);
+    }
+    ZooModel::try_load_archive(SharedZipArchive::from_directory(model_dir(model_path))?)
+}
+
+fn load_model_best_effort(model_path: &Path) -> Result<(ZooModel, Vec<ParsingWarning>), ModelLoadingError> {
+    if !is_unpacked(model_path) {
+        return ZooModel::try_load_best_effort(model_path);
+    }
+    ZooModel::try_load_archive_best_effort(SharedZipArchive::from_directory(model_dir(model_path))?)
+}
+
+fn read_rdf(model_path: &Path) -> Option<Vec<u8>> {
+    if is_unpacked(model_path) {
+        return std::fs::read(model_path).ok();
+    }
+    let archive = SharedZipArchive::open(model_path).ok()?;
+    RDF_FILE_NAMES.iter().find_map(|name| archive.read_full_entry(name).ok())
+}
+
+fn compat_finding(warning: &ParsingWarning) -> Finding {
+    let finding = Finding::new(Severity::Warning, "compat", warning.to_string());
+    match warning {
+        ParsingWarning::UnknownField { path } => finding.at(path.clone()),
+        ParsingWarning::NewerFormatVersion { .. } => finding.at("format_version"),
+    }
+}
+
+pub(crate) fn core_finding(severity: Severity, entry: &CoreValidationEntry) -> Finding {
+    let finding = Finding::new(severity, "core", entry.msg.clone());
+    match entry.location() {
+        Some(location) => finding.at(location),
+        None => finding,
+    }
+}
+
+/// Re-parses the rdf of the model at `model_path`, tracking the field path, to find where exactly
+/// it doesn't match the spec
+fn locate_rdf_error(model_path: &Path, finding: Finding) -> Finding {
+    let Some(rdf_bytes) = read_rdf(model_path) else {
+        return finding;
+    };
+    let deserializer = serde_yaml::Deserializer::from_slice(&rdf_bytes);
+    let Err(err) = serde_path_to_error::deserialize::<_, ModelRdfV0_5>(deserializer) else {
+        return finding;
+    };
+    let field_path = err.path().to_string();
+    let mut finding = if field_path == "." { finding } else { finding.at(field_path) };
+    if let Some(position) = err.inner().location() {
+        finding = finding.at_position(position.line(), position.column());
+    }
+    finding
+}
+
+fn validate(model_path: &Path, core_validator: Option<&CoreValidator>, best_effort: bool) -> anyhow::Result<Report> {
+    let mut findings = vec![];
+    let loaded = if best_effort {
+        load_model_best_effort(model_path)
+    } else {
+        load_model(model_path).map(|model| (model, vec![]))
+    };
+    match loaded {
+        Ok((_, warnings)) => findings.extend(warnings.iter().map(compat_finding)),
+        Err(err) => {
+            let finding = Finding::new(Severity::Error, "load", format!("Could not load model: {err}"));
+            findings.push(match err {
+                ModelLoadingError::BadModel { .. } => locate_rdf_error(model_path, finding),
+                _ => finding,
+            });
+        }
+    }
+
+    if let Some(validator) = core_validator {
+        let summary = validator.validate_package(model_path)?;
+        findings.extend(summary.errors().map(|entry| core_finding(Severity::Error, entry)));
+        findings.extend(summary.warnings().map(|entry| core_finding(Severity::Warning, entry)));
+        if !summary.passed() && summary.errors().next().is_none() {
+            let message = format!("bioimageio.core reported status '{}'", summary.status);
+            findings.push(Finding::new(Severity::Error, "core", message));
+        }
+    }
+    Ok(Report::new("validate", model_path, findings))
+}
+
+/// The files whose changes trigger a new validation: the package itself, or an unpacked rdf.yaml
+/// plus every local file it mentions. Only these are polled, instead of the whole model directory
+fn watched_files(model_path: &Path) -> Vec<PathBuf> {
+    fn visit(value: &serde_yaml::Value, dir: &Path, files: &mut Vec<PathBuf>) {
+        match value {
+            serde_yaml::Value::String(s) if !s.contains("://") => {
+                let path = dir.join(s);
+                if path.is_file() && !files.contains(&path) {
+                    files.push(path);
+                }
+            }
+            serde_yaml::Value::Sequence(items) => items.iter().for_each(|item| visit(item, dir, files)),
+            serde_yaml::Value::Mapping(mapping) => mapping.values().for_each(|value| visit(value, dir, files)),
+            _ => (),
+        }
+    }
+    let mut files = vec![model_path.to_owned()];
+    if !is_unpacked(model_path) {
+        return files;
+    }
+    let rdf = std::fs::read(model_path).ok().and_then(|raw| serde_yaml::from_slice(&raw).ok());
+    if let Some(rdf) = rdf {
+        visit(&rdf, model_dir(model_path), &mut files);
+    }
+    files
+}
+
+fn modification_times(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files.iter()
+        .map(|file| std::fs::metadata(file).and_then(|metadata| metadata.modified()).ok())
+        .collect()
+}
+
+/// Prints only what changed since the `previous` report, followed by the current counts
+fn print_incremental(previous: &Report, current: &Report) {
+    let mut changed = false;
+    for finding in current.findings.iter().filter(|finding| !previous.findings.contains(finding)) {
+        println!("new {}[{}]: {}", finding.severity, finding.code, finding.message);
+        changed = true;
+    }
+    for finding in previous.findings.iter().filter(|finding| !current.findings.contains(finding)) {
+        println!("fixed {}[{}]: {}", finding.severity, finding.code, finding.message);
+        changed = true;
+    }
+    if !changed {
+        println!("No changes in findings");
+    }
+    println!(
+        "{} errors, {} warnings",
+        current.count(Severity::Error),
+        current.count(Severity::Warning)
+    );
+}
+
+fn watch(args: &ValidateArgs, core_validator: Option<&CoreValidator>) -> anyhow::Result<ExitCode> {
+    let mut previous: Option<Report> = None;
+    loop {
+        // an edited rdf.yaml can mention different files, so they are looked up again on every change
+        let files = watched_files(&args.model);
+        let times = modification_times(&files);
+        let report = match validate(&args.model, core_validator, args.best_effort) {
+            Ok(report) => report,
+            Err(err) => {
+                eprintln!("Could not validate {}: {err:#}", args.model.to_string_lossy());
+                wait_for_changes(&files, &times);
+                continue;
+            }
+        };
+        match (&previous, args.format) {
+            (Some(previous), ReportFormat::Text) => {
+                println!("--- {} changed, validating again ---", args.model.to_string_lossy());
+                print_incremental(previous, &report);
+            }
+            _ => {
+                report.emit(args.format)?;
+            }
+        }
+        previous = Some(report);
+        wait_for_changes(&files, &times);
+    }
+}
+
+fn wait_for_changes(files: &[PathBuf], times: &[Option<SystemTime>]) {
+    while modification_times(files) == times {
+        std::thread::sleep(WATCH_POLL_INTERVAL);
+    }
+}
+
+pub fn run(args: ValidateArgs) -> anyhow::Result<ExitCode> {
+    let core_validator = if args.core {
+        let validator = CoreValidator::discover().with_context(|| {
+            format!("Could not find a Python interpreter with bioimageio.core installed. Set ${PYTHON_ENV_VAR} to point to one")
+        })?;
+        if args.format == ReportFormat::Text {
+            println!("Running bioimageio.core {} ({})...", validator.core_version, validator.python().to_string_lossy());
+        }
+        Some(validator)
+    } else {
+        None
+    };
+    if args.watch {
+        return watch(&args, core_validator.as_ref());
+    }
+    validate(&args.model, core_validator.as_ref(), args.best_effort)?.emit(args.format)
+}
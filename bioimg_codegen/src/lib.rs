@@ -5,6 +5,7 @@ use proc_macro::TokenStream;
 
 mod syn_extensions;
 mod restore;
+mod value_widget;
 mod serde_attributes;
 
 #[proc_macro_derive(Restore, attributes(restore))]
@@ -14,3 +15,11 @@ pub fn derive_restore(input: TokenStream) -> TokenStream {
         Err(err) => err.to_compile_error().into(),
     }
 }
+
+#[proc_macro_derive(ValueWidget, attributes(value_widget))]
+pub fn derive_value_widget(input: TokenStream) -> TokenStream {
+    match value_widget::do_derive_value_widget(input) {
+        Ok(tokens) => tokens,
+        Err(err) => err.to_compile_error().into(),
+    }
+}
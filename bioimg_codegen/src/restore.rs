@@ -3,6 +3,8 @@ use syn::spanned::Spanned;
 use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 
+use crate::syn_extensions::FieldExt;
+
 /// The `saved_data=path::to::SavedDataType` in
 /// `#[restore(saved_data=path::to::SavedDataType)]` for setting the `SavedData`
 /// associated type in the generated `impl Restore`
@@ -130,8 +132,10 @@ pub fn do_derive_restore(input: TokenStream) -> syn::Result<TokenStream>{
         if FieldRestoreMode::try_from_attrs(&field.attrs)?.skips_dump(){
             continue;
         }
+        let cfg_attrs = field.cfg_attrs();
         saved_data_field_initializers.push(quote_spanned! {ident_span=>
             // FIXME: could we not use this path into bioimg_gui?
+            #(#cfg_attrs)*
             #ident: crate::widgets::Restore::dump(&self.#ident),
         });
     }
@@ -142,9 +146,11 @@ pub fn do_derive_restore(input: TokenStream) -> syn::Result<TokenStream>{
         let ident = field.ident.as_ref().map(|id| quote!(#id)).unwrap_or(quote!(#field_idx));
         let span = ident.span();
         let ty_span = field.ty.span();
+        let cfg_attrs = field.cfg_attrs();
 
         let statement = match FieldRestoreMode::try_from_attrs(&field.attrs)?{
             FieldRestoreMode::CallDefault => quote_spanned! {ty_span=>
+                #(#cfg_attrs)*
                 self.#ident = std::default::Default::default();
             },
             FieldRestoreMode::OnUpdate(update_marker) => {
@@ -153,6 +159,7 @@ pub fn do_derive_restore(input: TokenStream) -> syn::Result<TokenStream>{
             },
             FieldRestoreMode::FromSavedData => quote_spanned! {span=>
                 // FIXME: could we not use this path into bioimg_gui?
+                #(#cfg_attrs)*
                 crate::widgets::Restore::restore(&mut self.#ident, saved_data.#ident);
             }
         };
@@ -26,3 +26,15 @@
 //     }
 // }
 // 
+
+pub trait FieldExt {
+    /// The `#[cfg(...)]` attributes of the field, which any code generated for it has to carry as well,
+    /// since the field doesn't exist when they don't hold
+    fn cfg_attrs(&self) -> Vec<&syn::Attribute>;
+}
+
+impl FieldExt for syn::Field {
+    fn cfg_attrs(&self) -> Vec<&syn::Attribute> {
+        self.attrs.iter().filter(|attr| attr.path().is_ident("cfg")).collect()
+    }
+}
@@ -0,0 +1,134 @@
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+
+use crate::syn_extensions::FieldExt;
+
+/// The `value=path::to::ValueType` in `#[value_widget(value=path::to::ValueType)]`
+/// for setting the `Value` associated type in the generated `impl ValueWidget`
+struct ValueTypeConfig{
+    value_type_path: syn::Type,
+}
+
+impl syn::parse::Parse for ValueTypeConfig{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "value" => {
+                let _equals_sign: syn::Token![=] = input.parse()?;
+                Ok(ValueTypeConfig { value_type_path: input.parse()? })
+            },
+            _ => Err(syn::Error::new(
+                ident.span(),
+                format!("Unrecognized ValueWidget config. Expected 'value', found '{ident}'")
+            ))
+        }
+    }
+}
+
+impl ValueTypeConfig {
+    fn try_from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut out = Err(syn::Error::new(
+            Span::call_site(),
+            "No value type configuration. Expected #[value_widget(value=path::to::ValueType)]"
+        ));
+        for attr in attrs{
+            if attr.path().segments.last().unwrap().ident.to_string() != "value_widget" {
+                continue
+            }
+            let syn::Meta::List(meta_list) = &attr.meta else {
+                return Err(syn::Error::new(attr.meta.span(), "Expected key = value"))
+            };
+            out = Ok(meta_list.parse_args::<ValueTypeConfig>()?);
+        }
+        out
+    }
+}
+
+/// Determines which field of the value a widget field is set from when deriving `ValueWidget`
+enum FieldValueSource {
+    /// The field of the value named like the widget field minus its `_widget` suffix, e.g.
+    /// `self.name_widget` is set from `value.name`. Used when no `#[value_widget(...)]`
+    /// attribute is applied to a field.
+    SameName,
+    /// The field of the value with the given name. Activated by annotating a field with
+    /// `#[value_widget(from=field_name)]`
+    Renamed(syn::Ident),
+    /// Leave this field untouched when setting a value, e.g. for caches or UI state.
+    /// Activated by annotating a field with `#[value_widget(skip)]`
+    Skip,
+}
+
+impl syn::parse::Parse for FieldValueSource {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "skip" => Ok(FieldValueSource::Skip),
+            "from" => {
+                let _equals_sign: syn::Token![=] = input.parse()?;
+                Ok(FieldValueSource::Renamed(input.parse()?))
+            },
+            _ => Err(syn::Error::new(ident.span(), "Unexpected config, expected 'skip' or 'from=field_name'"))
+        }
+    }
+}
+
+impl FieldValueSource {
+    fn try_from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut source: Option<FieldValueSource> = None;
+        for attr in attrs {
+            if attr.path().segments.last().unwrap().ident.to_string() != "value_widget" {
+                continue
+            }
+            let syn::Meta::List(meta_list) = &attr.meta else {
+                return Err(syn::Error::new(attr.span(), "Expected value_widget(skip) or value_widget(from=field_name)"))
+            };
+            if source.replace(meta_list.parse_args::<FieldValueSource>()?).is_some(){
+                return Err(syn::Error::new(meta_list.span(), "Setting value source again"))
+            }
+        }
+        Ok(source.unwrap_or(FieldValueSource::SameName))
+    }
+}
+
+pub fn do_derive_value_widget(input: TokenStream) -> syn::Result<TokenStream>{
+    let input = syn::parse::<syn::ItemStruct>(input)?;
+    let struct_name = &input.ident;
+    let ValueTypeConfig { value_type_path } = ValueTypeConfig::try_from_attrs(&input.attrs)?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut set_statements = Vec::<TokenStream2>::new();
+    for field in input.fields.iter(){
+        let Some(ident) = &field.ident else {
+            return Err(syn::Error::new(field.span(), "ValueWidget can only be derived for structs with named fields"))
+        };
+        let value_field = match FieldValueSource::try_from_attrs(&field.attrs)?{
+            FieldValueSource::Skip => continue,
+            FieldValueSource::Renamed(value_field) => value_field,
+            FieldValueSource::SameName => {
+                let name = ident.to_string();
+                let name = name.strip_suffix("_widget").unwrap_or(&name);
+                syn::Ident::new(name, ident.span())
+            },
+        };
+        let span = ident.span();
+        let cfg_attrs = field.cfg_attrs();
+        set_statements.push(quote_spanned! {span=>
+            // FIXME: could we not use this path into bioimg_gui?
+            #(#cfg_attrs)*
+            crate::widgets::ValueWidget::set_value(&mut self.#ident, value.#value_field);
+        });
+    }
+
+    let expanded = quote! {
+        impl #impl_generics crate::widgets::ValueWidget for #struct_name #ty_generics #where_clause {
+            type Value<'v> = #value_type_path;
+            fn set_value<'v>(&mut self, value: Self::Value<'v>){
+                #(#set_statements)*
+            }
+        }
+    };
+
+    Ok(proc_macro::TokenStream::from(expanded))
+}
@@ -0,0 +1,122 @@
+//! A local library of people that are frequently added as authors or maintainers of models,
+//! so that they don't have to be typed in again for every new model.
+
+use std::fmt::Display;
+use std::sync::Mutex;
+
+use bioimg_spec::rdf::author::Author2;
+use bioimg_spec::rdf::Maintainer;
+
+use crate::result::Result;
+
+static ADDRESS_BOOK: Mutex<Option<AddressBook>> = Mutex::new(None);
+
+#[derive(Default, serde::Serialize, serde::Deserialize)]
+pub struct AddressBook{
+    #[serde(default)]
+    pub authors: Vec<Author2>,
+    #[serde(default)]
+    pub maintainers: Vec<Maintainer>,
+}
+
+/// Something that can be stored in the [AddressBook]
+pub trait AddressBookEntry: Clone + Display{
+    fn entries(book: &AddressBook) -> &Vec<Self>;
+    fn entries_mut(book: &mut AddressBook) -> &mut Vec<Self>;
+    /// Whether both entries describe the same person, in which case a new entry replaces the old one
+    fn is_same_person(&self, other: &Self) -> bool;
+}
+
+impl AddressBookEntry for Author2{
+    fn entries(book: &AddressBook) -> &Vec<Self>{
+        &book.authors
+    }
+    fn entries_mut(book: &mut AddressBook) -> &mut Vec<Self>{
+        &mut book.authors
+    }
+    fn is_same_person(&self, other: &Self) -> bool{
+        self.name == other.name
+    }
+}
+
+impl AddressBookEntry for Maintainer{
+    fn entries(book: &AddressBook) -> &Vec<Self>{
+        &book.maintainers
+    }
+    fn entries_mut(book: &mut AddressBook) -> &mut Vec<Self>{
+        &mut book.maintainers
+    }
+    fn is_same_person(&self, other: &Self) -> bool{
+        self.github_user == other.github_user
+    }
+}
+
+impl AddressBook{
+    /// Where the address book is stored, in the platform's usual location for configuration files
+    #[cfg(not(target_arch="wasm32"))]
+    pub fn file_path() -> Option<std::path::PathBuf>{
+        bioimg_runtime::platform_dirs::config_dir().map(|dir| dir.join("address_book.json"))
+    }
+
+    #[cfg(not(target_arch="wasm32"))]
+    fn load() -> Self{
+        let Some(path) = Self::file_path() else {
+            return Self::default()
+        };
+        let Ok(contents) = std::fs::read(&path) else {
+            return Self::default()
+        };
+        match serde_json::from_slice(&contents){
+            Ok(book) => book,
+            Err(err) => {
+                log::warn!("Ignoring malformed address book at {}: {err}", path.to_string_lossy());
+                Self::default()
+            }
+        }
+    }
+
+    /// In the browser there is no file system to persist to, so the address book only lasts for the session
+    #[cfg(target_arch="wasm32")]
+    fn load() -> Self{
+        Self::default()
+    }
+
+    #[cfg(not(target_arch="wasm32"))]
+    fn save(&self) -> Result<()>{
+        let Some(path) = Self::file_path() else {
+            return Err(crate::result::GuiError::new("Could not determine where to save the address book"))
+        };
+        if let Some(parent) = path.parent(){
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+
+    #[cfg(target_arch="wasm32")]
+    fn save(&self) -> Result<()>{
+        Ok(())
+    }
+
+    /// Runs `f` on the address book, loading it from disk the first time it's needed
+    pub fn with<R>(f: impl FnOnce(&AddressBook) -> R) -> R{
+        let mut guard = ADDRESS_BOOK.lock().unwrap();
+        f(guard.get_or_insert_with(Self::load))
+    }
+
+    /// Runs `f` on the address book and persists the changes it made
+    pub fn modify(f: impl FnOnce(&mut AddressBook)) -> Result<()>{
+        let mut guard = ADDRESS_BOOK.lock().unwrap();
+        let book = guard.get_or_insert_with(Self::load);
+        f(book);
+        book.save()
+    }
+
+    pub fn insert<T: AddressBookEntry>(&mut self, entry: T){
+        let entries = T::entries_mut(self);
+        match entries.iter_mut().find(|existing| existing.is_same_person(&entry)){
+            Some(existing) => *existing = entry,
+            None => entries.push(entry),
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use std::sync::Arc;
 use std::thread::JoinHandle;
 
+use bioimg_runtime::model_interface::TileFootprint;
+use bioimg_runtime::npy_array::ArcNpyArray;
+use bioimg_runtime::size_report::format_size;
 use bioimg_runtime::zip_archive_ext::SharedZipArchive;
 use bioimg_spec::rdf::model::model_rdf_0_5::PartialModelRdfV0_5;
 use bioimg_spec::rdf::model::ModelRdfName;
@@ -9,15 +12,19 @@ use indoc::indoc;
 
 use bioimg_runtime as rt;
 use bioimg_runtime::zoo_model::ZooModel;
+use bioimg_runtime::lint::LintSubject;
+use bioimg_runtime::ilastik_compat::check_ilastik_compatibility;
 use bioimg_spec::rdf;
 use bioimg_spec::rdf::ResourceId;
 use bioimg_spec::rdf::bounded_string::BoundedString;
 use bioimg_spec::rdf::non_empty_list::NonEmptyList;
 
-use crate::project_data::{AppState1SavedData};
+use crate::project_data::LatestAppStateSavedData;
 #[cfg(not(target_arch="wasm32"))]
 use crate::project_data::{AppStateSavedData, ProjectLoadError};
 use crate::result::{GuiError, Result, VecResultExt};
+use crate::templates::ModelTemplate;
+use crate::widgets::archive_browser_widget::ArchiveBrowserWidget;
 use crate::widgets::attachments_widget::AttachmentsWidget;
 
 use crate::widgets::code_editor_widget::MarkdwownLang;
@@ -25,21 +32,40 @@ use crate::widgets::collapsible_widget::SummarizableWidget;
 use crate::widgets::icon_widget::IconWidgetValue;
 use crate::widgets::image_widget_2::SpecialImageWidget;
 use crate::widgets::json_editor_widget::JsonObjectEditorWidget;
+use crate::widgets::run_mode_widget::RunModeWidget;
+use crate::widgets::parent_model_widget::ParentModelWidget;
+use crate::widgets::training_data_widget::TrainingDataWidget;
+use crate::widgets::uploader_widget::UploaderWidget;
+use crate::widgets::timestamp_widget::TimestampWidget;
+use crate::widgets::labels::{spec_link, SpecLinkExt};
 use crate::widgets::model_interface_widget::ModelInterfaceWidget;
 use crate::widgets::model_links_widget::ModelLinksWidget;
+use crate::widgets::consumers_widget::{draw_consumers, draw_ilastik_issues, ILASTIK};
 use crate::widgets::notice_widget::{Notification, NotificationsWidget};
+use crate::widgets::original_rdf_widget::OriginalRdfWidget;
+use crate::widgets::export_preview_widget::ExportPreviewWidget;
+use crate::widgets::import_conflict_widget::{self, ImportConflictWidget, ImportDecision};
+use crate::widgets::field_finder_widget::{findable_label, FieldFinderWidget};
+use crate::widgets::rdf_yaml_editor_widget::RdfYamlEditorWidget;
 use crate::widgets::pipeline_widget::PipelineWidget;
 use crate::widgets::search_and_pick_widget::SearchAndPickWidget;
 use crate::widgets::staging_opt::StagingOpt;
 use crate::widgets::staging_string::{InputLines, StagingString};
 use crate::widgets::staging_vec::StagingVec;
-use crate::widgets::util::{widget_vec_from_values, TaskChannel, VecItemRender, VecWidget};
+use crate::widgets::util::{widget_vec_from_values, FrameCache, TaskChannel, VecItemRender, VecWidget};
 use crate::widgets::version_widget::VersionWidget;
 use crate::widgets::weights_widget::WeightsWidget;
+use crate::widgets::dtype_support::DtypeSupport;
+use crate::widgets::wizard_widget::{draw_wizard_footer, draw_wizard_header, WizardNavigation, WizardStep};
+use crate::widgets::inout_tensor_widget::{InputTensorWidget, OutputTensorWidget};
 #[cfg(not(target_arch="wasm32"))]
-use crate::widgets::zoo_widget::{upload_model, ZooLoginWidget};
+use crate::widgets::example_gallery_widget::ExampleGalleryWidget;
+use crate::widgets::expansion_state::ExpansionState;
+#[cfg(not(target_arch="wasm32"))]
+use crate::widgets::zoo_widget::{upload_model, ModelIdCheckWidget, ZooLoginWidget};
 use crate::widgets::ValueWidget;
 use crate::widgets::Restore;
+use crate::widgets::completeness_widget::{draw_section_badge, Completeness, CompletenessCheck};
 use crate::widgets::{
     author_widget::AuthorWidget, cite_widget::CiteEntryWidget, code_editor_widget::CodeEditorWidget,
     icon_widget::IconWidget, maintainer_widget::MaintainerWidget, url_widget::StagingUrl,
@@ -47,18 +73,36 @@ use crate::widgets::{
 };
 
 pub struct AppStateFromPartial{
-    state: AppState1SavedData,
+    state: LatestAppStateSavedData,
     warnings: String,
 }
 
+/// A model that was loaded to be imported into the form
+enum IncomingModel{
+    Full(Box<rt::zoo_model::ZooModel>, SharedZipArchive),
+    Recovered(AppStateFromPartial, SharedZipArchive),
+}
+
 #[must_use]
 pub enum TaskResult{
     Notification(Result<String, String>),
-    ModelImport(Box<rt::zoo_model::ZooModel>),
-    PartialModelLoad(AppStateFromPartial),
+    ModelImport(Box<rt::zoo_model::ZooModel>, SharedZipArchive),
+    PartialModelLoad(AppStateFromPartial, SharedZipArchive),
+    /// Weights taken from another model, to replace the ones of the draft
+    WeightsImport{weights: Box<rt::ModelWeights>, source_name: String, tensor_ids: Vec<String>},
+    /// ONNX weights derived from the TorchScript weights of the draft
+    OnnxConversion(Box<rt::model_weights::OnnxWeights>),
+    /// Timing of a successful bioimageio.core test run of the draft, to be offered for recording in its config
+    #[cfg(not(target_arch="wasm32"))]
+    TestRunRecorded(Box<rt::TestRunRecord>),
 }
 
 impl TaskResult{
+    /// Keeps only the weights of `zoo_model`, plus what's needed to check that they fit the draft
+    fn weights_import(zoo_model: ZooModel, source_name: String) -> Self{
+        let tensor_ids = zoo_model.interface.tensor_ids().into_iter().map(|id| id.to_string()).collect();
+        Self::WeightsImport{weights: Box::new(zoo_model.weights), source_name, tensor_ids}
+    }
     pub fn ok_message(msg: impl Into<String>) -> Self{
         Self::Notification(Ok(msg.into()))
     }
@@ -67,6 +111,43 @@ impl TaskResult{
     }
 }
 
+/// The state of the "YAML mode", where the whole model is edited as rdf.yaml text instead of via the form.
+///
+/// The yaml text is regenerated from the form when entering this mode, and applied back to the form
+/// when leaving it, so that users can switch between both views freely
+#[derive(Default)]
+struct YamlModeState{
+    is_active: bool,
+    editor: RdfYamlEditorWidget,
+    /// The archive that file references in the yaml text point into
+    archive: Option<SharedZipArchive>,
+    /// Whether the yaml text was generated from the form without packing it, so that its file paths
+    /// only exist once the form is packed. See [AppState1::yaml_mode_archive]
+    paths_from_form: bool,
+    /// The yaml text as it was when it was last generated from or applied to the form
+    synced_raw: Option<String>,
+    #[cfg(not(target_arch="wasm32"))]
+    generation_task: Option<JoinHandle<Result<String>>>,
+}
+
+impl YamlModeState{
+    fn has_unsynced_edits(&self) -> bool{
+        match &self.synced_raw{
+            Some(synced_raw) => *synced_raw != self.editor.raw,
+            None => !self.editor.raw.trim().is_empty(),
+        }
+    }
+
+    /// Shows `raw` as the synced yaml text. Its file references point into `archive`, or, if that is
+    /// `None`, into the packed form
+    fn set_generated(&mut self, raw: String, archive: Option<SharedZipArchive>){
+        self.editor.set_raw(raw.clone());
+        self.synced_raw = Some(raw);
+        self.paths_from_form = archive.is_none();
+        self.archive = archive;
+    }
+}
+
 #[derive(Default, Copy, Clone)]
 enum ExitingStatus{
     #[default]
@@ -75,8 +156,98 @@ enum ExitingStatus{
     Exiting,
 }
 
+/// The file the draft was last saved to or loaded from, and whether it was edited since then,
+/// so that unsaved changes can be shown in the window title and checked for before quitting
+#[cfg(not(target_arch="wasm32"))]
+#[derive(Default)]
+struct DraftStatus{
+    path: Option<std::path::PathBuf>,
+    /// Bumped whenever something could have changed the form, so that telling whether it changed doesn't
+    /// require dumping it (and its test tensors)
+    generation: u64,
+    /// [Self::generation] when the draft was last saved or loaded. A new, untouched draft has nothing to save
+    saved_generation: u64,
+    /// The title last sent to the window, so that it is only sent again when it changes
+    window_title: String,
+}
+
+#[cfg(not(target_arch="wasm32"))]
+impl DraftStatus{
+    fn mark_edited(&mut self){
+        self.generation += 1;
+    }
+
+    /// Notes whether this frame's input could have edited the form
+    fn track_edits(&mut self, ctx: &egui::Context){
+        if has_possible_edits(ctx){
+            self.mark_edited();
+        }
+    }
+
+    fn mark_saved(&mut self, path: &std::path::Path){
+        self.path = Some(path.to_owned());
+        self.saved_generation = self.generation;
+    }
+
+    /// Whether the form could have changed since the draft was last saved or loaded
+    fn has_unsaved_changes(&self) -> bool{
+        self.generation != self.saved_generation
+    }
+
+    fn title(&self, has_unsaved_changes: bool) -> String{
+        let draft_name = match &self.path{
+            Some(path) => path.file_name().unwrap_or(path.as_os_str()).to_string_lossy().into_owned(),
+            None => "Untitled draft".to_owned(),
+        };
+        let dirty_marker = if has_unsaved_changes { "*" } else { "" };
+        format!("{draft_name}{dirty_marker} — bioimage.io model builder")
+    }
+
+    /// A file dialog for saving the draft, starting out at the file it was last saved to
+    fn save_dialog(&self) -> rfd::FileDialog{
+        let dialog = rfd::FileDialog::new().add_filter("bioimage model builder", &["bmb"]);
+        let Some(path) = &self.path else {
+            return dialog.set_file_name("MyDraft.bmb")
+        };
+        let dialog = match path.parent(){
+            Some(dir) if !dir.as_os_str().is_empty() => dialog.set_directory(dir),
+            _ => dialog,
+        };
+        match path.file_name(){
+            Some(file_name) => dialog.set_file_name(file_name.to_string_lossy()),
+            None => dialog.set_file_name("MyDraft.bmb"),
+        }
+    }
+}
+
+/// Whether this frame's input could have edited the form. Pointer movement alone can't
+fn has_possible_edits(ctx: &egui::Context) -> bool{
+    let is_edit = |event: &egui::Event| !matches!(
+        event,
+        egui::Event::PointerMoved(_) | egui::Event::MouseMoved(_) | egui::Event::PointerGone | egui::Event::WindowFocused(_)
+    );
+    ctx.input(|input| input.events.iter().any(is_edit))
+}
+
+/// The outcome of validating a wizard step. Validating the export step builds the whole model, so it is only
+/// repeated when the step changes, after possible edits, or every [Self::MAX_AGE_SECS] to pick up files that
+/// finished loading in the background
+struct WizardValidation{
+    step: WizardStep,
+    validated_at: f64,
+    result: Result<()>,
+}
+
+impl WizardValidation{
+    const MAX_AGE_SECS: f64 = 1.0;
+
+    fn is_current(&self, ctx: &egui::Context, step: WizardStep) -> bool{
+        self.step == step && !has_possible_edits(ctx) && ctx.input(|i| i.time) - self.validated_at < Self::MAX_AGE_SECS
+    }
+}
+
 #[derive(Restore)]
-#[restore(saved_data=crate::project_data::AppState1SavedData)]
+#[restore(saved_data=crate::project_data::LatestAppStateSavedData)]
 pub struct AppState1 {
     pub staging_name: StagingString<ModelRdfName>,
     pub staging_description: StagingString<BoundedString<0, 1024>>,
@@ -93,9 +264,14 @@ pub struct AppState1 {
     pub staging_tags: StagingVec<StagingString<rdf::Tag>>,
     pub staging_version: StagingOpt<VersionWidget, false>,
     pub staging_version_comment: StagingOpt<StagingString<BoundedString<0, 512>>, false>,
+    pub uploader_widget: StagingOpt<UploaderWidget, false>,
 
     pub staging_documentation: CodeEditorWidget<MarkdwownLang>,
     pub staging_license: SearchAndPickWidget<rdf::LicenseId>,
+    pub run_mode_widget: StagingOpt<RunModeWidget, false>,
+    pub parent_widget: StagingOpt<ParentModelWidget>,
+    pub training_data_widget: StagingOpt<TrainingDataWidget>,
+    pub timestamp_widget: TimestampWidget,
     //badges
     pub model_interface_widget: ModelInterfaceWidget,
     ////
@@ -111,15 +287,55 @@ pub struct AppState1 {
     #[cfg(not(target_arch="wasm32"))]
     #[restore(default)]
     pub zoo_login_widget: ZooLoginWidget,
+    #[cfg(not(target_arch="wasm32"))]
+    #[restore(default)]
+    pub model_id_check_widget: ModelIdCheckWidget,
+    #[cfg(not(target_arch="wasm32"))]
+    #[restore(default)]
+    pub example_gallery_widget: ExampleGalleryWidget,
+    #[cfg(not(target_arch="wasm32"))]
+    #[restore(default)]
+    pub remote_test_url_widget: StagingUrl,
+    /// Timing of the last successful local test run, until the user records or dismisses it
+    #[cfg(not(target_arch="wasm32"))]
+    #[restore(default)]
+    pending_test_record: Option<rt::TestRunRecord>,
     #[restore(default)]
     pub zoo_model_creation_task: Option<JoinHandle<Result<ZooNickname>>>,
 
+    pub expansion_state: ExpansionState,
+
     #[restore(default)]
     pub notifications_widget: NotificationsWidget,
     #[restore(default)]
     pub notifications_channel: TaskChannel<TaskResult>,
     #[restore(default)]
     exiting_status: ExitingStatus,
+    #[cfg(not(target_arch="wasm32"))]
+    #[restore(default)]
+    draft_status: DraftStatus,
+    /// An import that is waiting for the user to decide whether to save the draft first
+    #[cfg(not(target_arch="wasm32"))]
+    #[restore(default)]
+    import_awaiting_save: Option<IncomingModel>,
+    #[restore(default)]
+    wizard_step: Option<WizardStep>,
+    #[restore(default)]
+    wizard_validation: Option<WizardValidation>,
+    #[restore(default)]
+    size_report: Option<rt::SizeReport>,
+    #[restore(default)]
+    pub archive_browser_widget: ArchiveBrowserWidget,
+    #[restore(default)]
+    pub original_rdf_widget: OriginalRdfWidget,
+    #[restore(default)]
+    export_preview_widget: ExportPreviewWidget,
+    #[restore(default)]
+    yaml_mode: YamlModeState,
+    #[restore(default)]
+    pub field_finder_widget: FieldFinderWidget,
+    #[restore(default)]
+    import_conflict_widget: ImportConflictWidget,
 }
 
 impl ValueWidget for AppState1{
@@ -170,8 +386,13 @@ impl ValueWidget for AppState1{
             .collect();
         self.staging_tags.set_value(zoo_model.tags);
         self.staging_version.set_value(zoo_model.version);
+        self.uploader_widget.set_value(zoo_model.uploader);
         self.staging_documentation.set_value(&zoo_model.documentation);
         self.staging_license.set_value(zoo_model.license);
+        self.run_mode_widget.set_value(zoo_model.run_mode);
+        self.parent_widget.set_value(zoo_model.parent);
+        self.training_data_widget.set_value(zoo_model.training_data);
+        self.timestamp_widget.set_value(zoo_model.timestamp);
 
         self.model_interface_widget.set_value(zoo_model.interface);
 
@@ -183,7 +404,7 @@ impl Default for AppState1 {
     fn default() -> Self {
         Self {
             staging_name: StagingString::new(InputLines::SingleLine),
-            staging_description: StagingString::new(InputLines::Multiline),
+            staging_description: StagingString::new(InputLines::Prose),
             cover_images: Vec::default(),
             model_id_widget: Default::default(),
             staging_authors: Default::default(),
@@ -197,118 +418,190 @@ impl Default for AppState1 {
             staging_tags: StagingVec::default(),
             staging_version: Default::default(),
             staging_version_comment: Default::default(),
+            uploader_widget: Default::default(),
             staging_documentation: Default::default(),
-            staging_license: SearchAndPickWidget::from_enum(Default::default()),
+            staging_license: SearchAndPickWidget::licenses(Default::default()),
+            run_mode_widget: Default::default(),
+            parent_widget: Default::default(),
+            training_data_widget: Default::default(),
+            timestamp_widget: Default::default(),
 
             model_interface_widget: Default::default(),
 
             weights_widget: Default::default(),
+            expansion_state: Default::default(),
             notifications_widget: NotificationsWidget::new(),
             notifications_channel: Default::default(),
             #[cfg(not(target_arch="wasm32"))]
             zoo_login_widget: Default::default(),
+            #[cfg(not(target_arch="wasm32"))]
+            model_id_check_widget: Default::default(),
+            #[cfg(not(target_arch="wasm32"))]
+            example_gallery_widget: Default::default(),
+            #[cfg(not(target_arch="wasm32"))]
+            remote_test_url_widget: Default::default(),
+            #[cfg(not(target_arch="wasm32"))]
+            pending_test_record: None,
             zoo_model_creation_task: Default::default(),
             pipeline_widget: Default::default(),
 
             exiting_status: Default::default(),
+            #[cfg(not(target_arch="wasm32"))]
+            draft_status: Default::default(),
+            #[cfg(not(target_arch="wasm32"))]
+            import_awaiting_save: None,
+            wizard_step: None,
+            wizard_validation: None,
+            size_report: None,
+            archive_browser_widget: Default::default(),
+            original_rdf_widget: Default::default(),
+            export_preview_widget: Default::default(),
+            yaml_mode: Default::default(),
+            field_finder_widget: Default::default(),
+            import_conflict_widget: Default::default(),
         }
     }
 }
 
+/// Records the errors of the fields it checks, so that all of them can be reported at once
+#[derive(Default)]
+struct ErrorCollector(Vec<GuiError>);
+
+impl ErrorCollector{
+    fn check<T>(&mut self, result: Result<T>) -> Option<T>{
+        result.map_err(|err| self.0.push(err)).ok()
+    }
+
+    /// Checks every item of `results`, instead of stopping at the first error
+    fn check_all<T>(&mut self, results: impl Iterator<Item=Result<T>>) -> Option<Vec<T>>{
+        let checked: Vec<Option<T>> = results.map(|result| self.check(result)).collect();
+        checked.into_iter().collect()
+    }
+}
+
+/// The metadata fields of a [ZooModel], i.e. everything but its inputs, outputs and weights
+struct ModelMetadata{
+    name: ModelRdfName,
+    description: rdf::ResourceTextDescription,
+    covers: Vec<rt::CoverImage>,
+    id: Option<ResourceId>,
+    authors: NonEmptyList<rdf::Author2>,
+    attachments: Vec<rt::Attachment>,
+    cite: NonEmptyList<rdf::CiteEntry2>,
+    config: serde_json::Map<String, serde_json::Value>,
+    git_repo: Option<rdf::HttpUrl>,
+    icon: Option<rt::Icon>,
+    links: Vec<String>,
+    maintainers: Vec<rdf::Maintainer>,
+    tags: Vec<rdf::Tag>,
+    version: Option<rdf::Version>,
+    version_comment: Option<BoundedString<0, 512>>,
+    uploader: Option<rdf::Uploader>,
+    run_mode: Option<rdf::model::RunMode>,
+    parent: Option<rdf::model::LinkedModel>,
+    training_data: Option<rdf::model::dataset_descr::DatasetDescrEnum>,
+    documentation: String,
+    license: rdf::LicenseId,
+    timestamp: iso8601_timestamp::Timestamp,
+}
+
 impl AppState1{
-    pub fn create_model(&self) -> Result<ZooModel>{
-        let name = self.staging_name.state()
+    /// Parses the metadata fields of the form, i.e. everything but the inputs, outputs and weights.
+    /// Fails with the errors of all invalid fields, so that they can be shown at once
+    fn parse_metadata(&self) -> std::result::Result<ModelMetadata, Vec<GuiError>>{
+        let mut errors = ErrorCollector::default();
+        let name = errors.check(self.staging_name.state()
             .cloned()
-            .map_err(|e| GuiError::new_with_rect("Check resoure name for errors", e.failed_widget_rect))?;
-        let description = self.staging_description.state()
+            .map_err(|e| GuiError::new_with_rect("Check resource name for errors", e.failed_widget_rect)));
+        let description = errors.check(self.staging_description.state()
             .cloned()
-            .map_err(|e| GuiError::new_with_rect("Check resource text description for errors", e.failed_widget_rect))?;
-        let covers: Vec<_> = self.cover_images.iter()
-            .map(|cover_img_widget|{
-                cover_img_widget.state()
-                    .map(|val| val.clone())
-                    .map_err(|e| GuiError::new_with_rect("Check cover images for errors", e.failed_widget_rect))
-            })
-            .collect::<Result<Vec<_>, _>>()?;
-        let model_id = self.model_id_widget.state().transpose()
-            .map_err(|e| GuiError::new_with_rect("Check model id for errors", e.failed_widget_rect))?
-            .cloned();
-        let authors = NonEmptyList::try_from(
-                self.staging_authors.iter()
-                    .enumerate()
-                    .map(|(idx, widget)| {
-                        widget.state().map_err(|err| {
-                            GuiError::new_with_rect(format!("Check author #{} for errors", idx + 1), err.failed_widget_rect)
-                        })
-                    })
-                    .collect::<Result<Vec<_>>>()?
-            )
-            .map_err(|_| GuiError::new("Empty authors"))?;
-        let attachments = self.attachments_widget.iter()
-            .enumerate()
-            .map(|(idx, widget)| {
-                widget.state().map_err(|_| GuiError::new(format!("Check attachment #{} for errors", idx + 1)))
+            .map_err(|e| GuiError::new_with_rect("Check resource text description for errors", e.failed_widget_rect)));
+        let covers = errors.check_all(self.cover_images.iter().map(|cover_img_widget|{
+            cover_img_widget.state()
+                .map(|val| val.clone())
+                .map_err(|e| GuiError::new_with_rect("Check cover images for errors", e.failed_widget_rect))
+        }));
+        let id = errors.check(self.model_id_widget.state().transpose()
+            .map_err(|e| GuiError::new_with_rect("Check model id for errors", e.failed_widget_rect))
+            .map(|id| id.cloned()));
+        let authors = errors.check_all(self.staging_authors.iter().enumerate().map(|(idx, widget)| {
+            widget.state().map_err(|err| {
+                GuiError::new_with_rect(format!("Check author #{} for errors", idx + 1), err.failed_widget_rect)
             })
-            .collect::<Result<Vec<_>>>()?;
-            // .collect_result()
-            // .map_err(|e| GuiError::new_with_rect("Check model attachments for errors", e.failed_widget_rect))?;
-        let cite = self.staging_citations.iter().enumerate()
-            .map(|(idx, widget)| {
-                widget.state().map_err(|_| GuiError::new(format!("Check citation #{} for errors", idx + 1)))
-            })
-            .collect::<Result<Vec<_>>>()?;
-        let non_empty_cites = NonEmptyList::try_from(cite)
-            .map_err(|_| GuiError::new("Cites are empty"))?;
-        let config = self.custom_config_widget.state().cloned()
+        }));
+        let authors = authors.and_then(|authors| errors.check(
+            NonEmptyList::try_from(authors).map_err(|_| GuiError::new("Empty authors"))
+        ));
+        let attachments = errors.check_all(self.attachments_widget.iter().enumerate().map(|(idx, widget)| {
+            widget.state().map_err(|_| GuiError::new(format!("Check attachment #{} for errors", idx + 1)))
+        }));
+        let cite = errors.check_all(self.staging_citations.iter().enumerate().map(|(idx, widget)| {
+            widget.state().map_err(|_| GuiError::new(format!("Check citation #{} for errors", idx + 1)))
+        }));
+        let cite = cite.and_then(|cite| errors.check(
+            NonEmptyList::try_from(cite).map_err(|_| GuiError::new("Cites are empty"))
+        ));
+        let config = errors.check(self.custom_config_widget.state().cloned()
             .transpose()
-            .map_err(|e| GuiError::new_with_rect("Check custom configs for errors", e.failed_widget_rect))?
-            .unwrap_or(serde_json::Map::default());
-        let git_repo = self.staging_git_repo.state()
+            .map_err(|e| GuiError::new_with_rect("Check custom configs for errors", e.failed_widget_rect))
+            .map(|config| config.unwrap_or_default()));
+        let git_repo = errors.check(self.staging_git_repo.state()
             .transpose()
-            .map_err(|e| GuiError::new_with_rect("Check git repo field for errors", e.failed_widget_rect))?
-            .map(|val| val.as_ref().clone());
-        let icon = self.icon_widget.state().transpose().map_err(|_| GuiError::new("Check icons field for errors"))?;
-        let links = self.links_widget.state()
+            .map_err(|e| GuiError::new_with_rect("Check git repo field for errors", e.failed_widget_rect))
+            .map(|val| val.map(|val| val.as_ref().clone())));
+        let icon = errors.check(self.icon_widget.state().transpose().map_err(|_| GuiError::new("Check icons field for errors")));
+        let links = errors.check(self.links_widget.state()
             .collect_result()
-            .map_err(|e| GuiError::new_with_rect("Check links for errors", e.failed_widget_rect))?
-            .into_iter()
-            .map(|s| s.clone())
-            .collect();
-        let maintainers = self.staging_maintainers.iter()
-            .enumerate()
-            .map(|(idx, w)| {
-                w.state().map_err(|_| GuiError::new(format!("Check maintainer #{} for errors", idx + 1)))
-            })
-            .collect::<Result<Vec<_>>>()?;
-        let tags: Vec<rdf::Tag> = self.staging_tags.state()
+            .map_err(|e| GuiError::new_with_rect("Check links for errors", e.failed_widget_rect))
+            .map(|links| links.into_iter().map(|s| s.clone()).collect()));
+        let maintainers = errors.check_all(self.staging_maintainers.iter().enumerate().map(|(idx, w)| {
+            w.state().map_err(|_| GuiError::new(format!("Check maintainer #{} for errors", idx + 1)))
+        }));
+        let tags = errors.check(self.staging_tags.state()
             .into_iter()
             .map(|res_ref| res_ref.cloned())
             .collect::<Result<Vec<_>>>()
-            .map_err(|e| GuiError::new_with_rect("Check tags for errors", e.failed_widget_rect))?;
-        let version = self.staging_version.state()
+            .map_err(|e| GuiError::new_with_rect("Check tags for errors", e.failed_widget_rect)));
+        let version = errors.check(self.staging_version.state()
             .transpose()
-            .map_err(|e| GuiError::new_with_rect("Review resource version field", e.failed_widget_rect))?
-            .cloned();
-        let version_comment = self
-            .staging_version_comment
-            .state()
+            .map_err(|e| GuiError::new_with_rect("Review resource version field", e.failed_widget_rect))
+            .map(|version| version.cloned()));
+        let version_comment = errors.check(self.staging_version_comment.state()
             .transpose()
-            .map_err(|e| GuiError::new_with_rect("Review resource version comment field", e.failed_widget_rect))?
-            .cloned();
-        let documentation = self.staging_documentation.state().to_owned();
-        let license = self.staging_license.state();
-        let model_interface = self.model_interface_widget.get_value()
-            .map_err(|_| GuiError::new("Check model interface for errors"))?;
-        let weights = self.weights_widget.get_value()
-            .map_err(|e| GuiError::new_with_rect("Check model weights for errors", e.failed_widget_rect))?
-            .as_ref().clone();
-
-        Ok(ZooModel {
+            .map_err(|e| GuiError::new_with_rect("Review resource version comment field", e.failed_widget_rect))
+            .map(|comment| comment.cloned()));
+        let uploader = errors.check(self.uploader_widget.state()
+            .transpose()
+            .map_err(|e| GuiError::new_with_rect("Check uploader for errors", e.failed_widget_rect)));
+        let run_mode = errors.check(self.run_mode_widget.state()
+            .transpose()
+            .map_err(|e| GuiError::new_with_rect("Check run mode for errors", e.failed_widget_rect)));
+        let parent = errors.check(self.parent_widget.state()
+            .transpose()
+            .map_err(|e| GuiError::new_with_rect("Check parent model for errors", e.failed_widget_rect)));
+        let training_data = errors.check(self.training_data_widget.state()
+            .transpose()
+            .map_err(|e| GuiError::new_with_rect("Check training data for errors", e.failed_widget_rect)));
+
+        let (
+            Some(name), Some(description), Some(covers), Some(id), Some(authors), Some(attachments), Some(cite),
+            Some(config), Some(git_repo), Some(icon), Some(links), Some(maintainers), Some(tags), Some(version),
+            Some(version_comment), Some(uploader), Some(run_mode), Some(parent), Some(training_data),
+        ) = (
+            name, description, covers, id, authors, attachments, cite,
+            config, git_repo, icon, links, maintainers, tags, version,
+            version_comment, uploader, run_mode, parent, training_data,
+        ) else {
+            return Err(errors.0)
+        };
+        Ok(ModelMetadata{
             name,
             description,
             covers,
+            id,
+            authors,
             attachments,
-            cite: non_empty_cites,
+            cite,
             config,
             git_repo,
             icon,
@@ -317,15 +610,124 @@ impl AppState1{
             tags,
             version,
             version_comment,
-            authors,
-            documentation,
-            license,
-            id: model_id,
+            uploader,
+            run_mode,
+            parent,
+            training_data,
+            documentation: self.staging_documentation.state().to_owned(),
+            license: self.staging_license.state(),
+            timestamp: self.timestamp_widget.state(),
+        })
+    }
+
+    pub fn create_model(&self) -> Result<ZooModel>{
+        let metadata = self.parse_metadata()
+            .map_err(|errors| errors.into_iter().next().expect("parsing only fails with errors"))?;
+        let model_interface = self.model_interface_widget.get_value()
+            .map_err(|_| GuiError::new("Check model interface for errors"))?;
+        let weights = self.weights_widget.get_value()
+            .map_err(|e| GuiError::new_with_rect("Check model weights for errors", e.failed_widget_rect))?
+            .as_ref().clone();
+
+        Ok(ZooModel {
+            name: metadata.name,
+            description: metadata.description,
+            covers: metadata.covers,
+            attachments: metadata.attachments,
+            cite: metadata.cite,
+            config: metadata.config,
+            git_repo: metadata.git_repo,
+            icon: metadata.icon,
+            links: metadata.links,
+            maintainers: metadata.maintainers,
+            tags: metadata.tags,
+            version: metadata.version,
+            version_comment: metadata.version_comment,
+            uploader: metadata.uploader,
+            authors: metadata.authors,
+            documentation: metadata.documentation,
+            license: metadata.license,
+            id: metadata.id,
+            parent: metadata.parent,
+            run_mode: metadata.run_mode,
+            timestamp: metadata.timestamp,
+            training_data: metadata.training_data,
             weights,
             interface: model_interface,
         })
     }
 
+    /// Errors of all metadata fields, going through the same checks as [Self::create_model]
+    fn metadata_errors(&self) -> Vec<GuiError>{
+        self.parse_metadata().err().unwrap_or_default()
+    }
+
+    /// Errors of each input and output, or of how they relate to each other once they are all valid
+    fn interface_errors(&self) -> Vec<GuiError>{
+        let input_errors = self.model_interface_widget.input_widgets.iter().enumerate()
+            .filter_map(|(idx, widget)| widget.parse().err().map(|err| (format!("input #{}", idx + 1), err)));
+        let output_errors = self.model_interface_widget.output_widgets.iter().enumerate()
+            .filter_map(|(idx, widget)| widget.parse().err().map(|err| (format!("output #{}", idx + 1), err)));
+        let errors: Vec<GuiError> = input_errors.chain(output_errors)
+            .map(|(slot, err)| GuiError::new_with_rect(format!("Check {slot} for errors: {err}"), err.failed_widget_rect))
+            .collect();
+        if !errors.is_empty(){
+            return errors
+        }
+        match self.model_interface_widget.get_value(){
+            Ok(_) => vec![],
+            Err(err) => vec![err],
+        }
+    }
+
+    fn weights_errors(&self) -> Vec<GuiError>{
+        match self.weights_widget.get_value(){
+            Ok(_) => vec![],
+            Err(e) => vec![GuiError::new_with_rect(format!("Check model weights for errors: {e}"), e.failed_widget_rect)],
+        }
+    }
+
+    /// Which required fields are valid and which best practices are followed, even while the
+    /// draft can't be turned into a model yet
+    fn completeness(&self) -> Completeness{
+        let check = |name: &'static str, result: Result<()>| CompletenessCheck{
+            name, problem: result.err().map(|err| err.to_string())
+        };
+        let required = vec![
+            check("Name", self.staging_name.state().map(|_| ())),
+            check("Description", self.staging_description.state().map(|_| ())),
+            check("Authors", if self.staging_authors.is_empty(){
+                Err(GuiError::new("Add at least one author"))
+            } else {
+                self.staging_authors.iter().try_for_each(|widget| widget.state().map(|_| ()))
+            }),
+            check("Citations", if self.staging_citations.is_empty(){
+                Err(GuiError::new("Add at least one citation"))
+            } else {
+                self.staging_citations.iter().try_for_each(|widget| widget.state().map(|_| ()))
+            }),
+            check("Documentation", if self.staging_documentation.state().trim().is_empty(){
+                Err(GuiError::new("Documentation is empty"))
+            } else {
+                Ok(())
+            }),
+            check("Inputs and outputs", self.model_interface_widget.get_value().map(|_| ())),
+            check("Weights", self.weights_widget.get_value().map(|_| ())),
+        ];
+
+        let maintainers: Vec<rdf::Maintainer> = self.staging_maintainers.iter()
+            .filter_map(|widget| widget.state().ok())
+            .collect();
+        let subject = LintSubject{
+            documentation: self.staging_documentation.state(),
+            num_tags: self.staging_tags.state().into_iter().filter(|tag| tag.is_ok()).count(),
+            num_covers: self.cover_images.iter().filter(|widget| widget.state().is_ok()).count(),
+            has_version: matches!(self.staging_version.state(), Some(Ok(_))),
+            maintainer_github_users: maintainers.iter().map(|maintainer| maintainer.github_user.as_str()).collect(),
+        };
+        Completeness::new(required, &subject)
+    }
+
     #[cfg(not(target_arch="wasm32"))]
     fn save_project(&self, project_file: &std::path::Path) -> Result<String, String>{
         let writer = std::fs::File::options()
@@ -333,11 +735,42 @@ impl AppState1{
             .create(true)
             .truncate(true)
             .open(project_file).map_err(|err| format!("Could not open project file for writing: {err}"))?;
-        AppStateSavedData::Version1(self.dump()).save(writer)
+        AppStateSavedData::from(self.dump()).save(writer)
             .map_err(|err| format!("Could not serialize project to bytes: {err}"))
             .map(|_| format!("Saved project to {}", project_file.to_string_lossy()))
     }
 
+    #[cfg(not(target_arch="wasm32"))]
+    fn save_draft_to(&mut self, project_file: &std::path::Path) -> Result<String, String>{
+        let message = self.save_project(project_file)?;
+        self.draft_status.mark_saved(project_file);
+        Ok(message)
+    }
+
+    #[cfg(not(target_arch="wasm32"))]
+    fn update_window_title(&mut self, ctx: &egui::Context){
+        self.draft_status.track_edits(ctx);
+        let has_unsaved_changes = self.draft_status.has_unsaved_changes();
+        let title = self.draft_status.title(has_unsaved_changes);
+        if title != self.draft_status.window_title{
+            ctx.send_viewport_cmd(egui::ViewportCommand::Title(title.clone()));
+            self.draft_status.window_title = title;
+        }
+    }
+
+    /// Restores `state` into the form, which still represents the same draft file afterwards (e.g. after
+    /// merging an import into it), as opposed to [Restore::restore], which starts a new, unsaved one
+    fn restore_into_draft(&mut self, state: LatestAppStateSavedData){
+        #[cfg(not(target_arch="wasm32"))]
+        let draft_status = std::mem::take(&mut self.draft_status);
+        self.restore(state);
+        #[cfg(not(target_arch="wasm32"))]
+        {
+            self.draft_status = draft_status;
+            self.draft_status.mark_edited();
+        }
+    }
+
     #[cfg(not(target_arch="wasm32"))]
     fn load_project(&mut self, project_file: &std::path::Path) -> Result<(), String>{
         let reader = std::fs::File::open(&project_file).map_err(|err| format!("Could not open project file: {err}"))?;
@@ -350,9 +783,8 @@ impl AppState1{
             Err(err) => return Err(format!("Could not load project file at {}: {err}", project_file.to_string_lossy())),
             Ok(proj_data) => proj_data,
         };
-        match proj_data{
-            AppStateSavedData::Version1(ver1) => self.restore(ver1),
-        }
+        self.restore(proj_data.into_latest());
+        self.draft_status.mark_saved(project_file);
         Ok(())
     }
     fn launch_model_saving(&mut self, zoo_model: ZooModel) {
@@ -427,117 +859,1346 @@ impl AppState1{
         std::thread::spawn(move || smol::block_on(fut));
     }
 
-    fn load_partial_model(archive: &SharedZipArchive) -> Result<AppStateFromPartial>{
-        let model_rdf_bytes: Vec<u8> = 'model_rdf: {
-            for file_name in ["rdf.yaml", "bioimageio.yaml"]{
-                match archive.read_full_entry(file_name) {
-                    Ok(bytes) => break 'model_rdf bytes,
-                    Err(zip_err) => match zip_err{
-                        zip::result::ZipError::FileNotFound => continue,
-                        err => return Err(GuiError::new(format!("Could not read rdf file: {err}")))
-                    }
-                };
-            }
-            return Err(GuiError::new("Could not find rdf file inside archive"))
+    fn launch_entry_extraction(&mut self, entry_path: String) {
+        let Some(archive) = self.archive_browser_widget.archive().cloned() else {
+            return
+        };
+        let sender = self.notifications_channel.sender().clone();
+        let fut = async move {
+            let file_name = entry_path.rsplit('/').next().unwrap_or(&entry_path).to_owned();
+            let Some(file_handle) = rfd::AsyncFileDialog::new().set_file_name(&file_name).save_file().await else {
+                return;
+            };
+            let contents = match archive.read_full_entry_async(&entry_path).await{
+                Ok(contents) => contents,
+                Err(err) => {
+                    sender.send(TaskResult::err_message(format!("Could not read {entry_path}: {err}"))).unwrap();
+                    return
+                }
+            };
+            let message = match file_handle.write(&contents).await {
+                Ok(()) => TaskResult::ok_message(format!("Extracted {entry_path}")),
+                Err(err) => TaskResult::err_message(format!("Could not save {entry_path}: {err}")),
+            };
+            sender.send(message).unwrap();
         };
-        let yaml_deserializer = serde_yaml::Deserializer::from_slice(&model_rdf_bytes);
-        let partial: PartialModelRdfV0_5 = ::serde_path_to_error::deserialize(yaml_deserializer)?;
-        let mut warnings = String::with_capacity(16 * 1024);
-        let state = AppState1SavedData::from_partial(&archive, partial, &mut warnings); //FIXME: retrieve errors and notify
-        warnings += indoc!("
-            PLEASE BE AWARE THAT RECOVERING AND THEN RE-EXPORTING A MODEL MIGHT PRODUCE A NEW, VALID MODEL THAT DOES NOT
-            BEHAVE LIKE THE ORIGINAL\n"
-        );
-        Ok(AppStateFromPartial { state, warnings})
-    }
-}
-
 
-impl eframe::App for AppState1 {
-    fn save(&mut self, _storage: &mut dyn eframe::Storage) {
-        // eframe::set_value(storage, eframe::APP_KEY, self);
+        #[cfg(target_arch="wasm32")]
+        wasm_bindgen_futures::spawn_local(fut);
+        #[cfg(not(target_arch="wasm32"))]
+        std::thread::spawn(move || smol::block_on(fut));
     }
 
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
-            egui::menu::bar(ui, |ui| {
-                #[cfg(not(target_arch="wasm32"))]
-                ui.menu_button("Zoo", |ui|{ ui.add_enabled_ui(false, |ui|{
-                    self.zoo_login_widget.draw_and_parse(ui, egui::Id::from("zoo login"));
+    fn draw_metadata(&mut self, ui: &mut egui::Ui){
+        ui.horizontal(|ui|{
+            ui.heading("Model Metadata");
+            draw_section_badge(ui, &self.metadata_errors());
+        });
+        ui.separator();
+
+        ui.horizontal_top(|ui| {
+            findable_label(ui, "Name: ").on_hover_text_with_spec(ui, "ModelDescr.name",
+                "A human-friendly name of the resource description. \
+                May only contains letters, digits, underscore, minus, parentheses and spaces."
+            );
+            self.staging_name.draw_and_parse(ui, egui::Id::from("Name"));
+            let _name_result = self.staging_name.state();
+        });
 
-                    let upload_button = egui::Button::new("⬆ Upload Model");
-                    let Ok(user_token) = self.zoo_login_widget.state() else {
-                        ui.add_enabled_ui(false, |ui|{
-                            ui.add(upload_button).on_disabled_hover_text("Please login first");
-                        });
-                        return;
-                    };
-                    let Some(packing_task) = self.zoo_model_creation_task.take() else {
-                        if !ui.add(upload_button).clicked(){
-                            return;
-                        }
-                        let model = match self.create_model(){
-                            Ok(model) => model,
-                            Err(err) => {
-                                self.notifications_widget.push(Notification::error(err.to_string(), None));
-                                return;
-                            }
-                        };
-                        let user_token = user_token.as_ref().clone();
-                        let sender = self.notifications_channel.sender().clone();
-                        let on_progress = move |msg: String|{
-                            sender.send(TaskResult::Notification(Ok(msg))).unwrap(); //FIXME: is there anything sensible to do if this fails?
-                        };
-                        self.zoo_model_creation_task = Some(
-                            std::thread::spawn(|| upload_model(user_token, model, on_progress))
-                        );
-                        return
-                    };
-                    if !packing_task.is_finished() {
-                        ui.add_enabled_ui(false, |ui|{
-                            ui.add(upload_button).on_disabled_hover_text("Uploading model...");
+        ui.horizontal_top(|ui| {
+            findable_label(ui, "Description: ").on_hover_text_with_spec(ui, "ModelDescr.description", "A brief description of the model.");
+            self.staging_description.draw_and_parse(ui, egui::Id::from("Name"));
+            let _description_result = self.staging_description.state();
+        });
+
+        ui.horizontal_top(|ui| {
+            findable_label(ui, "Cover Images: ").on_hover_text_with_spec(ui, "ModelDescr.covers",
+                "Images to be shown to users on the model zoo, preferrably showing what the input \
+                and output look like."
+            );
+            let covers_base_id = egui::Id::from("cover images");
+            let vec_widget = VecWidget{
+                items: &mut self.cover_images,
+                item_label: "Cover Image",
+                min_items: 1,
+                show_reorder_buttons: true,
+                new_item: Some(SpecialImageWidget::default),
+                item_renderer: VecItemRender::HeaderAndBody{
+                    render_header: |widget: &mut SpecialImageWidget<_>, idx, ui|{
+                        ui.horizontal(|ui|{
+                            ui.weak(format!("Cover image #{idx}"));
+                            ui.add_space(3.0);
+                            widget.summarize(ui, covers_base_id.with(idx));
                         });
-                        self.zoo_model_creation_task = Some(packing_task);
-                        return;
-                    }
-                    match packing_task.join().unwrap(){
-                        Ok(nickname) => self.notifications_widget.push(
-                            Notification::info(format!("Model successfully uploaded: {nickname}"), None)
-                        ),
-                        Err(upload_err) => self.notifications_widget.push(
-                            Notification::error(format!("Could not upload model: {upload_err}"), None)
-                        ),
+                    },
+                    render_body: |widg: &mut SpecialImageWidget<_>, idx, ui|{
+                        widg.draw_and_parse(ui, covers_base_id.with(("body".as_ptr(), idx)));
+                    },
+                    collapsible_id_source: Some(covers_base_id),
+                    marker: Default::default(),
+                }
+            };
+            ui.add(vec_widget);
+        });
+
+        ui.horizontal_top(|ui| {
+            findable_label(ui, "Model Id: ").on_hover_text_with_spec(ui, "ModelDescr.id",
+                "A model zoo id of the form <adjective>-<animal>, like 'affable-shark'.\
+                If you're creating a model from scratch, leave this empty and an id will be generated \
+                for you when you upload your model to the zoo."
+            );
+            ui.vertical(|ui|{
+                self.model_id_widget.draw_and_parse(ui, egui::Id::from("Model Id"));
+                #[cfg(not(target_arch="wasm32"))]
+                {
+                    let model_id = match self.model_id_widget.state(){
+                        Some(Ok(model_id)) => Some(model_id.to_string()),
+                        _ => None,
                     };
-                })});
-                ui.menu_button("File", |ui| {
-                    if ui.button("📦⤴ Import Model")
-                        .on_hover_text("Import a .zip model file, like the ones you'd get from bioimage.io")
-                        .clicked()
-                    {
-                        ui.close_menu();
-                        let sender = self.notifications_channel.sender().clone();
+                    self.model_id_check_widget.draw(ui, model_id.as_deref());
+                }
+            });
+        });
 
-                        #[cfg(target_arch="wasm32")]
-                        wasm_bindgen_futures::spawn_local(async move {
-                            use bioimg_runtime::zip_archive_ext::SharedZipArchive;
+        ui.horizontal_top(|ui| {
+            findable_label(ui, "Parent Model: ").on_hover_text_with_spec(ui, "ModelDescr.parent",
+                "The zoo model this model was derived from, e.g. by fine-tuning its weights on new data. \
+                Declaring it lets users trace the provenance of your model."
+            );
+            self.parent_widget.draw_and_parse(ui, egui::Id::from("Parent Model"));
+        });
 
-                            if let Some(handle) = rfd::AsyncFileDialog::new().add_filter("bioimage model", &["zip"],).pick_file().await {
-                                let contents = handle.read().await;
-                                let shared_archive = SharedZipArchive::from_raw_data(contents, handle.file_name());
-                                let message = match rt::zoo_model::ZooModel::try_load_archive(shared_archive){
-                                    Err(err) => TaskResult::Notification(Err(format!("Could not import model: {err}"))),
-                                    Ok(zoo_model) => TaskResult::ModelImport(Box::new(zoo_model)),
-                                };
-                                sender.send(message).unwrap();
-                            }
-                        });
+        ui.horizontal_top(|ui| {
+            findable_label(ui, "Training Data: ").on_hover_text_with_spec(ui, "ModelDescr.training_data",
+                "The dataset used to train this model, either as a dataset in the zoo or as a link to an external source"
+            );
+            self.training_data_widget.draw_and_parse(ui, egui::Id::from("Training Data"));
+        });
 
-                        #[cfg(not(target_arch="wasm32"))]
-                        if let Some(model_path) = rfd::FileDialog::new().add_filter("bioimage model", &["zip"],).pick_file() {
+        ui.horizontal_top(|ui| {
+            let authors_base_id = egui::Id::from("authors");
+            findable_label(ui, "Authors: ").on_hover_text_with_spec(ui, "ModelDescr.authors",
+                "The authors are the creators of this resource description and the primary points of contact."
+            );
+            let vec_widget = VecWidget{
+                items: &mut self.staging_authors,
+                item_label: "Author",
+                min_items: 1,
+                show_reorder_buttons: true,
+                new_item: Some(AuthorWidget::default),
+                item_renderer: VecItemRender::HeaderAndBody{
+                    render_header: |widg: &mut AuthorWidget, idx, ui|{
+                        widg.summarize(ui, authors_base_id.with(("header".as_ptr(), idx)));
+                    },
+                    render_body: |widg: &mut AuthorWidget, idx, ui|{
+                        widg.draw_and_parse(ui, authors_base_id.with(("body".as_ptr(), idx)));
+                    },
+                    collapsible_id_source: Some(authors_base_id),
+                    marker: Default::default(),
+                }
+            };
+            ui.add(vec_widget);
+        });
+
+        ui.horizontal_top(|ui| {
+            let attachments_base_id = egui::Id::from("attachments");
+            findable_label(ui, "Attachments: ").on_hover_text_with_spec(ui, "ModelDescr.attachments",
+                "Any other files that are relevant to your model can be listed as 'attachments'"
+            );
+            let vec_widget = VecWidget{
+                items: &mut self.attachments_widget,
+                min_items: 0,
+                item_label: "Attachment",
+                show_reorder_buttons: true,
+                new_item: Some(AttachmentsWidget::default),
+                item_renderer: VecItemRender::HeaderAndBody{
+                    render_header: |widg: &mut AttachmentsWidget, idx, ui|{
+                        widg.summarize(ui, attachments_base_id.with(("header".as_ptr(), idx)));
+                    },
+                    render_body: |widg: &mut AttachmentsWidget, idx, ui|{
+                        widg.draw_and_parse(ui, attachments_base_id.with(("body".as_ptr(), idx)));
+                    },
+                    collapsible_id_source: Some(attachments_base_id),
+                    marker: Default::default(),
+                }
+            };
+            ui.add(vec_widget);
+        });
+
+        ui.horizontal_top(|ui| {
+            let cite_base_id = egui::Id::from("cite");
+            findable_label(ui, "Cite: ").on_hover_text_with_spec(ui, "ModelDescr.cite", "How this model should be cited in other publications.");
+
+            let vec_widget = VecWidget{
+                items: &mut self.staging_citations,
+                min_items: 1,
+                item_label: "Citation Entry",
+                show_reorder_buttons: true,
+                new_item: Some(CiteEntryWidget::default),
+                item_renderer: VecItemRender::HeaderAndBody{
+                    render_header: |widg: &mut CiteEntryWidget, idx, ui|{
+                        widg.summarize(ui, cite_base_id.with(("header".as_ptr(), idx)));
+                    },
+                    render_body: |widg: &mut CiteEntryWidget, idx, ui|{
+                        widg.draw_and_parse(ui, cite_base_id.with(("body".as_ptr(), idx)));
+                    },
+                    collapsible_id_source: Some(cite_base_id),
+                    marker: Default::default(),
+                }
+            };
+            ui.add(vec_widget);
+        });
+
+        ui.horizontal_top(|ui| {
+            ui.weak("Custom configs: ").on_hover_text(
+                "A JSON value representing any extra, 'proprietary' parameters your model might need during runtime. \
+                This field is still available for legacy reasons and its use is strongly discouraged"
+            );
+            self.custom_config_widget.draw_and_parse(ui, egui::Id::from("Custom configs"));
+            // let citation_results = self.staging_citations.state();
+        });
+
+        ui.horizontal_top(|ui| {
+            ui.weak("Run Mode: ").on_hover_text_with_spec(ui,
+                "ModelDescr.run_mode",
+                "A custom run mode for this model, for consumers that need to run it in some special way. \
+                Most models don't need this, and consumers that don't know the run mode will refuse to run the model"
+            );
+            self.run_mode_widget.draw_and_parse(ui, egui::Id::from("Run Mode"));
+        });
+
+        ui.horizontal_top(|ui| {
+            findable_label(ui, "Git Repo: ").on_hover_text_with_spec(ui, "ModelDescr.git_repo",
+                "A URL to the git repository with the source code that produced this model"
+            );
+            self.staging_git_repo.draw_and_parse(ui, egui::Id::from("Git Repo"));
+        });
+
+        ui.horizontal_top(|ui| {
+            findable_label(ui, "Icon: ").on_hover_text_with_spec(ui, "ModelDescr.icon", indoc!("
+                An icon for quick identification on bioimage.io.
+                This can either be an emoji or a small square image."
+            ));
+            self.icon_widget.draw_and_parse(ui, egui::Id::from("Icon"));
+        });
+
+        ui.horizontal_top(|ui| {
+            findable_label(ui, "Model Zoo Links: ").on_hover_text_with_spec(ui, "ModelDescr.links", "IDs of other bioimage.io resources");
+            group_frame(ui, |ui| {
+                self.links_widget.draw_and_parse(ui, egui::Id::from("Model Zoo Links"));
+            });
+        });
+
+        ui.horizontal_top(|ui| {
+            let maintainers_base_id = egui::Id::from("maintainers");
+            findable_label(ui, "Maintainers: ").on_hover_text_with_spec(ui, "ModelDescr.maintainers",
+                "Maintainers of this resource. If not specified, 'authors' are considered maintainers \
+                and at least one of them must specify their `github_user` name."
+            );
+
+            let vec_widget = VecWidget{
+                items: &mut self.staging_maintainers,
+                min_items: 0,
+                item_label: "Maintainer",
+                show_reorder_buttons: true,
+                new_item: Some(MaintainerWidget::default),
+                item_renderer: VecItemRender::HeaderAndBody{
+                    render_header: |widg: &mut MaintainerWidget, idx, ui|{
+                        widg.summarize(ui, maintainers_base_id.with(("header".as_ptr(), idx)));
+                    },
+                    render_body: |widg: &mut MaintainerWidget, idx, ui|{
+                        widg.draw_and_parse(ui, maintainers_base_id.with(("body".as_ptr(), idx)));
+                    },
+                    collapsible_id_source: Some(maintainers_base_id),
+                    marker: Default::default(),
+                }
+            };
+            ui.add(vec_widget);
+        });
+
+        ui.horizontal_top(|ui| {
+            findable_label(ui, "Works With: ").on_hover_text(
+                "The tools this model is meant to be used with. This adds the tags and links that bioimage.io \
+                uses to list models under each tool"
+            );
+            draw_consumers(ui, &mut self.staging_tags, &mut self.links_widget);
+            if ILASTIK.is_targeted(&self.staging_tags){
+                if let (Ok(interface), Ok(weights)) = (self.model_interface_widget.get_value(), self.weights_widget.get_value()){
+                    draw_ilastik_issues(ui, &check_ilastik_compatibility(&interface, &weights));
+                }
+            }
+        });
+
+        ui.horizontal_top(|ui| {
+            findable_label(ui, "Tags: ").on_hover_text_with_spec(ui, "ModelDescr.tags", "Tags to help search and classifying your model in the model zoo");
+            self.staging_tags.draw_and_parse(ui, egui::Id::from("Tags"));
+        });
+
+        ui.horizontal_top(|ui| {
+            findable_label(ui, "Resource Version: ").on_hover_ui(|ui|{
+                ui.horizontal(|ui|{
+                    ui.label("The version of this model, following");
+                    ui.hyperlink_to("SermVer 2.0", "https://semver.org/#semantic-versioning-200");
+                });
+
+                ui.label(indoc!("
+                    If you upload an updated version of this model to the zoo, you should bump this version \
+                    to differentiate it from the previous uploads"
+                ));
+            });
+            spec_link(ui, "ModelDescr.version");
+            self.staging_version.draw_and_parse(ui, egui::Id::from("Version"));
+        });
+
+        ui.horizontal_top(|ui| {
+            findable_label(ui, "Timestamp: ").on_hover_text_with_spec(ui,
+                "ModelDescr.timestamp",
+                "When this model was created. Imported models keep their original timestamp unless you choose to update it"
+            );
+            self.timestamp_widget.draw_and_parse(ui, egui::Id::from("Timestamp"));
+        });
+
+        ui.horizontal_top(|ui| {
+            findable_label(ui, "Resource Version comment: ").on_hover_text_with_spec(ui, "ModelDescr.version_comment", indoc!(
+                "
+                A comment about what changed in this version of this model.
+                Here you can explain why you bumped the resource version"
+            ));
+
+            self.staging_version_comment
+                .draw_and_parse(ui, egui::Id::from("Version Comment"));
+        });
+
+        ui.horizontal_top(|ui| {
+            findable_label(ui, "Uploader: ").on_hover_text_with_spec(ui,
+                "ModelDescr.uploader",
+                "The person who uploads this model to the zoo. Can be filled in from your zoo account once you log in via the Zoo menu"
+            );
+            ui.vertical(|ui|{
+                self.uploader_widget.draw_and_parse(ui, egui::Id::from("Uploader"));
+                #[cfg(not(target_arch="wasm32"))]
+                if self.uploader_widget.0.is_none(){
+                    let account_email = self.zoo_login_widget.state().ok()
+                        .and_then(|token| token.email().map(|email| email.to_owned()));
+                    if let Some(email) = account_email{
+                        if ui.button(format!("👤 Use zoo account ({email})")).clicked(){
+                            self.uploader_widget.0 = Some(UploaderWidget{
+                                email_widget: StagingString::new_with_raw(email),
+                                name_widget: Default::default(),
+                            });
+                        }
+                    }
+                }
+            });
+        });
+
+        ui.horizontal(|ui| {
+            findable_label(ui, "License: ").on_hover_text_with_spec(ui, "ModelDescr.license", "A standard software licence, specifying how this model can be used and for what purposes.");
+            self.staging_license.draw_and_parse(ui, egui::Id::from("License"));
+            let license = self.staging_license.value;
+            if let Some(name) = license.name(){
+                ui.weak(name);
+            }
+            ui.hyperlink_to("🔗 SPDX", license.spdx_url()).on_hover_text("Full text of the license on spdx.org");
+        });
+        ui.add_space(20.0);
+
+
+        ui.heading("Documentation (markdown): ").on_hover_text(
+            "All model documentation should be written here. This field accepts Markdown syntax"
+        );
+        ui.separator();
+        self.staging_documentation.draw_and_parse(ui, egui::Id::from("Documentation"));
+        ui.add_space(20.0);
+    }
+
+    fn draw_size_report(&mut self, ui: &mut egui::Ui){
+        ui.horizontal(|ui|{
+            let check_size_clicked = ui.button("📏 Check Model Size")
+                .on_hover_text("Lists how much space each file (weights, test tensors, covers, etc) will take in the exported model")
+                .clicked();
+            if check_size_clicked {
+                match self.create_model(){
+                    Ok(zoo_model) => self.size_report = Some(zoo_model.size_report()),
+                    Err(err) => self.notifications_widget.push(
+                        Notification::error(format!("Could not create zoo model: {err}"), None)
+                    ),
+                }
+            }
+            if self.size_report.is_some() && ui.button("Hide").clicked(){
+                self.size_report = None;
+            }
+            let preview_clicked = ui.button("🔍 Preview Export")
+                .on_hover_text("Lists every file the exported model would contain, where it comes from and its size, without writing anything")
+                .clicked();
+            if preview_clicked {
+                match self.create_model(){
+                    Ok(zoo_model) => self.export_preview_widget.preview(zoo_model),
+                    Err(err) => self.export_preview_widget.show_error(format!("Could not create zoo model: {err}")),
+                }
+            }
+        });
+        let Some(report) = &self.size_report else {
+            return
+        };
+        let total_bytes = report.total_bytes();
+        group_frame(ui, |ui|{
+            egui::Grid::new("size report").striped(true).num_columns(3).show(ui, |ui|{
+                for entry in &report.entries{
+                    ui.label(format!("{} ({})", entry.kind, entry.name));
+                    match entry.size_bytes{
+                        Some(size) => {
+                            ui.label(format_size(size));
+                            let fraction = if total_bytes == 0 { 0.0 } else { size as f32 / total_bytes as f32 };
+                            ui.add(egui::ProgressBar::new(fraction).desired_width(150.0).show_percentage());
+                        },
+                        None => {
+                            ui.weak("unknown").on_hover_text("The size of this file could not be determined, e.g. because it is referenced by a URL that wasn't downloaded yet");
+                            ui.label("");
+                        },
+                    }
+                    ui.end_row();
+                }
+                ui.strong("Total");
+                ui.strong(format_size(total_bytes));
+                ui.end_row();
+            });
+        });
+    }
+
+    /// Shows how much memory one tile of the inputs and outputs takes for a few choices of `n`, the
+    /// number of steps added to the minimum size of the parameterized axes
+    fn draw_tile_memory_estimate(&self, ui: &mut egui::Ui){
+        let Ok(interface) = self.model_interface_widget.get_value() else {
+            return
+        };
+        let footprints = ui.memory_mut(|mem| mem.caches.cache::<TileFootprintCache>().get(TensorDescrs(&interface)));
+        if footprints.is_empty(){
+            return
+        }
+        egui::CollapsingHeader::new("🖩 Memory per tile").id_salt("tile memory estimate").show(ui, |ui|{
+            ui.weak(
+                "Estimated memory taken by a single tile (with batch size 1) when every parameterized axis \
+                has size min + n * step. Weights and intermediate activations are not included"
+            );
+            egui::Grid::new("tile memory estimate grid").striped(true).num_columns(4).show(ui, |ui|{
+                ui.strong("n");
+                ui.strong("Inputs");
+                ui.strong("Outputs");
+                ui.strong("Total");
+                ui.end_row();
+                for (n, footprint) in footprints{
+                    ui.label(n.to_string());
+                    ui.label(format_size(footprint.input_bytes));
+                    ui.label(format_size(footprint.output_bytes));
+                    ui.label(format_size(footprint.total_bytes()));
+                    ui.end_row();
+                }
+            });
+        });
+    }
+
+    /// An archive to resolve the file references of a hand-written rdf.yaml against. If the text was
+    /// generated from the form, the form is only packed now, since its paths are the ones packing assigns.
+    /// If it was neither generated nor imported, that's an archive containing just the rdf.yaml itself
+    pub(crate) fn yaml_mode_archive(&self, raw_rdf: &str) -> Result<SharedZipArchive>{
+        use std::io::Write;
+        if let Some(archive) = &self.yaml_mode.archive{
+            return Ok(archive.clone())
+        }
+        if self.yaml_mode.paths_from_form{
+            let mut buffer = std::io::Cursor::new(Vec::<u8>::new());
+            self.create_model()?.pack_into(&mut buffer)?;
+            return Ok(SharedZipArchive::from_raw_data(buffer.into_inner(), "model form".to_owned()))
+        }
+        if let Some(archive) = self.archive_browser_widget.archive(){
+            return Ok(archive.clone())
+        }
+        let file_options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+        let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::<u8>::new()));
+        writer.start_file("rdf.yaml", file_options)?;
+        writer.write_all(raw_rdf.as_bytes())?;
+        let contents = writer.finish()?.into_inner();
+        Ok(SharedZipArchive::from_raw_data(contents, "rdf.yaml".to_owned()))
+    }
+
+    /// The rdf.yaml of the model, with the paths its files would get if it were packed, but without
+    /// reading any of them
+    fn generate_rdf_yaml(zoo_model: ZooModel) -> Result<String>{
+        let model_rdf = zoo_model.rdf_with_placeholders()?;
+        Ok(serde_yaml::to_string(&serde_json::to_value(&model_rdf)?)?)
+    }
+
+    fn enter_yaml_mode(&mut self){
+        self.yaml_mode.is_active = true;
+        let zoo_model = match self.create_model(){
+            Ok(zoo_model) => zoo_model,
+            Err(err) => {
+                if self.yaml_mode.editor.raw.is_empty(){
+                    if let (Some(original_rdf), Some(archive)) = (self.original_rdf_widget.raw_rdf(), self.archive_browser_widget.archive()){
+                        // nothing to apply unless the user edits it
+                        self.yaml_mode.set_generated(original_rdf.to_owned(), Some(archive.clone()));
+                    }
+                }
+                self.notifications_widget.push(Notification::warning(
+                    format!("Could not generate rdf.yaml from the form, showing the last edited rdf instead: {err}"), None
+                ));
+                return
+            }
+        };
+        #[cfg(target_arch="wasm32")]
+        match Self::generate_rdf_yaml(zoo_model){
+            Ok(raw) => self.yaml_mode.set_generated(raw, None),
+            Err(err) => self.notifications_widget.push(Notification::error(format!("Could not generate rdf.yaml: {err}"), None)),
+        }
+        #[cfg(not(target_arch="wasm32"))]
+        {
+            self.yaml_mode.generation_task = Some(std::thread::spawn(move || Self::generate_rdf_yaml(zoo_model)));
+        }
+    }
+
+    fn leave_yaml_mode(&mut self){
+        #[cfg(not(target_arch="wasm32"))]
+        if self.yaml_mode.generation_task.take().is_some(){
+            // the form hasn't been turned into yaml yet, so there can't be any edits to apply
+            self.yaml_mode.is_active = false;
+            return
+        }
+        if self.yaml_mode.has_unsynced_edits() {
+            if let Err(err) = self.apply_rdf_yaml(){
+                self.notifications_widget.push(Notification::error(
+                    format!("Could not apply your rdf.yaml edits to the form. Fix them or undo them first: {err}"), None
+                ));
+                return
+            }
+        }
+        self.yaml_mode.is_active = false;
+    }
+
+    fn apply_rdf_yaml(&mut self) -> Result<()>{
+        let raw_rdf = self.yaml_mode.editor.state()?.to_owned();
+        let archive = self.yaml_mode_archive(&raw_rdf)?;
+        let AppStateFromPartial{state, warnings} = Self::read_partial_rdf(&archive, raw_rdf.as_bytes())?;
+
+        // restoring resets all non-model state, but we want to keep the yaml mode and the imported archive around
+        let archive_browser_widget = std::mem::take(&mut self.archive_browser_widget);
+        let original_rdf_widget = std::mem::take(&mut self.original_rdf_widget);
+        let yaml_mode = std::mem::take(&mut self.yaml_mode);
+        self.restore_into_draft(state);
+        self.archive_browser_widget = archive_browser_widget;
+        self.original_rdf_widget = original_rdf_widget;
+        self.yaml_mode = yaml_mode;
+        self.yaml_mode.archive = Some(archive);
+        self.yaml_mode.synced_raw = Some(raw_rdf);
+
+        if !warnings.trim().is_empty(){
+            self.notifications_widget.push(Notification::warning(warnings, None));
+        }
+        Ok(())
+    }
+
+    fn draw_yaml_mode(&mut self, ui: &mut egui::Ui){
+        ui.heading("Model rdf.yaml");
+        #[cfg(not(target_arch="wasm32"))]
+        if let Some(task) = self.yaml_mode.generation_task.take_if(|task| task.is_finished()){
+            match task.join(){
+                Ok(Ok(raw)) => self.yaml_mode.set_generated(raw, None),
+                Ok(Err(err)) => self.notifications_widget.push(Notification::error(format!("Could not generate rdf.yaml: {err}"), None)),
+                Err(_) => self.notifications_widget.push(Notification::error("Generating rdf.yaml panicked".to_owned(), None)),
+            }
+        }
+        #[cfg(not(target_arch="wasm32"))]
+        if self.yaml_mode.generation_task.is_some(){
+            ui.horizontal(|ui|{
+                ui.spinner();
+                ui.weak("Generating rdf.yaml from the form...");
+            });
+            ui.ctx().request_repaint();
+            return
+        }
+        ui.weak(
+            "Edit the whole model description as yaml. Your edits are applied to the form when you leave this mode."
+        );
+        ui.horizontal(|ui|{
+            let is_valid = self.yaml_mode.editor.error().is_none();
+            let apply_clicked = ui.add_enabled(is_valid, egui::Button::new("⤴ Apply to Form"))
+                .on_hover_text("Replace the contents of the form with this rdf")
+                .on_disabled_hover_text("Fix the errors in the rdf first")
+                .clicked();
+            if apply_clicked {
+                if let Err(err) = self.apply_rdf_yaml(){
+                    self.notifications_widget.push(Notification::error(format!("Could not apply rdf.yaml: {err}"), None));
+                }
+            }
+            if let Some(synced_raw) = &self.yaml_mode.synced_raw {
+                let undo_clicked = ui.add_enabled(self.yaml_mode.has_unsynced_edits(), egui::Button::new("⟲ Undo Edits"))
+                    .on_hover_text("Discard your edits and show the rdf.yaml matching the form")
+                    .clicked();
+                if undo_clicked {
+                    let synced_raw = synced_raw.clone();
+                    self.yaml_mode.editor.set_raw(synced_raw);
+                }
+            }
+            if let Some(original_rdf) = self.original_rdf_widget.raw_rdf(){
+                if ui.button("⟲ Reset to Original").on_hover_text("Discard your edits and show the rdf.yaml of the imported model").clicked(){
+                    let original_rdf = original_rdf.to_owned();
+                    self.yaml_mode.editor.set_raw(original_rdf);
+                    self.yaml_mode.archive = self.archive_browser_widget.archive().cloned();
+                    self.yaml_mode.paths_from_form = false;
+                }
+            }
+        });
+        self.yaml_mode.editor.draw_and_parse(ui, egui::Id::from("rdf yaml editor"));
+    }
+
+    fn draw_export_button(&mut self, ui: &mut egui::Ui){
+        self.draw_size_report(ui);
+        let save_button_clicked = ui.button("Export Model ⤵📦")
+            .on_hover_text("Exports this model to a .zip file, ready to be used or uploaded to the Model Zoo")
+            .clicked();
+
+        if save_button_clicked {
+            match self.create_model(){
+                Ok(zoo_model) => self.launch_model_saving(zoo_model),
+                Err(err) => self.notifications_widget.push(
+                    Notification::error(format!("Could not create zoo model: {err}"), None)
+                ),
+            }
+        }
+        #[cfg(not(target_arch="wasm32"))]
+        {
+            let core_button_clicked = ui.button("✔ bioimageio.core")
+                .on_hover_text(format!(
+                    "Runs the validation and test suite of bioimageio.core on the exported model. Requires a Python \
+                    interpreter with bioimageio.core installed, either in the PATH or pointed to by ${}",
+                    rt::core_validator::PYTHON_ENV_VAR,
+                ))
+                .clicked();
+            if core_button_clicked{
+                match self.create_model(){
+                    Ok(zoo_model) => self.launch_core_validation(zoo_model),
+                    Err(err) => self.notifications_widget.push(
+                        Notification::error(format!("Could not create zoo model: {err}"), None)
+                    ),
+                }
+            }
+            self.draw_test_record_buttons(ui);
+            self.draw_onnx_conversion_button(ui);
+            self.draw_remote_test_button(ui);
+        }
+    }
+
+    #[cfg(not(target_arch="wasm32"))]
+    fn draw_test_record_buttons(&mut self, ui: &mut egui::Ui){
+        let Some(record) = &self.pending_test_record else {
+            return
+        };
+        let summary = format!(
+            "{} took {:.1} ms per tile on {}",
+            record.backend,
+            record.duration_per_tile.as_secs_f64() * 1000.0,
+            record.hardware,
+        );
+        let record_clicked = ui.button("📝 Record test results")
+            .on_hover_text(format!(
+                "Adds the timing of the last successful test run ({summary}) to config.bioimageio.test_results"
+            ))
+            .clicked();
+        let dismiss_clicked = ui.small_button("🗙").on_hover_text("Discard the timing of the last test run").clicked();
+        if dismiss_clicked{
+            self.pending_test_record = None;
+            return
+        }
+        if !record_clicked{
+            return
+        }
+        let mut config = match self.custom_config_widget.state(){
+            None => serde_json::Map::new(),
+            Some(Ok(config)) => config.clone(),
+            Some(Err(e)) => {
+                self.notifications_widget.push(Notification::error(
+                    "Fix the custom configs before recording test results".to_owned(), e.failed_widget_rect
+                ));
+                return
+            },
+        };
+        match record.record_into(&mut config){
+            Ok(()) => {
+                self.custom_config_widget.set_value(Some(config));
+                self.notifications_widget.push(Notification::info(format!("Recorded test results: {summary}"), None));
+                self.pending_test_record = None;
+            },
+            Err(err) => self.notifications_widget.push(
+                Notification::error(format!("Could not record test results: {err}"), None)
+            ),
+        }
+    }
+
+    #[cfg(not(target_arch="wasm32"))]
+    fn draw_onnx_conversion_button(&mut self, ui: &mut egui::Ui){
+        let weights = &self.weights_widget;
+        if weights.torchscript_weights_widget.0.is_none() || weights.onnx_weights_widget.0.is_some(){
+            return
+        }
+        let convert_clicked = ui.button("TorchScript ➡ ONNX")
+            .on_hover_text(format!(
+                "Derives ONNX weights from the TorchScript weights, so that the model can run in more places. Uses \
+                the command in ${} if set, or else the converter of bioimageio.core",
+                rt::weights_converter::CONVERTER_ENV_VAR,
+            ))
+            .clicked();
+        if !convert_clicked{
+            return
+        }
+        let zoo_model = match self.create_model(){
+            Ok(zoo_model) => zoo_model,
+            Err(err) => {
+                self.notifications_widget.push(Notification::error(format!("Could not create zoo model: {err}"), None));
+                return
+            }
+        };
+        let sender = self.notifications_channel.sender().clone();
+        sender.send(TaskResult::ok_message("Converting TorchScript weights to ONNX...")).unwrap();
+        std::thread::spawn(move ||{
+            let Some(converter) = rt::weights_converter::OnnxConverter::discover() else {
+                let msg = format!(
+                    "No weights converter found. Set ${} or install bioimageio.core in the Python pointed to by ${}",
+                    rt::weights_converter::CONVERTER_ENV_VAR,
+                    rt::core_validator::PYTHON_ENV_VAR,
+                );
+                sender.send(TaskResult::err_message(msg)).unwrap();
+                return
+            };
+            let message = match converter.convert_model(zoo_model){
+                Ok(onnx_weights) => TaskResult::OnnxConversion(Box::new(onnx_weights)),
+                Err(err) => TaskResult::err_message(format!("Could not convert weights to ONNX: {err}")),
+            };
+            sender.send(message).unwrap();
+        });
+    }
+
+    #[cfg(not(target_arch="wasm32"))]
+    fn launch_core_validation(&mut self, zoo_model: ZooModel){
+        let sender = self.notifications_channel.sender().clone();
+        sender.send(TaskResult::ok_message("Validating model with bioimageio.core...")).unwrap();
+        std::thread::spawn(move ||{
+            let Some(validator) = rt::core_validator::CoreValidator::discover() else {
+                let msg = format!(
+                    "Could not find a Python interpreter with bioimageio.core installed. Set ${} to point to one",
+                    rt::core_validator::PYTHON_ENV_VAR,
+                );
+                sender.send(TaskResult::err_message(msg)).unwrap();
+                return
+            };
+            match validator.test_model(zoo_model){
+                Ok(test_run) if test_run.summary.passed() => {
+                    sender.send(TaskResult::ok_message(test_run.summary.to_string())).unwrap();
+                    if let Some(record) = test_run.record{
+                        sender.send(TaskResult::TestRunRecorded(Box::new(record))).unwrap();
+                    }
+                },
+                Ok(test_run) => sender.send(TaskResult::err_message(test_run.summary.to_string())).unwrap(),
+                Err(err) => sender.send(
+                    TaskResult::err_message(format!("Could not validate with bioimageio.core: {err}"))
+                ).unwrap(),
+            }
+        });
+    }
+
+    #[cfg(not(target_arch="wasm32"))]
+    fn draw_remote_test_button(&mut self, ui: &mut egui::Ui){
+        use bioimg_zoo::remote_test::RemoteModelSource;
+
+        let mut source = None;
+        let mut test_draft = false;
+        ui.menu_button("🧪 Remote Test", |ui|{
+            ui.weak("Runs the bioimage.io test suite on the BioEngine, for when this machine can't run the model");
+            let logged_in = self.zoo_login_widget.state().is_ok();
+            test_draft = ui.add_enabled(logged_in, egui::Button::new("Test current draft"))
+                .on_hover_text("Packs the model as it is now and uploads it temporarily, without staging it")
+                .on_disabled_hover_text("Log in via the Zoo menu to upload the draft for testing. Zoo models and public URLs below need no login")
+                .clicked();
+            ui.separator();
+            match self.model_id_widget.state(){
+                Some(Ok(model_id)) => if ui.button(format!("Test zoo model '{model_id}'")).clicked(){
+                    source = Some(RemoteModelSource::ZooId(model_id.to_string()));
+                },
+                _ => {
+                    ui.add_enabled(false, egui::Button::new("Test zoo model"))
+                        .on_disabled_hover_text("Set a valid model id to test the model that is in the zoo");
+                },
+            }
+            ui.separator();
+            ui.label("Or test a packaged model at a public URL:");
+            ui.horizontal(|ui|{
+                self.remote_test_url_widget.draw_and_parse(ui, egui::Id::new("remote test url"));
+                let url = self.remote_test_url_widget.state();
+                if ui.add_enabled(url.is_ok(), egui::Button::new("Test package")).clicked(){
+                    source = url.ok().map(|url| RemoteModelSource::PackageUrl(url.to_string()));
+                }
+            });
+            if source.is_some() || test_draft{
+                ui.close_menu();
+            }
+        }).response.on_hover_text("Test the model on remote hardware via the BioEngine");
+        if let Some(source) = source{
+            self.launch_remote_test(source);
+        }
+        if test_draft{
+            self.launch_remote_draft_test();
+        }
+    }
+
+    #[cfg(not(target_arch="wasm32"))]
+    fn launch_remote_draft_test(&mut self){
+        use bioimg_zoo::remote_test::RemoteModelSource;
+        use crate::widgets::zoo_widget::upload_for_remote_test;
+
+        let Ok(user_token) = self.zoo_login_widget.state() else {
+            self.notifications_widget.push(Notification::error("Log in to the zoo to test the current draft remotely", None));
+            return
+        };
+        let user_token = user_token.as_ref().clone();
+        let model = match self.create_model(){
+            Ok(model) => model,
+            Err(err) => {
+                self.notifications_widget.push(Notification::error(format!("Can't test the current draft: {err}"), None));
+                return
+            }
+        };
+        self.notifications_widget.push(
+            Notification::info("Uploading the current draft for a remote test...", None)
+        );
+        let sender = self.notifications_channel.sender().clone();
+        std::thread::spawn(move ||{
+            let message = match upload_for_remote_test(user_token, model){
+                Ok(url) => run_remote_test(RemoteModelSource::PackageUrl(url.to_string())),
+                Err(err) => TaskResult::err_message(format!("Could not upload the draft for a remote test: {err}")),
+            };
+            sender.send(message).unwrap();
+        });
+    }
+
+    #[cfg(not(target_arch="wasm32"))]
+    fn launch_remote_test(&mut self, source: bioimg_zoo::remote_test::RemoteModelSource){
+        self.notifications_widget.push(
+            Notification::info(format!("Requesting remote test of {source}. This may take a few minutes..."), None)
+        );
+        let sender = self.notifications_channel.sender().clone();
+        std::thread::spawn(move ||{
+            sender.send(run_remote_test(source)).unwrap();
+        });
+    }
+
+    fn validate_wizard_step(&self, step: WizardStep) -> Result<()>{
+        match step{
+            WizardStep::Metadata => {
+                self.staging_name.state()
+                    .map_err(|e| GuiError::new_with_rect("Check resource name for errors", e.failed_widget_rect))?;
+                self.staging_description.state()
+                    .map_err(|e| GuiError::new_with_rect("Check resource text description for errors", e.failed_widget_rect))?;
+                if self.staging_authors.is_empty(){
+                    return Err(GuiError::new("Add at least one author"))
+                }
+                for (idx, widget) in self.staging_authors.iter().enumerate(){
+                    widget.state().map_err(|err| {
+                        GuiError::new_with_rect(format!("Check author #{} for errors", idx + 1), err.failed_widget_rect)
+                    })?;
+                }
+                if self.staging_citations.is_empty(){
+                    return Err(GuiError::new("Add at least one citation"))
+                }
+                for (idx, widget) in self.staging_citations.iter().enumerate(){
+                    widget.state().map_err(|_| GuiError::new(format!("Check citation #{} for errors", idx + 1)))?;
+                }
+                Ok(())
+            },
+            WizardStep::Inputs => {
+                if self.model_interface_widget.input_widgets.is_empty(){
+                    return Err(GuiError::new("Add at least one input"))
+                }
+                for (idx, widget) in self.model_interface_widget.input_widgets.iter().enumerate(){
+                    widget.parse().map_err(|err| {
+                        GuiError::new_with_rect(format!("Check input #{} for errors: {err}", idx + 1), err.failed_widget_rect)
+                    })?;
+                }
+                Ok(())
+            },
+            WizardStep::Outputs => {
+                if self.model_interface_widget.output_widgets.is_empty(){
+                    return Err(GuiError::new("Add at least one output"))
+                }
+                for (idx, widget) in self.model_interface_widget.output_widgets.iter().enumerate(){
+                    widget.parse().map_err(|err| {
+                        GuiError::new_with_rect(format!("Check output #{} for errors: {err}", idx + 1), err.failed_widget_rect)
+                    })?;
+                }
+                self.model_interface_widget.get_value().map(|_| ())
+            },
+            WizardStep::Weights => {
+                self.weights_widget.get_value()
+                    .map_err(|e| GuiError::new_with_rect(format!("Check model weights for errors: {e}"), e.failed_widget_rect))
+                    .map(|_| ())
+            },
+            WizardStep::Export => self.create_model().map(|_| ()),
+        }
+    }
+
+    fn draw_wizard(&mut self, ui: &mut egui::Ui, step: WizardStep){
+        let header_nav = draw_wizard_header(ui, step);
+        match step{
+            WizardStep::Metadata => self.draw_metadata(ui),
+            WizardStep::Inputs => {
+                let inputs_base_id = egui::Id::from("wizard inputs");
+                let vec_widget = VecWidget{
+                    items: &mut self.model_interface_widget.input_widgets,
+                    item_label: "Input",
+                    min_items: 1,
+                    show_reorder_buttons: true,
+                    new_item: Some(InputTensorWidget::default),
+                    item_renderer: VecItemRender::HeaderAndBody{
+                        render_header: |widget: &mut InputTensorWidget, idx, ui|{
+                            widget.summarize(ui, inputs_base_id.with(("header".as_ptr(), idx)));
+                        },
+                        render_body: |widget: &mut InputTensorWidget, idx, ui|{
+                            widget.draw(ui, inputs_base_id.with(("body", idx)));
+                        },
+                        collapsible_id_source: Some(inputs_base_id),
+                        marker: Default::default(),
+                    }
+                };
+                ui.add(vec_widget);
+            },
+            WizardStep::Outputs => {
+                let outputs_base_id = egui::Id::from("wizard outputs");
+                let vec_widget = VecWidget{
+                    items: &mut self.model_interface_widget.output_widgets,
+                    item_label: "Output",
+                    min_items: 1,
+                    show_reorder_buttons: true,
+                    new_item: Some(OutputTensorWidget::default),
+                    item_renderer: VecItemRender::HeaderAndBody{
+                        render_header: |widget: &mut OutputTensorWidget, idx, ui|{
+                            widget.summarize(ui, outputs_base_id.with(("header".as_ptr(), idx)));
+                        },
+                        render_body: |widget: &mut OutputTensorWidget, idx, ui|{
+                            widget.draw(ui, outputs_base_id.with(("body", idx)));
+                        },
+                        collapsible_id_source: Some(outputs_base_id),
+                        marker: Default::default(),
+                    }
+                };
+                ui.add(vec_widget);
+                ui.menu_button("Create output matching an input", |ui|{
+                    for (idx, input_widget) in self.model_interface_widget.input_widgets.iter().enumerate(){
+                        let label = if input_widget.id_widget.raw.is_empty() {
+                            format!("Input #{}", idx + 1)
+                        } else {
+                            input_widget.id_widget.raw.clone()
+                        };
+                        if ui.button(label).clicked(){
+                            let output = input_widget.matching_output();
+                            self.model_interface_widget.output_widgets.push(output);
+                            ui.close_menu();
+                        }
+                    }
+                });
+            },
+            WizardStep::Weights => {
+                self.weights_widget.draw(ui, egui::Id::from("wizard weights"));
+            },
+            WizardStep::Export => {
+                egui::ScrollArea::horizontal().show(ui, |ui|{
+                    self.pipeline_widget.draw(
+                        ui,
+                        egui::Id::from("pipeline"),
+                        &mut self.model_interface_widget,
+                        &mut self.weights_widget,
+                    );
+                });
+                self.draw_export_button(ui);
+            },
+        }
+        let validation = match &self.wizard_validation{
+            Some(validation) if validation.is_current(ui.ctx(), step) => validation.result.clone(),
+            _ => {
+                let result = self.validate_wizard_step(step);
+                let validated_at = ui.ctx().input(|i| i.time);
+                self.wizard_validation = Some(WizardValidation{step, validated_at, result: result.clone()});
+                result
+            },
+        };
+        let footer_nav = draw_wizard_footer(ui, step, &validation);
+        for nav in [header_nav, footer_nav]{
+            if let WizardNavigation::GoTo(next_step) = nav{
+                self.wizard_step = Some(next_step);
+            }
+        }
+    }
+
+    fn load_partial_model(archive: &SharedZipArchive) -> Result<AppStateFromPartial>{
+        let AppStateFromPartial{state, mut warnings} = Self::read_partial_archive(archive)?;
+        warnings += indoc!("
+            PLEASE BE AWARE THAT RECOVERING AND THEN RE-EXPORTING A MODEL MIGHT PRODUCE A NEW, VALID MODEL THAT DOES NOT
+            BEHAVE LIKE THE ORIGINAL\n"
+        );
+        Ok(AppStateFromPartial { state, warnings})
+    }
+
+    fn load_template(&mut self, template: ModelTemplate){
+        let archive = match template.to_archive(){
+            Ok(archive) => archive,
+            Err(err) => {
+                self.notifications_widget.push(Notification::error(format!("Could not create template: {err}"), None));
+                return
+            }
+        };
+        match Self::read_partial_archive(&archive){
+            Ok(AppStateFromPartial{state, warnings}) => {
+                self.restore(state);
+                #[cfg(not(target_arch="wasm32"))]
+                self.draft_status.mark_edited();
+                self.notifications_widget.push(Notification::info(
+                    format!("Created a new draft from the '{template}' template. Fill in the following before exporting:\n{warnings}"),
+                    None,
+                ));
+            },
+            Err(err) => self.notifications_widget.push(Notification::error(format!("Could not load template: {err}"), None)),
+        }
+    }
+
+    fn read_rdf_bytes(archive: &SharedZipArchive) -> Result<Vec<u8>>{
+        for file_name in ["rdf.yaml", "bioimageio.yaml"]{
+            match archive.read_full_entry(file_name) {
+                Ok(bytes) => return Ok(bytes),
+                Err(zip_err) => match zip_err{
+                    zip::result::ZipError::FileNotFound => continue,
+                    err => return Err(GuiError::new(format!("Could not read rdf file: {err}")))
+                }
+            };
+        }
+        Err(GuiError::new("Could not find rdf file inside archive"))
+    }
+
+    /// Whether the form has anything in it that an import could overwrite
+    fn has_draft_contents(&self) -> bool{
+        import_conflict_widget::contents_differ(&self.dump(), &AppState1::default().dump())
+    }
+
+    /// Imports `incoming`, first offering to save the draft if the import could overwrite unsaved changes
+    fn receive_import(&mut self, incoming: IncomingModel){
+        #[cfg(not(target_arch="wasm32"))]
+        if self.has_draft_contents(){
+            if self.draft_status.has_unsaved_changes(){
+                self.import_awaiting_save = Some(incoming);
+                return
+            }
+        }
+        self.apply_import(incoming);
+    }
+
+    fn apply_import(&mut self, incoming: IncomingModel){
+        match incoming{
+            IncomingModel::Full(model, archive) => {
+                if self.has_draft_contents(){
+                    let mut imported = AppState1::default();
+                    imported.set_value(*model);
+                    self.import_into_draft(imported.dump(), archive);
+                } else {
+                    self.set_value(*model);
+                    self.set_imported_archive(archive);
+                    #[cfg(not(target_arch="wasm32"))]
+                    self.draft_status.mark_edited();
+                }
+            },
+            IncomingModel::Recovered(AppStateFromPartial{state, warnings}, archive) => {
+                if self.has_draft_contents(){
+                    self.import_into_draft(state, archive);
+                } else {
+                    self.restore(state);
+                    self.set_imported_archive(archive);
+                    #[cfg(not(target_arch="wasm32"))]
+                    self.draft_status.mark_edited();
+                }
+                self.notifications_widget.push(Notification::warning(warnings, None));
+            },
+        }
+    }
+
+    #[cfg(not(target_arch="wasm32"))]
+    fn draw_import_save_confirmation(&mut self, ctx: &egui::Context){
+        if self.import_awaiting_save.is_none(){
+            return
+        }
+        let mut proceed = false;
+        egui::Modal::new(egui::Id::from("import save confirmation dialog"))
+            .show(ctx, |ui| {
+                ui.label("The current draft has unsaved changes, which the import could overwrite. Save it first?");
+                ui.horizontal(|ui| {
+                    if ui.button("Yes 💾").clicked() || ui.input(|i| i.key_pressed(egui::Key::Enter)){ 'save_draft: {
+                        let Some(path) = self.draft_status.save_dialog().save_file() else {
+                            break 'save_draft;
+                        };
+                        let result = self.save_draft_to(&path);
+                        proceed = result.is_ok();
+                        self.notifications_widget.push(result.into());
+                    }}
+                    if ui.button("No, import anyway").clicked() {
+                        proceed = true;
+                    }
+                    if ui.button("Cancel 🗙").clicked() || ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                        self.import_awaiting_save = None;
+                    }
+                });
+            });
+        if !proceed{
+            return
+        }
+        if let Some(incoming) = self.import_awaiting_save.take(){
+            self.apply_import(incoming);
+        }
+    }
+
+    /// Merges an imported model into a form that already has contents, asking the user which side to keep
+    /// wherever they differ
+    fn import_into_draft(&mut self, incoming: LatestAppStateSavedData, archive: SharedZipArchive){
+        let current = self.dump();
+        match self.import_conflict_widget.start(&current, incoming, archive){
+            Ok(ImportDecision::ApplyDirectly(state, archive)) => {
+                self.restore_into_draft(state);
+                self.set_imported_archive(archive);
+            },
+            Ok(ImportDecision::AwaitingUser) => (),
+            Err(err) => self.notifications_widget.push(Notification::error(err.to_string(), None)),
+        }
+    }
+
+    /// Remembers the archive a model was imported or recovered from, so that its contents can be inspected later
+    fn set_imported_archive(&mut self, archive: SharedZipArchive){
+        match Self::read_rdf_bytes(&archive){
+            Ok(bytes) => self.original_rdf_widget.set_rdf(
+                archive.identifier().to_string(),
+                String::from_utf8_lossy(&bytes).into_owned(),
+            ),
+            Err(err) => self.notifications_widget.push(Notification::warning(
+                format!("Could not read original rdf.yaml: {err}"), None
+            )),
+        }
+        self.archive_browser_widget.set_archive(archive);
+    }
+
+    pub(crate) fn read_partial_archive(archive: &SharedZipArchive) -> Result<AppStateFromPartial>{
+        let model_rdf_bytes = Self::read_rdf_bytes(archive)?;
+        Self::read_partial_rdf(archive, &model_rdf_bytes)
+    }
+
+    /// Loads a (potentially incomplete) model rdf, resolving its file references against `archive`
+    pub(crate) fn read_partial_rdf(archive: &SharedZipArchive, model_rdf_bytes: &[u8]) -> Result<AppStateFromPartial>{
+        let yaml_deserializer = serde_yaml::Deserializer::from_slice(model_rdf_bytes);
+        let partial: PartialModelRdfV0_5 = ::serde_path_to_error::deserialize(yaml_deserializer)?;
+        let mut warnings = String::with_capacity(16 * 1024);
+        let state = LatestAppStateSavedData::from_partial(&archive, partial, &mut warnings); //FIXME: retrieve errors and notify
+        Ok(AppStateFromPartial { state, warnings})
+    }
+}
+
+
+/// The tensor descriptions of a model interface, which is all that its tile footprint depends on
+#[derive(Clone, Copy)]
+struct TensorDescrs<'a>(&'a rt::ModelInterface<ArcNpyArray>);
+
+impl std::hash::Hash for TensorDescrs<'_>{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for input in self.0.inputs().iter(){
+            serde_json::to_string(&input.tensor_meta).unwrap_or_default().hash(state);
+        }
+        for output in self.0.outputs().iter(){
+            serde_json::to_string(&output.tensor_meta).unwrap_or_default().hash(state);
+        }
+    }
+}
+
+#[derive(Default)]
+struct TileFootprintEstimator;
+
+impl egui::cache::ComputerMut<TensorDescrs<'_>, Vec<(usize, TileFootprint)>> for TileFootprintEstimator{
+    /// Footprints for a few choices of `n`, or none if no axis is parameterized
+    fn compute(&mut self, TensorDescrs(interface): TensorDescrs<'_>) -> Vec<(usize, TileFootprint)> {
+        if !interface.has_parameterized_axes(){
+            return vec![]
+        }
+        [0, 1, 2, 4, 8, 16, 32].into_iter()
+            .map_while(|n| interface.tile_footprint(n).ok().map(|footprint| (n, footprint)))
+            .collect()
+    }
+}
+
+/// Footprint estimates, recomputed when the tensor descriptions change
+type TileFootprintCache = FrameCache<Vec<(usize, TileFootprint)>, TileFootprintEstimator>;
+
+/// Asks the BioEngine to test `source`, blocking until the test finished, and describes the outcome
+#[cfg(not(target_arch="wasm32"))]
+fn run_remote_test(source: bioimg_zoo::remote_test::RemoteModelSource) -> TaskResult{
+    use bioimg_zoo::remote_test::RemoteTestSummary;
+
+    match crate::request::send_bytes(RemoteTestSummary::request(&source)){
+        Err(err) => TaskResult::err_message(format!("Could not reach the BioEngine: {err}")),
+        Ok(response) => match RemoteTestSummary::parse_response(&response){
+            Ok(summary) if summary.passed() => TaskResult::ok_message(format!("{source}: {summary}")),
+            Ok(summary) => TaskResult::err_message(format!("{source}: {summary}")),
+            Err(err) => TaskResult::err_message(format!("Bad response from the BioEngine: {err}")),
+        },
+    }
+}
+
+impl eframe::App for AppState1 {
+    fn save(&mut self, _storage: &mut dyn eframe::Storage) {
+        // eframe::set_value(storage, eframe::APP_KEY, self);
+    }
+
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        self.expansion_state.install(ctx);
+        #[cfg(not(target_arch="wasm32"))]
+        self.update_window_title(ctx);
+        #[cfg(not(target_arch="wasm32"))]
+        self.zoo_login_widget.update(); // keeps logging in while the Zoo menu is closed
+        DtypeSupport::from_weights(&self.weights_widget).install(ctx);
+        egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
+            egui::menu::bar(ui, |ui| {
+                #[cfg(not(target_arch="wasm32"))]
+                ui.menu_button("Zoo", |ui|{
+                    self.zoo_login_widget.draw_and_parse(ui, egui::Id::from("zoo login"));
+                    ui.add_enabled_ui(false, |ui|{
+                    let upload_button = egui::Button::new("⬆ Upload Model");
+                    let Ok(user_token) = self.zoo_login_widget.state() else {
+                        ui.add_enabled_ui(false, |ui|{
+                            ui.add(upload_button).on_disabled_hover_text("Please login first");
+                        });
+                        return;
+                    };
+                    let Some(packing_task) = self.zoo_model_creation_task.take() else {
+                        if !ui.add(upload_button).clicked(){
+                            return;
+                        }
+                        let model = match self.create_model(){
+                            Ok(model) => model,
+                            Err(err) => {
+                                self.notifications_widget.push(Notification::error(err.to_string(), None));
+                                return;
+                            }
+                        };
+                        let user_token = user_token.as_ref().clone();
+                        let sender = self.notifications_channel.sender().clone();
+                        let on_progress = move |msg: String|{
+                            sender.send(TaskResult::Notification(Ok(msg))).unwrap(); //FIXME: is there anything sensible to do if this fails?
+                        };
+                        self.zoo_model_creation_task = Some(
+                            std::thread::spawn(|| upload_model(user_token, model, on_progress))
+                        );
+                        return
+                    };
+                    if !packing_task.is_finished() {
+                        ui.add_enabled_ui(false, |ui|{
+                            ui.add(upload_button).on_disabled_hover_text("Uploading model...");
+                        });
+                        self.zoo_model_creation_task = Some(packing_task);
+                        return;
+                    }
+                    match packing_task.join().unwrap(){
+                        Ok(nickname) => self.notifications_widget.push(
+                            Notification::info(format!("Model successfully uploaded: {nickname}"), None)
+                        ),
+                        Err(upload_err) => self.notifications_widget.push(
+                            Notification::error(format!("Could not upload model: {upload_err}"), None)
+                        ),
+                    };
+                })});
+                ui.menu_button("File", |ui| {
+                    ui.menu_button("🗋 New from template", |ui|{
+                        use strum::VariantArray;
+                        for template in ModelTemplate::VARIANTS.iter().copied(){
+                            if ui.button(template.to_string()).on_hover_text(template.description()).clicked(){
+                                ui.close_menu();
+                                self.load_template(template);
+                            }
+                        }
+                    });
+                    #[cfg(not(target_arch="wasm32"))]
+                    if ui.button("🔍 Example Models")
+                        .on_hover_text("Inspect well-formed models from the zoo to see how their fields are usually filled in")
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        self.example_gallery_widget.is_open = true;
+                    }
+                    if ui.button("📦⤴ Import Model")
+                        .on_hover_text("Import a .zip model file, like the ones you'd get from bioimage.io")
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        let sender = self.notifications_channel.sender().clone();
+
+                        #[cfg(target_arch="wasm32")]
+                        wasm_bindgen_futures::spawn_local(async move {
+                            use bioimg_runtime::zip_archive_ext::SharedZipArchive;
+
+                            if let Some(handle) = rfd::AsyncFileDialog::new().add_filter("bioimage model", &["zip"],).pick_file().await {
+                                let contents = handle.read().await;
+                                let shared_archive = SharedZipArchive::from_raw_data(contents, handle.file_name());
+                                let message = match rt::zoo_model::ZooModel::try_load_archive(shared_archive.clone()){
+                                    Err(err) => TaskResult::Notification(Err(format!("Could not import model: {err}"))),
+                                    Ok(zoo_model) => TaskResult::ModelImport(Box::new(zoo_model), shared_archive),
+                                };
+                                sender.send(message).unwrap();
+                            }
+                        });
+
+                        #[cfg(not(target_arch="wasm32"))]
+                        if let Some(model_path) = rfd::FileDialog::new().add_filter("bioimage model", &["zip"],).pick_file() {
                             let model_path_str = model_path.to_string_lossy();
-                            let message = match rt::zoo_model::ZooModel::try_load(&model_path){
+                            let loaded = SharedZipArchive::open(&model_path)
+                                .map_err(|err| err.to_string())
+                                .and_then(|archive|{
+                                    rt::zoo_model::ZooModel::try_load_archive(archive.clone())
+                                        .map(|zoo_model| (zoo_model, archive))
+                                        .map_err(|err| err.to_string())
+                                });
+                            let message = match loaded{
                                 Err(err) => TaskResult::Notification(Err(format!("Could not import model {model_path_str}: {err}"))),
-                                Ok(zoo_model) => TaskResult::ModelImport(Box::new(zoo_model)),
+                                Ok((zoo_model, archive)) => TaskResult::ModelImport(Box::new(zoo_model), archive),
+                            };
+                            sender.send(message).unwrap();
+                        }
+                    }
+                    if ui.button("🏋⤴ Import Weights from Another Model")
+                        .on_hover_text(
+                            "Replace only the weights of the current draft with the ones of another model .zip, \
+                            e.g. after retraining the network. Metadata and inputs/outputs are kept as they are"
+                        )
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        let sender = self.notifications_channel.sender().clone();
+
+                        #[cfg(target_arch="wasm32")]
+                        wasm_bindgen_futures::spawn_local(async move {
+                            if let Some(handle) = rfd::AsyncFileDialog::new().add_filter("bioimage model", &["zip"],).pick_file().await {
+                                let contents = handle.read().await;
+                                let shared_archive = SharedZipArchive::from_raw_data(contents, handle.file_name());
+                                let message = match rt::zoo_model::ZooModel::try_load_archive(shared_archive){
+                                    Err(err) => TaskResult::err_message(format!("Could not import weights: {err}")),
+                                    Ok(zoo_model) => TaskResult::weights_import(zoo_model, handle.file_name()),
+                                };
+                                sender.send(message).unwrap();
+                            }
+                        });
+
+                        #[cfg(not(target_arch="wasm32"))]
+                        if let Some(model_path) = rfd::FileDialog::new().add_filter("bioimage model", &["zip"],).pick_file() {
+                            let model_path_str = model_path.to_string_lossy().into_owned();
+                            let message = match rt::zoo_model::ZooModel::try_load(&model_path){
+                                Err(err) => TaskResult::err_message(format!("Could not import weights from {model_path_str}: {err}")),
+                                Ok(zoo_model) => TaskResult::weights_import(zoo_model, model_path_str),
                             };
                             sender.send(message).unwrap();
                         }
@@ -570,7 +2231,7 @@ impl eframe::App for AppState1 {
                             // };
                             let message = match Self::load_partial_model(&archive) {
                                 Err(err) => TaskResult::Notification(Err(format!("Could not recover model: {err}"))),
-                                Ok(state_from_partial) => TaskResult::PartialModelLoad(state_from_partial),
+                                Ok(state_from_partial) => TaskResult::PartialModelLoad(state_from_partial, archive),
                             };
                             sender.send(message).unwrap();
                         };
@@ -579,16 +2240,33 @@ impl eframe::App for AppState1 {
                         #[cfg(not(target_arch="wasm32"))]
                         std::thread::spawn(move || smol::block_on(fut));
                     }
+                    let has_imported_archive = self.archive_browser_widget.archive().is_some();
+                    let browse_clicked = ui.add_enabled(has_imported_archive, egui::Button::new("🗀 Browse Imported Archive"))
+                        .on_hover_text("List the files inside the archive of the last imported or recovered model")
+                        .on_disabled_hover_text("Import or recover a model first")
+                        .clicked();
+                    if browse_clicked {
+                        ui.close_menu();
+                        self.archive_browser_widget.is_open = true;
+                    }
+                    let view_rdf_clicked = ui.add_enabled(self.original_rdf_widget.has_rdf(), egui::Button::new("📄 View Original rdf.yaml"))
+                        .on_hover_text("Show the rdf.yaml of the last imported or recovered model as it was inside its archive")
+                        .on_disabled_hover_text("Import or recover a model first")
+                        .clicked();
+                    if view_rdf_clicked {
+                        ui.close_menu();
+                        self.original_rdf_widget.is_open = true;
+                    }
                     #[cfg(not(target_arch="wasm32"))]
                     if ui.button("🗊⤵ Save Draft ")
                         .on_hover_text("Save your current work as-is, even with unresolved errors")
                         .clicked()
                     { 'save_project: {
                         ui.close_menu();
-                        let Some(path) = rfd::FileDialog::new().set_file_name("MyDraft.bmb").save_file() else {
+                        let Some(path) = self.draft_status.save_dialog().save_file() else {
                             break 'save_project;
                         };
-                        let result = self.save_project(&path);
+                        let result = self.save_draft_to(&path);
                         self.notifications_widget.push(result.into());
                     }}
                     #[cfg(not(target_arch="wasm32"))]
@@ -604,24 +2282,132 @@ impl eframe::App for AppState1 {
                             self.notifications_widget.push(Notification::error(err, None));
                         }
                     }}
+                    #[cfg(not(target_arch="wasm32"))]
+                    {
+                        let cache = bioimg_runtime::download_cache::DownloadCache::global();
+                        let clear_clicked = ui.button("🗑 Clear Download Cache")
+                            .on_hover_ui(|ui|{
+                                ui.label("Delete local copies of files that were downloaded from URLs, like remote weights");
+                                ui.weak(format!("Cache directory: {}", cache.root().to_string_lossy()));
+                                if let Ok(size) = cache.size_bytes(){
+                                    ui.weak(format!("Currently using {:.1} MB", size as f64 / 1_000_000.0));
+                                }
+                            })
+                            .clicked();
+                        if clear_clicked{
+                            ui.close_menu();
+                            let notification = match cache.clear(){
+                                Ok(()) => Notification::info("Download cache cleared".to_owned(), None),
+                                Err(err) => Notification::error(format!("Could not clear download cache: {err}"), None),
+                            };
+                            self.notifications_widget.push(notification);
+                        }
+                    }
                 });
                 ui.menu_button("View", |ui|{
                     egui::widgets::global_theme_preference_buttons(ui);
+                    if ui.button("🔎 Find field (Ctrl+F)").clicked(){
+                        self.field_finder_widget.is_open = true;
+                        ui.close_menu();
+                    }
+                    let mut guided_mode = self.wizard_step.is_some();
+                    if ui.checkbox(&mut guided_mode, "Guided Mode")
+                        .on_hover_text("Walk through the required fields one section at a time")
+                        .changed()
+                    {
+                        self.wizard_step = guided_mode.then_some(WizardStep::default());
+                        ui.close_menu();
+                    }
+                    let mut yaml_mode = self.yaml_mode.is_active;
+                    if ui.checkbox(&mut yaml_mode, "YAML Mode (expert)")
+                        .on_hover_text("Edit the whole model description as yaml text instead of using the form")
+                        .changed()
+                    {
+                        if yaml_mode {
+                            self.enter_yaml_mode();
+                        } else {
+                            self.leave_yaml_mode();
+                        }
+                        ui.close_menu();
+                    }
+                    #[cfg(not(target_arch="wasm32"))]
+                    {
+                        let mut check_urls = crate::widgets::url_reachability::is_enabled(ui.ctx());
+                        if ui.checkbox(&mut check_urls, "Check URLs")
+                            .on_hover_text("Sends a request to every URL in the form, to warn about the ones that can't be reached")
+                            .changed()
+                        {
+                            crate::widgets::url_reachability::set_enabled(ui.ctx(), check_urls);
+                        }
+                    }
                 });
                 ui.menu_button("About", |ui|{
                     ui.label(format!("bioimage.io model builder version {}", env!("CARGO_PKG_VERSION")))
                 });
             });
         });
+        #[cfg(not(target_arch="wasm32"))]
+        self.example_gallery_widget.show(ctx);
+        self.original_rdf_widget.show(ctx);
+        self.export_preview_widget.show(ctx);
+        self.field_finder_widget.show(ctx);
+        #[cfg(not(target_arch="wasm32"))]
+        self.draw_import_save_confirmation(ctx);
+        match self.import_conflict_widget.show(ctx){
+            None => (),
+            Some(Ok((merged, archive))) => {
+                self.restore_into_draft(merged);
+                self.set_imported_archive(archive);
+            },
+            Some(Err(err)) => self.notifications_widget.push(Notification::error(err.to_string(), None)),
+        }
+        if let Some(entry_path) = self.archive_browser_widget.show(ctx){
+            self.launch_entry_extraction(entry_path);
+        }
+
         egui::CentralPanel::default().show(ctx, |ui| {
             while let Ok(msg) = self.notifications_channel.receiver().try_recv(){
                 match msg{
                     TaskResult::Notification(msg) => self.notifications_widget.push(msg.into()),
-                    TaskResult::ModelImport(model) => self.set_value(*model),
-                    TaskResult::PartialModelLoad(AppStateFromPartial{state, warnings}) => {
-                        self.restore(state);
-                        self.notifications_widget.push(Notification::warning(warnings, None));
-                    }
+                    TaskResult::ModelImport(model, archive) => {
+                        self.receive_import(IncomingModel::Full(model, archive));
+                    },
+                    TaskResult::PartialModelLoad(recovered, archive) => {
+                        self.receive_import(IncomingModel::Recovered(recovered, archive));
+                    },
+                    TaskResult::OnnxConversion(onnx_weights) => {
+                        let opset_version = onnx_weights.opset_version.clone();
+                        self.weights_widget.onnx_weights_widget.set_value(Some(*onnx_weights));
+                        #[cfg(not(target_arch="wasm32"))]
+                        self.draft_status.mark_edited();
+                        self.notifications_widget.push(Notification::info(
+                            format!("Added ONNX weights (opset {opset_version}) converted from the TorchScript ones"), None
+                        ));
+                    },
+                    #[cfg(not(target_arch="wasm32"))]
+                    TaskResult::TestRunRecorded(record) => {
+                        self.pending_test_record = Some(*record);
+                    },
+                    TaskResult::WeightsImport{weights, source_name, tensor_ids} => {
+                        self.weights_widget.set_value(*weights);
+                        #[cfg(not(target_arch="wasm32"))]
+                        self.draft_status.mark_edited();
+                        let current_tensor_ids: Option<Vec<String>> = self.model_interface_widget.get_value().ok()
+                            .map(|interface| interface.tensor_ids().into_iter().map(|id| id.to_string()).collect());
+                        let notification = match current_tensor_ids{
+                            Some(current_tensor_ids) if current_tensor_ids != tensor_ids => Notification::warning(
+                                format!(
+                                    "Imported weights from {source_name}, but that model has tensors [{}] while this one has [{}]. \
+                                    Make sure the weights really fit the inputs and outputs of this model",
+                                    tensor_ids.join(", "),
+                                    current_tensor_ids.join(", "),
+                                ),
+                                None,
+                            ),
+                            _ => Notification::info(format!("Imported weights from {source_name}"), None),
+                        };
+                        self.notifications_widget.push(notification);
+                    },
                 }
             }
             if let Some(error_rect) = self.notifications_widget.draw(ui, egui::Id::from("messages_widget")){
@@ -630,244 +2416,24 @@ impl eframe::App for AppState1 {
 
             ui.style_mut().spacing.item_spacing = egui::Vec2 { x: 10.0, y: 10.0 };
             egui::ScrollArea::vertical().show(ui, |ui| {
-                ui.heading("Model Metadata");
-                ui.separator();
-
-                ui.horizontal_top(|ui| {
-                    ui.strong("Name: ").on_hover_text(
-                        "A human-friendly name of the resource description. \
-                        May only contains letters, digits, underscore, minus, parentheses and spaces."
-                    );
-                    self.staging_name.draw_and_parse(ui, egui::Id::from("Name"));
-                    let _name_result = self.staging_name.state();
-                });
-
-                ui.horizontal_top(|ui| {
-                    ui.strong("Description: ").on_hover_text("A brief description of the model.");
-                    self.staging_description.draw_and_parse(ui, egui::Id::from("Name"));
-                    let _description_result = self.staging_description.state();
-                });
-
-                ui.horizontal_top(|ui| {
-                    ui.strong("Cover Images: ").on_hover_text(
-                        "Images to be shown to users on the model zoo, preferrably showing what the input \
-                        and output look like."
-                    );
-                    let covers_base_id = egui::Id::from("cover images");
-                    let vec_widget = VecWidget{
-                        items: &mut self.cover_images,
-                        item_label: "Cover Image",
-                        min_items: 1,
-                        show_reorder_buttons: true,
-                        new_item: Some(SpecialImageWidget::default),
-                        item_renderer: VecItemRender::HeaderAndBody{
-                            render_header: |widget: &mut SpecialImageWidget<_>, idx, ui|{
-                                ui.horizontal(|ui|{
-                                    ui.weak(format!("Cover image #{idx}"));
-                                    ui.add_space(3.0);
-                                    widget.summarize(ui, covers_base_id.with(idx));
-                                });
-                            },
-                            render_body: |widg: &mut SpecialImageWidget<_>, idx, ui|{
-                                widg.draw_and_parse(ui, covers_base_id.with(("body".as_ptr(), idx)));
-                            },
-                            collapsible_id_source: Some(covers_base_id),
-                            marker: Default::default(),
-                        }
-                    };
-                    ui.add(vec_widget);
-                });
-
-                ui.horizontal_top(|ui| {
-                    ui.strong("Model Id: ").on_hover_text(
-                        "A model zoo id of the form <adjective>-<animal>, like 'affable-shark'.\
-                        If you're creating a model from scratch, leave this empty and an id will be generated \
-                        for you when you upload your model to the zoo."
-                    );
-                    self.model_id_widget.draw_and_parse(ui, egui::Id::from("Model Id"));
-                    // let cover_img_results = self.cover_images.state();
-                });
-
-                ui.horizontal_top(|ui| {
-                    let authors_base_id = egui::Id::from("authors");
-                    ui.strong("Authors: ").on_hover_text(
-                        "The authors are the creators of this resource description and the primary points of contact."
-                    );
-                    let vec_widget = VecWidget{
-                        items: &mut self.staging_authors,
-                        item_label: "Author",
-                        min_items: 1,
-                        show_reorder_buttons: true,
-                        new_item: Some(AuthorWidget::default),
-                        item_renderer: VecItemRender::HeaderAndBody{
-                            render_header: |widg: &mut AuthorWidget, idx, ui|{
-                                widg.summarize(ui, authors_base_id.with(("header".as_ptr(), idx)));
-                            },
-                            render_body: |widg: &mut AuthorWidget, idx, ui|{
-                                widg.draw_and_parse(ui, authors_base_id.with(("body".as_ptr(), idx)));
-                            },
-                            collapsible_id_source: Some(authors_base_id),
-                            marker: Default::default(),
-                        }
-                    };
-                    ui.add(vec_widget);
-                });
-
-                ui.horizontal_top(|ui| {
-                    let attachments_base_id = egui::Id::from("attachments");
-                    ui.strong("Attachments: ").on_hover_text(
-                        "Any other files that are relevant to your model can be listed as 'attachments'"
-                    );
-                    let vec_widget = VecWidget{
-                        items: &mut self.attachments_widget,
-                        min_items: 0,
-                        item_label: "Attachment",
-                        show_reorder_buttons: true,
-                        new_item: Some(AttachmentsWidget::default),
-                        item_renderer: VecItemRender::HeaderAndBody{
-                            render_header: |widg: &mut AttachmentsWidget, idx, ui|{
-                                widg.summarize(ui, attachments_base_id.with(("header".as_ptr(), idx)));
-                            },
-                            render_body: |widg: &mut AttachmentsWidget, idx, ui|{
-                                widg.draw_and_parse(ui, attachments_base_id.with(("body".as_ptr(), idx)));
-                            },
-                            collapsible_id_source: Some(attachments_base_id),
-                            marker: Default::default(),
-                        }
-                    };
-                    ui.add(vec_widget);
-                });
-
-                ui.horizontal_top(|ui| {
-                    let cite_base_id = egui::Id::from("cite");
-                    ui.strong("Cite: ").on_hover_text("How this model should be cited in other publications.");
-
-                    let vec_widget = VecWidget{
-                        items: &mut self.staging_citations,
-                        min_items: 1,
-                        item_label: "Citation Entry",
-                        show_reorder_buttons: true,
-                        new_item: Some(CiteEntryWidget::default),
-                        item_renderer: VecItemRender::HeaderAndBody{
-                            render_header: |widg: &mut CiteEntryWidget, idx, ui|{
-                                widg.summarize(ui, cite_base_id.with(("header".as_ptr(), idx)));
-                            },
-                            render_body: |widg: &mut CiteEntryWidget, idx, ui|{
-                                widg.draw_and_parse(ui, cite_base_id.with(("body".as_ptr(), idx)));
-                            },
-                            collapsible_id_source: Some(cite_base_id),
-                            marker: Default::default(),
-                        }
-                    };
-                    ui.add(vec_widget);
-                });
-
-                ui.horizontal_top(|ui| {
-                    ui.weak("Custom configs: ").on_hover_text(
-                        "A JSON value representing any extra, 'proprietary' parameters your model might need during runtime. \
-                        This field is still available for legacy reasons and its use is strongly discouraged"
-                    );
-                    self.custom_config_widget.draw_and_parse(ui, egui::Id::from("Custom configs"));
-                    // let citation_results = self.staging_citations.state();
-                });
-
-                ui.horizontal_top(|ui| {
-                    ui.strong("Git Repo: ").on_hover_text(
-                        "A URL to the git repository with the source code that produced this model"
-                    );
-                    self.staging_git_repo.draw_and_parse(ui, egui::Id::from("Git Repo"));
-                    // let git_repo_result = self.staging_git_repo.state();
-                });
-
-                ui.horizontal_top(|ui| {
-                    ui.strong("Icon: ").on_hover_text(indoc!("
-                        An icon for quick identification on bioimage.io.
-                        This can either be an emoji or a small square image."
-                    ));
-                    self.icon_widget.draw_and_parse(ui, egui::Id::from("Icon"));
-                });
-
-                ui.horizontal_top(|ui| {
-                    ui.strong("Model Zoo Links: ").on_hover_text("IDs of other bioimage.io resources");
-                    group_frame(ui, |ui| {
-                        self.links_widget.draw_and_parse(ui, egui::Id::from("Model Zoo Links"));
-                    });
-                });
-
-                ui.horizontal_top(|ui| {
-                    let maintainers_base_id = egui::Id::from("maintainers");
-                    ui.strong("Maintainers: ").on_hover_text(
-                        "Maintainers of this resource. If not specified, 'authors' are considered maintainers \
-                        and at least one of them must specify their `github_user` name."
-                    );
-
-                    let vec_widget = VecWidget{
-                        items: &mut self.staging_maintainers,
-                        min_items: 0,
-                        item_label: "Maintainer",
-                        show_reorder_buttons: true,
-                        new_item: Some(MaintainerWidget::default),
-                        item_renderer: VecItemRender::HeaderAndBody{
-                            render_header: |widg: &mut MaintainerWidget, idx, ui|{
-                                widg.summarize(ui, maintainers_base_id.with(("header".as_ptr(), idx)));
-                            },
-                            render_body: |widg: &mut MaintainerWidget, idx, ui|{
-                                widg.draw_and_parse(ui, maintainers_base_id.with(("body".as_ptr(), idx)));
-                            },
-                            collapsible_id_source: Some(maintainers_base_id),
-                            marker: Default::default(),
-                        }
-                    };
-                    ui.add(vec_widget);
-                });
-
-                ui.horizontal_top(|ui| {
-                    ui.strong("Tags: ").on_hover_text("Tags to help search and classifying your model in the model zoo");
-                    self.staging_tags.draw_and_parse(ui, egui::Id::from("Tags"));
-                });
-
-                ui.horizontal_top(|ui| {
-                    ui.strong("Resource Version: ").on_hover_ui(|ui|{
-                        ui.horizontal(|ui|{
-                            ui.label("The version of this model, following");
-                            ui.hyperlink_to("SermVer 2.0", "https://semver.org/#semantic-versioning-200");
-                        });
-
-                        ui.label(indoc!("
-                            If you upload an updated version of this model to the zoo, you should bump this version \
-                            to differentiate it from the previous uploads"
-                        ));
-                    });
-                    self.staging_version.draw_and_parse(ui, egui::Id::from("Version"));
-                });
-
-                ui.horizontal_top(|ui| {
-                    ui.strong("Resource Version comment: ").on_hover_text(indoc!(
-                        "
-                        A comment about what changed in this version of this model.
-                        Here you can explain why you bumped the resource version"
-                    ));
-
-                    self.staging_version_comment
-                        .draw_and_parse(ui, egui::Id::from("Version Comment"));
-                });
-
-                ui.horizontal(|ui| {
-                    ui.strong("License: ").on_hover_text("A standard software licence, specifying how this model can be used and for what purposes.");
-                    self.staging_license.draw_and_parse(ui, egui::Id::from("License"));
+                if self.yaml_mode.is_active{
+                    self.draw_yaml_mode(ui);
+                    return
+                }
+                if let Some(wizard_step) = self.wizard_step{
+                    self.draw_wizard(ui, wizard_step);
+                    return
+                }
+                self.completeness().draw(ui, egui::Id::from("completeness"));
+                self.draw_metadata(ui);
+
+                ui.horizontal(|ui|{
+                    ui.heading("Model Interface");
+                    draw_section_badge(ui, &self.interface_errors());
+                    ui.add_space(20.0);
+                    ui.label("Weights");
+                    draw_section_badge(ui, &self.weights_errors());
                 });
-                ui.add_space(20.0);
-
-
-                ui.heading("Documentation (markdown): ").on_hover_text(
-                    "All model documentation should be written here. This field accepts Markdown syntax"
-                );
-                ui.separator();
-                self.staging_documentation.draw_and_parse(ui, egui::Id::from("Documentation"));
-                ui.add_space(20.0);
-
-
-                ui.heading("Model Interface");
                 ui.separator();
                 egui::ScrollArea::horizontal().show(ui, |ui|{
                     self.pipeline_widget.draw(
@@ -877,27 +2443,23 @@ impl eframe::App for AppState1 {
                         &mut self.weights_widget,
                     );
                 });
+                self.model_interface_widget.draw_bulk_scale_editor(ui, egui::Id::from("bulk scale of all tensors"));
+                self.draw_tile_memory_estimate(ui);
                 ui.add_space(20.0);
 
                 ui.separator();
 
-                let save_button_clicked = ui.button("Export Model ⤵📦")
-                    .on_hover_text("Exports this model to a .zip file, ready to be used or uploaded to the Model Zoo")
-                    .clicked();
-
-                if save_button_clicked {
-                    match self.create_model(){
-                        Ok(zoo_model) => self.launch_model_saving(zoo_model),
-                        Err(err) => self.notifications_widget.push(
-                            Notification::error(format!("Could not create zoo model: {err}"), None)
-                        ),
-                    }
-                }
+                self.draw_export_button(ui);
             });
         });
 
         let close_requested = ctx.input(|i| i.viewport().close_requested());
+        #[cfg(not(target_arch="wasm32"))]
+        let has_unsaved_changes = close_requested && self.draft_status.has_unsaved_changes();
+        #[cfg(target_arch="wasm32")]
+        let has_unsaved_changes = true;
         self.exiting_status = match self.exiting_status {
+            ExitingStatus::NotExiting if close_requested && !has_unsaved_changes => ExitingStatus::Exiting,
             ExitingStatus::NotExiting => {
                 if close_requested {
                     ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
@@ -926,11 +2488,11 @@ impl eframe::App for AppState1 {
                     ui.label("Save draft before quitting?");
                     ui.horizontal(|ui| {
                         if ui.button("Yes 💾").clicked() || ui.input(|i| i.key_pressed(egui::Key::Enter)){ 'save_draft: {
-                            let Some(path) = rfd::FileDialog::new().set_file_name("MyDraft.bmb").save_file() else {
+                            let Some(path) = self.draft_status.save_dialog().save_file() else {
                                 self.exiting_status = ExitingStatus::NotExiting;
                                 break 'save_draft;
                             };
-                            let result = self.save_project(&path);
+                            let result = self.save_draft_to(&path);
                             if result.is_ok(){
                                 self.exiting_status = ExitingStatus::Exiting;
                             }
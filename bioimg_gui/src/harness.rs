@@ -0,0 +1,149 @@
+//! Drives the model builder headlessly, e.g. for integration tests or for filling out
+//! the form from a script. Enabled by the `harness` feature.
+//!
+//! Everything goes through the same code paths as the interactive app: imports are delivered
+//! via the app's task channel and only take effect on the next frame, and errors end up as
+//! notifications, which can be inspected with [AppHarness::notifications].
+
+use std::path::Path;
+
+use bioimg_runtime::zip_archive_ext::SharedZipArchive;
+use bioimg_runtime::zoo_model::ZooModel;
+
+use crate::app::{AppState1, TaskResult};
+use crate::widgets::notice_widget::Notification;
+
+/// Number of frames rendered after each action, so that its effects settle
+const SETTLE_FRAMES: usize = 3;
+
+pub struct AppHarness{
+    harness: egui_kittest::Harness<'static, AppState1>,
+}
+
+impl Default for AppHarness{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AppHarness{
+    /// An app with a blank form
+    pub fn new() -> Self{
+        let harness = egui_kittest::Harness::builder()
+            .with_size(egui::vec2(1280.0, 1024.0))
+            .build_eframe(|_cc| AppState1::default());
+        let mut out = Self{harness};
+        out.run_frames(SETTLE_FRAMES);
+        out
+    }
+
+    /// Renders `num_frames` frames. The app may keep requesting repaints (e.g. while notifications
+    /// fade out), so this doesn't wait for it to become idle
+    pub fn run_frames(&mut self, num_frames: usize){
+        for _ in 0..num_frames{
+            self.harness.step();
+        }
+    }
+
+    pub fn app(&self) -> &AppState1{
+        self.harness.state()
+    }
+
+    pub fn app_mut(&mut self) -> &mut AppState1{
+        self.harness.state_mut()
+    }
+
+    /// Access to the underlying [egui_kittest::Harness], e.g. to query or click widgets by their labels
+    pub fn kittest(&mut self) -> &mut egui_kittest::Harness<'static, AppState1>{
+        &mut self.harness
+    }
+
+    /// Modifies the app state directly, then lets the UI catch up
+    pub fn edit(&mut self, edit: impl FnOnce(&mut AppState1)){
+        edit(self.harness.state_mut());
+        self.run_frames(SETTLE_FRAMES);
+    }
+
+    fn send(&mut self, message: TaskResult){
+        self.app().notifications_channel.sender().send(message).unwrap();
+        self.run_frames(SETTLE_FRAMES);
+    }
+
+    /// Fills out the form with a complete model, like "File > Import Model" does
+    pub fn import_model(&mut self, model_path: &Path){
+        let loaded = SharedZipArchive::open(model_path)
+            .map_err(|err| err.to_string())
+            .and_then(|archive|{
+                ZooModel::try_load_archive(archive.clone())
+                    .map(|zoo_model| (zoo_model, archive))
+                    .map_err(|err| err.to_string())
+            });
+        self.send(match loaded{
+            Err(err) => TaskResult::Notification(Err(format!("Could not import model {}: {err}", model_path.to_string_lossy()))),
+            Ok((zoo_model, archive)) => TaskResult::ModelImport(Box::new(zoo_model), archive),
+        });
+    }
+
+    /// Fills out the form with whatever can be salvaged from a (potentially invalid) model, like
+    /// "File > Recover Model" does
+    pub fn recover_model(&mut self, model_path: &Path){
+        let loaded = SharedZipArchive::open(model_path)
+            .map_err(|err| err.to_string())
+            .and_then(|archive|{
+                AppState1::read_partial_archive(&archive)
+                    .map(|partial| (partial, archive))
+                    .map_err(|err| err.to_string())
+            });
+        self.send(match loaded{
+            Err(err) => TaskResult::Notification(Err(format!("Could not recover model {}: {err}", model_path.to_string_lossy()))),
+            Ok((partial, archive)) => TaskResult::PartialModelLoad(partial, archive),
+        });
+    }
+
+    /// Fills out the form from a (potentially incomplete) rdf.yaml. File references are resolved
+    /// against the last imported model, if any
+    pub fn fill_from_rdf_yaml(&mut self, raw_rdf: &str){
+        let loaded = self.app().yaml_mode_archive(raw_rdf)
+            .and_then(|archive|{
+                AppState1::read_partial_rdf(&archive, raw_rdf.as_bytes()).map(|partial| (partial, archive))
+            });
+        self.send(match loaded{
+            Err(err) => TaskResult::Notification(Err(format!("Could not load rdf.yaml: {err}"))),
+            Ok((partial, archive)) => TaskResult::PartialModelLoad(partial, archive),
+        });
+    }
+
+    /// Builds a model out of the current state of the form, like the "Export Model" button does
+    /// before asking where to save it. Failures are also pushed as notifications
+    pub fn create_model(&mut self) -> Result<ZooModel, String>{
+        let result = self.app().create_model().map_err(|err| err.to_string());
+        if let Err(err) = &result{
+            self.app_mut().notifications_widget.push(
+                Notification::error(format!("Could not create zoo model: {err}"), None)
+            );
+        }
+        self.run_frames(SETTLE_FRAMES);
+        result
+    }
+
+    /// Builds a model out of the current state of the form and packs it into `output`
+    pub fn export_model(&mut self, output: &Path) -> Result<(), String>{
+        let zoo_model = self.create_model()?;
+        let file = std::fs::File::create(output).map_err(|err| err.to_string())?;
+        zoo_model.pack_into(file).map_err(|err| err.to_string())
+    }
+
+    /// The notifications currently on screen, oldest first
+    pub fn notifications(&self) -> Vec<String>{
+        self.app().notifications_widget.texts().map(|text| text.to_owned()).collect()
+    }
+
+    /// Panics unless a notification containing `needle` is currently on screen
+    #[track_caller]
+    pub fn assert_notification(&self, needle: &str){
+        let notifications = self.notifications();
+        if !notifications.iter().any(|text| text.contains(needle)){
+            panic!("No notification containing {needle:?}. Notifications: {notifications:#?}");
+        }
+    }
+}
@@ -4,5 +4,9 @@ pub mod app;
 mod result;
 mod widgets;
 mod project_data;
+mod templates;
+mod address_book;
 #[cfg(not(target_arch="wasm32"))]
 mod request;
+#[cfg(all(feature="harness", not(target_arch="wasm32")))]
+pub mod harness;
@@ -9,8 +9,12 @@ fn main() -> eframe::Result<()> {
     #[derive(Parser, Debug)]
     #[command(version, about, long_about = None)]
     struct Args {
+        /// Where to keep files downloaded from URLs. Defaults to $BIOIMG_CACHE_DIR or the platform's cache directory
+        #[arg(long)]
+        cache_dir: Option<std::path::PathBuf>,
     }
-    let _args = Args::parse();
+    let args = Args::parse();
+    bioimg_runtime::download_cache::DownloadCache::configure(args.cache_dir);
 
     env_logger::init(); // Log to stderr (if you run with `RUST_LOG=debug`).
 
@@ -89,6 +89,21 @@ impl MaintainerWidgetSavedData {
     }
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct UploaderWidgetSavedData {
+    pub email_widget: String,
+    pub name_widget: Option<String>,
+}
+
+impl UploaderWidgetSavedData {
+    pub fn from_partial(_archive: &SharedZipArchive, partial: Partial<rdf::Uploader>) -> Self{
+        Self {
+            email_widget: partial.email.unwrap_or(String::new()),
+            name_widget: partial.name.unwrap_or(None),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Default)]
 pub enum FileWidgetSavedData{
     #[default]
@@ -101,6 +116,7 @@ pub enum TestTensorWidgetSavedData{
     #[default]
     Empty,
     Loaded{path: Option<PathBuf>, data: Vec<u8>},
+    Url{url: String},
 }
 
 impl TestTensorWidgetSavedData {
@@ -183,6 +199,56 @@ impl FileSourceWidgetSavedData {
     }
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+#[serde(from = "AttachmentsWidgetSavedDataMsg")]
+pub struct AttachmentsWidgetSavedData{
+    pub file_source_widget: FileSourceWidgetSavedData,
+    pub description_widget: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum AttachmentsWidgetSavedDataMsg{
+    Current{
+        file_source_widget: FileSourceWidgetSavedData,
+        #[serde(default)]
+        description_widget: Option<String>,
+    },
+    /// Attachments were saved as bare file sources before they had descriptions
+    Legacy(FileSourceWidgetSavedData),
+}
+
+impl From<AttachmentsWidgetSavedDataMsg> for AttachmentsWidgetSavedData{
+    fn from(msg: AttachmentsWidgetSavedDataMsg) -> Self {
+        match msg{
+            AttachmentsWidgetSavedDataMsg::Current{file_source_widget, description_widget} => {
+                Self{file_source_widget, description_widget}
+            },
+            AttachmentsWidgetSavedDataMsg::Legacy(file_source_widget) => {
+                Self{file_source_widget, description_widget: None}
+            },
+        }
+    }
+}
+
+impl AttachmentsWidgetSavedData{
+    pub fn from_partial(
+        archive: &SharedZipArchive,
+        partial: PartialFileDescription,
+        descriptions: &JsonMap,
+        warnings: &mut impl std::fmt::Write,
+    ) -> Self {
+        let description_widget = partial.source.as_ref()
+            .and_then(|source| descriptions.get(source))
+            .and_then(|descr| descr.as_str())
+            .map(|descr| descr.to_owned());
+        Self{
+            file_source_widget: FileSourceWidgetSavedData::from_partial_file_descr(archive, partial, warnings),
+            description_widget,
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub enum ImageWidget2LoadingStateSavedData{
     Empty,
@@ -453,15 +519,21 @@ pub struct ChannelAxisWidgetSavedData {
 
 impl ChannelAxisWidgetSavedData {
     pub fn from_partial(_archive: &SharedZipArchive, partial: Partial<rdf::model::ChannelAxis>) -> Self {
+        let staging_explicit_names: Vec<String> = match partial.channel_names {
+            Some(names) => names.into_iter().map(|name| name.to_string()).collect(),
+            None => vec![]
+        };
+        let pattern = modelrdf::ChannelNamePattern::detect(staging_explicit_names.iter().map(String::as_str));
         Self{
             description_widget: partial.description.to_string(),
-            channel_extent_widget: partial.channel_names.as_ref().map(|names| names.len()).unwrap_or_default(),
-            channel_names_mode_widget: ChannelNamesModeSavedData::Explicit,
-            staging_explicit_names: match partial.channel_names {
-                Some(names) => names.into_iter().map(|name| name.to_string()).collect(),
-                None => vec![]
+            channel_extent_widget: staging_explicit_names.len(),
+            channel_names_mode_widget: match pattern {
+                Some(_) => ChannelNamesModeSavedData::Pattern,
+                None => ChannelNamesModeSavedData::Explicit,
             },
-            ..Default::default()
+            channel_name_prefix_widget: pattern.as_ref().map(|pattern| pattern.prefix.clone()).unwrap_or_default(),
+            channel_name_suffix_widget: pattern.map(|pattern| pattern.suffix).unwrap_or_default(),
+            staging_explicit_names,
         }
     }
 }
@@ -489,13 +561,16 @@ impl InputSpaceAxisWidgetSavedData {
                     let Some(unit) = partial.unit else {
                         break 'unit None;
                     };
-                    let parsed_unit = match unit.parse::<modelrdf::SpaceUnit>() {
+                    let parsed_unit = match modelrdf::SpaceUnit::parse_lenient(&unit) {
                         Ok(parsed_unit) => parsed_unit,
                         Err(e) => {
                             _ = writeln!(warnings, "Could not parse spacial unit '{unit}': {e}");
                             break 'unit None
                         },
                     };
+                    if parsed_unit.to_string() != unit {
+                        _ = writeln!(warnings, "Interpreted spacial unit '{unit}' as '{parsed_unit}'");
+                    }
                     Some(parsed_unit)
                 }
             }
@@ -526,7 +601,7 @@ impl InputTimeAxisWidgetSavedData {
                     let Some(unit) = partial.unit else {
                         break 'unit None;
                     };
-                    let parsed_unit = match unit.parse::<modelrdf::TimeUnit>() {
+                    let parsed_unit = match modelrdf::TimeUnit::parse_lenient(&unit) {
                         Ok(parsed_unit) => parsed_unit,
                         Err(e) => {
                             log::warn!("Could not parse time unit '{unit}': {e}");
@@ -607,6 +682,8 @@ impl InputAxisWidgetSavedData {
 pub struct WeightsDescrBaseWidgetSavedData{
     pub source_widget: FileSourceWidgetSavedData,
     pub authors_widget: Option<Vec<CollapsibleWidgetSavedData<AuthorWidget>>>,
+    #[serde(default)]
+    pub parent_widget: Option<modelrdf::WeightsFormat>,
 }
 
 impl WeightsDescrBaseWidgetSavedData {
@@ -626,7 +703,10 @@ impl WeightsDescrBaseWidgetSavedData {
                 })
                 .collect::<Vec<_>>()
         });
-        Self{source_widget: source, authors_widget: authors}
+        let parent = partial.parent.and_then(|raw| {
+            serde_yaml::from_value(serde_yaml::Value::String(raw)).ok()
+        });
+        Self{source_widget: source, authors_widget: authors, parent_widget: parent}
     }
 }
 
@@ -660,6 +740,89 @@ impl JsonObjectEditorWidgetSavedData {
     }
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct RunModeWidgetSavedData{
+    pub name_widget: String,
+    pub kwargs_widget: JsonObjectEditorWidgetSavedData,
+}
+
+impl RunModeWidgetSavedData {
+    pub fn from_partial(archive: &SharedZipArchive, partial: Partial<modelrdf::RunMode>) -> Self {
+        Self{
+            name_widget: partial.name.unwrap_or_default(),
+            kwargs_widget: JsonObjectEditorWidgetSavedData::from_partial(archive, partial.kwargs),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct ParentModelWidgetSavedData{
+    pub id_widget: String,
+    pub version_number_widget: Option<u32>,
+}
+
+impl ParentModelWidgetSavedData {
+    pub fn from_partial(_archive: &SharedZipArchive, partial: Partial<modelrdf::LinkedModel>) -> Self {
+        Self{
+            id_widget: partial.id.unwrap_or_default(),
+            version_number_widget: partial.version_number,
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub enum TrainingDataModeSavedData{
+    #[default]
+    Linked,
+    Described,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct TrainingDataWidgetSavedData{
+    pub mode_widget: TrainingDataModeSavedData,
+    pub dataset_id_widget: String,
+    pub source_widget: Option<String>,
+}
+
+impl TrainingDataWidgetSavedData {
+    pub fn from_partial(
+        _archive: &SharedZipArchive,
+        partial: Partial<modelrdf::dataset_descr::DatasetDescrEnum>,
+    ) -> Self {
+        if let Some(linked) = partial.linked_dataset_descr{
+            return Self{
+                mode_widget: TrainingDataModeSavedData::Linked,
+                dataset_id_widget: linked.id.unwrap_or_default(),
+                source_widget: None,
+            }
+        }
+        if let Some(descr) = partial.dataset_descr{
+            return Self{
+                mode_widget: TrainingDataModeSavedData::Described,
+                dataset_id_widget: String::new(),
+                source_widget: descr.source,
+            }
+        }
+        Self::default()
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct TimestampWidgetSavedData{
+    pub imported: Option<String>,
+    pub refresh_on_export: bool,
+}
+
+impl TimestampWidgetSavedData {
+    pub fn from_partial(_archive: &SharedZipArchive, partial: Partial<iso8601_timestamp::Timestamp>) -> Self {
+        let raw = partial.to_string();
+        Self{
+            imported: if raw.is_empty() { None } else { Some(raw) },
+            refresh_on_export: false,
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Default)]
 pub struct CondaEnvEditorWidgetSavedData{
     pub code_editor_widget: CodeEditorWidgetSavedData,
@@ -879,6 +1042,8 @@ pub struct InputTensorWidgetSavedData {
     pub axis_widgets: Vec<InputAxisWidgetSavedData>,
     pub test_tensor_widget: TestTensorWidgetSavedData,
     pub preprocessing_widget: Vec<PreprocessingWidgetSavedData>,
+    #[serde(default)]
+    pub data_widget: Option<Vec<TensorDataWidgetSavedData>>,
 }
 
 impl InputTensorWidgetSavedData {
@@ -892,6 +1057,7 @@ impl InputTensorWidgetSavedData {
         let mut description_widget = String::new();
         let mut axis_widgets = Vec::<InputAxisWidgetSavedData>::new();
         let mut preprocessing_widget = Vec::<PreprocessingWidgetSavedData>::new();
+        let mut data_widget = None;
         
         if let Some(meta) = partial.meta {
             if let Some(id) = meta.id {
@@ -911,12 +1077,15 @@ impl InputTensorWidgetSavedData {
                     preprocessing_widget.push(PreprocessingWidgetSavedData::from_partial(archive, partial_preproc));
                 }
             }
+            if let Some(data) = meta.data {
+                data_widget = Some(TensorDataWidgetSavedData::from_partial_multiple(archive, data, warnings));
+            }
         }
         let test_tensor_widget = partial.test_tensor
             .map(|tt| TestTensorWidgetSavedData::from_partial(archive, tt, warnings))
             .unwrap_or_default();
 
-        Self{id_widget, is_optional, description_widget, axis_widgets, test_tensor_widget, preprocessing_widget}
+        Self{id_widget, is_optional, description_widget, axis_widgets, test_tensor_widget, preprocessing_widget, data_widget}
     }
 }
 
@@ -1011,6 +1180,110 @@ impl ClipWidgetSavedData {
     }
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct DataRangeWidgetSavedData{
+    pub min_widget: Option<String>,
+    pub max_widget: Option<String>,
+}
+
+impl DataRangeWidgetSavedData {
+    pub fn from_partial(_archive: &SharedZipArchive, partial: Partial<modelrdf::DataRange>) -> Self {
+        Self{
+            min_widget: partial.0.map(|min| min.to_string()),
+            max_widget: partial.1.map(|max| max.to_string()),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub enum TensorDataModeSavedData{
+    #[default]
+    IntervalOrRatio,
+    NominalOrOrdinal,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct TensorDataWidgetSavedData{
+    pub mode_widget: TensorDataModeSavedData,
+    pub data_type_widget: modelrdf::DataType,
+    pub range_widget: DataRangeWidgetSavedData,
+    pub unit_widget: String,
+    pub scale_widget: String,
+    pub offset_widget: Option<String>,
+    pub values_widget: Vec<String>,
+}
+
+impl TensorDataWidgetSavedData {
+    pub fn from_partial(
+        archive: &SharedZipArchive,
+        partial: Partial<modelrdf::tensor_data_descr::TensorDataDescr>,
+        warnings: &mut impl std::fmt::Write,
+    ) -> Self {
+        let parse_data_type = |raw_type: String, default: modelrdf::DataType, warnings: &mut dyn std::fmt::Write|{
+            if raw_type.is_empty(){
+                return default
+            }
+            raw_type.parse::<modelrdf::DataType>().unwrap_or_else(|e|{
+                _ = writeln!(warnings, "Could not parse data type '{raw_type}': {e}");
+                default
+            })
+        };
+        let unit_to_string = |partial_unit: Partial<modelrdf::tensor_data_descr::TensorDataUnit>|{
+            partial_unit.si.unwrap_or_default()
+        };
+
+        // the interval description has only optional fields, so prefer the nominal one when values are present
+        if let Some(nominal) = partial.nominal_or_ordinal.filter(|nominal| nominal.values.is_some()){
+            let values_widget = match nominal.values.unwrap(){
+                partial_values if partial_values.ints.is_some() => {
+                    partial_values.ints.unwrap().iter().map(|v| v.to_string()).collect()
+                },
+                partial_values if partial_values.bools.is_some() => {
+                    partial_values.bools.unwrap().iter().map(|v| v.to_string()).collect()
+                },
+                partial_values if partial_values.floats.is_some() => {
+                    partial_values.floats.unwrap().iter().map(|v| v.to_string()).collect()
+                },
+                partial_values => partial_values.strings.unwrap_or_default(),
+            };
+            return Self{
+                mode_widget: TensorDataModeSavedData::NominalOrOrdinal,
+                data_type_widget: parse_data_type(nominal.data_type, modelrdf::DataType::Uint8, warnings),
+                unit_widget: nominal.unit.map(unit_to_string).unwrap_or_default(),
+                scale_widget: 1.0.to_string(),
+                values_widget,
+                ..Default::default()
+            }
+        }
+
+        let Some(interval) = partial.interval_or_ratio else {
+            return Self{scale_widget: 1.0.to_string(), ..Default::default()}
+        };
+        Self{
+            mode_widget: TensorDataModeSavedData::IntervalOrRatio,
+            data_type_widget: parse_data_type(interval.data_type, modelrdf::DataType::Float32, warnings),
+            range_widget: DataRangeWidgetSavedData::from_partial(archive, interval.range),
+            unit_widget: unit_to_string(interval.unit),
+            scale_widget: interval.scale.to_string(),
+            offset_widget: interval.offset.map(|offset| offset.to_string()),
+            values_widget: vec![],
+        }
+    }
+
+    pub fn from_partial_multiple(
+        archive: &SharedZipArchive,
+        partial: PartialSingleOrMultiple<modelrdf::tensor_data_descr::TensorDataDescr>,
+        warnings: &mut impl std::fmt::Write,
+    ) -> Vec<Self> {
+        match partial{
+            PartialSingleOrMultiple::Single(descr) => vec![Self::from_partial(archive, descr, warnings)],
+            PartialSingleOrMultiple::Multiple(descrs) => descrs.into_iter()
+                .map(|descr| Self::from_partial(archive, descr, warnings))
+                .collect(),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Default)]
 pub enum ScaleLinearModeSavedData{
     #[default]
@@ -1387,7 +1660,7 @@ impl OutputTimeAxisWidgetSavedData {
                 let Some(raw_unit) = partial.unit else {
                     break 'unit None;
                 };
-                match raw_unit.parse::<modelrdf::TimeUnit>() {
+                match modelrdf::TimeUnit::parse_lenient(&raw_unit) {
                     Ok(unit) => Some(unit),
                     Err(e) => {
                         log::warn!("Could not parse time unit '{raw_unit}': {e}");
@@ -1423,7 +1696,7 @@ impl OutputSpaceAxisWidgetSavedData {
                     let Some(unit) = partial.unit else {
                         break 'unit None;
                     };
-                    let parsed_unit = match unit.parse::<modelrdf::SpaceUnit>() {
+                    let parsed_unit = match modelrdf::SpaceUnit::parse_lenient(&unit) {
                         Ok(parsed_unit) => parsed_unit,
                         Err(e) => {
                             log::warn!("Could not parse spacial unit '{unit}': {e}");
@@ -1623,6 +1896,8 @@ pub struct OutputTensorWidgetSavedData {
     pub axis_widgets: Vec<OutputAxisWidgetSavedData>,
     pub test_tensor_widget: TestTensorWidgetSavedData,
     pub postprocessing_widgets: Vec<CollapsibleWidgetSavedData<PostprocessingWidget>>,
+    #[serde(default)]
+    pub data_widget: Option<Vec<TensorDataWidgetSavedData>>,
 }
 
 impl OutputTensorWidgetSavedData {
@@ -1635,6 +1910,7 @@ impl OutputTensorWidgetSavedData {
         let mut description_widget = String::new();
         let mut axis_widgets = Vec::<OutputAxisWidgetSavedData>::new();
         let mut postprocessing_widgets = Vec::<CollapsibleWidgetSavedData<PostprocessingWidget>>::new();
+        let mut data_widget = None;
         
         if let Some(meta) = partial.metadata {
             if let Some(id) = meta.id {
@@ -1656,12 +1932,15 @@ impl OutputTensorWidgetSavedData {
                     postprocessing_widgets.push(widget);
                 }
             }
+            if let Some(data) = meta.data {
+                data_widget = Some(TensorDataWidgetSavedData::from_partial_multiple(archive, data, warnings));
+            }
         }
         let test_tensor_widget = partial.test_tensor
             .map(|tt| TestTensorWidgetSavedData::from_partial(archive, tt, warnings))
             .unwrap_or_default();
 
-        Self{id_widget, description_widget, axis_widgets, test_tensor_widget, postprocessing_widgets}
+        Self{id_widget, description_widget, axis_widgets, test_tensor_widget, postprocessing_widgets, data_widget}
     }
 }
 
@@ -1694,14 +1973,96 @@ impl ModelInterfaceWidgetSavedData {
     }
 }
 
-/// The data that will be persisted to disk when saving the model draft.
-/// It is an enum so that newer, incompatible versions can be added as additional
-/// variants, and older versions can still be recognized and converted to the newer
-/// ones.
-#[derive(serde::Serialize, serde::Deserialize, strum::VariantNames)]
-#[serde(tag = "app_state_raw_data_version")]
-pub enum AppStateSavedData{
-    Version1(AppState1SavedData),
+/// Declares an enum of all versions of some saved data, each in a variant named after the version,
+/// and generates the plumbing around it:
+///
+/// * serde tagging of the variant with the version name under `$tag`;
+/// * `supported_versions()`, `highest_supported_version()`, `save()` and `load()`, which refuses
+///   versions from the future with a [ProjectLoadError::FutureVersion];
+/// * `into_latest()`, which upgrades data of any version to the last one by chaining
+///   `From<VersionN> for VersionN+1` impls, which must be written by hand;
+/// * a `From` impl from, and an alias to, the type of the last version.
+///
+/// Adding a new version is then a matter of appending a variant and writing its `From` impl.
+macro_rules! versioned_saved_data {
+    (
+        $(#[$meta:meta])*
+        pub enum $enum_name:ident(tag = $tag:literal, latest = $latest_alias:ident){
+            $($variant:ident($data:ty)),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(serde::Serialize, serde::Deserialize, strum::VariantNames)]
+        #[serde(tag = $tag)]
+        pub enum $enum_name{
+            $($variant($data)),+
+        }
+
+        impl $enum_name{
+            /// The key under which the version name is saved
+            pub const VERSION_TAG: &'static str = $tag;
+
+            pub fn supported_versions() -> &'static [&'static str]{
+                <Self as strum::VariantNames>::VARIANTS
+            }
+
+            pub fn highest_supported_version() -> &'static str{
+                *Self::supported_versions().last().unwrap()
+            }
+
+            pub fn save(&self, writer: impl std::io::Write) -> Result<(), bson::ser::Error>{
+                let doc = bson::to_document(self)?;
+                doc.to_writer(writer)
+            }
+
+            pub fn load(reader: impl std::io::Read) -> Result<Self, ProjectLoadError>{
+                let doc: bson::Document = bson::from_reader(reader)?;
+                let found_version = match doc.get(Self::VERSION_TAG){
+                    Some(bson::Bson::String(version)) => version.to_owned(),
+                    _ => return Err(ProjectLoadError::MissingVersion)
+                };
+                if Self::supported_versions().iter().find(|ver| **ver == found_version.as_str()).is_none(){
+                    return Err(ProjectLoadError::FutureVersion { found_version })
+                }
+                Ok(bson::from_document::<Self>(doc)?)
+            }
+
+            versioned_saved_data!(@into_latest $enum_name [] $($variant($data),)+);
+        }
+
+        versioned_saved_data!(@latest $enum_name $latest_alias $($variant($data),)+);
+    };
+
+    // Accumulates one match arm per version, upgrading it to the next version and recursing
+    (@into_latest $enum_name:ident [$($arms:tt)*] $variant:ident($data:ty), $next_variant:ident($next_data:ty), $($rest:tt)*) => {
+        versioned_saved_data!(
+            @into_latest $enum_name
+            [$($arms)* $enum_name::$variant(data) => $enum_name::$next_variant(<$next_data>::from(data)).into_latest(),]
+            $next_variant($next_data), $($rest)*
+        );
+    };
+    (@into_latest $enum_name:ident [$($arms:tt)*] $variant:ident($data:ty),) => {
+        /// Upgrades the saved data to the latest version
+        pub fn into_latest(self) -> $data{
+            match self{
+                $($arms)*
+                $enum_name::$variant(data) => data,
+            }
+        }
+    };
+
+    (@latest $enum_name:ident $latest_alias:ident $variant:ident($data:ty), $($rest:tt)+) => {
+        versioned_saved_data!(@latest $enum_name $latest_alias $($rest)+);
+    };
+    (@latest $enum_name:ident $latest_alias:ident $variant:ident($data:ty),) => {
+        pub type $latest_alias = $data;
+
+        impl From<$data> for $enum_name{
+            fn from(data: $data) -> Self{
+                Self::$variant(data)
+            }
+        }
+    };
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -1716,32 +2077,13 @@ pub enum ProjectLoadError{
     FutureVersion{ found_version: String },
 }
 
-impl AppStateSavedData{
-    pub fn supported_versions() -> &'static [&'static str]{
-        <Self as strum::VariantNames>::VARIANTS        
-    }
-
-    pub fn highest_supported_version() -> &'static str{
-        *Self::supported_versions().last().unwrap()
-    }
-
-    pub fn save(&self, writer: impl std::io::Write) -> Result<(), bson::ser::Error>{
-        let doc = bson::to_document(self)?;
-        doc.to_writer(writer)
-    }
-
-    pub fn load(reader: impl std::io::Read) -> Result<Self, ProjectLoadError>{
-        let doc: bson::Document = bson::from_reader(reader)?;
-        // FIXME: this app_state_raw_data_version must be manually kep in sync
-        // with #[serde(tag=...)]. Maybe a catch-all variant would work?
-        let found_version = match doc.get("app_state_raw_data_version"){
-            Some(bson::Bson::String(version)) => version.to_owned(),
-            _ => return Err(ProjectLoadError::MissingVersion)
-        };
-        if Self::supported_versions().iter().find(|ver| **ver == found_version.as_str()).is_none(){
-            return Err(ProjectLoadError::FutureVersion { found_version })
-        }
-        Ok(bson::from_document::<Self>(doc)?)
+versioned_saved_data!{
+    /// The data that will be persisted to disk when saving the model draft.
+    /// It is an enum so that newer, incompatible versions can be added as additional
+    /// variants, and older versions can still be recognized and converted to the newer
+    /// ones.
+    pub enum AppStateSavedData(tag = "app_state_raw_data_version", latest = LatestAppStateSavedData){
+        Version1(AppState1SavedData),
     }
 }
 
@@ -1753,7 +2095,7 @@ pub struct AppState1SavedData{
     #[serde(default)] // added after AppState1SavedData
     pub model_id_widget: Option<String>,
     pub staging_authors: Vec<AuthorWidgetSavedData>,
-    pub attachments_widget: Vec<FileSourceWidgetSavedData>,
+    pub attachments_widget: Vec<AttachmentsWidgetSavedData>,
     pub staging_citations: Vec<CiteEntryWidgetSavedData>,
     #[serde(default)] // added after AppState1SavedData
     pub custom_config_widget: Option<JsonObjectEditorWidgetSavedData>,
@@ -1766,12 +2108,24 @@ pub struct AppState1SavedData{
     pub staging_version: Option<VersionWidgetSavedData>,
     #[serde(default)]
     pub staging_version_comment: Option<String>,
+    #[serde(default)] // added after AppState1SavedData
+    pub uploader_widget: Option<UploaderWidgetSavedData>,
     pub staging_documentation: CodeEditorWidgetSavedData,
     pub staging_license: ::bioimg_spec::rdf::LicenseId,
+    #[serde(default)] // added after AppState1SavedData
+    pub run_mode_widget: Option<RunModeWidgetSavedData>,
+    #[serde(default)] // added after AppState1SavedData
+    pub parent_widget: Option<ParentModelWidgetSavedData>,
+    #[serde(default)] // added after AppState1SavedData
+    pub training_data_widget: Option<TrainingDataWidgetSavedData>,
+    #[serde(default)] // added after AppState1SavedData
+    pub timestamp_widget: TimestampWidgetSavedData,
     //badges
     pub model_interface_widget: ModelInterfaceWidgetSavedData,
     ////
     pub weights_widget: WeightsWidgetSavedData,
+    #[serde(default)] // added after AppState1SavedData
+    pub expansion_state: std::collections::BTreeSet<u64>,
 }
 
 impl AppState1SavedData {
@@ -1780,6 +2134,8 @@ impl AppState1SavedData {
         partial: Partial<modelrdf::ModelRdfV0_5>,
         warnings: &mut impl std::fmt::Write,
     ) -> Self{
+        let mut config = partial.config;
+        let attachment_descriptions = bioimg_runtime::attachment::take_descriptions(&mut config);
         Self{
             staging_name: partial.name.unwrap_or_default(),
             staging_description: partial.description.unwrap_or_default(),
@@ -1794,14 +2150,14 @@ impl AppState1SavedData {
                 .collect(),
             attachments_widget: partial.attachments
                 .into_iter()
-                .map(|partial_fd| FileSourceWidgetSavedData::from_partial_file_descr(archive, partial_fd, warnings))
+                .map(|partial_fd| AttachmentsWidgetSavedData::from_partial(archive, partial_fd, &attachment_descriptions, warnings))
                 .collect(),
             staging_citations: partial.cite
                 .unwrap_or_default()
                 .into_iter()
                 .map(|partial| CiteEntryWidgetSavedData::from_partial(archive, partial))
                 .collect(),
-            custom_config_widget: Some(JsonObjectEditorWidgetSavedData::from_partial(archive, partial.config)),
+            custom_config_widget: Some(JsonObjectEditorWidgetSavedData::from_partial(archive, config)),
             staging_git_repo: partial.git_repo,
             icon_widget: partial.icon.map(|partial| IconWidgetSavedData::from_partial(archive, partial, warnings)),
             links_widget: partial.links,
@@ -1811,6 +2167,7 @@ impl AppState1SavedData {
             staging_tags: partial.tags,
             staging_version: partial.version.map(|v| VersionWidgetSavedData::from_partial(archive, v)),
             staging_version_comment: partial.version_comment,
+            uploader_widget: partial.uploader.map(|partial| UploaderWidgetSavedData::from_partial(archive, partial)),
             staging_documentation: 'documentation: {
                 let Some(doc_file_descr) = partial.documentation else {
                     break 'documentation Default::default();
@@ -1853,6 +2210,11 @@ impl AppState1SavedData {
                     }
                 })
                 .unwrap_or(rdf::LicenseId::MIT),
+            run_mode_widget: partial.run_mode.map(|partial| RunModeWidgetSavedData::from_partial(archive, partial)),
+            parent_widget: partial.parent.map(|partial| ParentModelWidgetSavedData::from_partial(archive, partial)),
+            training_data_widget: partial.training_data
+                .map(|partial| TrainingDataWidgetSavedData::from_partial(archive, partial)),
+            timestamp_widget: TimestampWidgetSavedData::from_partial(archive, partial.timestamp),
             model_interface_widget: ModelInterfaceWidgetSavedData::from_partial(
                 archive, partial.inputs.unwrap_or_default(), partial.outputs.unwrap_or_default(), warnings
             ),
@@ -1860,6 +2222,7 @@ impl AppState1SavedData {
             weights_widget: partial.weights
                 .map(|w| WeightsWidgetSavedData::from_partial(archive, w, warnings))
                 .unwrap_or_default(),
+            expansion_state: Default::default(),
         }
     }
 }
@@ -10,3 +10,25 @@ pub fn send_reader<R: std::io::Read>(req: http::Request<R>) -> Result<http::Resp
     let request: ureq::Request = http_parts.into();
     Ok(request.send(body)?.into())
 }
+
+/// Sends the requests of `bioimg_zoo`'s multi-step flows through [send_reader]
+pub struct UreqTransport;
+
+impl bioimg_zoo::upload::Transport for UreqTransport{
+    type Error = ureq::Error;
+
+    fn send_reader<R: std::io::Read>(&self, req: http::Request<R>) -> Result<http::Response<Vec<u8>>, ureq::Error>{
+        send_reader(req)
+    }
+}
+
+/// Checks that `url` can be fetched, returning its HTTP status. Redirects are followed, and servers that
+/// don't support HEAD requests are retried with a GET
+pub fn check_url_reachable(url: &str) -> Result<u16, ureq::Error>{
+    let agent = ureq::AgentBuilder::new().timeout(std::time::Duration::from_secs(10)).build();
+    match agent.head(url).call(){
+        Ok(response) => Ok(response.status()),
+        Err(ureq::Error::Status(405 | 501, _)) => Ok(agent.get(url).call()?.status()),
+        Err(err) => Err(err),
+    }
+}
@@ -0,0 +1,86 @@
+use std::io::Write;
+
+use bioimg_runtime::zip_archive_ext::{SharedZipArchive, ZipArchiveIdentifier};
+
+/// Minimal, pre-filled model drafts that authors can start from instead of an empty form.
+///
+/// Templates are stored as (partial) rdf.yaml files plus a documentation file, and are loaded
+/// the same way as a model being recovered from a zip file, so anything that is missing from them
+/// (like test tensors and weights) is simply left for the user to fill in.
+#[derive(Clone, Copy, strum::VariantArray, strum::Display)]
+pub enum ModelTemplate{
+    #[strum(to_string = "2D UNet Segmentation")]
+    Unet2dSegmentation,
+    #[strum(to_string = "3D Denoising")]
+    Denoising3d,
+    #[strum(to_string = "Image Classification")]
+    Classification,
+}
+
+impl ModelTemplate{
+    fn rdf_yaml(self) -> &'static str{
+        match self{
+            Self::Unet2dSegmentation => include_str!("../assets/templates/unet_2d_segmentation.yaml"),
+            Self::Denoising3d => include_str!("../assets/templates/denoising_3d.yaml"),
+            Self::Classification => include_str!("../assets/templates/classification.yaml"),
+        }
+    }
+
+    fn documentation(self) -> &'static str{
+        match self{
+            Self::Unet2dSegmentation => include_str!("../assets/templates/unet_2d_segmentation.md"),
+            Self::Denoising3d => include_str!("../assets/templates/denoising_3d.md"),
+            Self::Classification => include_str!("../assets/templates/classification.md"),
+        }
+    }
+
+    pub fn description(self) -> &'static str{
+        match self{
+            Self::Unet2dSegmentation => "Single channel 2D input, per-pixel foreground probability output",
+            Self::Denoising3d => "Single channel 3D input, denoised volume of the same shape as output",
+            Self::Classification => "Fixed size RGB patch input, per-class probability output",
+        }
+    }
+
+    /// Packs this template into an in-memory archive that looks like a (partial) zoo model
+    pub fn to_archive(self) -> Result<SharedZipArchive, zip::result::ZipError>{
+        let file_options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+        let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::<u8>::new()));
+        writer.start_file("rdf.yaml", file_options)?;
+        writer.write_all(self.rdf_yaml().as_bytes())?;
+        writer.start_file("README.md", file_options)?;
+        writer.write_all(self.documentation().as_bytes())?;
+        let contents = writer.finish()?.into_inner();
+        Ok(SharedZipArchive::from_raw_data(contents, ZipArchiveIdentifier::Name(format!("{self} template"))))
+    }
+}
+
+#[test]
+fn test_templates_parse(){
+    use strum::VariantArray;
+    use bioimg_spec::rdf;
+    use bioimg_spec::rdf::model::input_tensor::InputTensorMetadata;
+    use bioimg_spec::rdf::model::output_tensor::OutputTensorMetadata;
+
+    for template in ModelTemplate::VARIANTS.iter().copied(){
+        let rdf: serde_json::Value = serde_yaml::from_str(template.rdf_yaml()).unwrap();
+        // the form is filled in leniently, so check each part strictly to catch typos that would just leave fields empty
+        serde_json::from_value::<rdf::model::ModelRdfName>(rdf["name"].clone()).unwrap_or_else(|err| panic!("{template}: {err}"));
+        serde_json::from_value::<rdf::ResourceTextDescription>(rdf["description"].clone()).unwrap_or_else(|err| panic!("{template}: {err}"));
+        serde_json::from_value::<Vec<rdf::Author2>>(rdf["authors"].clone()).unwrap_or_else(|err| panic!("{template}: {err}"));
+        serde_json::from_value::<Vec<rdf::CiteEntry2>>(rdf["cite"].clone()).unwrap_or_else(|err| panic!("{template}: {err}"));
+        serde_json::from_value::<rdf::LicenseId>(rdf["license"].clone()).unwrap_or_else(|err| panic!("{template}: {err}"));
+        for input in rdf["inputs"].as_array().unwrap(){
+            serde_json::from_value::<InputTensorMetadata>(input.clone()).unwrap_or_else(|err| panic!("{template}: {err}"));
+        }
+        for output in rdf["outputs"].as_array().unwrap(){
+            serde_json::from_value::<OutputTensorMetadata>(output.clone()).unwrap_or_else(|err| panic!("{template}: {err}"));
+        }
+        assert!(!template.documentation().trim().is_empty());
+
+        let archive = template.to_archive().unwrap();
+        if let Err(err) = crate::app::AppState1::read_partial_archive(&archive){
+            panic!("{template}: {err}");
+        }
+    }
+}
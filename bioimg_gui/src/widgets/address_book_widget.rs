@@ -0,0 +1,59 @@
+use crate::address_book::{AddressBook, AddressBookEntry};
+use super::error_display::show_error;
+
+/// A menu listing the people saved in the [AddressBook] and a button to save the person currently being
+/// edited into it, so that frequent authors and maintainers don't have to be typed in for every model.
+///
+/// Returns the entry that was picked this frame, if any
+pub fn draw_address_book_menu<T: AddressBookEntry>(ui: &mut egui::Ui, id: egui::Id, current: Option<T>) -> Option<T>{
+    let error_id = id.with("address book error");
+    let mut picked = None;
+    ui.horizontal(|ui|{
+        ui.menu_button("📒 Address Book", |ui|{
+            let mut removed = None;
+            AddressBook::with(|book|{
+                let entries = T::entries(book);
+                if entries.is_empty(){
+                    ui.weak("No saved entries yet");
+                }
+                for (idx, entry) in entries.iter().enumerate(){
+                    ui.horizontal(|ui|{
+                        if ui.button("🗑").on_hover_text("Remove from address book").clicked(){
+                            removed = Some(idx);
+                        }
+                        if ui.selectable_label(false, entry.to_string()).clicked(){
+                            picked = Some(entry.clone());
+                            ui.close_menu();
+                        }
+                    });
+                }
+            });
+            if let Some(idx) = removed{
+                let mut removed_entry = None;
+                let result = AddressBook::modify(|book| removed_entry = Some(T::entries_mut(book).remove(idx)));
+                let removed_entry = removed_entry.map(|entry| entry.to_string()).unwrap_or_default();
+                ui.ctx().data_mut(|data| match result{
+                    Ok(()) => data.remove::<String>(error_id),
+                    Err(err) => data.insert_temp(error_id, format!("Could not remove {removed_entry} from the address book: {err}")),
+                });
+            }
+        });
+
+        let save_clicked = ui.add_enabled(current.is_some(), egui::Button::new("💾 Save to Address Book"))
+            .on_hover_text("Remember this person so they can be picked from the address book in other models")
+            .on_disabled_hover_text("Fix the errors in this entry first")
+            .clicked();
+        if let (true, Some(current)) = (save_clicked, current){
+            let current_name = current.to_string();
+            let result = AddressBook::modify(|book| book.insert(current));
+            ui.ctx().data_mut(|data| match result{
+                Ok(()) => data.remove::<String>(error_id),
+                Err(err) => data.insert_temp(error_id, format!("Could not save {current_name} to the address book: {err}")),
+            });
+        }
+    });
+    if let Some(message) = ui.ctx().data(|data| data.get_temp::<String>(error_id)){
+        show_error(ui, message);
+    }
+    picked
+}
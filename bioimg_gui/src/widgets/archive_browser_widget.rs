@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+
+use bioimg_runtime::size_report::format_size;
+use bioimg_runtime::zip_archive_ext::SharedZipArchive;
+
+struct ArchiveEntryInfo{
+    path: String,
+    name: String,
+    size: u64,
+    compressed_size: u64,
+}
+
+#[derive(Default)]
+struct ArchiveDir{
+    subdirs: BTreeMap<String, ArchiveDir>,
+    files: Vec<ArchiveEntryInfo>,
+}
+
+impl ArchiveDir{
+    fn from_archive(archive: &SharedZipArchive) -> Self{
+        let mut file_names: Vec<String> = archive.with_file_names(|names| names.map(|name| name.to_owned()).collect());
+        file_names.sort();
+
+        let mut root = Self::default();
+        for path in file_names{
+            if path.ends_with('/'){
+                continue
+            }
+            let Ok((size, compressed_size)) = archive.with_entry(&path, |entry| (entry.size(), entry.compressed_size())) else {
+                continue
+            };
+            let mut components: Vec<&str> = path.split('/').collect();
+            let name = components.pop().unwrap_or_default().to_owned();
+            let dir = components.into_iter().fold(&mut root, |dir, component|{
+                dir.subdirs.entry(component.to_owned()).or_default()
+            });
+            dir.files.push(ArchiveEntryInfo{path: path.clone(), name, size, compressed_size});
+        }
+        root
+    }
+
+    fn draw(&self, ui: &mut egui::Ui, id: egui::Id, extraction_request: &mut Option<String>){
+        for (dir_name, subdir) in &self.subdirs{
+            egui::CollapsingHeader::new(format!("🗀 {dir_name}"))
+                .id_salt(id.with(dir_name))
+                .default_open(true)
+                .show(ui, |ui| subdir.draw(ui, id.with(dir_name), extraction_request));
+        }
+        for file in &self.files{
+            ui.horizontal(|ui|{
+                ui.label(format!("🗋 {}", file.name));
+                ui.weak(format!("{} ({} compressed)", format_size(file.size), format_size(file.compressed_size)));
+                if ui.small_button("⤵ Extract").on_hover_text(format!("Save a copy of {}", file.path)).clicked(){
+                    *extraction_request = Some(file.path.clone());
+                }
+            });
+        }
+    }
+}
+
+/// A window listing the entries of the archive a model was imported from, so that users can
+/// check what the original model contained and extract individual files from it
+#[derive(Default)]
+pub struct ArchiveBrowserWidget{
+    pub is_open: bool,
+    archive: Option<(SharedZipArchive, ArchiveDir)>,
+}
+
+impl ArchiveBrowserWidget{
+    pub fn set_archive(&mut self, archive: SharedZipArchive){
+        let root = ArchiveDir::from_archive(&archive);
+        self.archive = Some((archive, root));
+    }
+
+    pub fn archive(&self) -> Option<&SharedZipArchive>{
+        self.archive.as_ref().map(|(archive, _)| archive)
+    }
+
+    /// Returns the path of the entry the user asked to extract, if any
+    pub fn show(&mut self, ctx: &egui::Context) -> Option<String>{
+        if !self.is_open{
+            return None
+        }
+        let Some((archive, root)) = &self.archive else {
+            return None
+        };
+        let mut extraction_request = None;
+        egui::Window::new("Imported Archive Contents")
+            .open(&mut self.is_open)
+            .default_size([500.0, 400.0])
+            .show(ctx, |ui|{
+                ui.weak(format!("Files inside {}", archive.identifier()));
+                ui.separator();
+                egui::ScrollArea::vertical().show(ui, |ui|{
+                    root.draw(ui, egui::Id::from("archive browser"), &mut extraction_request);
+                });
+            });
+        extraction_request
+    }
+}
@@ -1,6 +1,91 @@
-use super::{collapsible_widget::CollapsibleWidget, file_source_widget::FileSourceWidget, staging_vec::ItemWidgetConf};
+use bioimg_runtime as rt;
+use bioimg_spec::rdf::bounded_string::BoundedString;
+use bioimg_spec::rdf::file_description::Sha256;
+
+use crate::result::{GuiError, Result};
+
+use super::collapsible_widget::{CollapsibleWidget, SummarizableWidget};
+use super::field_finder_widget::findable_label;
+use super::file_source_widget::FileSourceWidget;
+use super::staging_opt::StagingOpt;
+use super::staging_string::StagingString;
+use super::staging_vec::ItemWidgetConf;
+use super::{Restore, StatefulWidget, ValueWidget};
+
+#[derive(Default, Restore)]
+#[restore(saved_data=crate::project_data::AttachmentsWidgetSavedData)]
+pub struct AttachmentsWidget{
+    pub file_source_widget: FileSourceWidget,
+    pub description_widget: StagingOpt<StagingString<BoundedString<1, 1024>>, false>,
+    /// The hash of the attachment as it was loaded, which stays valid for as long as the file is not swapped
+    #[restore(default)]
+    pub loaded_sha256: Option<(rt::FileSource, Sha256)>,
+}
+
+impl AttachmentsWidget{
+    fn known_sha256(&self, source: &rt::FileSource) -> Option<&Sha256>{
+        match &self.loaded_sha256{
+            Some((loaded_source, sha256)) if loaded_source == source => Some(sha256),
+            _ => None,
+        }
+    }
+}
 
-pub type AttachmentsWidget = FileSourceWidget;
 impl ItemWidgetConf for CollapsibleWidget<AttachmentsWidget>{
     const ITEM_NAME: &'static str = "Attachment";
 }
+
+impl SummarizableWidget for AttachmentsWidget{
+    fn summarize(&mut self, ui: &mut egui::Ui, id: egui::Id) {
+        ui.horizontal(|ui|{
+            self.file_source_widget.summarize(ui, id.with("file".as_ptr()));
+            if let Some(Ok(description)) = self.description_widget.state(){
+                ui.weak(description.to_string());
+            }
+        });
+    }
+}
+
+impl ValueWidget for AttachmentsWidget{
+    type Value<'v> = rt::Attachment;
+
+    fn set_value<'v>(&mut self, value: rt::Attachment){
+        self.loaded_sha256 = value.sha256.map(|sha256| (value.source.clone(), sha256));
+        self.file_source_widget.set_value(value.source);
+        self.description_widget.set_value(
+            value.description.and_then(|descr| BoundedString::try_from(descr).ok())
+        );
+    }
+}
+
+impl StatefulWidget for AttachmentsWidget{
+    type Value<'p> = Result<rt::Attachment>;
+
+    fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
+        ui.vertical(|ui|{
+            self.file_source_widget.draw_and_parse(ui, id.with("file".as_ptr()));
+            ui.horizontal(|ui|{
+                findable_label(ui, "Description: ");
+                self.description_widget.draw_and_parse(ui, id.with("description".as_ptr()));
+            });
+            if let Ok(source) = self.file_source_widget.state(){
+                match self.known_sha256(&source){
+                    Some(sha256) => ui.weak(format!("sha256: {sha256}")),
+                    None => ui.weak("sha256: computed when the model is packed"),
+                };
+            }
+        });
+    }
+
+    fn state<'p>(&'p self) -> Result<rt::Attachment>{
+        let source = self.file_source_widget.state()?;
+        let description = self.description_widget.state().transpose()
+            .map_err(|_| GuiError::new("Invalid attachment description"))?
+            .map(|descr| descr.to_string());
+        Ok(rt::Attachment{
+            sha256: self.known_sha256(&source).cloned(),
+            source,
+            description,
+        })
+    }
+}
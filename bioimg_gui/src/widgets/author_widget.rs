@@ -7,12 +7,15 @@ use super::staging_string::StagingString;
 use super::staging_opt::StagingOpt;
 use super::labels::{self, orcid_label};
 use super::error_display::show_error;
+use super::address_book_widget::draw_address_book_menu;
+use super::field_finder_widget::findable_label;
 use crate::result::{GuiError, Result};
 
 pub type ConfString = BoundedString<1, 1024>;
 
-#[derive(Restore)]
+#[derive(Restore, ValueWidget)]
 #[restore(saved_data=crate::project_data::AuthorWidgetSavedData)]
+#[value_widget(value=Author2)]
 pub struct AuthorWidget {
     pub name_widget: StagingString<ConfString>,
     pub affiliation_widget: StagingOpt<StagingString<ConfString>>,
@@ -22,17 +25,6 @@ pub struct AuthorWidget {
 }
 
 
-impl ValueWidget for AuthorWidget{
-    type Value<'a> = Author2;
-    fn set_value<'a>(&mut self, value: Self::Value<'a>) {
-        self.name_widget.set_value(value.name);
-        self.affiliation_widget.set_value(value.affiliation);
-        self.email_widget.set_value(value.email);
-        self.github_user_widget.set_value(value.github_user);
-        self.orcid_widget.set_value(value.orcid);
-    }
-}
-
 impl ItemWidgetConf for AuthorWidget{
     const ITEM_NAME: &'static str = "Author";
     const MIN_NUM_ITEMS: usize = 1;
@@ -74,26 +66,31 @@ impl StatefulWidget for AuthorWidget {
     type Value<'p> = Result<Author2>;
 
     fn draw_and_parse<'p>(&'p mut self, ui: &mut egui::Ui, id: egui::Id) {
-        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
-            ui.strong("Name: ").on_hover_text("The author's given name e.g. John Smith");
-            self.name_widget.draw_and_parse(ui, id.with("Name"));
-            ui.end_row();
-
-            labels::affiliation_label(ui);
-            self.affiliation_widget.draw_and_parse(ui, id.with("Affiliation"));
-            ui.end_row();
-
-            ui.strong("Email: ").on_hover_text("An email address where the author could be reached");
-            self.email_widget.draw_and_parse(ui, id.with("Email"));
-            ui.end_row();
-
-            labels::github_user_label(ui, self.github_user_widget.0.as_ref().map(|s| s.raw.as_str()));
-            self.github_user_widget.draw_and_parse(ui, id.with("Github User"));
-            ui.end_row();
-
-            orcid_label(ui, "author");
-            self.orcid_widget.draw_and_parse(ui, id.with("Orcid"));
-            ui.end_row();
+        ui.vertical(|ui|{
+            if let Some(picked) = draw_address_book_menu(ui, id.with("address book"), self.state().ok()){
+                self.set_value(picked);
+            }
+            egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+                findable_label(ui, "Name: ").on_hover_text("The author's given name e.g. John Smith");
+                self.name_widget.draw_and_parse(ui, id.with("Name"));
+                ui.end_row();
+
+                labels::affiliation_label(ui);
+                self.affiliation_widget.draw_and_parse(ui, id.with("Affiliation"));
+                ui.end_row();
+
+                findable_label(ui, "Email: ").on_hover_text("An email address where the author could be reached");
+                self.email_widget.draw_and_parse(ui, id.with("Email"));
+                ui.end_row();
+
+                labels::github_user_label(ui, self.github_user_widget.0.as_ref().map(|s| s.raw.as_str()));
+                self.github_user_widget.draw_and_parse(ui, id.with("Github User"));
+                ui.end_row();
+
+                orcid_label(ui, "author");
+                self.orcid_widget.draw_and_parse(ui, id.with("Orcid"));
+                ui.end_row();
+            });
         });
     }
 
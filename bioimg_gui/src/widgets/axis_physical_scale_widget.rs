@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use bioimg_spec::rdf::model as modelrdf;
+use bioimg_spec::rdf::si_units::{PhysicalUnit, Quantity};
 
 use crate::project_data::PhysicalScaleWidgetSavedData;
 // use crate::project_data::PhysicalSizeWidgetSavedData;
@@ -8,12 +9,16 @@ use crate::result::{Result, GuiError};
 
 use super::error_display::{show_error, show_warning};
 use super::search_and_pick_widget::SearchAndPickWidget;
+use super::staging_float::parse_decimal;
 use super::staging_opt::StagingOpt;
 use super::{Restore, StatefulWidget, ValueWidget};
+use super::field_finder_widget::findable_label;
 
 pub struct PhysicalScaleWidget<T>{
     pub raw_scale: String,
     pub unit_widget: StagingOpt<SearchAndPickWidget<T>, false>,
+    /// A unit change (from, to) for which the user hasn't yet decided whether to convert the scale
+    pending_rescale: Option<(T, T)>,
 }
 
 impl<U> ValueWidget for PhysicalScaleWidget<U>
@@ -25,6 +30,7 @@ where
     fn set_value<'v>(&mut self, value: Self::Value<'v>) {
         self.raw_scale = value.0.to_string();
         self.unit_widget.set_value(value.1);
+        self.pending_rescale = None;
     }
 }
 
@@ -44,6 +50,7 @@ where
     fn restore(&mut self, saved_data: Self::SavedData) {
         self.raw_scale = saved_data.raw_scale;
         self.unit_widget.restore(saved_data.unit_widget);
+        self.pending_rescale = None;
     }
 }
 
@@ -54,7 +61,8 @@ where
     fn default() -> Self {
         Self {
             raw_scale: "1.0".into(),
-            unit_widget: Default::default()
+            unit_widget: Default::default(),
+            pending_rescale: None,
         }
     }
 }
@@ -64,29 +72,69 @@ impl<U> PhysicalScaleWidget<U>{
         if self.raw_scale.is_empty(){
             return Ok(modelrdf::AxisScale::default())
         }
-        let raw_scale = self.raw_scale.parse::<f32>().map_err(GuiError::from)?;
+        let raw_scale = parse_decimal::<f32>(&self.raw_scale)?;
         modelrdf::AxisScale::try_from(raw_scale).map_err(GuiError::from)
     }
 }
 
+impl<U> PhysicalScaleWidget<U>
+where
+    U: PhysicalUnit + PartialEq + Display
+{
+    /// Offers to convert the scale after the unit was changed, so that the axis keeps its physical meaning
+    fn draw_rescale_prompt(&mut self, ui: &mut egui::Ui){
+        let Some((from, to)) = self.pending_rescale else {
+            return
+        };
+        let Ok(scale) = self.parse_scale() else {
+            return
+        };
+        let Ok(converted) = Quantity::try_new(f32::from(scale) as f64, from).and_then(|q| q.convert_to(to)) else {
+            return
+        };
+        let converted = converted.value() as f32;
+        ui.horizontal(|ui|{
+            ui.weak(format!("Unit changed from {from} to {to}."));
+            if ui.button(format!("Convert scale to {converted}")).clicked(){
+                self.raw_scale = converted.to_string();
+                self.pending_rescale = None;
+            }
+            if ui.button(format!("Keep {scale}")).clicked(){
+                self.pending_rescale = None;
+            }
+        });
+    }
+}
+
 impl<U> StatefulWidget for PhysicalScaleWidget<U>
 where
-    U: Default + strum::VariantArray + Clone + Display
+    U: Default + strum::VariantArray + Clone + Display + PhysicalUnit + PartialEq
 {
     type Value<'p> = Result<(modelrdf::AxisScale, Option<U>), GuiError> where U: 'p;
 
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.vertical(|ui|{
             ui.horizontal(|ui|{
-                ui.strong("Scale: ").on_hover_text(
+                findable_label(ui, "Scale: ").on_hover_text(
                     "When indexing into this axis, each index increment represents a physical increment of 'Scale * Unit'"
                 );
                 ui.add(egui::TextEdit::singleline(&mut self.raw_scale).desired_width(50.0));
-                ui.strong("Unit: ").on_hover_text(
+                findable_label(ui, "Unit: ").on_hover_text(
                     "When indexing into this axis, each index increment represents a physical increment of 'Scale * Unit'"
                 );
+                let unit_before = self.unit_widget.state();
                 self.unit_widget.draw_and_parse(ui, id.with("unit".as_ptr()));
+                if let (Some(before), Some(after)) = (unit_before, self.unit_widget.state()){
+                    if before != after {
+                        let from = self.pending_rescale.map(|(from, _)| from).unwrap_or(before);
+                        self.pending_rescale = if from == after { None } else { Some((from, after)) };
+                    }
+                }
             });
+            if self.pending_rescale.is_some_and(|(_, to)| self.unit_widget.state() != Some(to)){
+                self.pending_rescale = None;
+            }
+            self.draw_rescale_prompt(ui);
             match (self.parse_scale(), self.unit_widget.state()){
                 (Err(e), _) => show_error(ui, e.to_string()),
                 (Ok(_), None) => {
@@ -109,3 +157,68 @@ where
         Ok((scale, self.unit_widget.state()))
     }
 }
+
+/// A scale and/or unit picked once and copied into several space axes at once, since x, y and z
+/// nearly always share them
+pub struct BulkScaleWidget{
+    pub raw_scale: String,
+    pub unit_widget: SearchAndPickWidget<modelrdf::SpaceUnit>,
+    pub apply_scale: bool,
+    pub apply_unit: bool,
+}
+
+impl Default for BulkScaleWidget{
+    fn default() -> Self {
+        Self{
+            raw_scale: "1.0".into(),
+            unit_widget: SearchAndPickWidget::from_enum(Default::default()),
+            apply_scale: true,
+            apply_unit: true,
+        }
+    }
+}
+
+/// The edits picked in a [BulkScaleWidget]
+pub struct BulkScale{
+    raw_scale: Option<String>,
+    unit: Option<modelrdf::SpaceUnit>,
+}
+
+impl BulkScale{
+    pub fn apply_to(&self, widget: &mut PhysicalScaleWidget<modelrdf::SpaceUnit>){
+        if let Some(raw_scale) = &self.raw_scale{
+            widget.raw_scale = raw_scale.clone();
+        }
+        if let Some(unit) = self.unit{
+            widget.unit_widget.set_value(Some(unit));
+        }
+        widget.pending_rescale = None;
+    }
+}
+
+impl BulkScaleWidget{
+    /// Returns the edits to make once the user clicks the button labeled `apply_label`
+    pub fn draw(&mut self, ui: &mut egui::Ui, id: egui::Id, apply_label: &str) -> Option<BulkScale>{
+        ui.horizontal(|ui|{
+            ui.checkbox(&mut self.apply_scale, "Scale: ");
+            ui.add_enabled(self.apply_scale, egui::TextEdit::singleline(&mut self.raw_scale).desired_width(50.0));
+            ui.checkbox(&mut self.apply_unit, "Unit: ");
+            ui.add_enabled_ui(self.apply_unit, |ui| self.unit_widget.draw_and_parse(ui, id.with("unit")));
+
+            let scale = parse_decimal::<f32>(&self.raw_scale)
+                .and_then(|scale| modelrdf::AxisScale::try_from(scale).map_err(GuiError::from));
+            if let (true, Err(err)) = (self.apply_scale, &scale){
+                show_error(ui, err);
+                return None
+            }
+            let apply_button = egui::Button::new(apply_label);
+            if !ui.add_enabled(self.apply_scale || self.apply_unit, apply_button).clicked(){
+                return None
+            }
+            Some(BulkScale{
+                raw_scale: self.apply_scale.then(|| self.raw_scale.clone()),
+                unit: self.apply_unit.then(|| self.unit_widget.value),
+            })
+        }).inner
+    }
+}
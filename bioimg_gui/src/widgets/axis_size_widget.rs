@@ -5,10 +5,12 @@ use crate::result::Result;
 use bioimg_spec::rdf::model as modelrdf;
 use bioimg_spec::rdf::model::{axes::AxisId, tensor_id::TensorId};
 
+use super::error_display::show_warning;
 use super::staging_num::StagingNum;
 use super::staging_string::StagingString;
 use super::util::group_frame;
 use super::{Restore, StatefulWidget, ValueWidget};
+use super::field_finder_widget::findable_label;
 
 #[derive(Default, Restore)]
 #[restore(saved_data=crate::project_data::AxisSizeReferenceWidgetSavedData)]
@@ -32,17 +34,17 @@ impl StatefulWidget for AxisSizeReferenceWidget {
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.vertical(|ui| {
             ui.horizontal(|ui| {
-                ui.strong("Tensor Id: ");
+                findable_label(ui, "Tensor Id: ");
                 self.staging_tensor_id.draw_and_parse(ui, id.with("Tensor Id"));
             });
 
             ui.horizontal(|ui| {
-                ui.strong("Axis Id: ");
+                findable_label(ui, "Axis Id: ");
                 self.staging_axis_id.draw_and_parse(ui, id.with("Axis Id"));
             });
 
             ui.horizontal(|ui| {
-                ui.strong("Offset: ");
+                findable_label(ui, "Offset: ");
                 self.staging_offset.draw_and_parse(ui, id.with("Offset"));
             });
         });
@@ -79,14 +81,14 @@ impl StatefulWidget for ParameterizedAxisSizeWidget {
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.vertical(|ui| {
             ui.horizontal(|ui| {
-                ui.strong("Min: ").on_hover_text(
+                findable_label(ui, "Min: ").on_hover_text(
                     "The minimum size of this tensor axis"
                 );
                 self.staging_min.draw_and_parse(ui, id.with("Min"));
             });
 
             ui.horizontal(|ui| {
-                ui.strong("Step: ").on_hover_text(
+                findable_label(ui, "Step: ").on_hover_text(
                     "This axis size can be incremented by adding the 'step' amount an arbitrary numer of times"
                 );
                 self.staging_step.draw_and_parse(ui, id.with("Step"));
@@ -182,6 +184,48 @@ impl AnyAxisSizeWidget{
     }
 }
 
+impl AnyAxisSizeWidget{
+    pub fn set_fixed(&mut self, extent: NonZeroUsize){
+        self.mode = AxisSizeMode::Fixed;
+        self.staging_fixed_size.set_value(extent);
+    }
+
+    /// Checks a parameterized size against the `extent` of the test tensor along this axis, offering
+    /// min and step values that would make the test tensor a valid input
+    pub fn draw_extent_suggestions(&mut self, ui: &mut egui::Ui, extent: usize){
+        if self.mode != AxisSizeMode::Parameterized{
+            return
+        }
+        let Some(extent) = NonZeroUsize::new(extent) else {
+            return
+        };
+        let mut picked: Option<modelrdf::ParameterizedAxisSize> = None;
+        match self.staging_parameterized.state(){
+            Ok(size) if size.accepts(extent.get()) => (),
+            Ok(size) => {
+                show_warning(ui, format!("The test tensor has extent {extent} on this axis, which is not Min + N * Step"));
+                ui.horizontal_wrapped(|ui|{
+                    for adjusted in size.adjusted_to(extent){
+                        if ui.button(format!("Use min={}, step={}", adjusted.min, adjusted.step)).clicked(){
+                            picked = Some(adjusted);
+                        }
+                    }
+                });
+            },
+            Err(_) => {
+                let suggested = modelrdf::ParameterizedAxisSize::suggest_for(extent);
+                let button_text = format!("💡 Use min={}, step={}", suggested.min, suggested.step);
+                if ui.button(button_text).on_hover_text(format!("Values that accept the test tensor extent of {extent}")).clicked(){
+                    picked = Some(suggested);
+                }
+            },
+        }
+        if let Some(size) = picked{
+            self.staging_parameterized.set_value(size);
+        }
+    }
+}
+
 impl StatefulWidget for AnyAxisSizeWidget {
     type Value<'p> = Result<modelrdf::AnyAxisSize>;
 
@@ -199,7 +243,7 @@ impl StatefulWidget for AnyAxisSizeWidget {
             group_frame(ui, |ui| match self.mode {
                 AxisSizeMode::Fixed => {
                     ui.horizontal(|ui| {
-                        ui.strong("Extent: ").on_hover_text("Exactly how big a tensor must be in this axis");
+                        findable_label(ui, "Extent: ").on_hover_text("Exactly how big a tensor must be in this axis");
                         self.staging_fixed_size.draw_and_parse(ui, id.with("Fixed"));
                     });
                 }
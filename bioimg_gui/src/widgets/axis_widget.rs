@@ -15,17 +15,18 @@ use super::staging_string::StagingString;
 use super::util::group_frame;
 use super::{Restore, StatefulWidget, ValueWidget};
 use super::{axis_size_widget::AnyAxisSizeWidget, staging_num::StagingNum};
+use super::field_finder_widget::findable_label;
 use crate::project_data::ChannelNamesModeSavedData;
 use crate::result::{GuiError, Result};
 
 pub fn axis_id_label(ui: &mut egui::Ui){
-    ui.strong("Axis Id: ").on_hover_text(
+    findable_label(ui, "Axis Id: ").on_hover_text(
         "The unique name of this axis within the tensor. E.g.: 'x', 't'"
     );
 }
 
 pub fn axis_description_label(ui: &mut egui::Ui) -> egui::Response{
-    ui.strong("Axis Description: ").on_hover_ui(|ui|{
+    findable_label(ui, "Axis Description: ").on_hover_ui(|ui|{
         ui.label(indoc!("
             The semantic meaning of this axis, i.e. what it means to go backwards \
             and forwards on this axis."
@@ -66,7 +67,7 @@ impl StatefulWidget for BatchAxisWidget{
                 self.description_widget.draw_and_parse(ui, id.with("description"));
             });
             ui.horizontal(|ui| {
-                ui.strong("Allow arbitrary batch size: ").on_hover_text(indoc!("
+                findable_label(ui, "Allow arbitrary batch size: ").on_hover_text(indoc!("
                     Allows the batch size to be arbitrarily determined during inference. \
                     If left unchecked, the batch size will always be '1'."
                 ));
@@ -138,13 +139,57 @@ impl ValueWidget for ChannelAxisWidget{
 
     fn set_value(&mut self, value: modelrdf::ChannelAxis){
         self.description_widget.raw = value.description.into();
-        self.channel_names_mode_widget.set_value(ChannelNamesMode::Explicit);
+        match value.channel_name_pattern(){
+            Some(pattern) => self.set_pattern(pattern),
+            None => self.channel_names_mode_widget.set_value(ChannelNamesMode::Explicit),
+        }
         self.staging_explicit_names.staging = Vec::from(value.channel_names).into_iter().map(|ident|{
             StagingString::new_with_raw(String::from(ident))
         }).collect();
     }
 }
 
+impl ChannelAxisWidget{
+    fn set_pattern(&mut self, pattern: modelrdf::ChannelNamePattern){
+        self.channel_names_mode_widget.set_value(ChannelNamesMode::Pattern);
+        if let Some(count) = NonZeroUsize::new(pattern.count){
+            self.channel_extent_widget.set_value(count);
+        }
+        self.channel_name_prefix_widget.set_value(pattern.prefix);
+        self.channel_name_suffix_widget.set_value(pattern.suffix);
+    }
+
+    fn pattern(&self) -> Result<modelrdf::ChannelNamePattern>{
+        Ok(modelrdf::ChannelNamePattern{
+            prefix: self.channel_name_prefix_widget.state()?.clone(),
+            suffix: self.channel_name_suffix_widget.state()?.clone(),
+            count: self.channel_extent_widget.state()?.get(),
+        })
+    }
+
+    /// Carries the channel names over when switching between explicit names and a pattern, so that
+    /// no names are lost and the number of channels stays the same
+    fn sync_channel_names(&mut self, previous_mode: ChannelNamesMode){
+        match (previous_mode, self.channel_names_mode_widget.value){
+            (ChannelNamesMode::Explicit, ChannelNamesMode::Pattern) => {
+                let names = self.staging_explicit_names.staging.iter().map(|name| name.raw.as_str());
+                match modelrdf::ChannelNamePattern::detect(names){
+                    Some(pattern) => self.set_pattern(pattern),
+                    None => if let Some(count) = NonZeroUsize::new(self.staging_explicit_names.staging.len()){
+                        self.channel_extent_widget.set_value(count);
+                    },
+                }
+            },
+            (ChannelNamesMode::Pattern, ChannelNamesMode::Explicit) => {
+                if let Ok(pattern) = self.pattern(){
+                    self.staging_explicit_names.staging = pattern.names().map(StagingString::new_with_raw).collect();
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
 impl SummarizableWidget for ChannelAxisWidget{
     fn summarize(&mut self, ui: &mut egui::Ui, _id: egui::Id) {
         match self.state(){
@@ -169,29 +214,31 @@ impl StatefulWidget for ChannelAxisWidget{
                 self.description_widget.draw_and_parse(ui, id.with("description"));
             });
             ui.horizontal(|ui| {
-                ui.strong("Channel Names: ").on_hover_text(indoc!("
+                findable_label(ui, "Channel Names: ").on_hover_text(indoc!("
                     An ordered list of channel names. The number of channels in in this tensor will be inferred to \
                     be the number of channel names defined here"
                 ));
+                let previous_mode = self.channel_names_mode_widget.value;
                 self.channel_names_mode_widget.draw_and_parse(ui, id.with("mode".as_ptr()));
+                self.sync_channel_names(previous_mode);
             });
             match self.channel_names_mode_widget.value {
                 ChannelNamesMode::Pattern => {
                     group_frame(ui, |ui|{
                         ui.horizontal(|ui| {
-                            ui.strong("Number of Channels: ").on_hover_text(
+                            findable_label(ui, "Number of Channels: ").on_hover_text(
                                 "Number of channels (i.e. the size of the 'channel' dimension) in this Tensor"
                             );
                             self.channel_extent_widget.draw_and_parse(ui, id.with("extent"));
                         });
                         ui.horizontal(|ui| {
-                            ui.strong("Prefix: ").on_hover_text(
+                            findable_label(ui, "Prefix: ").on_hover_text(
                                 "Channel name prefix, prepended to the channel numerical index."
                             );
                             self.channel_name_prefix_widget.draw_and_parse(ui, id.with("prefix"));
                         });
                         ui.horizontal(|ui| {
-                            ui.strong("Suffix: ").on_hover_text(
+                            findable_label(ui, "Suffix: ").on_hover_text(
                                 "Channel name suffix, appended to the channel numerical index."
                             );
                             self.channel_name_suffix_widget.draw_and_parse(ui, id.with("suffix"));
@@ -215,18 +262,13 @@ impl StatefulWidget for ChannelAxisWidget{
     fn state<'p>(&'p self) -> Self::Value<'p> {
         let channel_names: NonEmptyList<rdf::Identifier> = match self.channel_names_mode_widget.value {
             ChannelNamesMode::Pattern => {
-                let extent: usize = self.channel_extent_widget.state()?.into();
-                (0..extent)
-                    .map(|idx| {
-                        let prefix = self.channel_name_prefix_widget.state()?;
-                        let suffix = self.channel_name_suffix_widget.state()?;
-                        let identifier = rdf::Identifier::try_from(format!("{prefix}{idx}{suffix}"))?;
-                        Ok(identifier)
-                    })
-                    .collect::<Result<Vec<_>>>()?
-                    .try_into()
-                    .map_err(|_| GuiError::new("Empty list of channel names".to_owned()))?
-
+                let pattern = self.pattern()?;
+                let names = pattern.names()
+                    .map(|name| rdf::Identifier::try_from(name.clone()).map_err(|err| {
+                        GuiError::new(format!("Generated channel name '{name}' is invalid: {err}"))
+                    }))
+                    .collect::<Result<Vec<_>>>()?;
+                names.try_into().map_err(|_| GuiError::new("Empty list of channel names".to_owned()))?
             }
             ChannelNamesMode::Explicit => {
                 let channel_names_result: Result<Vec<rdf::Identifier>, GuiError> =
@@ -266,12 +308,12 @@ impl StatefulWidget for IndexAxisWidget{
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.vertical(|ui|{
             ui.horizontal(|ui| {
-                ui.strong("Axis Description: ");
+                findable_label(ui, "Axis Description: ");
                 self.description_widget.draw_and_parse(ui, id.with("description"));
             });
         });
         ui.horizontal(|ui| {
-            ui.strong("Size: ");
+            findable_label(ui, "Size: ");
             self.size_widget.draw_and_parse(ui, id.with("size"));
         });
     }
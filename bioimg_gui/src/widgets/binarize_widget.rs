@@ -11,6 +11,7 @@ use super::iconify::Iconify;
 use super::staging_float::StagingFloat;
 use super::{Restore, ValueWidget};
 use super::{staging_string::StagingString, staging_vec::{ItemWidgetConf, StagingVec}, StatefulWidget};
+use super::field_finder_widget::findable_label;
 
 #[derive(PartialEq, Eq, Copy, Clone, Default, strum::Display, strum::VariantArray, strum::AsRefStr)]
 pub enum BinarizeMode{
@@ -54,7 +55,7 @@ impl StatefulWidget for SimpleBinarizeWidget{
 
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.horizontal(|ui|{
-            ui.strong("Threshold: ").on_hover_text("Value to compare every tensor elemnt to");
+            findable_label(ui, "Threshold: ").on_hover_text("Value to compare every tensor elemnt to");
             self.threshold_widget.draw_and_parse(ui, id.with("threshold"));
         });
         if let Ok(threshold) = self.threshold_widget.state(){
@@ -134,11 +135,11 @@ impl StatefulWidget for BinarizeAlongAxisWidget{
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         self.update();
         ui.horizontal(|ui|{
-            ui.strong("Thresholds: ");
+            findable_label(ui, "Thresholds: ");
             self.thresholds_widget.draw_and_parse(ui, id.with("ts"));
         });
         ui.horizontal(|ui|{
-            ui.strong("Axis Id: ");
+            findable_label(ui, "Axis Id: ");
             self.axis_id_widget.draw_and_parse(ui, id.with("id"))
         });
         show_if_error(ui, &self.parsed);
@@ -199,7 +200,7 @@ impl StatefulWidget for BinarizePreprocessingWidget{
                 The data type after this preprocessing is 'bool'"
             ));
             ui.horizontal(|ui|{
-                ui.strong("Mode: ");
+                findable_label(ui, "Mode: ");
 
                 ui.radio_value(&mut self.mode, BinarizeMode::Simple, "General")
                     .on_hover_text(indoc!("
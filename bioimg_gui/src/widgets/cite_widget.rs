@@ -5,6 +5,7 @@ use bioimg_spec::rdf::cite_entry::{CiteEntry2, CiteEntry2Msg};
 use bioimg_spec::rdf::bounded_string::BoundedString;
 
 use super::{collapsible_widget::{CollapsibleWidget, SummarizableWidget}, error_display::show_error, staging_opt::StagingOpt, staging_string::StagingString, staging_vec::ItemWidgetConf, url_widget::StagingUrl, Restore, StatefulWidget, ValueWidget};
+use super::field_finder_widget::findable_label;
 
 pub type ConfString = BoundedString<1, 1024>;
 
@@ -64,15 +65,15 @@ impl StatefulWidget for CiteEntryWidget {
 
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         egui::Grid::new(id).show(ui, |ui| {
-            ui.strong("Text: ");
+            findable_label(ui, "Text: ");
             self.citation_text_widget.draw_and_parse(ui, id.with("Text"));
             ui.end_row();
 
-            ui.strong("Doi: ");
+            findable_label(ui, "Doi: ");
             self.doi_widget.draw_and_parse(ui, id.with("Doi"));
             ui.end_row();
 
-            ui.strong("Url: ");
+            findable_label(ui, "Url: ");
             self.url_widget.draw_and_parse(ui, id.with("Url"));
             ui.end_row();
         });
@@ -1,9 +1,12 @@
 use std::marker::PhantomData;
 
+use super::spell_check::{self, underline_misspellings, SpellChecker};
 use super::{Restore, StatefulWidget, ValueWidget};
 
 pub trait CodeLanguage{
     const NAME: &'static str;
+    /// Whether the text is mostly prose, whose spelling is worth checking
+    const IS_PROSE: bool = false;
 }
 
 pub struct JsonLanguage;
@@ -14,6 +17,7 @@ impl CodeLanguage for JsonLanguage{
 pub struct MarkdwownLang;
 impl CodeLanguage for MarkdwownLang {
     const NAME: &'static str = "md";
+    const IS_PROSE: bool = true;
 }
 
 pub struct YamlLang;
@@ -23,12 +27,22 @@ impl CodeLanguage for YamlLang {
 
 pub struct CodeEditorWidget<LANG: CodeLanguage> {
     pub raw: String,
+    /// Shows the text with syntax highlighting (so it can still be selected and copied) but doesn't allow editing it
+    pub read_only: bool,
+    /// Underlines misspelled words, for languages that are [CodeLanguage::IS_PROSE]
+    pub spell_check: bool,
     marker: PhantomData<LANG>,
 }
 
 impl<LANG: CodeLanguage> Default for CodeEditorWidget<LANG>{
     fn default() -> Self {
-        Self{raw: Default::default(), marker: Default::default()}
+        Self{raw: Default::default(), read_only: false, spell_check: LANG::IS_PROSE, marker: Default::default()}
+    }
+}
+
+impl<LANG: CodeLanguage> CodeEditorWidget<LANG>{
+    pub fn new_read_only(raw: String) -> Self{
+        Self{raw, read_only: true, spell_check: false, marker: Default::default()}
     }
 }
 
@@ -61,6 +75,14 @@ impl<LANG: CodeLanguage + 'static> StatefulWidget for CodeEditorWidget<LANG> {
              ui.ctx(), ui.style()
          );
 
+        if LANG::IS_PROSE && !self.read_only && spell_check::IS_SUPPORTED{
+            let checkbox_response = ui.checkbox(&mut self.spell_check, "Check spelling");
+            if let Some(err) = SpellChecker::load_error(){
+                checkbox_response.on_hover_text(err.to_string());
+            }
+        }
+        let check_spelling = LANG::IS_PROSE && self.spell_check && !self.read_only;
+
         let mut layouter = |ui: &egui::Ui, text: &str, wrap_width: f32| {
             let mut layout_job = egui_extras::syntax_highlighting::highlight(
                 ui.ctx(),
@@ -70,6 +92,9 @@ impl<LANG: CodeLanguage + 'static> StatefulWidget for CodeEditorWidget<LANG> {
                 LANG::NAME,
             );
             layout_job.wrap.max_width = wrap_width;
+            if check_spelling{
+                underline_misspellings(ui, &mut layout_job);
+            }
             ui.fonts(|f| f.layout_job(layout_job))
         };
 
@@ -78,8 +103,14 @@ impl<LANG: CodeLanguage + 'static> StatefulWidget for CodeEditorWidget<LANG> {
                 .scroll_bar_visibility(egui::scroll_area::ScrollBarVisibility::AlwaysVisible)
                 .min_scrolled_height(300.0)
                 .show(ui, |ui| {
+                    let mut read_only_text = self.raw.as_str();
+                    let text: &mut dyn egui::TextBuffer = if self.read_only {
+                        &mut read_only_text
+                    } else {
+                        &mut self.raw
+                    };
                     ui.add(
-                        egui::TextEdit::multiline(&mut self.raw)
+                        egui::TextEdit::multiline(text)
                             .font(egui::TextStyle::Monospace) // for cursor height
                             .code_editor()
                             .desired_rows(50)
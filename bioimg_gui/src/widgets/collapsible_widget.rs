@@ -1,6 +1,7 @@
 use crate::project_data::CollapsibleWidgetSavedData;
 
 use super::{Restore, StatefulWidget, ValueWidget};
+use super::field_finder_widget::{draw_hidden_section, findable_section, is_open_requested};
 
 /// Widgets that can be represented in a compact form can implement this trait.
 /// Usually used in headers of collapsible widgets
@@ -46,6 +47,9 @@ where
     type Value<'p> = W::Value<'p> where W: 'p;
     
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id){
+        if self.is_closed && is_open_requested(ui.ctx(), id){
+            self.is_closed = false;
+        }
         let frame = egui::Frame::new()
             .inner_margin(4.0)
             .stroke(ui.visuals().window_stroke);
@@ -57,13 +61,14 @@ where
                     }
                     self.inner.summarize(ui, id.with("summary".as_ptr()));
                 });
+                draw_hidden_section(ui, id, |ui| self.inner.draw_and_parse(ui, id.with("inner".as_ptr())));
             }else{
                 ui.horizontal(|ui|{
                     if ui.button("⏶").on_hover_text("Collapse widget").clicked(){
                         self.is_closed = true;
                     }
                     ui.vertical(|ui|{
-                        self.inner.draw_and_parse(ui, id.with("inner".as_ptr()));
+                        findable_section(ui, id, |ui| self.inner.draw_and_parse(ui, id.with("inner".as_ptr())));
                     })
                 });
             }
@@ -0,0 +1,98 @@
+//! A meter of how "zoo-ready" a draft is: which required fields are filled in, and which of the
+//! best practices from [bioimg_runtime::lint] are followed
+
+use bioimg_runtime::lint::{lint_subject, LintRule, LintSubject};
+
+pub struct CompletenessCheck{
+    pub name: &'static str,
+    /// Why the check failed, if it did
+    pub problem: Option<String>,
+}
+
+pub struct Completeness{
+    pub required: Vec<CompletenessCheck>,
+    pub recommended: Vec<CompletenessCheck>,
+}
+
+impl Completeness{
+    /// Combines the `required` checks with one check per [LintRule] run on `subject`
+    pub fn new(required: Vec<CompletenessCheck>, subject: &LintSubject<'_>) -> Self{
+        let warnings = lint_subject(subject);
+        let recommended = LintRule::ALL.iter()
+            .map(|rule|{
+                let messages: Vec<&str> = warnings.iter()
+                    .filter(|warning| warning.rule == *rule)
+                    .map(|warning| warning.message.as_str())
+                    .collect();
+                CompletenessCheck{
+                    name: rule.description(),
+                    problem: if messages.is_empty() { None } else { Some(messages.join("\n")) },
+                }
+            })
+            .collect();
+        Self{required, recommended}
+    }
+
+    fn num_passed(checks: &[CompletenessCheck]) -> usize{
+        checks.iter().filter(|check| check.problem.is_none()).count()
+    }
+
+    /// A number between 0.0 and 1.0. Required fields weigh twice as much as recommendations
+    pub fn score(&self) -> f32{
+        let total = 2 * self.required.len() + self.recommended.len();
+        if total == 0{
+            return 1.0
+        }
+        let passed = 2 * Self::num_passed(&self.required) + Self::num_passed(&self.recommended);
+        passed as f32 / total as f32
+    }
+
+    pub fn draw(&self, ui: &mut egui::Ui, id: egui::Id){
+        let num_required_passed = Self::num_passed(&self.required);
+        let num_recommended_passed = Self::num_passed(&self.recommended);
+        let text = format!(
+            "Zoo readiness: {}/{} required, {}/{} recommended",
+            num_required_passed, self.required.len(), num_recommended_passed, self.recommended.len(),
+        );
+        let fill = if num_required_passed < self.required.len(){
+            egui::Color32::from_rgb(180, 60, 60)
+        } else if num_recommended_passed < self.recommended.len(){
+            egui::Color32::from_rgb(180, 140, 40)
+        } else {
+            egui::Color32::from_rgb(60, 140, 60)
+        };
+        ui.add(egui::ProgressBar::new(self.score()).text(text).fill(fill).desired_width(400.0));
+        egui::CollapsingHeader::new("Details").id_salt(id).show(ui, |ui|{
+            for (title, checks) in [("Required", &self.required), ("Recommended", &self.recommended)]{
+                ui.strong(title);
+                for check in checks{
+                    match &check.problem{
+                        None => {
+                            ui.label(format!("✔ {}", check.name));
+                        },
+                        Some(problem) => {
+                            ui.label(egui::RichText::new(format!("✖ {}", check.name)).color(ui.visuals().warn_fg_color))
+                                .on_hover_text(problem);
+                        },
+                    }
+                }
+            }
+        });
+    }
+}
+
+/// A small badge to put next to a section heading: a check mark if `errors` is empty, or the
+/// number of outstanding errors, listed when hovering
+pub fn draw_section_badge(ui: &mut egui::Ui, errors: &[crate::result::GuiError]){
+    if errors.is_empty(){
+        ui.label(egui::RichText::new("✔").color(egui::Color32::GREEN))
+            .on_hover_text("No errors in this section");
+        return
+    }
+    let text = egui::RichText::new(format!("⚠ {}", errors.len())).color(ui.visuals().error_fg_color);
+    ui.label(text).on_hover_ui(|ui|{
+        for error in errors{
+            ui.label(error.to_string());
+        }
+    });
+}
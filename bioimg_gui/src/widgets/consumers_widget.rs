@@ -0,0 +1,107 @@
+//! Lets users declare which tools ("consumers") a model is meant to be used with, by maintaining the
+//! tags and links bioimage.io uses to list models under each of those tools, instead of relying on
+//! users typing free-form tags that don't match
+
+use bioimg_runtime::ilastik_compat::{IlastikIssue, IlastikIssueSeverity};
+use bioimg_spec::rdf;
+
+use super::model_links_widget::ModelLinksWidget;
+use super::staging_string::StagingString;
+use super::staging_vec::StagingVec;
+
+pub struct Consumer{
+    pub name: &'static str,
+    /// The tag under which bioimage.io lists models for this consumer
+    pub tag: &'static str,
+    /// The id of the consumer's own bioimage.io resource, if it has one
+    pub link: Option<&'static str>,
+}
+
+pub const ILASTIK: Consumer = Consumer{name: "ilastik", tag: "ilastik", link: Some("ilastik/ilastik")};
+
+pub const CONSUMERS: &[Consumer] = &[
+    ILASTIK,
+    Consumer{name: "deepImageJ", tag: "deepimagej", link: Some("deepimagej/deepimagej")},
+    Consumer{name: "ZeroCostDL4Mic", tag: "zerocostdl4mic", link: None},
+    Consumer{name: "BiaPy", tag: "biapy", link: Some("biapy/biapy")},
+    Consumer{name: "QuPath", tag: "qupath", link: None},
+    Consumer{name: "Icy", tag: "icy", link: Some("icy/icy")},
+];
+
+fn position_of<'a>(mut raws: impl Iterator<Item=&'a str>, value: &str) -> Option<usize>{
+    raws.position(|raw| raw.trim().eq_ignore_ascii_case(value))
+}
+
+impl Consumer{
+    fn tag_position(&self, tags: &StagingVec<StagingString<rdf::Tag>>) -> Option<usize>{
+        position_of(tags.staging.iter().map(|tag| tag.raw.as_str()), self.tag)
+    }
+
+    fn link_position(&self, links: &ModelLinksWidget) -> Option<usize>{
+        position_of(links.staging.iter().map(|link| link.raw.as_str()), self.link?)
+    }
+
+    pub fn is_targeted(&self, tags: &StagingVec<StagingString<rdf::Tag>>) -> bool{
+        self.tag_position(tags).is_some()
+    }
+
+    fn set_targeted(&self, targeted: bool, tags: &mut StagingVec<StagingString<rdf::Tag>>, links: &mut ModelLinksWidget){
+        if targeted{
+            if self.tag_position(tags).is_none(){
+                tags.staging.push(StagingString::new_with_raw(self.tag));
+            }
+            if let Some(link) = self.link{
+                if self.link_position(links).is_none(){
+                    links.staging.push(StagingString::new_with_raw(link));
+                }
+            }
+        } else {
+            if let Some(idx) = self.tag_position(tags){
+                tags.staging.remove(idx);
+            }
+            if let Some(idx) = self.link_position(links){
+                links.staging.remove(idx);
+            }
+        }
+    }
+}
+
+/// Draws one checkbox per known consumer, adding or removing its tag and link when toggled
+pub fn draw_consumers(
+    ui: &mut egui::Ui,
+    tags: &mut StagingVec<StagingString<rdf::Tag>>,
+    links: &mut ModelLinksWidget,
+){
+    ui.horizontal_wrapped(|ui|{
+        for consumer in CONSUMERS{
+            let mut targeted = consumer.is_targeted(tags);
+            let mut hover_text = format!("Adds the '{}' tag", consumer.tag);
+            if let Some(link) = consumer.link{
+                hover_text += &format!(" and a link to '{link}'");
+            }
+            if ui.checkbox(&mut targeted, consumer.name).on_hover_text(hover_text).changed(){
+                consumer.set_targeted(targeted, tags, links);
+            }
+        }
+    });
+}
+
+/// Summarizes the outcome of [bioimg_runtime::ilastik_compat::check_ilastik_compatibility], listing the
+/// issues when hovering
+pub fn draw_ilastik_issues(ui: &mut egui::Ui, issues: &[IlastikIssue]){
+    if issues.is_empty(){
+        ui.label(egui::RichText::new("✔ Works in ilastik").color(egui::Color32::GREEN));
+        return
+    }
+    let has_errors = issues.iter().any(|issue| issue.severity == IlastikIssueSeverity::Error);
+    let (text, color) = if has_errors{
+        ("⚠ Won't work in ilastik", ui.visuals().error_fg_color)
+    } else {
+        ("⚠ May behave differently in ilastik", ui.visuals().warn_fg_color)
+    };
+    ui.label(egui::RichText::new(text).color(color)).on_hover_ui(|ui|{
+        for issue in issues{
+            ui.label(format!("• {issue}"));
+        }
+    });
+}
@@ -0,0 +1,79 @@
+use bioimg_spec::rdf::model::data_range::DataRange;
+
+use super::staging_float::StagingFloat;
+use super::staging_opt::StagingOpt;
+use super::Restore;
+use super::{error_display::show_if_error, StatefulWidget, ValueWidget};
+use super::field_finder_widget::findable_label;
+
+use crate::result::Result;
+
+/// Edits an interval of valid tensor values. Either bound can be left out (or set to -inf/inf)
+/// to make the interval open-ended on that side
+#[derive(Restore)]
+#[restore(saved_data=crate::project_data::DataRangeWidgetSavedData)]
+pub struct DataRangeWidget{
+    pub min_widget: StagingOpt<StagingFloat<f32>, false>,
+    pub max_widget: StagingOpt<StagingFloat<f32>, false>,
+    #[restore(on_update)]
+    pub parsed: Result<DataRange>,
+}
+
+impl DataRangeWidget {
+    pub fn update(&mut self){
+        self.parsed = || -> Result<DataRange> {
+            let min = self.min_widget.state().transpose()?;
+            let max = self.max_widget.state().transpose()?;
+            Ok(DataRange::try_from_min_max(min, max)?)
+        }();
+    }
+}
+
+impl ValueWidget for DataRangeWidget{
+    type Value<'v> = DataRange;
+    fn set_value<'v>(&mut self, value: Self::Value<'v>) {
+        self.min_widget.set_value(value.min());
+        self.max_widget.set_value(value.max());
+        self.parsed = Ok(value)
+    }
+}
+
+impl Default for DataRangeWidget{
+    fn default() -> Self {
+        Self{
+            min_widget: Default::default(),
+            max_widget: Default::default(),
+            parsed: Ok(DataRange::unbounded()),
+        }
+    }
+}
+
+impl StatefulWidget for DataRangeWidget{
+    type Value<'p> = &'p Result<DataRange>;
+
+    fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
+        self.update();
+        ui.vertical(|ui|{
+            ui.horizontal(|ui|{
+                findable_label(ui, "Min: ").on_hover_text("The smallest value the data can take. Leave empty for -inf");
+                if self.min_widget.0.is_none(){
+                    ui.weak("-∞");
+                }
+                self.min_widget.draw_and_parse(ui, id.with("min"));
+                findable_label(ui, "Max: ").on_hover_text("The largest value the data can take. Leave empty for inf");
+                if self.max_widget.0.is_none(){
+                    ui.weak("∞");
+                }
+                self.max_widget.draw_and_parse(ui, id.with("max"));
+            });
+            match &self.parsed{
+                Ok(range) => { ui.weak(format!("Values in {range}")); },
+                Err(_) => show_if_error(ui, &self.parsed),
+            }
+        });
+    }
+
+    fn state<'p>(&'p self) -> Self::Value<'p> {
+        &self.parsed
+    }
+}
@@ -0,0 +1,121 @@
+//! Data types that the weight formats of the model can actually run with, so that data type pickers
+//! only offer those and warn about the ones that are known to be troublesome.
+//!
+//! The weights are drawn far away from the tensors that use these data types, so the support of the
+//! current weights is installed in the egui context once per frame instead of being passed down.
+
+use bioimg_spec::rdf::model::DataType;
+
+use super::error_display::show_warning;
+use super::search_and_pick_widget::SearchAndPickWidget;
+use super::weights_widget::WeightsWidget;
+use super::StatefulWidget;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WeightsBackend{
+    KerasHdf5,
+    Torchscript,
+    PytorchStateDict,
+    Onnx,
+}
+
+impl WeightsBackend{
+    fn name(self) -> &'static str{
+        match self{
+            Self::KerasHdf5 => "Keras",
+            Self::Torchscript => "TorchScript",
+            Self::PytorchStateDict => "PyTorch",
+            Self::Onnx => "ONNX",
+        }
+    }
+
+    /// Whether tensors of `dtype` can be fed to or produced by weights of this format at all
+    fn supports(self, dtype: DataType) -> bool{
+        match self{
+            // PyTorch has only very limited support for unsigned integers wider than 8 bits
+            Self::Torchscript | Self::PytorchStateDict => {
+                !matches!(dtype, DataType::Uint16 | DataType::Uint32 | DataType::Uint64)
+            },
+            Self::KerasHdf5 | Self::Onnx => true,
+        }
+    }
+
+    /// Why `dtype` might not work even though it is [supported](Self::supports)
+    fn caveat(self, dtype: DataType) -> Option<&'static str>{
+        match (self, dtype){
+            (Self::Onnx, DataType::Float64) => Some(
+                "many ONNX runtime execution providers (e.g. for GPUs or the web) do not implement float64 operators"
+            ),
+            (Self::Onnx, DataType::Bool) => Some("most ONNX operators do not accept bool inputs"),
+            (Self::KerasHdf5, DataType::Uint32 | DataType::Uint64) => Some(
+                "most TensorFlow operations do not accept uint32 or uint64 tensors"
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct DtypeSupport{
+    backends: Vec<WeightsBackend>,
+}
+
+impl DtypeSupport{
+    fn storage_id() -> egui::Id{
+        egui::Id::new("data type support of the model weights")
+    }
+
+    pub fn from_weights(weights: &WeightsWidget) -> Self{
+        let backends = [
+            (weights.keras_weights_widget.0.is_some(), WeightsBackend::KerasHdf5),
+            (weights.torchscript_weights_widget.0.is_some(), WeightsBackend::Torchscript),
+            (weights.pytorch_state_dict_weights_widget.0.is_some(), WeightsBackend::PytorchStateDict),
+            (weights.onnx_weights_widget.0.is_some(), WeightsBackend::Onnx),
+        ];
+        Self{
+            backends: backends.into_iter().filter(|(present, _)| *present).map(|(_, backend)| backend).collect()
+        }
+    }
+
+    /// Makes this support available to the data type pickers drawn in `ctx`
+    pub fn install(self, ctx: &egui::Context){
+        ctx.data_mut(|data| data.insert_temp(Self::storage_id(), self));
+    }
+
+    fn current(ctx: &egui::Context) -> Self{
+        ctx.data(|data| data.get_temp(Self::storage_id())).unwrap_or_default()
+    }
+
+    /// Why `dtype` can't be used with the model weights, if it can't
+    pub fn unsupported_reason(&self, dtype: DataType) -> Option<String>{
+        let unsupported: Vec<&str> = self.backends.iter()
+            .filter(|backend| !backend.supports(dtype))
+            .map(|backend| backend.name())
+            .collect();
+        if unsupported.is_empty(){
+            return None
+        }
+        Some(format!("{dtype} is not supported by the {} weights", unsupported.join(" and ")))
+    }
+
+    pub fn warnings(&self, dtype: DataType) -> Vec<String>{
+        self.backends.iter()
+            .filter_map(|backend| backend.caveat(dtype).map(|caveat| format!("{} weights: {caveat}", backend.name())))
+            .collect()
+    }
+
+    /// Draws `picker` offering only the data types that work with the current weights, and warns
+    /// about the picked one if it is unsupported or has caveats
+    pub fn draw_picker(picker: &mut SearchAndPickWidget<DataType>, ui: &mut egui::Ui, id: egui::Id){
+        let support = Self::current(ui.ctx());
+        picker.set_hidden(|dtype| support.unsupported_reason(*dtype).is_some());
+        picker.draw_and_parse(ui, id);
+        let dtype = picker.value;
+        if let Some(reason) = support.unsupported_reason(dtype){
+            show_warning(ui, reason);
+        }
+        for warning in support.warnings(dtype){
+            show_warning(ui, warning);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::thread::JoinHandle;
+
+use bioimg_zoo::examples::{ExampleModel, EXAMPLE_MODELS};
+
+use crate::request::send_bytes;
+use crate::result::{GuiError, Result};
+
+use super::error_display::show_error;
+
+enum ExampleRdfState{
+    Fetching(JoinHandle<Result<String>>),
+    Ready(String),
+    Failed(GuiError),
+}
+
+/// A window listing a few well-formed models from the zoo, whose rdf.yaml can be
+/// inspected (read-only) as a reference for how the fields are usually filled in
+#[derive(Default)]
+pub struct ExampleGalleryWidget{
+    pub is_open: bool,
+    selected: Option<&'static str>,
+    rdfs: HashMap<&'static str, ExampleRdfState>,
+}
+
+impl ExampleGalleryWidget{
+    fn fetch(example: &'static ExampleModel) -> JoinHandle<Result<String>>{
+        std::thread::spawn(move ||{
+            let resp = send_bytes(example.rdf_request())?;
+            Ok(ExampleModel::parse_rdf_response(&resp)?)
+        })
+    }
+
+    fn update(&mut self){
+        for state in self.rdfs.values_mut(){
+            let ExampleRdfState::Fetching(task) = state else {
+                continue
+            };
+            if !task.is_finished(){
+                continue
+            }
+            let ExampleRdfState::Fetching(task) = std::mem::replace(state, ExampleRdfState::Ready(String::new())) else {
+                unreachable!()
+            };
+            *state = match task.join(){
+                Ok(Ok(rdf)) => ExampleRdfState::Ready(rdf),
+                Ok(Err(err)) => ExampleRdfState::Failed(err),
+                Err(_) => ExampleRdfState::Failed(GuiError::new("Fetching task panicked")),
+            };
+        }
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context){
+        if !self.is_open{
+            return
+        }
+        self.update();
+        let mut is_open = self.is_open;
+        egui::Window::new("Example Models")
+            .open(&mut is_open)
+            .default_size([900.0, 600.0])
+            .show(ctx, |ui|{
+                ui.weak("Well-formed models from the zoo. Inspect them to see how their fields were filled in.");
+                ui.separator();
+                ui.horizontal_top(|ui|{
+                    ui.vertical(|ui|{
+                        ui.set_max_width(250.0);
+                        for example in EXAMPLE_MODELS{
+                            let selected = self.selected == Some(example.id);
+                            let resp = ui.selectable_label(selected, egui::RichText::new(example.title).strong())
+                                .on_hover_text(example.highlights);
+                            ui.weak(example.id);
+                            if resp.clicked(){
+                                self.selected = Some(example.id);
+                                self.rdfs.entry(example.id).or_insert_with(|| ExampleRdfState::Fetching(Self::fetch(example)));
+                            }
+                        }
+                    });
+                    ui.separator();
+                    ui.vertical(|ui| 'rdf: {
+                        let Some(example) = self.selected.and_then(|id| EXAMPLE_MODELS.iter().find(|ex| ex.id == id)) else {
+                            ui.weak("Pick a model on the left to see its rdf.yaml");
+                            break 'rdf
+                        };
+                        ui.horizontal(|ui|{
+                            ui.strong(example.title);
+                            ui.hyperlink_to("open in bioimage.io ↗", format!("https://bioimage.io/#/?id={}", example.id));
+                        });
+                        ui.label(example.highlights);
+                        match self.rdfs.get(example.id){
+                            None => (),
+                            Some(ExampleRdfState::Fetching(_)) => {
+                                ui.horizontal(|ui|{
+                                    ui.spinner();
+                                    ui.weak("Fetching rdf.yaml...");
+                                });
+                                ui.ctx().request_repaint();
+                            },
+                            Some(ExampleRdfState::Failed(err)) => {
+                                show_error(ui, format!("Could not fetch {}: {err}", example.rdf_url()));
+                                if ui.button("Retry").clicked(){
+                                    self.rdfs.insert(example.id, ExampleRdfState::Fetching(Self::fetch(example)));
+                                }
+                            },
+                            Some(ExampleRdfState::Ready(rdf)) => {
+                                let theme = egui_extras::syntax_highlighting::CodeTheme::from_memory(ui.ctx(), ui.style());
+                                let mut layouter = |ui: &egui::Ui, text: &str, wrap_width: f32| {
+                                    let mut layout_job = egui_extras::syntax_highlighting::highlight(
+                                        ui.ctx(), ui.style(), &theme, text, "yaml",
+                                    );
+                                    layout_job.wrap.max_width = wrap_width;
+                                    ui.fonts(|f| f.layout_job(layout_job))
+                                };
+                                egui::ScrollArea::vertical().show(ui, |ui|{
+                                    ui.add(
+                                        egui::TextEdit::multiline(&mut rdf.as_str())
+                                            .code_editor()
+                                            .desired_width(f32::INFINITY)
+                                            .layouter(&mut layouter)
+                                    );
+                                });
+                            },
+                        }
+                    });
+                });
+            });
+        self.is_open = is_open;
+    }
+}
@@ -0,0 +1,73 @@
+//! Open/closed state of the collapsible items of [VecWidget](super::util::VecWidget)s, kept in the app
+//! state so that it is saved in drafts and reloading a draft restores the user's working view.
+//!
+//! egui keeps this state in its own memory, keyed by the `collapsible_id_source` of each list. Those ids
+//! are only stable across restarts if they are derived from strings and indices rather than pointers,
+//! so lists that should keep their state must be drawn with such ids.
+
+use std::collections::{BTreeSet, HashSet};
+use std::sync::{Arc, Mutex};
+
+use super::Restore;
+
+#[derive(Default)]
+struct ExpansionStateInner{
+    /// Ids of the items that are currently collapsed
+    closed: BTreeSet<u64>,
+    /// Ids of the items whose state was already applied to egui since the last restore
+    applied: HashSet<u64>,
+}
+
+#[derive(Clone, Default)]
+pub struct ExpansionState(Arc<Mutex<ExpansionStateInner>>);
+
+impl ExpansionState{
+    fn storage_id() -> egui::Id{
+        egui::Id::new("vec widget expansion state")
+    }
+
+    /// Makes this state available to the [VecWidget](super::util::VecWidget)s drawn in `ctx`
+    pub fn install(&self, ctx: &egui::Context){
+        ctx.data_mut(|data| data.insert_temp(Self::storage_id(), self.clone()));
+    }
+
+    fn current(ctx: &egui::Context) -> Option<Self>{
+        ctx.data(|data| data.get_temp(Self::storage_id()))
+    }
+
+    /// Whether the item `item_id` should be opened or closed because a draft was just restored.
+    /// Returns `None` once that has been applied
+    pub fn take_restored(ctx: &egui::Context, item_id: egui::Id) -> Option<bool>{
+        let state = Self::current(ctx)?;
+        let mut inner = state.0.lock().unwrap();
+        let key = item_id.value();
+        if !inner.applied.insert(key){
+            return None
+        }
+        Some(!inner.closed.contains(&key))
+    }
+
+    pub fn record(ctx: &egui::Context, item_id: egui::Id, is_open: bool){
+        let Some(state) = Self::current(ctx) else {
+            return
+        };
+        let mut inner = state.0.lock().unwrap();
+        if is_open{
+            inner.closed.remove(&item_id.value());
+        } else {
+            inner.closed.insert(item_id.value());
+        }
+    }
+}
+
+impl Restore for ExpansionState{
+    type SavedData = BTreeSet<u64>;
+    fn dump(&self) -> Self::SavedData {
+        self.0.lock().unwrap().closed.clone()
+    }
+    fn restore(&mut self, saved_data: Self::SavedData) {
+        let mut inner = self.0.lock().unwrap();
+        inner.closed = saved_data;
+        inner.applied.clear();
+    }
+}
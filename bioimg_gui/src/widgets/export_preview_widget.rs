@@ -0,0 +1,118 @@
+use bioimg_runtime::size_report::format_size;
+use bioimg_runtime::zip_writer_ext::PackedEntry;
+use bioimg_runtime::zoo_model::{PackingDryRun, PackingOptions, ZooModel};
+
+enum PreviewState{
+    #[cfg(not(target_arch="wasm32"))]
+    Running(std::thread::JoinHandle<PackingDryRun>),
+    Finished{entries: Vec<PackedEntry>, error: Option<String>},
+}
+
+impl From<PackingDryRun> for PreviewState{
+    fn from(dry_run: PackingDryRun) -> Self {
+        Self::Finished{entries: dry_run.entries, error: dry_run.error.map(|err| err.to_string())}
+    }
+}
+
+/// A window listing every file an export would create, where it comes from and how big it is, plus
+/// whatever would make the export fail, so users can check the archive before waiting for the real thing
+#[derive(Default)]
+pub struct ExportPreviewWidget{
+    pub is_open: bool,
+    state: Option<PreviewState>,
+}
+
+impl ExportPreviewWidget{
+    /// Opens the window and starts a dry run of packing `model`
+    pub fn preview(&mut self, model: ZooModel){
+        self.is_open = true;
+        #[cfg(not(target_arch="wasm32"))]
+        {
+            self.state = Some(PreviewState::Running(std::thread::spawn(move || {
+                model.pack_dry_run(&PackingOptions::default())
+            })));
+        }
+        #[cfg(target_arch="wasm32")]
+        {
+            self.state = Some(model.pack_dry_run(&PackingOptions::default()).into());
+        }
+    }
+
+    /// Opens the window showing only `error`, for when a model can't even be created from the form
+    pub fn show_error(&mut self, error: String){
+        self.is_open = true;
+        self.state = Some(PreviewState::Finished{entries: vec![], error: Some(error)});
+    }
+
+    fn draw_report(ui: &mut egui::Ui, entries: &[PackedEntry], error: Option<&str>){
+        match error{
+            Some(error) => {
+                ui.colored_label(ui.visuals().error_fg_color, format!("Export would fail: {error}"));
+                if !entries.is_empty(){
+                    ui.weak("Files packed before the failure:");
+                }
+            },
+            None => { ui.label("No problems found. The exported archive will contain:"); },
+        }
+        if entries.is_empty(){
+            return
+        }
+        ui.separator();
+        egui::ScrollArea::vertical().show(ui, |ui|{
+            egui::Grid::new("export preview").striped(true).num_columns(3).show(ui, |ui|{
+                ui.strong("File");
+                ui.strong("Size");
+                ui.strong("Source");
+                ui.end_row();
+                for entry in entries{
+                    ui.monospace(entry.path.to_string());
+                    ui.label(format_size(entry.size_bytes));
+                    match &entry.source{
+                        Some(source) => ui.label(source),
+                        None => ui.weak("generated"),
+                    };
+                    ui.end_row();
+                }
+                ui.strong("Total");
+                ui.strong(format_size(entries.iter().map(|entry| entry.size_bytes).sum()));
+                ui.end_row();
+            });
+        });
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context){
+        if !self.is_open{
+            return
+        }
+        #[cfg(not(target_arch="wasm32"))]
+        if let Some(PreviewState::Running(handle)) = &self.state{
+            if handle.is_finished(){
+                let Some(PreviewState::Running(handle)) = self.state.take() else {
+                    unreachable!()
+                };
+                self.state = Some(match handle.join(){
+                    Ok(dry_run) => dry_run.into(),
+                    Err(_) => PreviewState::Finished{entries: vec![], error: Some("Export preview crashed".to_owned())},
+                });
+            }
+        }
+        let Some(state) = &self.state else {
+            return
+        };
+        egui::Window::new("Export Preview")
+            .open(&mut self.is_open)
+            .default_size([700.0, 400.0])
+            .show(ctx, |ui|{
+                match state{
+                    #[cfg(not(target_arch="wasm32"))]
+                    PreviewState::Running(_) => {
+                        ui.horizontal(|ui|{
+                            ui.spinner();
+                            ui.label("Reading every file the model references...");
+                        });
+                    },
+                    PreviewState::Finished{entries, error} => Self::draw_report(ui, entries, error.as_deref()),
+                }
+            });
+    }
+}
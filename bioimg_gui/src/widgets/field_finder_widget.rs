@@ -0,0 +1,260 @@
+//! A "find field" box (Ctrl+F) that jumps to a field of the form by (fuzzy) matching its label.
+//!
+//! Labels drawn with [findable_label] are indexed as they are drawn, together with the collapsible
+//! sections enclosing them (see [findable_section]). Picking a label in the [FieldFinderWidget] asks
+//! those sections to open up and scrolls the label into view.
+//!
+//! The index is rebuilt on every pass, so labels of deleted widgets disappear from it. While the finder
+//! is open, collapsed sections draw their contents invisibly (see [draw_hidden_section]) so that the
+//! labels inside of them can be found too.
+
+use std::collections::HashSet;
+
+/// How long (in seconds) a found label stays highlighted
+const HIGHLIGHT_DURATION: f64 = 2.0;
+/// How long (in seconds) to keep looking for a label that doesn't show up, e.g. because its widget was deleted
+const TARGET_TIMEOUT: f64 = 1.0;
+const MAX_SUGGESTIONS: usize = 10;
+
+fn index_id() -> egui::Id{
+    egui::Id::new("field finder index")
+}
+
+fn section_stack_id() -> egui::Id{
+    egui::Id::new("field finder section stack")
+}
+
+fn target_id() -> egui::Id{
+    egui::Id::new("field finder target")
+}
+
+fn finder_open_id() -> egui::Id{
+    egui::Id::new("field finder open")
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct IndexedField{
+    label: String,
+    /// Collapsible sections containing the label, outermost first
+    enclosing_sections: Vec<egui::Id>,
+}
+
+/// The labels drawn during the current pass, plus the ones of the last complete pass
+#[derive(Clone, Default)]
+struct FieldIndex{
+    pass_nr: u64,
+    current: HashSet<IndexedField>,
+    previous: HashSet<IndexedField>,
+}
+
+impl FieldIndex{
+    /// Starts a new generation if `pass_nr` is newer than the one being indexed
+    fn advance_to(&mut self, pass_nr: u64){
+        if pass_nr != self.pass_nr{
+            self.previous = std::mem::take(&mut self.current);
+            self.pass_nr = pass_nr;
+        }
+    }
+
+    fn fields(&self) -> impl Iterator<Item = &IndexedField>{
+        self.current.union(&self.previous)
+    }
+}
+
+#[derive(Clone, Default)]
+struct SectionStack(Vec<egui::Id>);
+
+#[derive(Clone)]
+struct FindTarget{
+    field: IndexedField,
+    requested_at: f64,
+    found_at: Option<f64>,
+}
+
+fn current_target(ctx: &egui::Context) -> Option<FindTarget>{
+    let target: FindTarget = ctx.data(|data| data.get_temp(target_id()))?;
+    let now = ctx.input(|i| i.time);
+    let expired = match target.found_at{
+        Some(found_at) => now - found_at > HIGHLIGHT_DURATION,
+        None => now - target.requested_at > TARGET_TIMEOUT,
+    };
+    if expired{
+        ctx.data_mut(|data| data.remove::<FindTarget>(target_id()));
+        return None
+    }
+    Some(target)
+}
+
+fn label_text(label: &str) -> &str{
+    label.trim().trim_end_matches(':').trim_end()
+}
+
+/// Draws a field label like `ui.strong(label)` and makes it reachable via the [FieldFinderWidget]
+pub fn findable_label(ui: &mut egui::Ui, label: &str) -> egui::Response{
+    let response = ui.strong(label);
+    let field = IndexedField{
+        label: label_text(label).to_owned(),
+        enclosing_sections: ui.data(|data| data.get_temp::<SectionStack>(section_stack_id()).unwrap_or_default()).0,
+    };
+
+    // labels drawn by [draw_hidden_section] are only indexed; the real one shows up once its section opens
+    let target = current_target(ui.ctx()).filter(|_| ui.is_visible());
+    if let Some(mut target) = target{
+        if target.field == field{
+            if target.found_at.is_none(){
+                response.scroll_to_me(Some(egui::Align::Center));
+                target.found_at = Some(ui.input(|i| i.time));
+                ui.data_mut(|data| data.insert_temp(target_id(), target));
+            }
+            ui.painter().rect_stroke(
+                response.rect.expand(3.0),
+                3.0,
+                ui.visuals().selection.stroke,
+                egui::StrokeKind::Outside,
+            );
+            ui.ctx().request_repaint();
+        }
+    }
+
+    let pass_nr = ui.ctx().cumulative_pass_nr();
+    ui.data_mut(|data| {
+        let index = data.get_temp_mut_or_default::<FieldIndex>(index_id());
+        index.advance_to(pass_nr);
+        index.current.insert(field);
+    });
+    response
+}
+
+/// Draws the contents of a collapsible section, so that the labels inside of it know they must
+/// open `section_id` before they can be scrolled to
+pub fn findable_section<R>(ui: &mut egui::Ui, section_id: egui::Id, add_contents: impl FnOnce(&mut egui::Ui) -> R) -> R{
+    ui.data_mut(|data| data.get_temp_mut_or_default::<SectionStack>(section_stack_id()).0.push(section_id));
+    let out = add_contents(ui);
+    ui.data_mut(|data| data.get_temp_mut_or_default::<SectionStack>(section_stack_id()).0.pop());
+    out
+}
+
+/// Draws the contents of the collapsed section `section_id` invisibly, without taking up any space, so
+/// that its labels can be indexed. Does nothing unless the [FieldFinderWidget] is open, since drawing
+/// everything that is collapsed on every pass would be wasteful
+pub fn draw_hidden_section<R>(ui: &mut egui::Ui, section_id: egui::Id, add_contents: impl FnOnce(&mut egui::Ui) -> R){
+    let finder_is_open = ui.data(|data| data.get_temp::<bool>(finder_open_id())).unwrap_or(false);
+    if !finder_is_open{
+        return
+    }
+    let mut hidden_ui = ui.new_child(egui::UiBuilder::new().id_salt(section_id).invisible());
+    findable_section(&mut hidden_ui, section_id, add_contents);
+}
+
+/// Whether the [FieldFinderWidget] wants the section `section_id` to be open so that it can scroll to
+/// one of its fields
+pub fn is_open_requested(ctx: &egui::Context, section_id: egui::Id) -> bool{
+    match current_target(ctx){
+        Some(target) => target.found_at.is_none() && target.field.enclosing_sections.contains(&section_id),
+        None => false,
+    }
+}
+
+/// Scores how well `query` matches `label`, or `None` if the characters of `query` don't all
+/// appear in `label` in order. Higher is better
+fn fuzzy_score(query: &str, label: &str) -> Option<i64>{
+    let query = query.to_lowercase();
+    let label = label.to_lowercase();
+    if let Some(position) = label.find(&query){
+        return Some(1000 - position as i64)
+    }
+    let mut score = 0;
+    let mut label_chars = label.chars().enumerate();
+    let mut last_match: Option<usize> = None;
+    for query_char in query.chars().filter(|c| !c.is_whitespace()){
+        let (position, _) = label_chars.find(|(_, label_char)| *label_char == query_char)?;
+        if let Some(last_match) = last_match{
+            score -= (position - last_match - 1) as i64;
+        }
+        last_match = Some(position);
+    }
+    Some(score)
+}
+
+#[derive(Default)]
+pub struct FieldFinderWidget{
+    pub is_open: bool,
+    query: String,
+}
+
+impl FieldFinderWidget{
+    fn find(&self, ctx: &egui::Context, field: IndexedField){
+        let target = FindTarget{field, requested_at: ctx.input(|i| i.time), found_at: None};
+        ctx.data_mut(|data| data.insert_temp(target_id(), target));
+        ctx.request_repaint();
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context){
+        let find_shortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::F);
+        if ctx.input_mut(|i| i.consume_shortcut(&find_shortcut)){
+            self.is_open = true;
+            ctx.memory_mut(|mem| mem.request_focus(egui::Id::new("field finder query")));
+        }
+        if self.is_open && ctx.input(|i| i.key_pressed(egui::Key::Escape)){
+            self.is_open = false;
+        }
+        ctx.data_mut(|data| data.insert_temp(finder_open_id(), self.is_open));
+        if !self.is_open{
+            return
+        }
+
+        let pass_nr = ctx.cumulative_pass_nr();
+        let index = ctx.data_mut(|data| {
+            let index = data.get_temp_mut_or_default::<FieldIndex>(index_id());
+            index.advance_to(pass_nr);
+            index.clone()
+        });
+        let mut is_open = self.is_open;
+        egui::Window::new("🔎 Find field")
+            .open(&mut is_open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::RIGHT_TOP, [-10.0, 30.0])
+            .show(ctx, |ui|{
+                let query_response = ui.add(
+                    egui::TextEdit::singleline(&mut self.query)
+                        .id(egui::Id::new("field finder query"))
+                        .hint_text("e.g. license, opset, halo")
+                );
+                let query = self.query.trim();
+                if query.is_empty(){
+                    return
+                }
+                let mut matches: Vec<(i64, &IndexedField)> = index.fields()
+                    .filter_map(|field| Some((fuzzy_score(query, &field.label)?, field)))
+                    .collect();
+                matches.sort_by(|(score_a, field_a), (score_b, field_b)| {
+                    score_b.cmp(score_a).then_with(|| field_a.label.cmp(&field_b.label))
+                });
+                if matches.is_empty(){
+                    ui.weak("No matching fields");
+                    return
+                }
+                let enter_pressed = query_response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                for (idx, (_, field)) in matches.iter().take(MAX_SUGGESTIONS).enumerate(){
+                    if ui.button(&field.label).clicked() || (idx == 0 && enter_pressed){
+                        self.find(ctx, (*field).clone());
+                    }
+                }
+            });
+        self.is_open = is_open;
+    }
+}
+
+#[test]
+fn test_fuzzy_score(){
+    assert_eq!(fuzzy_score("license", "License"), Some(1000));
+    // substrings beat scattered matches, and earlier substrings beat later ones
+    assert!(fuzzy_score("name", "Name") > fuzzy_score("name", "Channel Name"));
+    assert!(fuzzy_score("name", "Channel Name") > fuzzy_score("nme", "Name"));
+    // scattered matches lose a point for every character skipped between them
+    assert_eq!(fuzzy_score("mdl id", "Model Id"), Some(-3));
+    assert!(fuzzy_score("min perc", "Min Percentile") > fuzzy_score("mprc", "Min Percentile"));
+    assert_eq!(fuzzy_score("halo", "Axes"), None);
+    assert_eq!(fuzzy_score("ol", "Lo"), None);
+}
@@ -17,6 +17,7 @@ use super::{Restore, StatefulWidget, ValueWidget};
 use super::error_display::show_error;
 use super::url_widget::StagingUrl;
 use super::search_and_pick_widget::SearchAndPickWidget;
+use super::field_finder_widget::findable_label;
 
 #[derive(Default)]
 pub enum LocalFileState{
@@ -294,7 +295,7 @@ impl StatefulWidget for LocalFileSourceWidget{
             });
             if let LocalFileState::PickingInner{inner_options_widget, ..} = state {
                 ui.horizontal(|ui|{
-                    ui.strong("Inner Path: ");
+                    findable_label(ui, "Inner Path: ");
                     inner_options_widget.draw_and_parse(ui, id.with("inner_widget".as_ptr()));
                 });
             }
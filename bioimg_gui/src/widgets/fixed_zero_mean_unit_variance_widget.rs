@@ -1,13 +1,19 @@
+use std::borrow::Borrow;
+
+use bioimg_runtime::tensor_stats::TensorStats;
 use bioimg_spec::rdf::model::{self as modelrdf, preprocessing::zero_mean_unit_variance::ZmuvStdDeviation};
 use bioimg_spec::rdf::model::preprocessing as preproc;
 
 use crate::project_data::ZmuvWidgetModeSavedData;
 use crate::result::{GuiError, Result, VecResultExt};
+use super::error_display::show_error;
 use super::iconify::Iconify;
+use super::test_tensor_widget::TestTensorContext;
 use super::search_and_pick_widget::SearchAndPickWidget;
 use super::staging_float::StagingFloat;
 use super::Restore;
 use super::{error_display::show_if_error, staging_string::StagingString, staging_vec::{ItemWidgetConf, StagingVec}, StatefulWidget, ValueWidget};
+use super::field_finder_widget::findable_label;
 
 #[derive(PartialEq, Eq, Default, strum::VariantArray, strum::AsRefStr, strum::Display, Copy, Clone)]
 pub enum ZmuvWidgetMode{
@@ -33,21 +39,14 @@ impl Restore for ZmuvWidgetMode{
     }
 }
 
-#[derive(Restore)]
+#[derive(Restore, ValueWidget)]
 #[restore(saved_data=crate::project_data::SimpleFixedZmuvWidgetSavedData)]
+#[value_widget(value=preproc::SimpleFixedZmuv)]
 pub struct SimpleFixedZmuvWidget{
     pub mean_widget: StagingFloat<f32>,
     pub std_widget: StagingFloat<ZmuvStdDeviation>,
 }
 
-impl ValueWidget for SimpleFixedZmuvWidget{
-    type Value<'v> = preproc::SimpleFixedZmuv;
-    fn set_value<'v>(&mut self, value: Self::Value<'v>) {
-        self.mean_widget.set_value(value.mean);
-        self.std_widget.set_value(value.std);
-    }
-}
-
 impl Default for SimpleFixedZmuvWidget{
     fn default() -> Self {
         Self {
@@ -57,13 +56,22 @@ impl Default for SimpleFixedZmuvWidget{
     }
 }
 
+impl SimpleFixedZmuvWidget{
+    fn from_stats(stats: &TensorStats) -> Self{
+        Self {
+            mean_widget: StagingFloat::new_with_raw(stats.mean as f32),
+            std_widget: StagingFloat::new_with_raw(stats.std as f32),
+        }
+    }
+}
+
 impl StatefulWidget for SimpleFixedZmuvWidget{
     type Value<'p> = Result<preproc::SimpleFixedZmuv>;
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.horizontal(|ui|{
-            ui.strong("Mean: ");
+            findable_label(ui, "Mean: ");
             self.mean_widget.draw_and_parse(ui, id.with("mean".as_ptr()));
-            ui.strong(" Standard Deviation: ");
+            findable_label(ui, " Standard Deviation: ");
             self.std_widget.draw_and_parse(ui, id.with("std".as_ptr()));
         });
     }
@@ -144,7 +152,7 @@ impl StatefulWidget for FixedZmuvAlongAxisWidget{
                 self.axis_widget.draw_and_parse(ui, id.with("ax".as_ptr()));
             });
             ui.horizontal(|ui|{
-                ui.strong("Gains and Offsets:");
+                findable_label(ui, "Gains and Offsets:");
                 self.mean_and_std_widget.draw_and_parse(ui, id.with("go".as_ptr()));
             });
             show_if_error(ui, &self.parsed)
@@ -164,6 +172,42 @@ pub struct FixedZmuvWidget{
     pub mode_widget: SearchAndPickWidget<ZmuvWidgetMode, false>,
     pub simple_widget: SimpleFixedZmuvWidget,
     pub along_axis_widget: FixedZmuvAlongAxisWidget,
+    #[restore(default)]
+    pub test_tensor_error: Option<GuiError>,
+}
+
+impl FixedZmuvWidget{
+    /// Fills in mean and standard deviation of the test tensor; in "Along Axis" mode, one pair for each
+    /// entry of the selected axis
+    pub fn fill_from_test_tensor(&mut self, test_tensor: &TestTensorContext) -> Result<()>{
+        match self.mode_widget.value{
+            ZmuvWidgetMode::Simple => {
+                self.simple_widget = SimpleFixedZmuvWidget::from_stats(&TensorStats::compute(test_tensor.data));
+            },
+            ZmuvWidgetMode::AlongAxis => {
+                let axis_id = self.along_axis_widget.axis_widget.state()?;
+                let axis_index = test_tensor.axis_index(axis_id.borrow())
+                    .ok_or_else(|| GuiError::new(format!("Test tensor has no axis '{axis_id}'")))?;
+                let stats = TensorStats::along_axis(test_tensor.data, axis_index).unwrap_or_default();
+                self.along_axis_widget.mean_and_std_widget.staging = stats.iter()
+                    .map(SimpleFixedZmuvWidget::from_stats)
+                    .collect();
+                self.along_axis_widget.update();
+            },
+        }
+        Ok(())
+    }
+
+    pub fn draw_test_tensor_tools(&mut self, ui: &mut egui::Ui, test_tensor: &TestTensorContext){
+        if ui.button("Compute from test tensor").on_hover_text(
+            "Fills in the mean and standard deviation of the values in the test tensor"
+        ).clicked(){
+            self.test_tensor_error = self.fill_from_test_tensor(test_tensor).err();
+        }
+        if let Some(err) = &self.test_tensor_error{
+            show_error(ui, err);
+        }
+    }
 }
 
 impl Iconify for FixedZmuvWidget{
@@ -195,7 +239,7 @@ impl StatefulWidget for FixedZmuvWidget{
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.vertical(|ui|{
             ui.horizontal(|ui|{
-                ui.strong("Mode: ");
+                findable_label(ui, "Mode: ");
                 self.mode_widget.draw_and_parse(ui, id.with("mode".as_ptr()));
             });
             match self.mode_widget.value{
@@ -153,7 +153,78 @@ impl ValueWidget for ImageWidget2{
     }
 }
 
+/// Whether the paste shortcut was used this frame. Checks for the release of the `V` key because
+/// egui only reports the press as [egui::Event::Paste] when the clipboard holds text, which it
+/// doesn't for e.g. screenshots
+fn paste_shortcut_used(ui: &egui::Ui) -> bool{
+    ui.input(|i| i.events.iter().any(|event| match event{
+        egui::Event::Paste(_) => true,
+        egui::Event::Key{key: egui::Key::V, pressed: false, modifiers, ..} => modifiers.command,
+        _ => false,
+    }))
+}
+
+#[cfg(not(target_arch="wasm32"))]
+async fn read_clipboard_image() -> Result<image::DynamicImage>{
+    let mut clipboard = arboard::Clipboard::new()
+        .map_err(|err| GuiError::new(format!("Could not access the clipboard: {err}")))?;
+    let clipboard_img = clipboard.get_image().map_err(|err| match err{
+        arboard::Error::ContentNotAvailable => GuiError::new("There is no image in the clipboard"),
+        err => GuiError::new(format!("Could not read image from the clipboard: {err}")),
+    })?;
+    let width = clipboard_img.width as u32;
+    let height = clipboard_img.height as u32;
+    let Some(img) = image::RgbaImage::from_raw(width, height, clipboard_img.bytes.into_owned()) else {
+        return Err(GuiError::new("Clipboard image data doesn't match its dimensions"))
+    };
+    Ok(image::DynamicImage::ImageRgba8(img))
+}
+
+/// Reads the first image in the clipboard via the async Clipboard API. Browsers only allow this in
+/// secure contexts and may ask the user for permission first
+#[cfg(target_arch="wasm32")]
+async fn read_clipboard_image() -> Result<image::DynamicImage>{
+    use eframe::wasm_bindgen::{JsCast as _, JsValue};
+    use wasm_bindgen_futures::JsFuture;
+
+    let js_err = |err: JsValue| GuiError::new(format!("Could not read the clipboard: {err:?}"));
+    let window = eframe::web_sys::window().ok_or_else(|| GuiError::new("No browser window"))?;
+    let items = JsFuture::from(window.navigator().clipboard().read()).await.map_err(js_err)?;
+    for item in js_sys::Array::from(&items).iter(){
+        let item: eframe::web_sys::ClipboardItem = item.unchecked_into();
+        let image_type = item.types().iter()
+            .filter_map(|mime_type| mime_type.as_string())
+            .find(|mime_type| mime_type.starts_with("image/"));
+        let Some(image_type) = image_type else {
+            continue
+        };
+        let blob: eframe::web_sys::Blob = JsFuture::from(item.get_type(&image_type)).await.map_err(js_err)?.unchecked_into();
+        let buffer = JsFuture::from(blob.array_buffer()).await.map_err(js_err)?;
+        let img_data = js_sys::Uint8Array::new(&buffer).to_vec();
+        return Ok(image::load_from_memory(&img_data)?)
+    }
+    Err(GuiError::new("There is no image in the clipboard"))
+}
+
 impl ImageWidget2{
+    fn spawn_paste_image_task(
+        generation: Generation,
+        loading_state: GenSync<LoadingState>,
+        ctx: egui::Context,
+    ){
+        let fut = async move {
+            loading_state.lock_then_maybe_set(generation, match read_clipboard_image().await{
+                Err(err) => LoadingState::Failed { source: None, err },
+                Ok(img) => LoadingState::Forced { img: Arc::new(img), texture: None },
+            });
+            ctx.request_repaint();
+        };
+        #[cfg(target_arch="wasm32")]
+        wasm_bindgen_futures::spawn_local(fut);
+        #[cfg(not(target_arch="wasm32"))]
+        std::thread::spawn(move || smol::block_on(fut));
+    }
+
     fn spawn_load_image_task(
         generation: Generation,
         file_source: FileSource,
@@ -161,12 +232,12 @@ impl ImageWidget2{
         ctx: egui::Context,
     ){
         let fut = async move {
-            let res = || -> Result<ArcDynImg>{
+            let res = async {
                 let mut img_data = Vec::<u8>::new();
-                file_source.read_to_end(&mut img_data)?;
+                file_source.read_to_end_async(&mut img_data, None).await?;
                 let img = image::io::Reader::new(Cursor::new(img_data)).with_guessed_format()?.decode()?;
-                Ok(Arc::new(img))
-            }();
+                Result::<ArcDynImg>::Ok(Arc::new(img))
+            }.await;
             loading_state.lock_then_maybe_set(generation, match res{
                 Err(e) => LoadingState::Failed { source: Some(file_source), err: e },
                 Ok(img) => LoadingState::Ready { source: file_source, img, texture: None },
@@ -192,7 +263,8 @@ impl StatefulWidget for ImageWidget2{
             tex.show(ui, egui::Vec2 { y: 50.0, x: 50.0 * ratio as f32 }); //FIXME: can we not hardcode this?
         }
 
-        ui.vertical(|ui|{
+        let mut paste_clicked = false;
+        let response = ui.vertical(|ui|{
             let state_clone = self.loading_state.clone();
             self.loading_state.lock_then_replace_with(|generation, state| match state{
                 LoadingState::Forced { img, mut texture } => {
@@ -209,7 +281,12 @@ impl StatefulWidget for ImageWidget2{
                     }
                 },
                 LoadingState::Empty => 'empty: {
-                    self.file_source_widget.draw_and_parse(ui, id.with("file source".as_ptr()));
+                    ui.horizontal(|ui|{
+                        self.file_source_widget.draw_and_parse(ui, id.with("file source".as_ptr()));
+                        paste_clicked = ui.button("📋 Paste")
+                            .on_hover_text("Paste an image from the clipboard (or press Ctrl+V over this field)")
+                            .clicked();
+                    });
                     let Ok(source) = self.file_source_widget.state() else{
                         break 'empty (generation, LoadingState::Empty)
                     };
@@ -244,7 +321,12 @@ impl StatefulWidget for ImageWidget2{
                     }).inner
                 },
                 LoadingState::Failed { source, err } => 'failed: {
-                    self.file_source_widget.draw_and_parse(ui, id.with("file source".as_ptr()));
+                    ui.horizontal(|ui|{
+                        self.file_source_widget.draw_and_parse(ui, id.with("file source".as_ptr()));
+                        paste_clicked = ui.button("📋 Paste")
+                            .on_hover_text("Paste an image from the clipboard (or press Ctrl+V over this field)")
+                            .clicked();
+                    });
                     show_error(ui, &err);
                     let Ok(widget_source) = self.file_source_widget.state() else {
                         break 'failed (generation.incremented(), LoadingState::Empty)
@@ -255,8 +337,18 @@ impl StatefulWidget for ImageWidget2{
                     (generation.incremented(), LoadingState::Empty)
                 },
             })
-        });
+        }).response;
 
+        // a focused text field (e.g. the url of the file source) gets the pasted text instead
+        let nothing_focused = ui.memory(|mem| mem.focused().is_none());
+        if paste_clicked || (nothing_focused && response.contains_pointer() && paste_shortcut_used(ui)){
+            let generation = {
+                let mut state_guard = self.loading_state.lock();
+                state_guard.0 = state_guard.0.incremented();
+                state_guard.0
+            };
+            Self::spawn_paste_image_task(generation, self.loading_state.clone(), ui.ctx().clone());
+        }
     }
 
     fn state(&self) -> Result<ArcDynImg>{
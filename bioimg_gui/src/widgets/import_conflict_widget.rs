@@ -0,0 +1,278 @@
+//! Lets users merge an imported model into a draft that already has content, choosing per section
+//! whether to keep what's in the form or take what comes from the import.
+//!
+//! Both sides are compared via their [crate::project_data::LatestAppStateSavedData], so that sections
+//! that didn't change aren't even shown, and merging is just a matter of picking fields from either side.
+
+use bioimg_runtime::zip_archive_ext::SharedZipArchive;
+
+use crate::project_data::LatestAppStateSavedData;
+use crate::result::{GuiError, Result};
+
+type JsonMap = serde_json::Map<String, serde_json::Value>;
+
+const METADATA: &str = "Metadata";
+const AUTHORS: &str = "Authors & Citations";
+const DOCS: &str = "Documentation";
+const INTERFACE: &str = "Inputs & Outputs";
+const WEIGHTS: &str = "Weights";
+/// Groups of sections that can be taken from the import as a whole
+const SECTION_GROUPS: &[&str] = &[METADATA, AUTHORS, DOCS, INTERFACE, WEIGHTS];
+
+/// Sections of the form, as shown to the user, the group they belong to and the saved data fields they consist of
+const SECTIONS: &[(&str, &str, &[&str])] = &[
+    (METADATA, "Name", &["staging_name"]),
+    (METADATA, "Description", &["staging_description"]),
+    (METADATA, "Cover Images", &["cover_images"]),
+    (METADATA, "Model Id", &["model_id_widget"]),
+    (AUTHORS, "Authors", &["staging_authors"]),
+    (METADATA, "Attachments", &["attachments_widget"]),
+    (AUTHORS, "Citations", &["staging_citations"]),
+    (METADATA, "Custom Config", &["custom_config_widget"]),
+    (METADATA, "Git Repository", &["staging_git_repo"]),
+    (METADATA, "Icon", &["icon_widget"]),
+    (METADATA, "Links", &["links_widget"]),
+    (AUTHORS, "Maintainers", &["staging_maintainers"]),
+    (METADATA, "Tags", &["staging_tags"]),
+    (METADATA, "Version", &["staging_version", "staging_version_comment"]),
+    (METADATA, "Uploader", &["uploader_widget"]),
+    (DOCS, "Documentation", &["staging_documentation"]),
+    (METADATA, "License", &["staging_license"]),
+    (METADATA, "Run Mode", &["run_mode_widget"]),
+    (METADATA, "Parent Model", &["parent_widget"]),
+    (METADATA, "Training Data", &["training_data_widget"]),
+    (METADATA, "Timestamp", &["timestamp_widget"]),
+    (INTERFACE, "Model Interface", &["model_interface_widget"]),
+    (WEIGHTS, "Weights", &["weights_widget"]),
+];
+
+/// Saved data fields that only describe how the form is displayed rather than what it contains
+const VIEW_STATE_FIELDS: &[&str] = &["expansion_state"];
+
+const MAX_SUMMARY_CHARS: usize = 120;
+/// Lists longer than this (e.g. raw file contents) are summarized by their length only
+const MAX_LISTED_ITEMS: usize = 8;
+
+fn to_json_map(data: &LatestAppStateSavedData) -> Result<JsonMap>{
+    match serde_json::to_value(data){
+        Ok(serde_json::Value::Object(map)) => Ok(map),
+        Ok(_) => Err(GuiError::new("Form contents did not serialize into an object")),
+        Err(err) => Err(GuiError::new(format!("Could not serialize form contents: {err}"))),
+    }
+}
+
+fn contents_of(data: &LatestAppStateSavedData) -> Result<JsonMap>{
+    let mut map = to_json_map(data)?;
+    for field in VIEW_STATE_FIELDS{
+        map.remove(*field);
+    }
+    Ok(map)
+}
+
+/// A short, single line rendering of a saved data value
+fn summarize(value: &serde_json::Value) -> String{
+    fn write_summary(value: &serde_json::Value, out: &mut String){
+        if out.len() > MAX_SUMMARY_CHARS{
+            return
+        }
+        match value{
+            serde_json::Value::Null => out.push('-'),
+            serde_json::Value::String(s) => out.push_str(&format!("{s:?}")),
+            serde_json::Value::Array(items) if items.len() > MAX_LISTED_ITEMS => {
+                out.push_str(&format!("[{} items]", items.len()))
+            },
+            serde_json::Value::Array(items) => {
+                out.push('[');
+                for (idx, item) in items.iter().enumerate(){
+                    if idx > 0 { out.push_str(", ") }
+                    write_summary(item, out);
+                }
+                out.push(']');
+            },
+            serde_json::Value::Object(fields) => {
+                out.push('{');
+                for (idx, (key, field)) in fields.iter().enumerate(){
+                    if idx > 0 { out.push_str(", ") }
+                    out.push_str(key);
+                    out.push_str(": ");
+                    write_summary(field, out);
+                }
+                out.push('}');
+            },
+            other => out.push_str(&other.to_string()),
+        }
+    }
+    let mut out = String::new();
+    write_summary(value, &mut out);
+    if out.chars().count() > MAX_SUMMARY_CHARS{
+        out = out.chars().take(MAX_SUMMARY_CHARS).collect::<String>() + "…";
+    }
+    out
+}
+
+struct SectionChoice{
+    group: &'static str,
+    name: &'static str,
+    fields: &'static [&'static str],
+    current_summary: String,
+    incoming_summary: String,
+    keep_incoming: bool,
+}
+
+struct PendingImport{
+    current: JsonMap,
+    incoming: JsonMap,
+    archive: SharedZipArchive,
+    source_name: String,
+    sections: Vec<SectionChoice>,
+}
+
+impl PendingImport{
+    fn merged(&self) -> Result<LatestAppStateSavedData>{
+        let mut merged = self.current.clone();
+        for section in self.sections.iter().filter(|section| section.keep_incoming){
+            for field in section.fields{
+                match self.incoming.get(*field){
+                    Some(value) => merged.insert(field.to_string(), value.clone()),
+                    None => merged.remove(*field),
+                };
+            }
+        }
+        serde_json::from_value(serde_json::Value::Object(merged))
+            .map_err(|err| GuiError::new(format!("Could not merge imported model into the form: {err}")))
+    }
+}
+
+/// Whether two states of the form have different contents
+pub fn contents_differ(a: &LatestAppStateSavedData, b: &LatestAppStateSavedData) -> bool{
+    match (contents_of(a), contents_of(b)){
+        (Ok(a), Ok(b)) => a != b,
+        _ => true,
+    }
+}
+
+/// What to do with an incoming model
+pub enum ImportDecision{
+    /// Nothing in the form would be lost, so the incoming state can be used as is
+    ApplyDirectly(LatestAppStateSavedData, SharedZipArchive),
+    /// There are conflicts, which the user must resolve via [ImportConflictWidget::show]
+    AwaitingUser,
+}
+
+#[derive(Default)]
+pub struct ImportConflictWidget{
+    pending: Option<PendingImport>,
+}
+
+impl ImportConflictWidget{
+    /// Compares the current contents of the form with an incoming model
+    pub fn start(
+        &mut self,
+        current: &LatestAppStateSavedData,
+        incoming: LatestAppStateSavedData,
+        archive: SharedZipArchive,
+    ) -> Result<ImportDecision>{
+        let current_map = to_json_map(current)?;
+        let incoming_map = to_json_map(&incoming)?;
+        let sections: Vec<SectionChoice> = SECTIONS.iter()
+            .filter(|(_, _, fields)| fields.iter().any(|field| current_map.get(*field) != incoming_map.get(*field)))
+            .map(|(group, name, fields)|{
+                let summarize_side = |side: &JsonMap| fields.iter()
+                    .map(|field| side.get(*field).map(summarize).unwrap_or_else(|| "-".to_owned()))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                SectionChoice{
+                    group,
+                    name,
+                    fields,
+                    current_summary: summarize_side(&current_map),
+                    incoming_summary: summarize_side(&incoming_map),
+                    keep_incoming: true,
+                }
+            })
+            .collect();
+        if sections.is_empty(){
+            return Ok(ImportDecision::ApplyDirectly(incoming, archive))
+        }
+        self.pending = Some(PendingImport{
+            current: current_map,
+            incoming: incoming_map,
+            source_name: archive.identifier().to_string(),
+            archive,
+            sections,
+        });
+        Ok(ImportDecision::AwaitingUser)
+    }
+
+    /// Shows the sections that differ between the form and the import. Returns the merged state
+    /// once the user is done choosing
+    pub fn show(&mut self, ctx: &egui::Context) -> Option<Result<(LatestAppStateSavedData, SharedZipArchive)>>{
+        let pending = self.pending.as_mut()?;
+        let mut apply = false;
+        let mut cancel = false;
+        egui::Modal::new(egui::Id::new("import conflicts")).show(ctx, |ui|{
+            ui.heading("Import into current draft");
+            ui.label(format!(
+                "The form already has contents that differ from {}. Choose what to keep in each section:",
+                pending.source_name,
+            ));
+            ui.horizontal(|ui|{
+                if ui.button("Keep all current").clicked(){
+                    pending.sections.iter_mut().for_each(|section| section.keep_incoming = false);
+                }
+                if ui.button("Use all imported").clicked(){
+                    pending.sections.iter_mut().for_each(|section| section.keep_incoming = true);
+                }
+            });
+            ui.horizontal(|ui|{
+                ui.label("Take from import:");
+                for group in SECTION_GROUPS{
+                    let mut group_sections = pending.sections.iter().filter(|section| section.group == *group).peekable();
+                    if group_sections.peek().is_none(){
+                        continue
+                    }
+                    let (num_sections, num_incoming) = group_sections.fold((0, 0), |(total, incoming), section| {
+                        (total + 1, incoming + usize::from(section.keep_incoming))
+                    });
+                    let mut take_group = num_incoming == num_sections;
+                    let checkbox = egui::Checkbox::new(&mut take_group, *group)
+                        .indeterminate(num_incoming > 0 && num_incoming < num_sections);
+                    if ui.add(checkbox).clicked(){
+                        pending.sections.iter_mut()
+                            .filter(|section| section.group == *group)
+                            .for_each(|section| section.keep_incoming = take_group);
+                    }
+                }
+            });
+            ui.separator();
+            egui::ScrollArea::vertical().max_height(ctx.screen_rect().height() * 0.6).show(ui, |ui|{
+                egui::Grid::new("import conflicts grid").striped(true).num_columns(3).show(ui, |ui|{
+                    ui.strong("Section");
+                    ui.strong("Current");
+                    ui.strong("Imported");
+                    ui.end_row();
+                    for section in pending.sections.iter_mut(){
+                        ui.label(section.name);
+                        ui.radio_value(&mut section.keep_incoming, false, &section.current_summary);
+                        ui.radio_value(&mut section.keep_incoming, true, &section.incoming_summary);
+                        ui.end_row();
+                    }
+                });
+            });
+            ui.separator();
+            ui.horizontal(|ui|{
+                apply = ui.button("Apply").clicked();
+                cancel = ui.button("Cancel import").clicked() || ui.input(|i| i.key_pressed(egui::Key::Escape));
+            });
+        });
+        if cancel{
+            self.pending = None;
+            return None
+        }
+        if !apply{
+            return None
+        }
+        let pending = self.pending.take()?;
+        Some(pending.merged().map(|merged| (merged, pending.archive)))
+    }
+}
@@ -1,29 +1,69 @@
+use std::borrow::Borrow;
 use std::marker::PhantomData;
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 
 use bioimg_spec::rdf::model::axes::output_axes::{HaloedOutputSpacetimeSize, OutputSpacetimeSize, StandardOutputSpacetimeSize};
 use bioimg_spec::rdf::model::axis_size::FixedOrRefAxisSize;
+use bioimg_spec::rdf::model::axes::NonBatchAxisId;
 use indoc::indoc;
 
 use bioimg_runtime::model_interface::{InputSlot, OutputSlot};
 use bioimg_runtime::npy_array::ArcNpyArray;
 
 use crate::result::{GuiError, Result};
-use bioimg_spec::rdf::model::{self as modelrdf, AnyAxisSize, AxisId, AxisSizeReference, AxisType, InputAxis, ParameterizedAxisSize};
+use bioimg_spec::rdf::model::{
+    self as modelrdf, AnyAxisSize, AxisId, AxisSizeReference, AxisType, InputAxis, OutputAxis, ParameterizedAxisSize, QualifiedAxisId
+};
 use bioimg_spec::rdf::model::input_tensor as rdfinput;
+use bioimg_spec::util::SingleOrMultiple;
 
+use super::axis_physical_scale_widget::{BulkScaleWidget, PhysicalScaleWidget};
 use super::collapsible_widget::{CollapsibleWidget, SummarizableWidget};
 use super::error_display::show_error;
+use super::labels::{spec_link, SpecLinkExt};
 use super::posstprocessing_widget::{PostprocessingWidget, ShowPostprocTypePicker};
 use super::preprocessing_widget::{PreprocessingWidget, ShowPreprocTypePicker};
+use super::preprocessing_suggestions::draw_preprocessing_suggestions;
+use super::shape_fixes::{apply_shape_fix, draw_shape_fixes};
+use super::staging_opt::StagingOpt;
 use super::staging_string::StagingString;
+use super::staging_vec::StagingVec;
+use super::tensor_data_widget::TensorDataWidget;
 use super::input_axis_widget::InputAxisWidget;
 use super::output_axis_widget::OutputAxisWidget;
-use super::test_tensor_widget::{TestTensorWidget, TestTensorWidgetState};
+use super::test_tensor_widget::{TestTensorContext, TestTensorWidget, TestTensorWidgetState};
 use super::util::{VecItemRender, VecWidget};
 use super::{Restore, StatefulWidget, ValueWidget};
+use super::field_finder_widget::findable_label;
 use crate::widgets::staging_vec::ItemWidgetConf;
 
+/// Ids and sizes of the axes that have a fixed size
+fn fixed_axis_sizes(axes: impl Iterator<Item=(AxisId, Option<AnyAxisSize>)>) -> Vec<(AxisId, usize)>{
+    axes
+        .filter_map(|(axis_id, size)| match size{
+            Some(AnyAxisSize::Fixed(size)) => Some((axis_id, size.get())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Shows an error if a pre/postprocessing step doesn't have exactly one value per entry of the axis it applies along
+fn show_values_along_axis_mismatch(
+    ui: &mut egui::Ui, values_along_axis: Option<(&NonBatchAxisId, usize)>, fixed_axis_sizes: &[(AxisId, usize)]
+){
+    let Some((axis_id, num_values)) = values_along_axis else {
+        return
+    };
+    let axis_id: &AxisId = axis_id.borrow();
+    let Some((_, axis_size)) = fixed_axis_sizes.iter().find(|(id, _)| id == axis_id) else {
+        return
+    };
+    if *axis_size != num_values{
+        show_error(ui, format!("There are {num_values} values but axis '{axis_id}' has size {axis_size}"));
+    }
+}
+
 trait IAnyAxisSizeExt{
     fn as_header(&self, axis_id: &AxisId) -> String;
 }
@@ -50,18 +90,79 @@ impl IAnyAxisSizeExt for AnyAxisSize{
 impl IAnyAxisSizeExt for OutputSpacetimeSize{
     fn as_header(&self, axis_id: &AxisId) -> String{
         match self{
-            Self::Haloed(HaloedOutputSpacetimeSize { size, .. }) => size.as_header(axis_id), //FIXME: use halo
+            Self::Haloed(HaloedOutputSpacetimeSize { size, halo }) => format!("{} (halo {})", size.as_header(axis_id), u64::from(*halo)),
             Self::Standard(StandardOutputSpacetimeSize { size }) => size.as_header(axis_id),
         }
     }
 }
 
+/// The spec accepts either a single data description for the whole tensor or one per channel
+fn data_descrs_to_widget_value(data: Option<SingleOrMultiple<modelrdf::tensor_data_descr::TensorDataDescr>>) -> Option<Vec<modelrdf::tensor_data_descr::TensorDataDescr>>{
+    data.map(|data| match data{
+        SingleOrMultiple::Single(descr) => vec![descr],
+        SingleOrMultiple::Multiple(descrs) => descrs,
+    })
+}
+
+fn parse_data_descrs(widget: &StagingOpt<StagingVec<TensorDataWidget>>) -> Result<Option<SingleOrMultiple<modelrdf::tensor_data_descr::TensorDataDescr>>>{
+    let Some(descrs) = widget.state() else {
+        return Ok(None)
+    };
+    let mut descrs = descrs.into_iter()
+        .enumerate()
+        .map(|(idx, descr)| descr.map_err(|err| GuiError::new(format!("Error parsing data description #{}: {err}", idx + 1))))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Some(if descrs.len() == 1 {
+        SingleOrMultiple::Single(descrs.pop().unwrap())
+    } else {
+        SingleOrMultiple::Multiple(descrs)
+    }))
+}
+
 trait IAxisNameLabel{
     fn draw_header_label(&self, ui: &mut egui::Ui, axis_idx: usize) -> egui::Response;
 }
 
+/// What the input and output tensor widgets need from their axis widgets, which have the same layout
+trait IAxisWidget{
+    /// A new axis for a test tensor dimension of size `extent`
+    fn for_extent(extent: usize) -> Self;
+    fn axis_type(&self) -> AxisType;
+    /// The size of the axis, if it parses and has one
+    fn parsed_size(&self) -> Option<AnyAxisSize>;
+    fn set_fixed_size(&mut self, extent: NonZeroUsize);
+    fn space_scale_widget_mut(&mut self) -> &mut PhysicalScaleWidget<modelrdf::SpaceUnit>;
+}
+
+macro_rules! impl_axis_widget_traits_for {($axis_widget_ty:ty) => {
+    impl IAxisWidget for $axis_widget_ty{
+        /// A channel axis for singleton dimensions, otherwise a space axis
+        fn for_extent(extent: usize) -> Self{
+            let mut axis_widget = Self::default();
+            axis_widget.axis_type_widget.value = if extent == 1{
+                AxisType::Channel
+            } else {
+                AxisType::Space
+            };
+            axis_widget.space_axis_widget.prefil_parameterized_size(extent);
+            axis_widget
+        }
+        fn axis_type(&self) -> AxisType{
+            self.axis_type_widget.value
+        }
+        fn parsed_size(&self) -> Option<AnyAxisSize>{
+            self.state().ok().and_then(|axis| axis.size())
+        }
+        fn set_fixed_size(&mut self, extent: NonZeroUsize){
+            if let Some(size_widget) = self.size_widget_mut(){
+                size_widget.set_fixed(extent);
+            }
+        }
+        fn space_scale_widget_mut(&mut self) -> &mut PhysicalScaleWidget<modelrdf::SpaceUnit>{
+            &mut self.space_axis_widget.physical_scale_widget
+        }
+    }
 
-macro_rules! impl_IAxisNameLabel_for {($axis_widget_ty:ty) => {
     impl IAxisNameLabel for $axis_widget_ty{
         fn draw_header_label(&self, ui: &mut egui::Ui, axis_idx: usize) -> egui::Response {
             let state = self.state();
@@ -96,8 +197,48 @@ macro_rules! impl_IAxisNameLabel_for {($axis_widget_ty:ty) => {
     }
 };}
 
-impl_IAxisNameLabel_for!(InputAxisWidget);
-impl_IAxisNameLabel_for!(OutputAxisWidget);
+impl_axis_widget_traits_for!(InputAxisWidget);
+impl_axis_widget_traits_for!(OutputAxisWidget);
+
+/// The physical scale widgets of the space axes in `axis_widgets`
+fn space_scale_widgets_mut<W: IAxisWidget>(
+    axis_widgets: &mut [W]
+) -> impl Iterator<Item=&mut PhysicalScaleWidget<modelrdf::SpaceUnit>>{
+    axis_widgets.iter_mut()
+        .filter(|widget| widget.axis_type() == AxisType::Space)
+        .map(|widget| widget.space_scale_widget_mut())
+}
+
+fn num_space_axes<W: IAxisWidget>(axis_widgets: &[W]) -> usize{
+    axis_widgets.iter().filter(|widget| widget.axis_type() == AxisType::Space).count()
+}
+
+/// Offers fixes for axes that don't match the test tensor of shape `shape`, applying the one the user picks
+fn draw_axis_shape_fixes<W: IAxisWidget>(ui: &mut egui::Ui, shape: &[usize], axis_widgets: &mut Vec<W>){
+    let axis_sizes: Vec<Option<AnyAxisSize>> = axis_widgets.iter().map(|widget| widget.parsed_size()).collect();
+    if let Some(fix) = draw_shape_fixes(ui, shape, &axis_sizes){
+        apply_shape_fix(fix, axis_widgets, W::for_extent, |widget, extent|{
+            if let Some(extent) = NonZeroUsize::new(extent){
+                widget.set_fixed_size(extent);
+            }
+        });
+    }
+}
+
+/// Lets the user set the scale of all space axes of a tensor at once, if it has more than one
+fn draw_bulk_scale_editor<W: IAxisWidget>(
+    ui: &mut egui::Ui, id: egui::Id, bulk_scale_widget: &mut BulkScaleWidget, axis_widgets: &mut [W]
+){
+    if num_space_axes(axis_widgets) < 2{
+        return
+    }
+    ui.horizontal(|ui|{
+        findable_label(ui, "All space axes: ");
+        if let Some(edit) = bulk_scale_widget.draw(ui, id, "Apply to all space axes"){
+            space_scale_widgets_mut(axis_widgets).for_each(|widget| edit.apply_to(widget));
+        }
+    });
+}
 
 
 #[derive(Restore, Default)]
@@ -112,6 +253,9 @@ pub struct InputTensorWidget {
     pub axis_widgets: Vec<InputAxisWidget>,
     pub test_tensor_widget: TestTensorWidget,
     pub preprocessing_widget: Vec<PreprocessingWidget>,
+    pub data_widget: StagingOpt<StagingVec<TensorDataWidget>>,
+    #[restore(default)]
+    pub bulk_scale_widget: BulkScaleWidget,
 }
 
 
@@ -132,6 +276,7 @@ impl ValueWidget for InputTensorWidget{
                 w
             })
             .collect(); //FIXME: use current alloc?
+        self.data_widget.set_value(data_descrs_to_widget_value(value.tensor_meta.data.clone()));
         self.id_widget.set_value(value.tensor_meta.id);
         self.description_widget.set_value(value.tensor_meta.description);
         self.test_tensor_widget.set_value(value.test_tensor);
@@ -164,7 +309,7 @@ impl SummarizableWidget for InputTensorWidget{
 impl InputTensorWidget{
     fn autofill_from_test_tensor(&mut self){
         let guard = self.test_tensor_widget.state();
-        let TestTensorWidgetState::Loaded { path, data: gui_npy_arr } = &guard.1 else {
+        let TestTensorWidgetState::Loaded { path, data: gui_npy_arr, .. } = &guard.1 else {
             self.adjust_num_axes_on_file_selected = true;
             return;
         };
@@ -184,15 +329,55 @@ impl InputTensorWidget{
         let mut extents = sample_shape.iter().skip(self.axis_widgets.len());
 
         while let Some(extent) = extents.next() {
-            let mut axis_widget = InputAxisWidget::default();
-            axis_widget.axis_type_widget.value = if *extent == 1{
-                modelrdf::AxisType::Channel
-            } else {
-                modelrdf::AxisType::Space
+            self.axis_widgets.push(InputAxisWidget::for_extent(*extent))
+        }
+    }
+    pub fn space_scale_widgets_mut(&mut self) -> impl Iterator<Item=&mut PhysicalScaleWidget<modelrdf::SpaceUnit>>{
+        space_scale_widgets_mut(&mut self.axis_widgets)
+    }
+    pub fn num_space_axes(&self) -> usize{
+        num_space_axes(&self.axis_widgets)
+    }
+    /// A new output with the same axes as this input, since outputs usually mirror their inputs. Parameterized
+    /// space and time sizes become references to the matching axis of this input, and preprocessing is left out
+    pub fn matching_output(&self) -> OutputTensorWidget{
+        let tensor_id = self.id_widget.state().ok().cloned();
+        let reference_input_axis = |axis_id: &AxisId, size: &mut OutputSpacetimeSize|{
+            let OutputSpacetimeSize::Standard(StandardOutputSpacetimeSize{size}) = size else {
+                return
+            };
+            let (Some(tensor_id), AnyAxisSize::Parameterized(_)) = (&tensor_id, &*size) else {
+                return
             };
-            axis_widget.space_axis_widget.prefil_parameterized_size(*extent);
-            self.axis_widgets.push(axis_widget)
+            *size = AnyAxisSize::Reference(AxisSizeReference{
+                qualified_axis_id: QualifiedAxisId{tensor_id: tensor_id.clone(), axis_id: axis_id.clone()},
+                offset: 0,
+            });
+        };
+        let mut output = OutputTensorWidget::default();
+        output.axis_widgets = self.axis_widgets.iter()
+            .map(|input_axis_widget|{
+                let mut widget = OutputAxisWidget::default();
+                let Ok(input_axis) = input_axis_widget.state() else {
+                    widget.axis_type_widget.value = input_axis_widget.axis_type_widget.value;
+                    return widget
+                };
+                let mut axis = OutputAxis::from(input_axis);
+                match &mut axis{
+                    OutputAxis::Space(space) => reference_input_axis(&space.id, &mut space.size),
+                    OutputAxis::Time(time) => reference_input_axis(&time.id, &mut time.size),
+                    _ => (),
+                }
+                widget.set_value(axis);
+                widget
+            })
+            .collect();
+        if !self.id_widget.raw.is_empty(){
+            output.id_widget.raw = format!("{}_output", self.id_widget.raw);
         }
+        output.description_widget.raw = self.description_widget.raw.clone();
+        output.data_widget.set_value(data_descrs_to_widget_value(parse_data_descrs(&self.data_widget).ok().flatten()));
+        output
     }
     pub fn parse(&self) -> Result<InputSlot<ArcNpyArray>>{
         let guard = self.test_tensor_widget.state();
@@ -223,6 +408,7 @@ impl InputTensorWidget{
                 .map_err(|err| GuiError::new(format!("Preprocessing error: {err}")))?,
             description: self.description_widget.state()?.clone(),
             axes: input_axis_group,
+            data: parse_data_descrs(&self.data_widget)?,
         };
         return Ok(
             InputSlot{ tensor_meta: meta_msg.try_into()?, test_tensor: Arc::clone(gui_npy_array) }
@@ -232,7 +418,7 @@ impl InputTensorWidget{
         self.autofill_from_test_tensor();
         ui.vertical(|ui| {
             ui.horizontal(|ui| {
-                ui.strong("Test Sample Input: ").on_hover_text(indoc!("
+                findable_label(ui, "Test Sample Input: ").on_hover_text_with_spec(ui, "InputTensorDescr.test_tensor", indoc!("
                     A .npy file with a sample input for testing this model. This data, along with that from other \
                     input tensors will be put through preprocessing and fed to the model network weights. \
                     The outputs from the network will then be postprocessed and compared to `Expected Test outputs` \
@@ -245,13 +431,13 @@ impl InputTensorWidget{
                 }
             });
             ui.horizontal(|ui|{
-                ui.strong("Input is optional: ").on_hover_text(indoc!("
+                findable_label(ui, "Input is optional: ").on_hover_text_with_spec(ui, "InputTensorDescr.optional", indoc!("
                     Marks whether the model can do inference without this input."
                 ));
                 ui.add(egui::widgets::Checkbox::without_text(&mut self.is_optional));
             });
             ui.horizontal(|ui| {
-                ui.strong("Tensor Id: ").on_hover_text(indoc!(
+                findable_label(ui, "Tensor Id: ").on_hover_text_with_spec(ui, "InputTensorDescr.id", indoc!(
                     "The name of this input tensor. During inference, tensors are passed to the model as a \
                     mapping of strings to tensors; The keys in this Mapping should be the tensor IDs \
                     entered in fields like this one."
@@ -259,7 +445,7 @@ impl InputTensorWidget{
                 self.id_widget.draw_and_parse(ui, id.with("Id"));
             });
             ui.horizontal(|ui| {
-                ui.strong("Description: ").on_hover_ui(|ui|{
+                findable_label(ui, "Description: ").on_hover_ui(|ui|{
                     ui.label(indoc!("
                         A human-readable description of this input tensor to help users of the model produce \
                         compliant inputs."
@@ -272,14 +458,18 @@ impl InputTensorWidget{
                         )).italics());
                     });
                 });
+                spec_link(ui, "InputTensorDescr.description");
                 self.description_widget.draw_and_parse(ui, id.with("Description"));
             });
             ui.horizontal(|ui| {
-                ui.strong("Axes: ").on_hover_text(indoc!("
+                findable_label(ui, "Axes: ").on_hover_text_with_spec(ui, "InputTensorDescr.axes", indoc!("
                     A list of axis descriptions that determine how this tensor is to be interpreted. Notice \
                     that the axis should be given in C-order, i.e., that last axis given is the one that changes \
                     more quickly when going through the bytes of the tensor.
                 "));
+                let test_tensor_shape: Vec<usize> = self.test_tensor_widget.loaded_data()
+                    .map(|data| data.shape().to_vec())
+                    .unwrap_or_default();
                 let vec_widget = VecWidget{
                     items: &mut self.axis_widgets,
                     min_items: 1,
@@ -292,6 +482,9 @@ impl InputTensorWidget{
                         },
                         render_body: |widget: &mut InputAxisWidget, idx, ui|{
                             widget.draw(ui, id.with("input axis").with(idx), false);
+                            if let (Some(extent), Some(size_widget)) = (test_tensor_shape.get(idx), widget.size_widget_mut()){
+                                size_widget.draw_extent_suggestions(ui, *extent);
+                            }
                         },
                         collapsible_id_source: Some(id.with("axis list")),
                         marker: PhantomData,
@@ -300,11 +493,20 @@ impl InputTensorWidget{
                 };
                 ui.add(vec_widget);
             });
+            if let Some(data) = self.test_tensor_widget.loaded_data(){
+                draw_axis_shape_fixes(ui, data.shape(), &mut self.axis_widgets);
+            }
+            draw_bulk_scale_editor(ui, id.with("bulk scale"), &mut self.bulk_scale_widget, &mut self.axis_widgets);
             ui.horizontal(|ui| {
-                ui.strong("Preprocessing: ").on_hover_text(indoc!("
+                findable_label(ui, "Preprocessing: ").on_hover_text_with_spec(ui, "InputTensorDescr.preprocessing", indoc!("
                     A list of preprocessing steps that will be applied to this input tensor before it is \
                     fed to the model weights."
                 ));
+                let fixed_axis_sizes = fixed_axis_sizes(
+                    self.axis_widgets.iter().filter_map(|w| w.state().ok()).map(|axis| (axis.id(), axis.size()))
+                );
+                let loaded_test_tensor = self.test_tensor_widget.loaded_tensor();
+                let axis_ids: Vec<Option<AxisId>> = self.axis_widgets.iter().map(|w| w.state().ok().map(|axis| axis.id())).collect();
                 let vec_widget = VecWidget{
                     items: &mut self.preprocessing_widget,
                     min_items: 0,
@@ -313,9 +515,15 @@ impl InputTensorWidget{
                         render_header: |widget: &mut PreprocessingWidget, idx, ui: &mut egui::Ui|{
                             widget.draw_preproc_type_picker(ui, id.with("preproc type".as_ptr()).with(idx));
                         },
-                        render_body: |widget: &mut PreprocessingWidget, index, ui| widget.draw_and_parse(
-                            ui, ShowPreprocTypePicker::Hide, id.with("preprocs".as_ptr()).with(index)
-                        ),
+                        render_body: |widget: &mut PreprocessingWidget, index, ui: &mut egui::Ui| {
+                            widget.draw_and_parse(ui, ShowPreprocTypePicker::Hide, id.with("preprocs".as_ptr()).with(index));
+                            if let Ok(preproc) = widget.state(){
+                                show_values_along_axis_mismatch(ui, preproc.values_along_axis(), &fixed_axis_sizes);
+                            }
+                            if let Some((_, data)) = &loaded_test_tensor{
+                                widget.draw_test_tensor_tools(ui, &TestTensorContext{data, axis_ids: &axis_ids});
+                            }
+                        },
                         collapsible_id_source: Some(id.with("preproc list")),
                         marker: PhantomData
                     },
@@ -323,6 +531,25 @@ impl InputTensorWidget{
                     new_item: Some(PreprocessingWidget::default),
                 };
                 ui.add(vec_widget);
+                if let Some((test_tensor_id, data)) = &loaded_test_tensor{
+                    let axes: Vec<InputAxis> = self.axis_widgets.iter().filter_map(|w| w.state().ok()).collect();
+                    if let Some(steps) = draw_preprocessing_suggestions(ui, *test_tensor_id, data, &axes){
+                        self.preprocessing_widget = steps.into_iter()
+                            .map(|step|{
+                                let mut widget = PreprocessingWidget::default();
+                                widget.set_value(step);
+                                widget
+                            })
+                            .collect();
+                    }
+                }
+            });
+            ui.horizontal(|ui| {
+                findable_label(ui, "Data: ").on_hover_text_with_spec(ui, "InputTensorDescr.data", indoc!("
+                    Description of the values in this tensor, either for the whole tensor or one per channel. \
+                    If left empty, values are assumed to be float32 in an arbitrary unit."
+                ));
+                self.data_widget.draw_and_parse(ui, id.with("data".as_ptr()));
             });
         });
     }
@@ -339,6 +566,9 @@ pub struct OutputTensorWidget {
     pub axis_widgets: Vec<OutputAxisWidget>,
     pub test_tensor_widget: TestTensorWidget,
     pub postprocessing_widgets: Vec<CollapsibleWidget<PostprocessingWidget>>,
+    pub data_widget: StagingOpt<StagingVec<TensorDataWidget>>,
+    #[restore(default)]
+    pub bulk_scale_widget: BulkScaleWidget,
 }
 
 impl Default for OutputTensorWidget{
@@ -350,6 +580,8 @@ impl Default for OutputTensorWidget{
             axis_widgets: Default::default(),
             test_tensor_widget: Default::default(),
             postprocessing_widgets: Default::default(),
+            data_widget: Default::default(),
+            bulk_scale_widget: Default::default(),
         }
     }
 }
@@ -371,6 +603,7 @@ impl ValueWidget for OutputTensorWidget{
                 w
             })
             .collect();
+        self.data_widget.set_value(data_descrs_to_widget_value(value.tensor_meta.data.clone()));
         self.id_widget.set_value(value.tensor_meta.id);
         self.description_widget.set_value(value.tensor_meta.description);
         self.test_tensor_widget.set_value(value.test_tensor);
@@ -402,7 +635,7 @@ impl SummarizableWidget for OutputTensorWidget{
 impl OutputTensorWidget{
     fn autofill_from_test_tensor(&mut self){
         let guard = self.test_tensor_widget.state();
-        let TestTensorWidgetState::Loaded { path, data: gui_npy_arr } = &guard.1 else {
+        let TestTensorWidgetState::Loaded { path, data: gui_npy_arr, .. } = &guard.1 else {
             self.adjust_num_axes_on_file_selected = true;
             return;
         };
@@ -422,17 +655,16 @@ impl OutputTensorWidget{
         let mut extents = sample_shape.iter().skip(self.axis_widgets.len());
 
         while let Some(extent) = extents.next() {
-            let mut axis_widget = OutputAxisWidget::default();
-            axis_widget.axis_type_widget.value = if *extent == 1{
-                modelrdf::AxisType::Channel
-            } else {
-                modelrdf::AxisType::Space
-            };
-            axis_widget.space_axis_widget.prefil_parameterized_size(*extent);
-            self.axis_widgets.push(axis_widget)
+            self.axis_widgets.push(OutputAxisWidget::for_extent(*extent))
         }
     }
 
+    pub fn space_scale_widgets_mut(&mut self) -> impl Iterator<Item=&mut PhysicalScaleWidget<modelrdf::SpaceUnit>>{
+        space_scale_widgets_mut(&mut self.axis_widgets)
+    }
+    pub fn num_space_axes(&self) -> usize{
+        num_space_axes(&self.axis_widgets)
+    }
     pub fn parse(&self) -> Result<OutputSlot<ArcNpyArray>> {
         let guard = self.test_tensor_widget.state();
         let TestTensorWidgetState::Loaded { data: gui_npy_array, .. } = &guard.1 else {
@@ -453,6 +685,7 @@ impl OutputTensorWidget{
                 .collect::<Result<_>>()?,
             description: self.description_widget.state()?.clone(),
             axes: axis_group,
+            data: parse_data_descrs(&self.data_widget)?,
         };
         Ok(
             OutputSlot{ tensor_meta: meta_msg.try_into()?, test_tensor: Arc::clone(gui_npy_array) }
@@ -461,7 +694,7 @@ impl OutputTensorWidget{
     pub fn draw(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.vertical(|ui| {
             ui.horizontal(|ui| {
-                ui.strong("Expected Test Output: ").on_hover_text(indoc!("
+                findable_label(ui, "Expected Test Output: ").on_hover_text_with_spec(ui, "OutputTensorDescr.test_tensor", indoc!("
                     A .npy file with a sample output for testing this model. The 'Test Sample Inputs' from \
                     the model input fields will be put through preprocessing and fed to the model network weights. \
                     The outputs from the network will then be postprocessed and compared to the data in fields like \
@@ -474,14 +707,14 @@ impl OutputTensorWidget{
                 }
             });
             ui.horizontal(|ui| {
-                ui.strong("Tensor Id: ").on_hover_text(indoc!("
+                findable_label(ui, "Tensor Id: ").on_hover_text_with_spec(ui, "OutputTensorDescr.id", indoc!("
                     The name of this output tensor. Running this model will produce a mapping of strings \
                     to tensors. The keys in this mapping should be the IDs entered in this field."
                 ));
                 self.id_widget.draw_and_parse(ui, id.with("Id"));
             });
             ui.horizontal(|ui| {
-                ui.strong("Description: ").on_hover_ui(|ui|{
+                findable_label(ui, "Description: ").on_hover_ui(|ui|{
                     ui.label(indoc!("
                         A human-readable description of this output tensor to help users of the model \
                         understand the semantics of the model outputs."
@@ -497,10 +730,11 @@ impl OutputTensorWidget{
                         );
                     });
                 });
+                spec_link(ui, "OutputTensorDescr.description");
                 self.description_widget.draw_and_parse(ui, id.with("Description"));
             });
             ui.horizontal(|ui| {
-                ui.strong("Axes: ").on_hover_text(indoc!("
+                findable_label(ui, "Axes: ").on_hover_text_with_spec(ui, "OutputTensorDescr.axes", indoc!("
                     A list of axis descriptions that determine how this tensor is to be interpreted. Notice \
                     that the axis should be given in C-order, i.e., that last axis given is the one that changes \
                     more quickly when going through the bytes of the tensor.
@@ -525,12 +759,19 @@ impl OutputTensorWidget{
                 };
                 ui.add(vec_widget);
             });
+            if let Some(data) = self.test_tensor_widget.loaded_data(){
+                draw_axis_shape_fixes(ui, data.shape(), &mut self.axis_widgets);
+            }
+            draw_bulk_scale_editor(ui, id.with("bulk scale"), &mut self.bulk_scale_widget, &mut self.axis_widgets);
             ui.horizontal(|ui| {
-                ui.strong("Postprocessing: ").on_hover_text(indoc!("
+                findable_label(ui, "Postprocessing: ").on_hover_text_with_spec(ui, "OutputTensorDescr.postprocessing", indoc!("
                     A list of postprocessing steps that will be applied to this output tensor \
                     after it is produced by the network models."
                 ));
 
+                let fixed_axis_sizes = fixed_axis_sizes(
+                    self.axis_widgets.iter().filter_map(|w| w.state().ok()).map(|axis| (axis.id(), axis.size()))
+                );
                 let vec_widget = VecWidget{
                     items: &mut self.postprocessing_widgets,
                     min_items: 0,
@@ -539,9 +780,12 @@ impl OutputTensorWidget{
                         render_header: |widget: &mut CollapsibleWidget<PostprocessingWidget>, idx, ui: &mut egui::Ui|{
                             widget.inner.draw_type_picker(ui, id.with("postproc type".as_ptr()).with(idx));
                         },
-                        render_body: |widget: &mut CollapsibleWidget<PostprocessingWidget>, index, ui| widget.inner.draw_and_parse(
-                            ui, ShowPostprocTypePicker::Hide, id.with("postprocs".as_ptr()).with(index)
-                        ),
+                        render_body: |widget: &mut CollapsibleWidget<PostprocessingWidget>, index, ui: &mut egui::Ui| {
+                            widget.inner.draw_and_parse(ui, ShowPostprocTypePicker::Hide, id.with("postprocs".as_ptr()).with(index));
+                            if let Ok(postproc) = widget.inner.state(){
+                                show_values_along_axis_mismatch(ui, postproc.values_along_axis(), &fixed_axis_sizes);
+                            }
+                        },
                         collapsible_id_source: Some(id.with("posproc list")),
                         marker: PhantomData,
                     },
@@ -550,6 +794,13 @@ impl OutputTensorWidget{
                 };
                 ui.add(vec_widget);
             });
+            ui.horizontal(|ui| {
+                findable_label(ui, "Data: ").on_hover_text_with_spec(ui, "OutputTensorDescr.data", indoc!("
+                    Description of the values in this tensor, either for the whole tensor or one per channel. \
+                    If left empty, values are assumed to be float32 in an arbitrary unit."
+                ));
+                self.data_widget.draw_and_parse(ui, id.with("data".as_ptr()));
+            });
         });
     }
 }
@@ -13,6 +13,7 @@ use super::staging_vec::ItemWidgetConf;
 use super::axis_widget::{axis_description_label, axis_id_label, BatchAxisWidget, ChannelAxisWidget, IndexAxisWidget};
 use super::{Restore, StatefulWidget, ValueWidget};
 use super::axis_size_widget::AnyAxisSizeWidget;
+use super::field_finder_widget::findable_label;
 use crate::result::Result;
 
 
@@ -56,7 +57,7 @@ impl StatefulWidget for InputSpaceAxisWidget{
                 self.description_widget.draw_and_parse(ui, id.with("description"));
             });
             ui.horizontal(|ui| {
-                ui.strong("Size: ").on_hover_text(indoc!("
+                findable_label(ui, "Size: ").on_hover_text(indoc!("
                     The acceptable sizes of this axis such that they can be put through the model network"
                 ));
                 self.size_widget.draw_and_parse(ui, id.with("size"));
@@ -104,15 +105,15 @@ impl StatefulWidget for InputTimeAxisWidget{
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.vertical(|ui|{
             ui.horizontal(|ui| {
-                ui.strong("Axis Id: ");
+                findable_label(ui, "Axis Id: ");
                 self.id_widget.draw_and_parse(ui, id.with("id"));
             });
             ui.horizontal(|ui| {
-                ui.strong("Axis Description: ");
+                findable_label(ui, "Axis Description: ");
                 self.description_widget.draw_and_parse(ui, id.with("description"));
             });
             ui.horizontal(|ui| {
-                ui.strong("Size: ");
+                findable_label(ui, "Size: ");
                 self.size_widget.draw_and_parse(ui, id.with("size"));
             });
             self.physical_scale_widget.draw_and_parse(ui, id.with("physical_size"));
@@ -150,6 +151,15 @@ impl InputAxisWidget{
         }
         out
     }
+    /// The size widget of the currently selected axis type, if that type has a configurable size
+    pub fn size_widget_mut(&mut self) -> Option<&mut AnyAxisSizeWidget>{
+        match self.axis_type_widget.value{
+            AxisType::Space => Some(&mut self.space_axis_widget.size_widget),
+            AxisType::Time => Some(&mut self.time_axis_widget.size_widget),
+            AxisType::Index => Some(&mut self.index_axis_widget.size_widget),
+            AxisType::Batch | AxisType::Channel => None,
+        }
+    }
     pub fn name_label(&self, axis_idx: usize) -> egui::RichText {
         match self.axis_type_widget.value{
             AxisType::Space => match self.space_axis_widget.id_widget.raw.len() {
@@ -225,7 +235,7 @@ impl InputAxisWidget{
         ui.vertical(|ui|{
             if show_type_picker{
                 ui.horizontal(|ui| {
-                    ui.strong("Axis Type: ");
+                    findable_label(ui, "Axis Type: ");
                     self.draw_type_picker(ui, id.with("axis_type".as_ptr()));
                 });
             }
@@ -1,5 +1,7 @@
+use super::field_finder_widget::findable_label;
+
 pub fn github_user_label(ui: &mut egui::Ui, staging_user: Option<&str>) -> egui::Response{
-    ui.strong("Github User: ").on_hover_ui(|ui|{
+    findable_label(ui, "Github User: ").on_hover_ui(|ui|{
         ui.vertical(|ui|{
             ui.label("The Author's github user, if any, without the '@' symbol.");
             match staging_user{
@@ -22,11 +24,11 @@ pub fn github_user_label(ui: &mut egui::Ui, staging_user: Option<&str>) -> egui:
 }
 
 pub fn affiliation_label(ui: &mut egui::Ui) -> egui::Response{
-    ui.strong("Affiliation: ").on_hover_text("The company, institute or entity for which the author works, if any.")
+    findable_label(ui, "Affiliation: ").on_hover_text("The company, institute or entity for which the author works, if any.")
 }
 
 pub fn orcid_label(ui: &mut egui::Ui, person_title: &str) -> egui::Response{
-    ui.strong("Orcid: ").on_hover_ui(|ui| {
+    findable_label(ui, "Orcid: ").on_hover_ui(|ui| {
         ui.horizontal(|ui|{
             ui.label(format!("The {person_title}'s"));
             ui.hyperlink_to("ORCID number", "https://orcid.org/");
@@ -34,3 +36,25 @@ pub fn orcid_label(ui: &mut egui::Ui, person_title: &str) -> egui::Response{
         });
     })
 }
+
+/// Base URL of the documentation for the version of the model spec that this app produces
+pub const MODEL_SPEC_DOCS_URL: &str = "https://bioimage-io.github.io/spec-bioimage-io/bioimageio_spec/model/v0_5.html";
+
+pub fn spec_link(ui: &mut egui::Ui, spec_anchor: &str) -> egui::Response{
+    ui.hyperlink_to(egui::RichText::new("↗").small(), format!("{MODEL_SPEC_DOCS_URL}#{spec_anchor}"))
+        .on_hover_text("Open the bioimage.io spec documentation for this field")
+}
+
+pub trait SpecLinkExt{
+    /// Like `on_hover_text`, but also adds a small link to the section of the spec docs at `spec_anchor`
+    /// (e.g. "ModelDescr.name") right after this label, where it can be clicked
+    fn on_hover_text_with_spec(self, ui: &mut egui::Ui, spec_anchor: &str, text: impl Into<egui::WidgetText>) -> Self;
+}
+
+impl SpecLinkExt for egui::Response{
+    fn on_hover_text_with_spec(self, ui: &mut egui::Ui, spec_anchor: &str, text: impl Into<egui::WidgetText>) -> Self{
+        let response = self.on_hover_text(text);
+        spec_link(ui, spec_anchor);
+        response
+    }
+}
@@ -1,10 +1,13 @@
 use bioimg_spec::rdf::{self, bounded_string::BoundedString, orcid::Orcid};
 
 use super::{collapsible_widget::{CollapsibleWidget, SummarizableWidget}, error_display::show_error, labels::{self, orcid_label}, staging_opt::StagingOpt, staging_string::StagingString, staging_vec::ItemWidgetConf, Restore, StatefulWidget, ValueWidget};
+use super::address_book_widget::draw_address_book_menu;
+use super::field_finder_widget::findable_label;
 use crate::result::{GuiError, Result};
 
-#[derive(Restore)]
+#[derive(Restore, ValueWidget)]
 #[restore(saved_data=crate::project_data::MaintainerWidgetSavedData)]
+#[value_widget(value=rdf::Maintainer)]
 pub struct MaintainerWidget {
     pub github_user_widget: StagingString<BoundedString<1, 1024>>, //FIXME validate this somehow}
     pub affiliation_widget: StagingOpt<StagingString<BoundedString<1, 1024>>, false>,
@@ -13,17 +16,6 @@ pub struct MaintainerWidget {
     pub name_widget: StagingOpt<StagingString<rdf::MaintainerName>, false>,
 }
 
-impl ValueWidget for MaintainerWidget{
-    type Value<'a> = rdf::Maintainer;
-    fn set_value<'a>(&mut self, value: Self::Value<'a>) {
-        self.github_user_widget.set_value(value.github_user);
-        self.affiliation_widget.set_value(value.affiliation);
-        self.email_widget.set_value(value.email);
-        self.orcid_widget.set_value(value.orcid);
-        self.name_widget.set_value(value.name);
-    }
-}
-
 impl ItemWidgetConf for MaintainerWidget{
     const ITEM_NAME: &'static str = "Maintainer";
 }
@@ -62,26 +54,31 @@ impl StatefulWidget for MaintainerWidget {
     type Value<'p> = Result<rdf::Maintainer>;
 
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
-        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
-            labels::github_user_label(ui, Some(self.github_user_widget.raw.as_str()));
-            self.github_user_widget.draw_and_parse(ui, id.with("github_user"));
-            ui.end_row();
+        ui.vertical(|ui|{
+            if let Some(picked) = draw_address_book_menu(ui, id.with("address book"), self.state().ok()){
+                self.set_value(picked);
+            }
+            egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+                labels::github_user_label(ui, Some(self.github_user_widget.raw.as_str()));
+                self.github_user_widget.draw_and_parse(ui, id.with("github_user"));
+                ui.end_row();
 
-            labels::affiliation_label(ui);
-            self.affiliation_widget.draw_and_parse(ui, id.with("affiliation"));
-            ui.end_row();
+                labels::affiliation_label(ui);
+                self.affiliation_widget.draw_and_parse(ui, id.with("affiliation"));
+                ui.end_row();
 
-            ui.strong("Email: ").on_hover_text("An email address where the maintainer could be reached");
-            self.email_widget.draw_and_parse(ui, id.with("email"));
-            ui.end_row();
+                findable_label(ui, "Email: ").on_hover_text("An email address where the maintainer could be reached");
+                self.email_widget.draw_and_parse(ui, id.with("email"));
+                ui.end_row();
 
-            orcid_label(ui, "maintainer");
-            self.orcid_widget.draw_and_parse(ui, id.with("orcid"));
-            ui.end_row();
+                orcid_label(ui, "maintainer");
+                self.orcid_widget.draw_and_parse(ui, id.with("orcid"));
+                ui.end_row();
 
-            ui.strong("Name: ").on_hover_text("The maintainer's given name e.g. John Smith");
-            self.name_widget.draw_and_parse(ui, id.with("name"));
-            ui.end_row();
+                findable_label(ui, "Name: ").on_hover_text("The maintainer's given name e.g. John Smith");
+                self.name_widget.draw_and_parse(ui, id.with("name"));
+                ui.end_row();
+            });
         });
     }
 
@@ -1,17 +1,20 @@
 use serde::de::DeserializeOwned;
-pub use bioimg_codegen::Restore;
+pub use bioimg_codegen::{Restore, ValueWidget};
 use bioimg_spec::rdf;
 
 pub mod pipeline_widget;
 pub mod author_widget;
+pub mod address_book_widget;
 pub mod axis_size_widget;
 pub mod cite_widget;
 pub mod code_editor_widget;
 pub mod error_display;
+pub mod expansion_state;
 pub mod functional;
 pub mod icon_widget;
 pub mod inout_tensor_widget;
 pub mod maintainer_widget;
+pub mod uploader_widget;
 pub mod model_interface_widget;
 pub mod staging_from_vec;
 pub mod staging_num;
@@ -20,6 +23,8 @@ pub mod staging_string;
 pub mod staging_vec;
 pub mod axis_widget;
 pub mod url_widget;
+#[cfg(not(target_arch="wasm32"))]
+pub mod url_reachability;
 pub mod util;
 pub mod weights_widget;
 pub mod onnx_weights_widget;
@@ -31,31 +36,52 @@ pub mod notice_widget;
 pub mod output_axis_widget;
 pub mod input_axis_widget;
 pub mod preprocessing_widget;
+pub mod preprocessing_suggestions;
 pub mod posstprocessing_widget;
 pub mod binarize_widget;
 pub mod clip_widget;
+pub mod data_range_widget;
+pub mod tensor_data_widget;
 pub mod scale_linear_widget;
 pub mod zero_mean_unit_variance_widget;
 pub mod scale_range_widget;
 pub mod json_editor_widget;
+pub mod run_mode_widget;
+pub mod parent_model_widget;
+pub mod training_data_widget;
+pub mod timestamp_widget;
 pub mod conda_env_editor_widget;
 pub mod version_widget;
+pub mod field_finder_widget;
 pub mod file_source_widget;
 pub mod search_and_pick_widget;
+pub mod spell_check;
+pub mod dtype_support;
+pub mod shape_fixes;
 pub mod popup_widget;
 pub mod image_widget_2;
 pub mod fixed_zero_mean_unit_variance_widget;
 pub mod scale_mean_variance_widget;
 pub mod staging_float;
 pub mod collapsible_widget;
+pub mod completeness_widget;
 pub mod model_links_widget;
+pub mod consumers_widget;
 pub mod test_tensor_widget;
 #[cfg(not(target_arch="wasm32"))]
 pub mod zoo_widget;
+#[cfg(not(target_arch="wasm32"))]
+pub mod example_gallery_widget;
+pub mod archive_browser_widget;
+pub mod original_rdf_widget;
+pub mod export_preview_widget;
+pub mod import_conflict_widget;
+pub mod rdf_yaml_editor_widget;
 pub mod labels;
 pub mod axis_physical_scale_widget;
 pub mod button_ext;
 pub mod iconify;
+pub mod wizard_widget;
 
 /// Types that implement StatefulWidget are similar to what people call
 /// "components" in other UI frameworks
@@ -145,3 +171,4 @@ impl_Restore_for!(rdf::model::axes::AxisType);
 impl_Restore_for!(rdf::model::SpaceUnit);
 impl_Restore_for!(rdf::model::TimeUnit);
 impl_Restore_for!(rdf::model::DataType);
+impl_Restore_for!(rdf::model::WeightsFormat);
@@ -1,5 +1,7 @@
 use indoc::indoc;
 
+use super::axis_physical_scale_widget::BulkScaleWidget;
+use super::field_finder_widget::findable_label;
 use super::{Restore, ValueWidget};
 use super::
     inout_tensor_widget::{InputTensorWidget, OutputTensorWidget}
@@ -14,6 +16,8 @@ use crate::result::{GuiError, Result};
 pub struct ModelInterfaceWidget {
     pub input_widgets: Vec<InputTensorWidget>,
     pub output_widgets: Vec<OutputTensorWidget>,
+    #[restore(default)]
+    pub bulk_scale_widget: BulkScaleWidget,
 }
 
 pub static MODEL_INPUTS_TIP: &'static str = indoc!("
@@ -47,6 +51,26 @@ impl ModelInterfaceWidget {
             .collect();
     }
 
+    /// Sets the scale and/or unit of every space axis of every input and output
+    pub fn draw_bulk_scale_editor(&mut self, ui: &mut egui::Ui, id: egui::Id){
+        let num_space_axes = self.input_widgets.iter().map(|widget| widget.num_space_axes()).sum::<usize>()
+            + self.output_widgets.iter().map(|widget| widget.num_space_axes()).sum::<usize>();
+        if num_space_axes < 2{
+            return
+        }
+        ui.horizontal(|ui|{
+            findable_label(ui, "Space axes of all tensors: ");
+            if let Some(edit) = self.bulk_scale_widget.draw(ui, id, "Apply to all tensors"){
+                self.input_widgets.iter_mut()
+                    .flat_map(|widget| widget.space_scale_widgets_mut())
+                    .for_each(|widget| edit.apply_to(widget));
+                self.output_widgets.iter_mut()
+                    .flat_map(|widget| widget.space_scale_widgets_mut())
+                    .for_each(|widget| edit.apply_to(widget));
+            }
+        });
+    }
+
     pub fn get_value<'p>(&'p self) -> Result<rt::ModelInterface<ArcNpyArray>> {
         let inputs = self.input_widgets.iter()
             .map(|i| i.parse())
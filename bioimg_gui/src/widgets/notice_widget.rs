@@ -60,6 +60,11 @@ impl NotificationsWidget{
         self.notifications.push_back(notification);
     }
 
+    /// The texts of the notifications that haven't faded away yet, oldest first
+    pub fn texts(&self) -> impl Iterator<Item=&str>{
+        self.notifications.iter().map(|notification| notification.text.as_str())
+    }
+
     pub fn draw(&mut self, ui: &mut egui::Ui, id: egui::Id) -> Option<egui::Rect>{
         let current_time: f64 = ui.ctx().input(|inp| inp.time);
         let mut scroll_to: Option<egui::Rect> = None;
@@ -3,10 +3,13 @@ use bioimg_spec::rdf::model as modelrdf;
 
 use crate::result::Result;
 use super::{collapsible_widget::SummarizableWidget, error_display::show_error, staging_num::StagingNum, weights_widget::WeightsDescrBaseWidget, Restore, StatefulWidget, ValueWidget};
+use super::field_finder_widget::findable_label;
 
-#[derive(Default, Restore)]
+#[derive(Default, Restore, ValueWidget)]
 #[restore(saved_data=crate::project_data::OnnxWeightsWidgetSavedData)]
+#[value_widget(value=rt::model_weights::OnnxWeights)]
 pub struct OnnxWeightsWidget{
+    #[value_widget(from=weights)]
     pub base_widget: WeightsDescrBaseWidget,
     pub opset_version_widget: StagingNum<u32, modelrdf::weights::OnnxOpsetVersion>,
 }
@@ -25,15 +28,6 @@ impl SummarizableWidget for OnnxWeightsWidget{
     }
 }
 
-impl ValueWidget for OnnxWeightsWidget{
-    type Value<'v> = rt::model_weights::OnnxWeights;
-
-    fn set_value<'v>(&mut self, value: Self::Value<'v>) {
-        self.base_widget.set_value(value.weights);
-        self.opset_version_widget.set_value(value.opset_version);
-    }
-}
-
 impl StatefulWidget for OnnxWeightsWidget{
     type Value<'p> = Result<rt::model_weights::OnnxWeights>;
 
@@ -41,7 +35,7 @@ impl StatefulWidget for OnnxWeightsWidget{
         ui.vertical(|ui|{
             self.base_widget.draw_and_parse(ui, id.with("base"));
             ui.horizontal(|ui|{
-                ui.strong("Opset version: ");
+                findable_label(ui, "Opset version: ");
                 self.opset_version_widget.draw_and_parse(ui, id.with("tfversion"));
             });
         });
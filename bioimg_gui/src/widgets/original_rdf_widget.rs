@@ -0,0 +1,43 @@
+use super::code_editor_widget::{CodeEditorWidget, YamlLang};
+use super::StatefulWidget;
+
+/// A window showing the rdf.yaml of the last imported or recovered model exactly as it was in its archive,
+/// so users can compare what the archive said with what the form now contains
+#[derive(Default)]
+pub struct OriginalRdfWidget{
+    pub is_open: bool,
+    source_name: String,
+    rdf_viewer: Option<CodeEditorWidget<YamlLang>>,
+}
+
+impl OriginalRdfWidget{
+    pub fn set_rdf(&mut self, source_name: String, raw_rdf: String){
+        self.source_name = source_name;
+        self.rdf_viewer = Some(CodeEditorWidget::new_read_only(raw_rdf));
+    }
+
+    pub fn has_rdf(&self) -> bool{
+        self.rdf_viewer.is_some()
+    }
+
+    pub fn raw_rdf(&self) -> Option<&str>{
+        self.rdf_viewer.as_ref().map(|viewer| viewer.raw.as_str())
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context){
+        if !self.is_open{
+            return
+        }
+        let Some(rdf_viewer) = &mut self.rdf_viewer else {
+            return
+        };
+        egui::Window::new("Original rdf.yaml")
+            .open(&mut self.is_open)
+            .default_size([700.0, 600.0])
+            .show(ctx, |ui|{
+                ui.weak(format!("As found in {}. Changes made in the form are not reflected here.", self.source_name));
+                ui.separator();
+                rdf_viewer.draw_and_parse(ui, egui::Id::from("original rdf viewer"));
+            });
+    }
+}
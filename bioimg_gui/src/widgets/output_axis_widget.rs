@@ -15,6 +15,7 @@ use super::axis_widget::{axis_description_label, axis_id_label, BatchAxisWidget,
 use super::util::group_frame;
 use super::{Restore, StatefulWidget, ValueWidget};
 use super::{axis_size_widget::AnyAxisSizeWidget, staging_num::StagingNum};
+use super::field_finder_widget::findable_label;
 use crate::result::{GuiError, Result};
 
 #[derive(Default, Restore)]
@@ -45,6 +46,46 @@ impl OutputSpacetimeSizeWidget{
     }
 }
 
+/// Draws the axis as a bar, with the `halo` pixels discarded at each end and the valid region between
+/// them. The bar is drawn to scale if the axis has a fixed `size`
+fn draw_halo_illustration(ui: &mut egui::Ui, size: Option<usize>, halo: u64){
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(240.0, 36.0), egui::Sense::hover());
+    let bar = egui::Rect::from_min_size(rect.min, egui::vec2(rect.width(), 18.0));
+    let halo_fraction = match size{
+        Some(size) => (halo as f32 / size as f32).min(0.5),
+        None if halo == 0 => 0.0,
+        None => 0.15,
+    };
+    let halo_width = bar.width() * halo_fraction;
+    let left_halo = egui::Rect::from_min_max(bar.min, egui::pos2(bar.min.x + halo_width, bar.max.y));
+    let right_halo = egui::Rect::from_min_max(egui::pos2(bar.max.x - halo_width, bar.min.y), bar.max);
+    let valid = egui::Rect::from_min_max(left_halo.right_top(), right_halo.left_bottom());
+
+    let painter = ui.painter();
+    let visuals = ui.visuals();
+    let halo_color = visuals.error_fg_color.gamma_multiply(0.4);
+    painter.rect_filled(left_halo, 0.0, halo_color);
+    painter.rect_filled(right_halo, 0.0, halo_color);
+    painter.rect_filled(valid, 0.0, visuals.selection.bg_fill);
+    painter.rect_stroke(bar, 0.0, visuals.widgets.noninteractive.fg_stroke, egui::StrokeKind::Inside);
+
+    let font = egui::FontId::proportional(10.0);
+    let text_color = visuals.text_color();
+    let valid_text = match size{
+        Some(size) => format!("valid: {}", (size as u64).saturating_sub(halo.saturating_mul(2))),
+        None => format!("valid: size - {}", halo.saturating_mul(2)),
+    };
+    painter.text(valid.center(), egui::Align2::CENTER_CENTER, valid_text, font.clone(), text_color);
+    painter.text(bar.left_bottom() + egui::vec2(0.0, 2.0), egui::Align2::LEFT_TOP, format!("◀ {halo} discarded"), font.clone(), text_color);
+    painter.text(bar.right_bottom() + egui::vec2(0.0, 2.0), egui::Align2::RIGHT_TOP, format!("{halo} discarded ▶"), font, text_color);
+
+    if let Some(size) = size{
+        if halo.saturating_mul(2) >= size as u64{
+            show_error(ui, format!("A halo of {halo} on each side leaves nothing of an axis of size {size}"));
+        }
+    }
+}
+
 impl SummarizableWidget for OutputAxisWidget{
     fn summarize(&mut self, ui: &mut egui::Ui, _id: egui::Id) {
         match self.state(){
@@ -65,7 +106,7 @@ impl StatefulWidget for OutputSpacetimeSizeWidget{
         ui.vertical(|ui|{
             self.size_widget.draw_and_parse(ui, id.with("size"));
             ui.horizontal(|ui| {
-                ui.strong("Edges have artifacts: ").on_hover_text(indoc!("
+                findable_label(ui, "Edges have artifacts: ").on_hover_text(indoc!("
                     If checked, means that this tensor has edge artifact on this axis, and that those should \
                     be discarded by downstream callers of the model "
                 ));
@@ -73,12 +114,17 @@ impl StatefulWidget for OutputSpacetimeSizeWidget{
             });
             if self.has_halo {
                 ui.horizontal(|ui| {
-                    ui.strong("Bad pixels counting from the edge: ").on_hover_text(indoc!("
+                    findable_label(ui, "Halo (bad pixels counting from the edge): ").on_hover_text(indoc!("
                         How many pixels counting from both the start and end of this axis should be discarded \
                         to avoid edge artifacts"
                     ));
                     self.halo_widget.draw_and_parse(ui, id.with("halo"));
                 });
+                let fixed_size = match self.size_widget.state(){
+                    Ok(modelrdf::AnyAxisSize::Fixed(size)) => Some(size.get()),
+                    _ => None,
+                };
+                draw_halo_illustration(ui, fixed_size, self.halo_widget.raw);
             }
         });
     }
@@ -140,7 +186,7 @@ impl StatefulWidget for OutputSpaceAxisWidget{
                 self.description_widget.draw_and_parse(ui, id.with("description"));
             });
             ui.horizontal(|ui| {
-                ui.strong("Size: ").on_hover_text(indoc!("
+                findable_label(ui, "Size: ").on_hover_text(indoc!("
                     The output tensor size in this dimension"
                 ));
                 group_frame(ui, |ui|{
@@ -223,7 +269,7 @@ impl StatefulWidget for OutputTimeAxisWidget{
                 self.description_widget.draw_and_parse(ui, id.with("description"));
             });
             ui.horizontal(|ui| {
-                ui.strong("Size: ");
+                findable_label(ui, "Size: ");
                 group_frame(ui, |ui|{
                     self.size_widget.draw_and_parse(ui, id.with("size"));
                 });
@@ -257,15 +303,43 @@ pub struct OutputAxisWidget {
 }
 
 impl OutputAxisWidget{
+    /// The size widget of the currently selected axis type, if that type has a configurable size
+    pub fn size_widget_mut(&mut self) -> Option<&mut AnyAxisSizeWidget>{
+        match self.axis_type_widget.value{
+            AxisType::Space => Some(&mut self.space_axis_widget.size_widget.size_widget),
+            AxisType::Time => Some(&mut self.time_axis_widget.size_widget.size_widget),
+            AxisType::Index => Some(&mut self.index_axis_widget.size_widget),
+            AxisType::Batch | AxisType::Channel => None,
+        }
+    }
+    /// The halo of the currently selected axis type, if it has one
+    pub fn halo(&self) -> Option<u64>{
+        let size_widget = match self.axis_type_widget.value{
+            AxisType::Space => &self.space_axis_widget.size_widget,
+            AxisType::Time => &self.time_axis_widget.size_widget,
+            AxisType::Batch | AxisType::Channel | AxisType::Index => return None,
+        };
+        size_widget.has_halo.then_some(size_widget.halo_widget.raw)
+    }
     pub fn name_label(&self, axis_idx: usize) -> egui::RichText {
+        self.label_with_suffix(axis_idx, "")
+    }
+    /// Like [Self::name_label], but also showing the halo if the axis has one
+    pub fn summary_label(&self, axis_idx: usize) -> egui::RichText {
+        match self.halo(){
+            Some(halo) => self.label_with_suffix(axis_idx, &format!(" (halo {halo})")),
+            None => self.name_label(axis_idx),
+        }
+    }
+    fn label_with_suffix(&self, axis_idx: usize, suffix: &str) -> egui::RichText {
         match self.axis_type_widget.value{
             AxisType::Space => match self.space_axis_widget.id_widget.raw.len() {
-                0 => egui::RichText::new(format!("Axis #{axis_idx}")).color(egui::Color32::RED),
-                _ => egui::RichText::new(&self.space_axis_widget.id_widget.raw)
+                0 => egui::RichText::new(format!("Axis #{axis_idx}{suffix}")).color(egui::Color32::RED),
+                _ => egui::RichText::new(format!("{}{suffix}", self.space_axis_widget.id_widget.raw))
             },
             AxisType::Time => match self.time_axis_widget.id_widget.raw.len() {
-                0 => egui::RichText::new(format!("Axis #{axis_idx}")).color(egui::Color32::RED),
-                _ => egui::RichText::new(&self.time_axis_widget.id_widget.raw)
+                0 => egui::RichText::new(format!("Axis #{axis_idx}{suffix}")).color(egui::Color32::RED),
+                _ => egui::RichText::new(format!("{}{suffix}", self.time_axis_widget.id_widget.raw))
             },
             AxisType::Batch => "batch".into(),
             AxisType::Channel => "channel".into(),
@@ -292,7 +366,7 @@ impl OutputAxisWidget{
         ui.vertical(|ui|{
             if show_type_picker{
                 ui.horizontal(|ui| {
-                    ui.strong("Axis Type: ");
+                    findable_label(ui, "Axis Type: ");
                     self.draw_type_picker(ui, id.with("axis_type".as_ptr()));
                 });
             }
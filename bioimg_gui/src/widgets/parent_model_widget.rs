@@ -0,0 +1,73 @@
+use bioimg_spec::rdf::{self, model as modelrdf};
+
+use super::collapsible_widget::SummarizableWidget;
+use super::error_display::show_error;
+use super::staging_num::StagingNum;
+use super::staging_opt::StagingOpt;
+use super::staging_string::StagingString;
+#[cfg(not(target_arch="wasm32"))]
+use super::zoo_widget::ZooResourcePickerWidget;
+use super::{Restore, StatefulWidget, ValueWidget};
+use super::field_finder_widget::findable_label;
+use crate::result::{GuiError, Result};
+
+#[derive(Default, Restore, ValueWidget)]
+#[restore(saved_data=crate::project_data::ParentModelWidgetSavedData)]
+#[value_widget(value=modelrdf::LinkedModel)]
+pub struct ParentModelWidget{
+    pub id_widget: StagingString<rdf::ResourceId>,
+    pub version_number_widget: StagingOpt<StagingNum<u32, u32>, false>,
+    #[cfg(not(target_arch="wasm32"))]
+    #[restore(default)]
+    #[value_widget(skip)]
+    pub picker_widget: ZooResourcePickerWidget,
+}
+
+impl SummarizableWidget for ParentModelWidget{
+    fn summarize(&mut self, ui: &mut egui::Ui, _id: egui::Id) {
+        match self.state(){
+            Ok(parent) => {
+                ui.label(parent.to_string());
+            },
+            Err(err) => {
+                show_error(ui, err.to_string());
+            }
+        }
+    }
+}
+
+impl StatefulWidget for ParentModelWidget{
+    type Value<'p> = Result<modelrdf::LinkedModel>;
+
+    fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
+        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+            findable_label(ui, "Model Id: ").on_hover_text(
+                "The zoo id of the model this one was derived from, e.g. by fine-tuning its weights"
+            );
+            ui.horizontal(|ui|{
+                self.id_widget.draw_and_parse(ui, id.with("id".as_ptr()));
+                #[cfg(not(target_arch="wasm32"))]
+                if let Some(picked_id) = self.picker_widget.draw(ui, "model"){
+                    self.id_widget = StagingString::new_with_raw(picked_id);
+                }
+            });
+            ui.end_row();
+
+            findable_label(ui, "Version Number: ").on_hover_text(
+                "Which published version of the parent model was used (the n-th upload, not its semantic version). \
+                Leave empty to refer to the latest one"
+            );
+            self.version_number_widget.draw_and_parse(ui, id.with("version_number".as_ptr()));
+            ui.end_row();
+        });
+    }
+
+    fn state<'p>(&'p self) -> Self::Value<'p> {
+        Ok(modelrdf::LinkedModel{
+            id: self.id_widget.state()
+                .map_err(|_| GuiError::new("Invalid parent model id"))?
+                .clone(),
+            version_number: self.version_number_widget.state().transpose()?,
+        })
+    }
+}
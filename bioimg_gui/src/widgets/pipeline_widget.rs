@@ -449,7 +449,7 @@ impl PipelineWidget{
                                     let axes_resp = egui::Frame::new().inner_margin(4.0).show(ui, |ui|{
                                         ui.spacing_mut().item_spacing.y = 0.5;
                                         for (axis_idx, axis_widget) in output.axis_widgets.iter().enumerate(){
-                                            if egui::Button::new(axis_widget.name_label(axis_idx).small()).draw_as_label(ui).clicked(){
+                                            if egui::Button::new(axis_widget.summary_label(axis_idx).small()).draw_as_label(ui).clicked(){
                                                 pipeline_action = PipelineAction::OpenOutputAxis { output_idx, axis_idx };
                                             }
                                         }
@@ -726,7 +726,7 @@ impl PipelineWidget{
                 }).unwrap_or(PipelineAction::OpenPostproc { output_idx, postproc_idx })
             }
             PipelineAction::OpenInput { input_idx } => {
-                let id = id.with(input_idx).with("input modal".as_ptr());
+                let id = id.with(input_idx).with("input modal");
                 let input_widget = &interface_widget.input_widgets[input_idx];
                 let header = format!(
                     "Input '{}'",
@@ -735,7 +735,7 @@ impl PipelineWidget{
                 modal(id, ui, header, |ui| {
                     let mut action = None;
                     let input = &mut interface_widget.input_widgets[input_idx];
-                    input.draw(ui, id.with("input widget".as_ptr()));
+                    input.draw(ui, id.with("input widget"));
                     ui.separator();
                     if let Err(err) = input.parse(){
                         show_error(ui, err);
@@ -745,6 +745,14 @@ impl PipelineWidget{
                             interface_widget.input_widgets.remove(input_idx);
                             action.replace(PipelineAction::Nothing);
                         }
+                        let matching_output_clicked = ui.button("Create matching output")
+                            .on_hover_text("Adds an output with the same axes as this input")
+                            .clicked();
+                        if matching_output_clicked{
+                            let output = interface_widget.input_widgets[input_idx].matching_output();
+                            interface_widget.output_widgets.push(output);
+                            action.replace(PipelineAction::OpenOutput { output_idx: interface_widget.output_widgets.len() - 1 });
+                        }
                         if ui.button("Ok").clicked(){
                             action.replace(PipelineAction::Nothing);
                         }
@@ -753,7 +761,7 @@ impl PipelineWidget{
                 }).unwrap_or(PipelineAction::OpenInput { input_idx })
             },
             PipelineAction::OpenOutput { output_idx } => {
-                let id = id.with(output_idx).with("output modal".as_ptr());
+                let id = id.with(output_idx).with("output modal");
                 let output_widget = &interface_widget.output_widgets[output_idx];
                 let header = format!(
                     "Output '{}'",
@@ -762,7 +770,7 @@ impl PipelineWidget{
                 modal(id, ui, header, |ui| {
                     let mut action = None;
                     let output = &mut interface_widget.output_widgets[output_idx];
-                    output.draw(ui, id.with("output widget".as_ptr()));
+                    output.draw(ui, id.with("output widget"));
                     ui.separator();
                     if let Err(err) = output.parse(){
                         show_error(ui, err);
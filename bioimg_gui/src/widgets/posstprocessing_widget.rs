@@ -14,6 +14,8 @@ use super::util::search_and_pick;
 use super::util::SearchVisibility;
 use super::Restore;
 use super::{binarize_widget::BinarizePreprocessingWidget, clip_widget::ClipWidget, fixed_zero_mean_unit_variance_widget::FixedZmuvWidget, scale_linear_widget::ScaleLinearWidget, scale_range_widget::ScaleRangeWidget, search_and_pick_widget::SearchAndPickWidget, staging_vec::ItemWidgetConf, zero_mean_unit_variance_widget::ZeroMeanUnitVarianceWidget, StatefulWidget, ValueWidget};
+use super::dtype_support::DtypeSupport;
+use super::field_finder_widget::findable_label;
 
 #[derive(PartialEq, Eq, Default, Clone, Copy)]
 #[derive(strum::VariantArray, strum::AsRefStr, strum::VariantNames, strum::Display)]
@@ -66,7 +68,7 @@ impl Restore for PostprocessingWidgetMode{
     }
 }
 
-#[derive(Default, Restore)]
+#[derive(Restore)]
 #[restore(saved_data=crate::project_data::PostprocessingWidgetSavedData)]
 pub struct PostprocessingWidget{
     #[restore(default)]
@@ -83,6 +85,23 @@ pub struct PostprocessingWidget{
     pub scale_mean_var_widget: ScaleMeanVarianceWidget,
 }
 
+impl Default for PostprocessingWidget{
+    fn default() -> Self {
+        Self{
+            mode_search: Default::default(),
+            mode: Default::default(),
+            binarize_widget: Default::default(),
+            clip_widget: Default::default(),
+            scale_linear_widget: Default::default(),
+            zero_mean_unit_variance_widget: Default::default(),
+            scale_range_widget: Default::default(),
+            ensure_dtype_widget: SearchAndPickWidget::data_types(Default::default()),
+            fixed_zmuv_widget: Default::default(),
+            scale_mean_var_widget: Default::default(),
+        }
+    }
+}
+
 impl ValueWidget for PostprocessingWidget{
     type Value<'v> = postproc::PostprocessingDescr;
     fn set_value<'v>(&mut self, value: Self::Value<'v>) {
@@ -206,7 +225,7 @@ impl PostprocessingWidget {
         ui.vertical(|ui|{
             if matches!(show_type_picker, ShowPostprocTypePicker::Show){
                 ui.horizontal(|ui|{
-                    ui.strong("Postprocessing Type: ").on_hover_text(
+                    findable_label(ui, "Postprocessing Type: ").on_hover_text(
                         "What function is to be applied onto the output as it's produced by the model weights"
                     );
                     self.draw_type_picker(ui, id.with("postproc type".as_ptr()));
@@ -233,8 +252,8 @@ impl PostprocessingWidget {
                 },
                 PostprocessingWidgetMode::EnsureDtype => {
                     ui.horizontal(|ui|{
-                        ui.strong("Data Type: ");
-                        self.ensure_dtype_widget.draw_and_parse(ui, id.with("ensure_dtype".as_ptr()))
+                        findable_label(ui, "Data Type: ");
+                        DtypeSupport::draw_picker(&mut self.ensure_dtype_widget, ui, id.with("ensure_dtype".as_ptr()))
                     });
                 },
                 PostprocessingWidgetMode::FixedZmuv => {
@@ -0,0 +1,140 @@
+//! Proposes common preprocessing chains for an input, based on the element type and values of its test tensor
+
+use bioimg_runtime::tensor_stats::TensorStats;
+use bioimg_runtime::NpyArray;
+use bioimg_spec::rdf::model::{self as modelrdf, AxisId, AxisType, InputAxis};
+use bioimg_spec::rdf::model::preprocessing as modelrdfpreproc;
+use bioimg_spec::rdf::model::preprocessing::PreprocessingDescr;
+use bioimg_spec::rdf::NonEmptyList;
+
+use super::test_tensor_widget::TestTensorId;
+use super::util::FrameCache;
+
+/// Percentiles commonly used to normalize microscopy images
+const DEFAULT_MIN_PERCENTILE: f32 = 1.0;
+const DEFAULT_MAX_PERCENTILE: f32 = 99.8;
+
+pub struct PreprocessingSuggestion{
+    pub name: &'static str,
+    /// Why this chain makes sense for the test tensor
+    pub reason: String,
+    pub steps: Vec<PreprocessingDescr>,
+}
+
+/// Axes to normalize jointly: the spatial and time axes, so that each channel gets normalized separately.
+/// `None` (i.e. all axes jointly) if there is no channel axis
+fn axes_to_reduce(axes: &[InputAxis]) -> Option<Vec<AxisId>>{
+    if !axes.iter().any(|axis| axis.axis_type() == AxisType::Channel){
+        return None
+    }
+    let reduced: Vec<AxisId> = axes.iter()
+        .filter(|axis| matches!(axis.axis_type(), AxisType::Space | AxisType::Time))
+        .map(|axis| axis.id())
+        .collect();
+    if reduced.is_empty() { None } else { Some(reduced) }
+}
+
+/// Suggestions for a test tensor of type `data_type` whose values have `stats`
+pub fn suggest_preprocessing(data_type: modelrdf::DataType, stats: &TensorStats, axes: &[InputAxis]) -> Vec<PreprocessingSuggestion>{
+    let ensure_float32 = PreprocessingDescr::EnsureDtype(modelrdfpreproc::EnsureDtype{dtype: modelrdf::DataType::Float32});
+    if data_type == modelrdf::DataType::Bool{
+        return vec![PreprocessingSuggestion{
+            name: "Convert to float32",
+            reason: "The test tensor is a boolean mask, so it only needs its type converted".to_owned(),
+            steps: vec![ensure_float32],
+        }]
+    }
+
+    let reduced_axes = axes_to_reduce(axes);
+    let mut suggestions = vec![];
+
+    let is_float = matches!(data_type, modelrdf::DataType::Float32 | modelrdf::DataType::Float64);
+    if is_float && stats.min >= 0.0 && stats.max <= 1.0{
+        suggestions.push(PreprocessingSuggestion{
+            name: "Keep values as they are",
+            reason: format!("Values are already within [0, 1] (min {}, max {})", stats.min, stats.max),
+            steps: vec![ensure_float32.clone()],
+        });
+    }
+
+    let percentiles = modelrdfpreproc::ScaleRangePercentile::try_from_min_max(DEFAULT_MIN_PERCENTILE, DEFAULT_MAX_PERCENTILE)
+        .expect("default percentiles are valid");
+    suggestions.push(PreprocessingSuggestion{
+        name: "Percentile normalization",
+        reason: format!(
+            "Values range from {} to {} ({data_type}); this maps the {DEFAULT_MIN_PERCENTILE}th to \
+            {DEFAULT_MAX_PERCENTILE}th percentile to [0, 1], ignoring outliers",
+            stats.min, stats.max,
+        ),
+        steps: vec![
+            ensure_float32.clone(),
+            PreprocessingDescr::ScaleRange(modelrdfpreproc::ScaleRangeDescr{
+                axes: reduced_axes.clone(),
+                percentiles,
+                eps: Default::default(),
+                reference_tensor: None,
+            }),
+        ],
+    });
+
+    suggestions.push(PreprocessingSuggestion{
+        name: "Zero-mean, unit-variance",
+        reason: format!("Values have mean {:.4} and standard deviation {:.4}", stats.mean, stats.std),
+        steps: vec![
+            ensure_float32,
+            PreprocessingDescr::ZeroMeanUnitVariance(modelrdfpreproc::Zmuv{
+                axes: reduced_axes.and_then(|axes| NonEmptyList::try_from(axes).ok()),
+                eps: Default::default(),
+            }),
+        ],
+    });
+
+    suggestions
+}
+
+/// A loaded test tensor, identified by the id of its load alone so that it can be looked up without
+/// going through its values
+#[derive(Clone, Copy)]
+struct LoadedTensor<'a>{
+    id: TestTensorId,
+    data: &'a NpyArray,
+}
+
+impl std::hash::Hash for LoadedTensor<'_>{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+#[derive(Default)]
+struct StatsComputer;
+
+impl egui::cache::ComputerMut<LoadedTensor<'_>, TensorStats> for StatsComputer{
+    fn compute(&mut self, test_tensor: LoadedTensor<'_>) -> TensorStats {
+        TensorStats::compute(test_tensor.data)
+    }
+}
+
+/// Stats of a test tensor, recomputed when a different one is loaded. Computing them goes through every
+/// element of the tensor
+type StatsCache = FrameCache<TensorStats, StatsComputer>;
+
+/// Draws a menu with the suggested chains, returning the steps of the one the user picked
+pub fn draw_preprocessing_suggestions(
+    ui: &mut egui::Ui, test_tensor_id: TestTensorId, test_tensor: &NpyArray, axes: &[InputAxis]
+) -> Option<Vec<PreprocessingDescr>>{
+    let mut picked = None;
+    ui.menu_button("💡 Suggest", |ui|{
+        ui.weak("Replaces the current preprocessing steps");
+        let stats = ui.memory_mut(|mem| mem.caches.cache::<StatsCache>().get(LoadedTensor{id: test_tensor_id, data: test_tensor}));
+        for suggestion in suggest_preprocessing(test_tensor.data_type(), &stats, axes){
+            let steps_text = suggestion.steps.iter().map(|step| step.to_string()).collect::<Vec<_>>().join(" → ");
+            let response = ui.button(suggestion.name).on_hover_text(format!("{}\n\n{steps_text}", suggestion.reason));
+            if response.clicked(){
+                picked = Some(suggestion.steps);
+                ui.close_menu();
+            }
+        }
+    }).response.on_hover_text("Propose common preprocessing steps based on the test tensor");
+    picked
+}
@@ -11,11 +11,14 @@ use super::binarize_widget::BinarizePreprocessingWidget;
 use super::zero_mean_unit_variance_widget::ZeroMeanUnitVarianceWidget;
 use super::staging_vec::ItemWidgetConf;
 use super::search_and_pick_widget::SearchAndPickWidget;
+use super::dtype_support::DtypeSupport;
 use super::scale_range_widget::ScaleRangeWidget;
 use super::scale_linear_widget::ScaleLinearWidget;
 use super::fixed_zero_mean_unit_variance_widget::FixedZmuvWidget;
 use super::collapsible_widget::{CollapsibleWidget, SummarizableWidget};
 use super::clip_widget::ClipWidget;
+use super::test_tensor_widget::TestTensorContext;
+use super::field_finder_widget::findable_label;
 
 #[derive(Hash, PartialEq, Eq, Default, Copy, Clone, strum::VariantArray, strum::AsRefStr, strum::VariantNames, strum::Display)]
 pub enum PreprocessingWidgetMode {
@@ -63,7 +66,7 @@ impl Restore for PreprocessingWidgetMode{
     }
 }
 
-#[derive(Default, Restore)]
+#[derive(Restore)]
 #[restore(saved_data=crate::project_data::PreprocessingWidgetSavedData)]
 pub struct PreprocessingWidget{
     pub mode: PreprocessingWidgetMode,
@@ -79,6 +82,22 @@ pub struct PreprocessingWidget{
     pub fixed_zmuv_widget: FixedZmuvWidget,
 }
 
+impl Default for PreprocessingWidget{
+    fn default() -> Self {
+        Self{
+            mode: Default::default(),
+            mode_search: Default::default(),
+            binarize_widget: Default::default(),
+            clip_widget: Default::default(),
+            scale_linear_widget: Default::default(),
+            zero_mean_unit_variance_widget: Default::default(),
+            scale_range_widget: Default::default(),
+            ensure_dtype_widget: SearchAndPickWidget::data_types(Default::default()),
+            fixed_zmuv_widget: Default::default(),
+        }
+    }
+}
+
 impl Iconify for  PreprocessingWidget{
     fn iconify(&self) -> Result<egui::WidgetText> {
         match self.mode{
@@ -194,7 +213,7 @@ impl PreprocessingWidget {
         ui.vertical(|ui|{
             if matches!(show_type_picker, ShowPreprocTypePicker::Show){
                 ui.horizontal(|ui|{
-                    ui.strong("Preprocessing Type: ").on_hover_text(
+                    findable_label(ui, "Preprocessing Type: ").on_hover_text(
                         "What function is to be applied onto the input before it's fed to the model weights"
                     );
                     self.draw_preproc_type_picker(ui, id.with("preproc type".as_ptr()));
@@ -221,8 +240,8 @@ impl PreprocessingWidget {
                 },
                 PreprocessingWidgetMode::EnsureDtype => {
                     ui.horizontal(|ui|{
-                        ui.strong("Data Type: ");
-                        self.ensure_dtype_widget.draw_and_parse(ui, id.with("ensure_dtype".as_ptr()))
+                        findable_label(ui, "Data Type: ");
+                        DtypeSupport::draw_picker(&mut self.ensure_dtype_widget, ui, id.with("ensure_dtype".as_ptr()))
                     });
                 },
                 PreprocessingWidgetMode::FixedZmuv => {
@@ -232,6 +251,15 @@ impl PreprocessingWidget {
         });
     }
 
+    /// Draws helpers that derive this step's parameters from the test tensor, for the steps that have any
+    pub fn draw_test_tensor_tools(&mut self, ui: &mut egui::Ui, test_tensor: &TestTensorContext){
+        match self.mode{
+            PreprocessingWidgetMode::ScaleRange => self.scale_range_widget.draw_test_tensor_tools(ui, test_tensor),
+            PreprocessingWidgetMode::FixedZmuv => self.fixed_zmuv_widget.draw_test_tensor_tools(ui, test_tensor),
+            _ => (),
+        }
+    }
+
     pub fn state<'p>(&'p self) -> Result<modelrdfpreproc::PreprocessingDescr> {
         Ok(match self.mode {
             PreprocessingWidgetMode::Binarize => {
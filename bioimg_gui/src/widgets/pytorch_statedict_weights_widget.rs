@@ -16,6 +16,7 @@ use super::json_editor_widget::JsonObjectEditorWidget;
 use super::file_source_widget::FileSourceWidget;
 use super::error_display::show_error;
 use super::conda_env_editor_widget::CondaEnvEditorWidget;
+use super::field_finder_widget::findable_label;
 
 #[derive(Clone, strum::AsRefStr, strum::VariantArray, strum::VariantNames, Default, strum::Display)]
 pub enum PytorchArchMode{
@@ -49,7 +50,7 @@ pub struct PytorchArchWidget{
     pub callable_widget: StagingString<rdf::Identifier>,
     pub kwargs_widget: JsonObjectEditorWidget,
 
-    pub import_from_widget: StagingString<String>,
+    pub import_from_widget: StagingString<rdf::DottedIdentifier>,
     pub source_widget: FileSourceWidget,
 }
 
@@ -61,27 +62,34 @@ impl ValueWidget for PytorchArchWidget{
             rt::model_weights::PytorchArch::FromLib(fromlib) => {
                 self.mode_widget.value = PytorchArchMode::FromLib;
                 self.callable_widget.set_value(fromlib.callable);
-                self.kwargs_widget.set_value(fromlib.kwargs);
+                self.kwargs_widget.set_value(fromlib.kwargs.into());
                 self.import_from_widget.set_value(fromlib.import_from);
             },
             rt::model_weights::PytorchArch::FromFile { file_source, callable, kwargs } => {
                 self.mode_widget.value = PytorchArchMode::FromFile;
                 self.source_widget.set_value(file_source);
                 self.callable_widget.set_value(callable);
-                self.kwargs_widget.set_value(kwargs);
+                self.kwargs_widget.set_value(kwargs.into());
             }
         }
     }
 }
 
 
+impl PytorchArchWidget{
+    fn kwargs(&self) -> Result<modelrdf::weights::PythonKwargs>{
+        let kwargs = self.kwargs_widget.state().as_ref().map_err(|err| err.clone())?.clone();
+        modelrdf::weights::PythonKwargs::try_from(kwargs).map_err(|err| GuiError::new(err.to_string()))
+    }
+}
+
 impl StatefulWidget for PytorchArchWidget{
     type Value<'p> = Result<rt::model_weights::PytorchArch>;
 
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.vertical(|ui|{
             ui.horizontal(|ui|{
-                ui.strong("Mode: ").on_hover_text(
+                findable_label(ui, "Mode: ").on_hover_text(
                     "Is this model supposed to be loaded directly from a file or python-imported as a module?"
                 );
                 self.mode_widget.draw_and_parse(ui, id.with("mode".as_ptr()));
@@ -89,7 +97,7 @@ impl StatefulWidget for PytorchArchWidget{
             let callable_tooltip = match self.mode_widget.value{
                 PytorchArchMode::FromLib => {
                     ui.horizontal(|ui|{
-                        ui.strong("Import from: ").on_hover_text(
+                        findable_label(ui, "Import from: ").on_hover_text(
                             "A python module path where this model resides. E.g.: my_package.my_module"
                         );
                         self.import_from_widget.draw_and_parse(ui, id.with("import".as_ptr()));
@@ -98,7 +106,7 @@ impl StatefulWidget for PytorchArchWidget{
                 },
                 PytorchArchMode::FromFile => {
                     ui.horizontal(|ui|{
-                        ui.strong("Source File: ").on_hover_text("The source file where the model python code resides");
+                        findable_label(ui, "Source File: ").on_hover_text("The source file where the model python code resides");
                         group_frame(ui, |ui|{
                             self.source_widget.draw_and_parse(ui, id.with("source".as_ptr()));
                         })
@@ -107,7 +115,7 @@ impl StatefulWidget for PytorchArchWidget{
                 }
             };
             ui.horizontal(|ui|{
-                ui.strong("Callable: ").on_hover_text(callable_tooltip);
+                findable_label(ui, "Callable: ").on_hover_text(callable_tooltip);
                 self.callable_widget.draw_and_parse(ui, id.with("callable".as_ptr()));
             });
             if matches!(self.mode_widget.value, PytorchArchMode::FromLib) && !self.import_from_widget.raw.is_empty(){
@@ -128,9 +136,13 @@ impl StatefulWidget for PytorchArchWidget{
                     Ok(identifier) => format!("'{identifier}'"),
                     Err(_) => "the function in the 'Callable' field above".to_owned(),
                 };
-                ui.strong("Keyword Arguments: ").on_hover_text(format!("Keyword arguments to be passed to {callable_name}"));
+                findable_label(ui, "Keyword Arguments: ").on_hover_text(format!("Keyword arguments to be passed to {callable_name}"));
                 self.kwargs_widget.draw_and_parse(ui, id.with("kwargs".as_ptr()));
             });
+            // bad json is already reported by the editor itself
+            if let (Ok(_), Err(err)) = (self.kwargs_widget.state(), self.kwargs()){
+                show_error(ui, err);
+            }
         });
     }
 
@@ -140,13 +152,13 @@ impl StatefulWidget for PytorchArchWidget{
                 Ok(rt::model_weights::PytorchArch::FromFile {
                     file_source: self.source_widget.state()?,
                     callable: self.callable_widget.state()?.clone(),
-                    kwargs: self.kwargs_widget.state().as_ref().map_err(|err| err.clone())?.clone()
+                    kwargs: self.kwargs()?,
                 })
             },
             PytorchArchMode::FromLib => {
                 Ok(rt::model_weights::PytorchArch::FromLib(modelrdf::weights::PyTorchArchitectureFromLibraryDescr{
                     callable: self.callable_widget.state()?.clone(),
-                    kwargs: self.kwargs_widget.state().as_ref().map_err(|err| err.clone())?.clone(),
+                    kwargs: self.kwargs()?,
                     import_from: self.import_from_widget.state()?.clone(),
                 }))
             }
@@ -154,11 +166,14 @@ impl StatefulWidget for PytorchArchWidget{
     }
 }
 
-#[derive(Default, Restore)]
+#[derive(Default, Restore, ValueWidget)]
 #[restore(saved_data=crate::project_data::PytorchStateDictWidgetSavedData)]
+#[value_widget(value=rt::model_weights::PytorchStateDictWeights)]
 pub struct PytorchStateDictWidget{
+    #[value_widget(from=weights)]
     pub base_widget: WeightsDescrBaseWidget,
     pub architecture_widget: PytorchArchWidget,
+    #[value_widget(from=pytorch_version)]
     pub version_widget: VersionWidget,
     pub dependencies_widget: StagingOpt<CondaEnvEditorWidget>,
 }
@@ -177,17 +192,6 @@ impl SummarizableWidget for PytorchStateDictWidget{
     }
 }
 
-impl ValueWidget for PytorchStateDictWidget{
-    type Value<'v> = rt::model_weights::PytorchStateDictWeights;
-
-    fn set_value<'v>(&mut self, value: Self::Value<'v>) {
-        self.base_widget.set_value(value.weights);
-        self.architecture_widget.set_value(value.architecture);
-        self.version_widget.set_value(value.pytorch_version);
-        self.dependencies_widget.set_value(value.dependencies);
-    }
-}
-
 impl StatefulWidget for PytorchStateDictWidget{
     type Value<'p> = Result<rt::model_weights::PytorchStateDictWeights>;
 
@@ -195,17 +199,17 @@ impl StatefulWidget for PytorchStateDictWidget{
         ui.vertical(|ui|{
             self.base_widget.draw_and_parse(ui, id.with("base".as_ptr()));
             ui.horizontal(|ui|{
-                ui.strong("Architecture: ");
+                findable_label(ui, "Architecture: ");
                 group_frame(ui, |ui|{
                     self.architecture_widget.draw_and_parse(ui, id.with("arch".as_ptr()));
                 })
             });
             ui.horizontal(|ui|{
-                ui.strong("Pytorch Version: ").on_hover_text("The pytorch version used when training these weights");
+                findable_label(ui, "Pytorch Version: ").on_hover_text("The pytorch version used when training these weights");
                 self.version_widget.draw_and_parse(ui, id.with("ver".as_ptr()));
             });
             ui.horizontal(|ui|{
-                ui.strong("Conda Environment: ").on_hover_text("A conda environment to be used with this model");
+                findable_label(ui, "Conda Environment: ").on_hover_text("A conda environment to be used with this model");
                 self.dependencies_widget.draw_and_parse(ui, id.with("env".as_ptr()));
             });
             if let Err(e) = self.state(){
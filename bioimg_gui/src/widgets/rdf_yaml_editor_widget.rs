@@ -0,0 +1,152 @@
+use std::fmt::Display;
+
+use bioimg_spec::rdf::model::ModelRdfV0_5;
+
+use crate::result::{GuiError, Result};
+use super::error_display::show_error;
+use super::StatefulWidget;
+
+/// Where and why a raw rdf.yaml failed to parse as a model
+#[derive(Clone, Debug)]
+pub struct RdfYamlError{
+    /// The path to the offending field, e.g. `inputs[0].axes[2].size`
+    pub path: String,
+    /// One-based line number, if the yaml parser could determine it
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl Display for RdfYamlError{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(line) = self.line{
+            write!(f, "Line {line}")?;
+            if let Some(column) = self.column{
+                write!(f, ", column {column}")?;
+            }
+            write!(f, ": ")?;
+        }
+        if !self.path.is_empty() && self.path != "."{
+            write!(f, "{}: ", self.path)?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+pub fn parse_rdf_yaml(raw: &str) -> Result<ModelRdfV0_5, RdfYamlError>{
+    let deserializer = serde_yaml::Deserializer::from_str(raw);
+    serde_path_to_error::deserialize(deserializer).map_err(|err|{
+        let path = err.path().to_string();
+        let inner = err.into_inner();
+        let location = inner.location();
+        RdfYamlError{
+            path,
+            line: location.as_ref().map(|loc| loc.line()),
+            column: location.as_ref().map(|loc| loc.column()),
+            message: inner.to_string(),
+        }
+    })
+}
+
+/// Byte range of the given one-based line in `text`
+fn line_byte_range(text: &str, line: usize) -> Option<std::ops::Range<usize>>{
+    let mut start = 0;
+    for (idx, line_text) in text.split_inclusive('\n').enumerate(){
+        if idx + 1 == line{
+            return Some(start..start + line_text.len())
+        }
+        start += line_text.len();
+    }
+    None
+}
+
+/// Edits a whole model rdf as yaml text, re-validating it as it is typed and pointing at the
+/// line of the first error found
+#[derive(Default)]
+pub struct RdfYamlEditorWidget{
+    pub raw: String,
+    parsed_raw: Option<String>,
+    error: Option<RdfYamlError>,
+}
+
+impl RdfYamlEditorWidget{
+    pub fn set_raw(&mut self, raw: String){
+        self.raw = raw;
+    }
+
+    fn reparse_if_changed(&mut self){
+        if self.parsed_raw.as_deref() == Some(self.raw.as_str()){
+            return
+        }
+        self.error = parse_rdf_yaml(&self.raw).err();
+        self.parsed_raw = Some(self.raw.clone());
+    }
+
+    pub fn error(&self) -> Option<&RdfYamlError>{
+        self.error.as_ref()
+    }
+}
+
+impl StatefulWidget for RdfYamlEditorWidget{
+    type Value<'p> = Result<&'p str>;
+
+    fn draw_and_parse(&mut self, ui: &mut egui::Ui, _id: egui::Id) {
+        self.reparse_if_changed();
+
+        let theme = egui_extras::syntax_highlighting::CodeTheme::from_memory(ui.ctx(), ui.style());
+        let error_line = self.error.as_ref().and_then(|err| err.line);
+        let error_background = ui.visuals().error_fg_color.gamma_multiply(0.3);
+        let mut layouter = |ui: &egui::Ui, text: &str, _wrap_width: f32| {
+            let mut layout_job = egui_extras::syntax_highlighting::highlight(ui.ctx(), ui.style(), &theme, text, "yaml");
+            if let Some(error_range) = error_line.and_then(|line| line_byte_range(text, line)){
+                for section in &mut layout_job.sections{
+                    if section.byte_range.start < error_range.end && error_range.start < section.byte_range.end{
+                        section.format.background = error_background;
+                    }
+                }
+            }
+            // no wrapping, so that lines stay aligned with the line numbers
+            layout_job.wrap.max_width = f32::INFINITY;
+            ui.fonts(|f| f.layout_job(layout_job))
+        };
+
+        ui.vertical(|ui|{
+            match &self.error{
+                Some(err) => show_error(ui, err),
+                None => {
+                    ui.label(egui::RichText::new("✔ Valid model rdf").color(egui::Color32::GREEN));
+                },
+            }
+            egui::ScrollArea::both()
+                .min_scrolled_height(500.0)
+                .show(ui, |ui|{
+                    ui.horizontal_top(|ui|{
+                        let num_lines = self.raw.lines().count().max(1);
+                        let line_numbers = (1..=num_lines).map(|n| n.to_string()).collect::<Vec<_>>().join("\n");
+                        ui.add(egui::Label::new(egui::RichText::new(line_numbers).monospace().weak()).selectable(false));
+                        ui.add(
+                            egui::TextEdit::multiline(&mut self.raw)
+                                .font(egui::TextStyle::Monospace)
+                                .code_editor()
+                                .desired_rows(40)
+                                .lock_focus(true)
+                                .desired_width(f32::INFINITY)
+                                .layouter(&mut layouter)
+                        );
+                    });
+                });
+        });
+    }
+
+    fn state<'p>(&'p self) -> Self::Value<'p> {
+        if self.parsed_raw.as_deref() != Some(self.raw.as_str()){
+            return parse_rdf_yaml(&self.raw)
+                .map(|_| self.raw.as_str())
+                .map_err(|err| GuiError::new(err.to_string()))
+        }
+        match &self.error{
+            Some(err) => Err(GuiError::new(err.to_string())),
+            None => Ok(&self.raw),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use bioimg_spec::rdf::model as modelrdf;
+
+use crate::result::Result;
+use super::collapsible_widget::SummarizableWidget;
+use super::error_display::show_error;
+use super::json_editor_widget::JsonObjectEditorWidget;
+use super::labels::SpecLinkExt;
+use super::staging_string::StagingString;
+use super::{Restore, StatefulWidget, ValueWidget};
+use super::field_finder_widget::findable_label;
+
+#[derive(Default, Restore, ValueWidget)]
+#[restore(saved_data=crate::project_data::RunModeWidgetSavedData)]
+#[value_widget(value=modelrdf::RunMode)]
+pub struct RunModeWidget{
+    pub name_widget: StagingString<modelrdf::run_mode::RunModeName>,
+    pub kwargs_widget: JsonObjectEditorWidget,
+}
+
+impl SummarizableWidget for RunModeWidget{
+    fn summarize(&mut self, ui: &mut egui::Ui, _id: egui::Id) {
+        match self.state(){
+            Ok(run_mode) => {
+                ui.label(run_mode.to_string());
+            },
+            Err(err) => {
+                show_error(ui, err.to_string());
+            }
+        }
+    }
+}
+
+impl StatefulWidget for RunModeWidget{
+    type Value<'p> = Result<modelrdf::RunMode>;
+
+    fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
+        ui.vertical(|ui|{
+            ui.horizontal(|ui|{
+                findable_label(ui, "Name: ").on_hover_text_with_spec(ui,
+                    "RunMode.name",
+                    "The name of the custom run mode. Consumers that don't know this run mode will refuse to run the model"
+                );
+                self.name_widget.draw_and_parse(ui, id.with("name".as_ptr()));
+            });
+            ui.horizontal(|ui|{
+                findable_label(ui, "Keyword Arguments: ").on_hover_text_with_spec(ui,
+                    "RunMode.kwargs",
+                    "Run mode specific keyword arguments, as a JSON object"
+                );
+                self.kwargs_widget.draw_and_parse(ui, id.with("kwargs".as_ptr()));
+            });
+        });
+    }
+
+    fn state<'p>(&'p self) -> Self::Value<'p> {
+        Ok(modelrdf::RunMode{
+            name: self.name_widget.state()?.clone(),
+            kwargs: self.kwargs_widget.state().as_ref().map_err(|err| err.clone())?.clone(),
+        })
+    }
+}
@@ -9,6 +9,7 @@ use super::{Restore, StatefulWidget, ValueWidget};
 use super::staging_vec::{ItemWidgetConf, StagingVec};
 use super::staging_string::StagingString;
 use super::staging_float::StagingFloat;
+use super::field_finder_widget::findable_label;
 
 #[derive(PartialEq, Eq, Default, Copy, Clone, strum::VariantArray, strum::AsRefStr, strum::Display)]
 pub enum ScaleLinearMode{
@@ -34,21 +35,14 @@ impl Restore for ScaleLinearMode{
     }
 }
 
-#[derive(Restore)]
+#[derive(Restore, ValueWidget)]
 #[restore(saved_data=crate::project_data::SimpleScaleLinearWidgetSavedData)]
+#[value_widget(value=modelrdfpreproc::SimpleScaleLinearDescr)]
 pub struct SimpleScaleLinearWidget{
     pub gain_widget: StagingFloat<f32>,
     pub offset_widget: StagingFloat<f32>,
 }
 
-impl ValueWidget for SimpleScaleLinearWidget{
-    type Value<'v> = modelrdfpreproc::SimpleScaleLinearDescr;
-    fn set_value<'v>(&mut self, value: Self::Value<'v>) {
-        self.gain_widget.set_value(value.gain);
-        self.offset_widget.set_value(value.offset);
-    }
-}
-
 impl Default for SimpleScaleLinearWidget{
     fn default() -> Self {
         Self {
@@ -62,9 +56,9 @@ impl StatefulWidget for SimpleScaleLinearWidget{
     type Value<'p> = Result<modelrdfpreproc::SimpleScaleLinearDescr>;
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.horizontal(|ui|{
-            ui.strong("Gain: ");
+            findable_label(ui, "Gain: ");
             self.gain_widget.draw_and_parse(ui, id.with("gain"));
-            ui.strong(" Offset: ");
+            findable_label(ui, " Offset: ");
             self.offset_widget.draw_and_parse(ui, id.with("off"));
         });
         match self.state(){
@@ -163,7 +157,7 @@ impl StatefulWidget for ScaleLinearAlongAxisWidget{
                 self.axis_widget.draw_and_parse(ui, id.with("ax".as_ptr()));
             });
             ui.horizontal(|ui|{
-                ui.strong("Gains and Offsets:").on_hover_text(indoc!("
+                findable_label(ui, "Gains and Offsets:").on_hover_text(indoc!("
                     Each entry represents a linear transformation to be applied on a slice of the incoming
                     tensor. The incoming tensor is sliced along the axis in the 'Axis ID' field above."
                 ));
@@ -238,7 +232,7 @@ impl StatefulWidget for ScaleLinearWidget{
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.vertical(|ui|{
             ui.horizontal(|ui|{
-                ui.strong("Mode: ");
+                findable_label(ui, "Mode: ");
 
                 ui.radio_value(&mut self.mode, ScaleLinearMode::Simple, "General")
                     .on_hover_text(indoc!("
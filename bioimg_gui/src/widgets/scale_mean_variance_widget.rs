@@ -11,6 +11,7 @@ use super::staging_float::StagingFloat;
 use super::Restore;
 use super::ValueWidget;
 use super::{staging_opt::StagingOpt, staging_string::StagingString, staging_vec::{ItemWidgetConf, StagingVec}, StatefulWidget};
+use super::field_finder_widget::findable_label;
 
 #[derive(Default, Restore)]
 #[restore(saved_data=crate::project_data::ScaleMeanVarianceWidgetSavedData)]
@@ -44,15 +45,15 @@ impl StatefulWidget for ScaleMeanVarianceWidget{
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.vertical(|ui|{
             ui.horizontal(|ui|{
-                ui.strong("Reference Tensor: ");
+                findable_label(ui, "Reference Tensor: ");
                 self.reference_tensor_widget.draw_and_parse(ui, id.with("ref_tensor".as_ptr()));
             });
             ui.horizontal(|ui|{
-                ui.strong("Axes: ");
+                findable_label(ui, "Axes: ");
                 self.axes_widget.draw_and_parse(ui, id.with("axes".as_ptr()));
             });
             ui.horizontal(|ui|{
-                ui.strong("Epsilon: ");
+                findable_label(ui, "Epsilon: ");
                 self.eps_widget.draw_and_parse(ui, id.with("eps".as_ptr()));
             });
         });
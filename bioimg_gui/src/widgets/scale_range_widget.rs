@@ -1,15 +1,19 @@
 
+use bioimg_runtime::tensor_stats;
 use bioimg_spec::rdf::model::{self, preprocessing::PreprocessingEpsilon};
 use bioimg_spec::rdf::model::{preprocessing as modelrdfpreproc, TensorId};
 
 
 
 use crate::result::{GuiError, Result};
+use super::error_display::show_error;
 use super::iconify::Iconify;
+use super::test_tensor_widget::TestTensorContext;
 use super::staging_float::StagingFloat;
 use super::staging_vec::ItemWidgetConf;
 use super::{Restore, ValueWidget};
 use super::{staging_opt::StagingOpt, staging_string::StagingString, staging_vec::StagingVec, StatefulWidget};
+use super::field_finder_widget::findable_label;
 
 #[derive(Restore)]
 #[restore(saved_data=crate::project_data::PercentilesWidgetSavedData)]
@@ -42,8 +46,8 @@ impl ValueWidget for PercentilesWidget{
 impl Default for PercentilesWidget{
     fn default() -> Self {
         Self{
-            min_widget: StagingFloat::new_with_raw(0.0),
-            max_widget: StagingFloat::new_with_raw(100.0),
+            min_widget: StagingFloat::new_with_raw(0.0).with_range(0.0..=100.0),
+            max_widget: StagingFloat::new_with_raw(100.0).with_range(0.0..=100.0),
             parsed: Err(GuiError::new("empty".to_owned())),
         }
     }
@@ -55,9 +59,9 @@ impl StatefulWidget for PercentilesWidget{
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         self.update();
         ui.horizontal(|ui|{
-            ui.strong("Min Percentile: ");
+            findable_label(ui, "Min Percentile: ");
             self.min_widget.draw_and_parse(ui, id.with("min".as_ptr()));
-            ui.strong("Max Percentile: ");
+            findable_label(ui, "Max Percentile: ");
             self.max_widget.draw_and_parse(ui, id.with("max".as_ptr()));
         });
     }
@@ -74,13 +78,98 @@ impl ItemWidgetConf for AxesItemConfig{
     const MIN_NUM_ITEMS: usize = 1;
 }
 
-#[derive(Default, Restore)]
+/// The values found at the min and max percentiles of (part of) a test tensor
+#[derive(Clone)]
+pub struct PercentileValues{
+    /// Which part of the tensor these values come from, e.g. `channel = 2`. `None` for the whole tensor
+    pub label: Option<String>,
+    pub min: f64,
+    pub max: f64,
+}
+
+#[derive(Default, Restore, ValueWidget)]
 #[restore(saved_data=crate::project_data::ScaleRangeWidgetSavedData)]
+#[value_widget(value=modelrdfpreproc::ScaleRangeDescr)]
 pub struct ScaleRangeWidget{
     pub axes_widget: StagingOpt<StagingVec<StagingString<model::AxisId>, AxesItemConfig>>,
     pub percentiles_widget: PercentilesWidget,
+    #[value_widget(from=eps)]
     pub epsilon_widget: StagingFloat<PreprocessingEpsilon>,
     pub reference_tensor: StagingOpt<StagingString<TensorId>>,
+    #[restore(default)]
+    #[value_widget(skip)]
+    pub test_tensor_values: Option<Result<Vec<PercentileValues>>>,
+}
+
+impl ScaleRangeWidget{
+    /// Computes the values at the selected percentiles of the test tensor. Values are computed over the
+    /// selected axes (or over the whole tensor if there are none), separately for each entry of the
+    /// remaining axis
+    pub fn compute_test_tensor_values(&self, test_tensor: &TestTensorContext) -> Result<Vec<PercentileValues>>{
+        let percentiles = self.percentiles_widget.state().as_ref().map_err(|err| err.clone())?;
+        let percents = [percentiles.min() as f64, percentiles.max() as f64];
+        let shape = test_tensor.data.shape();
+
+        let kept_axes: Vec<usize> = match self.axes_widget.state(){
+            None => vec![],
+            Some(axis_ids) => {
+                let mut reduced_axes = Vec::with_capacity(axis_ids.len());
+                for axis_id in axis_ids{
+                    let axis_id = axis_id?;
+                    let axis_index = test_tensor.axis_index(axis_id)
+                        .ok_or_else(|| GuiError::new(format!("Test tensor has no axis '{axis_id}'")))?;
+                    reduced_axes.push(axis_index);
+                }
+                (0..shape.len())
+                    .filter(|axis_index| shape[*axis_index] > 1 && !reduced_axes.contains(axis_index))
+                    .collect()
+            }
+        };
+
+        Ok(match kept_axes.as_slice(){
+            [] => {
+                let values = tensor_stats::percentiles(test_tensor.data, &percents);
+                vec![PercentileValues{label: None, min: values[0], max: values[1]}]
+            },
+            [axis_index] => {
+                // the test tensor may have more dimensions than there are axis widgets
+                let axis_name = match test_tensor.axis_ids.get(*axis_index){
+                    Some(Some(axis_id)) => axis_id.to_string(),
+                    _ => format!("axis #{axis_index}"),
+                };
+                tensor_stats::percentiles_along_axis(test_tensor.data, *axis_index, &percents)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, values)| PercentileValues{
+                        label: Some(format!("{axis_name} = {idx}")), min: values[0], max: values[1]
+                    })
+                    .collect()
+            },
+            _ => return Err(GuiError::new(
+                "Values would have to be computed separately along more than one axis. Select more axes to reduce over"
+            )),
+        })
+    }
+
+    /// Lets the user check what the selected percentiles amount to in the test tensor
+    pub fn draw_test_tensor_tools(&mut self, ui: &mut egui::Ui, test_tensor: &TestTensorContext){
+        if ui.button("Compute on test tensor").on_hover_text(
+            "Shows which values of the test tensor lie at the selected percentiles"
+        ).clicked(){
+            self.test_tensor_values = Some(self.compute_test_tensor_values(test_tensor));
+        }
+        match &self.test_tensor_values{
+            None => (),
+            Some(Err(err)) => show_error(ui, err),
+            Some(Ok(values)) => {
+                for PercentileValues{label, min, max} in values{
+                    let prefix = label.as_ref().map(|label| format!("{label}: ")).unwrap_or_default();
+                    ui.weak(format!("{prefix}min = {min}, max = {max}"));
+                }
+            },
+        }
+    }
 }
 
 impl Iconify for ScaleRangeWidget{
@@ -90,35 +179,25 @@ impl Iconify for ScaleRangeWidget{
     }
 }
 
-impl ValueWidget for ScaleRangeWidget{
-    type Value<'v> = modelrdfpreproc::ScaleRangeDescr;
-    fn set_value<'v>(&mut self, value: Self::Value<'v>) {
-        self.axes_widget.set_value(value.axes);
-        self.percentiles_widget.set_value(value.percentiles);
-        self.epsilon_widget.set_value(value.eps);
-        self.reference_tensor.set_value(value.reference_tensor);
-    }
-}
-
 impl StatefulWidget for ScaleRangeWidget{
     type Value<'p> = Result<modelrdfpreproc::ScaleRangeDescr>;
 
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.vertical(|ui|{
             ui.horizontal(|ui|{
-                ui.strong("Axes: ");
+                findable_label(ui, "Axes: ");
                 self.axes_widget.draw_and_parse(ui, id.with("axes_widget".as_ptr()));
             });
             ui.horizontal(|ui|{
-                ui.strong("Percentiles: ");
+                findable_label(ui, "Percentiles: ");
                 self.percentiles_widget.draw_and_parse(ui, id.with("percentiles_widget".as_ptr()));
             });
             ui.horizontal(|ui|{
-                ui.strong("Epsilon: ");
+                findable_label(ui, "Epsilon: ");
                 self.epsilon_widget.draw_and_parse(ui, id.with("epsilong_widget".as_ptr()));
             });
             ui.horizontal(|ui|{
-                ui.strong("Reference Tensor: ");
+                findable_label(ui, "Reference Tensor: ");
                 self.reference_tensor.draw_and_parse(ui, id.with("reference_tensor".as_ptr()));
             });
         });
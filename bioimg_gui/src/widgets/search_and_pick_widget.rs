@@ -2,12 +2,20 @@ use std::{borrow::Borrow, fmt::Display};
 
 use egui::popup::PopupCloseBehavior::CloseOnClickOutside;
 
+use bioimg_spec::rdf;
+use bioimg_spec::rdf::model as modelrdf;
+
+use super::util::PickListNavigation;
 use super::{Restore, StatefulWidget, ValueWidget};
 
 pub struct SearchableEntry<T>{
     lowercase_display: String,
     display: String,
     value: T,
+    /// Header under which this entry is listed, if the entries are grouped
+    group: Option<String>,
+    /// Whether this entry is left out of the list, e.g. because it doesn't apply in the current context
+    hidden: bool,
 }
 
 pub struct SearchAndPickWidget<T, const SHOW_SEARCH: bool = true> {
@@ -49,6 +57,8 @@ impl<T: Display, const SHOW_SEARCH: bool> SearchAndPickWidget<T, SHOW_SEARCH>{
                     lowercase_display: e.to_string().to_lowercase(),
                     display: e.to_string(),
                     value: e,
+                    group: None,
+                    hidden: false,
                 })
                 .collect(),
         }
@@ -68,10 +78,72 @@ impl<T: Display, const SHOW_SEARCH: bool> SearchAndPickWidget<T, SHOW_SEARCH>{
                     lowercase_display: e.to_string().to_lowercase(),
                     display: e.to_string(),
                     value: e.clone(),
+                    group: None,
+                    hidden: false,
                 })
                 .collect(),
         }
     }
+
+    /// Leaves the entries for which `is_hidden` is true out of the list. The current value is kept
+    /// even if it is hidden
+    pub fn set_hidden(&mut self, is_hidden: impl Fn(&T) -> bool){
+        for entry in self.entries.iter_mut(){
+            entry.hidden = is_hidden(&entry.value);
+        }
+    }
+
+    /// Shows the entries as `display_of` renders them, also matching searches against that text
+    pub fn displayed_as(mut self, display_of: impl Fn(&T) -> String) -> Self{
+        for entry in self.entries.iter_mut(){
+            entry.display = display_of(&entry.value);
+            entry.lowercase_display = entry.display.to_lowercase();
+        }
+        self
+    }
+
+    /// Lists the entries under headers given by `group_of`. Entries of the same group are kept
+    /// together, with groups ordered by where their first entry was
+    pub fn grouped_by<G: Display>(mut self, group_of: impl Fn(&T) -> G) -> Self{
+        let mut group_order: Vec<String> = vec![];
+        for entry in self.entries.iter_mut(){
+            let group = group_of(&entry.value).to_string();
+            if !group_order.contains(&group){
+                group_order.push(group.clone());
+            }
+            entry.group = Some(group);
+        }
+        self.entries.sort_by_key(|entry| group_order.iter().position(|group| Some(group) == entry.group.as_ref()));
+        self
+    }
+}
+
+impl SearchAndPickWidget<rdf::LicenseId>{
+    /// A picker for licenses that shows their full names, listing the ones recommended for zoo models first
+    pub fn licenses(value: rdf::LicenseId) -> Self{
+        Self::from_enum(value)
+            .displayed_as(|license| match license.name(){
+                Some(name) => format!("{}  ({name})", license.spdx_id()),
+                None => license.spdx_id(),
+            })
+            .grouped_by(|license| match rdf::LicenseId::ZOO_RECOMMENDED.contains(license){
+                true => "Recommended for zoo models",
+                false => "All SPDX licenses",
+            })
+    }
+}
+
+impl SearchAndPickWidget<modelrdf::DataType>{
+    /// A picker for data types, grouped by kind so that e.g. all unsigned integers are listed together
+    pub fn data_types(value: modelrdf::DataType) -> Self{
+        use modelrdf::DataType;
+        Self::from_enum(value).grouped_by(|dtype| match dtype{
+            DataType::Float32 | DataType::Float64 => "Floating point",
+            DataType::Uint8 | DataType::Uint16 | DataType::Uint32 | DataType::Uint64 => "Unsigned integer",
+            DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => "Signed integer",
+            DataType::Bool => "Boolean",
+        })
+    }
 }
 
 
@@ -117,6 +189,10 @@ where
         };
         egui::popup::popup_above_or_below_widget(ui, popup_id, &button_response, above_or_below, CloseOnClickOutside, |ui| {
             ui.set_min_width(200.0); // if you want to control the size
+            let mut navigation = PickListNavigation::read_keys(ui, popup_id);
+            if navigation.cancelled{
+                ui.memory_mut(|mem| mem.close_popup());
+            }
             ui.vertical(|ui|{
                 let header_height = if SHOW_SEARCH{
                     let header_rect = ui.vertical(|ui|{
@@ -124,6 +200,9 @@ where
                             ui.label("🔎 ");
                             let search_resp = ui.text_edit_singleline(&mut self.search);
                             search_resp.request_focus();
+                            if search_resp.changed(){
+                                navigation.reset();
+                            }
                         });
                         ui.add_space(10.0);
                     }).response.rect;
@@ -136,28 +215,28 @@ where
                 let scroll_area = egui::ScrollArea::vertical()
                     .scroll_bar_visibility(egui::scroll_area::ScrollBarVisibility::AlwaysVisible)
                     .max_height(vert_space_above_button.max(vert_space_under_button) - header_height);
-                scroll_area.show(ui, |ui| {
-                    let mut value_on_enter: T = self.value.clone();
-                    let num_visible_entries = self.entries
+                let num_visible_entries = scroll_area.show(ui, |ui| {
+                    let mut current_group: Option<&str> = None;
+                    self.entries
                         .iter()
-                        .filter(|entry| entry.lowercase_display.contains(&lower_search))
-                        .inspect(|entry| {
-                            value_on_enter = entry.value.clone();
-                            if ui.button(&entry.display).clicked() {
+                        .filter(|entry| !entry.hidden && entry.lowercase_display.contains(&lower_search))
+                        .enumerate()
+                        .inspect(|(idx, entry)| {
+                            if let Some(group) = entry.group.as_deref() {
+                                if current_group != Some(group){
+                                    ui.label(egui::RichText::new(group).small().weak());
+                                    current_group = Some(group);
+                                }
+                            }
+                            if navigation.entry_button(ui, *idx, &entry.display).clicked() || navigation.is_confirmed(*idx) {
                                 self.value = entry.value.clone();
                                 ui.memory_mut(|mem| mem.toggle_popup(popup_id));
                                 self.search.clear();
                             }
                         })
-                        .count();
-
-                        if num_visible_entries == 1 && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
-                            self.value = value_on_enter;
-                            ui.memory_mut(|mem| mem.toggle_popup(popup_id));
-                            self.search.clear();
-                        }
-                });
-                
+                        .count()
+                }).inner;
+                navigation.store(ui, num_visible_entries);
             });        
         });
     }
@@ -0,0 +1,219 @@
+//! One-click fixes for axis descriptions that don't match the shape of the test tensor, derived
+//! from the shape of the npy file rather than just reporting the mismatching numbers.
+
+use std::num::NonZeroUsize;
+
+use bioimg_spec::rdf::model::{AnyAxisSize, ParameterizedAxisSize};
+
+use super::error_display::show_warning;
+use super::util::FrameCache;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ShapeFix{
+    /// Appends axes for the trailing test tensor dimensions that have no axis description
+    AddAxes{extents: Vec<usize>},
+    /// Removes the axis descriptions past the last test tensor dimension
+    RemoveAxes{count: usize},
+    SetFixedSize{axis_idx: usize, extent: usize},
+    /// Reorders the axes so that `order[i]` is the index of the axis that should end up at position `i`
+    Reorder{order: Vec<usize>},
+}
+
+impl ShapeFix{
+    fn label(&self) -> String{
+        match self{
+            Self::AddAxes{extents} if extents.len() == 1 => "➕ Add missing axis".to_owned(),
+            Self::AddAxes{extents} => format!("➕ Add {} missing axes", extents.len()),
+            Self::RemoveAxes{count: 1} => "🗑 Remove extra axis".to_owned(),
+            Self::RemoveAxes{count} => format!("🗑 Remove {count} extra axes"),
+            Self::SetFixedSize{axis_idx, extent} => format!("Set fixed size of axis #{} to {extent}", axis_idx + 1),
+            Self::Reorder{..} => "⇅ Reorder axes to match".to_owned(),
+        }
+    }
+}
+
+/// The part of an axis size that matters for matching it against a test tensor. Axes whose size is
+/// unknown (e.g. batch axes, references to other tensors or axes that don't parse yet) match anything
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum AxisSizeKey{
+    Unknown,
+    Fixed(NonZeroUsize),
+    Parameterized{min: NonZeroUsize, step: NonZeroUsize},
+}
+
+impl From<&Option<AnyAxisSize>> for AxisSizeKey{
+    fn from(size: &Option<AnyAxisSize>) -> Self {
+        match size{
+            None | Some(AnyAxisSize::Reference(_)) => Self::Unknown,
+            Some(AnyAxisSize::Fixed(fixed)) => Self::Fixed(*fixed),
+            Some(AnyAxisSize::Parameterized(ParameterizedAxisSize{min, step})) => Self::Parameterized{min: *min, step: *step},
+        }
+    }
+}
+
+/// Whether an axis of size `size` can have `extent`
+fn is_compatible(size: &AxisSizeKey, extent: usize) -> bool{
+    match *size{
+        AxisSizeKey::Unknown => true,
+        AxisSizeKey::Fixed(fixed) => fixed.get() == extent,
+        AxisSizeKey::Parameterized{min, step} => ParameterizedAxisSize{min, step}.accepts(extent),
+    }
+}
+
+/// Finds an order of the axes in which every one is compatible with the test tensor dimension at its position
+fn find_matching_order(shape: &[usize], axis_sizes: &[AxisSizeKey]) -> Option<Vec<usize>>{
+    fn search(shape: &[usize], axis_sizes: &[AxisSizeKey], order: &mut Vec<usize>) -> bool{
+        let Some(extent) = shape.get(order.len()) else {
+            return true
+        };
+        for axis_idx in 0..axis_sizes.len(){
+            if order.contains(&axis_idx) || !is_compatible(&axis_sizes[axis_idx], *extent){
+                continue
+            }
+            order.push(axis_idx);
+            if search(shape, axis_sizes, order){
+                return true
+            }
+            order.pop();
+        }
+        false
+    }
+    let mut order = Vec::with_capacity(shape.len());
+    search(shape, axis_sizes, &mut order).then_some(order)
+}
+
+/// The fixes that would make the axes with sizes `axis_sizes` match a test tensor of shape `shape`
+fn shape_fixes(shape: &[usize], axis_sizes: &[AxisSizeKey]) -> Vec<ShapeFix>{
+    if shape.len() > axis_sizes.len(){
+        return vec![ShapeFix::AddAxes{extents: shape[axis_sizes.len()..].to_vec()}]
+    }
+    if shape.len() < axis_sizes.len(){
+        return vec![ShapeFix::RemoveAxes{count: axis_sizes.len() - shape.len()}]
+    }
+    let mismatched: Vec<usize> = shape.iter().zip(axis_sizes)
+        .enumerate()
+        .filter(|(_, (extent, size))| !is_compatible(size, **extent))
+        .map(|(axis_idx, _)| axis_idx)
+        .collect();
+    if mismatched.is_empty(){
+        return vec![]
+    }
+    let mut fixes = vec![];
+    if let Some(order) = find_matching_order(shape, axis_sizes){
+        fixes.push(ShapeFix::Reorder{order});
+    }
+    for axis_idx in mismatched{
+        if let AxisSizeKey::Fixed(_) = axis_sizes[axis_idx]{
+            fixes.push(ShapeFix::SetFixedSize{axis_idx, extent: shape[axis_idx]});
+        }
+    }
+    fixes
+}
+
+#[derive(Default)]
+struct ShapeFixFinder;
+
+impl egui::cache::ComputerMut<(&[usize], &[AxisSizeKey]), Vec<ShapeFix>> for ShapeFixFinder{
+    fn compute(&mut self, (shape, axis_sizes): (&[usize], &[AxisSizeKey])) -> Vec<ShapeFix> {
+        shape_fixes(shape, axis_sizes)
+    }
+}
+
+/// Fixes for a shape, recomputed when the shape or the axis sizes change. Searching for a matching
+/// axis order is exponential in the worst case
+type ShapeFixCache = FrameCache<Vec<ShapeFix>, ShapeFixFinder>;
+
+/// Reports that the axes don't match `shape` and returns the fix the user picked, if any
+pub fn draw_shape_fixes(ui: &mut egui::Ui, shape: &[usize], axis_sizes: &[Option<AnyAxisSize>]) -> Option<ShapeFix>{
+    let axis_sizes: Vec<AxisSizeKey> = axis_sizes.iter().map(AxisSizeKey::from).collect();
+    let fixes = ui.memory_mut(|mem| mem.caches.cache::<ShapeFixCache>().get((shape, axis_sizes.as_slice())));
+    if fixes.is_empty(){
+        return None
+    }
+    let mut picked = None;
+    ui.vertical(|ui|{
+        show_warning(ui, format!("The axes don't match the test tensor shape {shape:?}"));
+        ui.horizontal_wrapped(|ui|{
+            for fix in fixes{
+                if ui.button(fix.label()).clicked(){
+                    picked = Some(fix);
+                }
+            }
+        });
+    });
+    picked
+}
+
+/// Applies `fix` to a list of axis widgets, creating new ones with `new_axis`
+pub fn apply_shape_fix<W>(
+    fix: ShapeFix,
+    axis_widgets: &mut Vec<W>,
+    new_axis: impl Fn(usize) -> W,
+    set_fixed_size: impl Fn(&mut W, usize),
+){
+    match fix{
+        ShapeFix::AddAxes{extents} => axis_widgets.extend(extents.into_iter().map(new_axis)),
+        ShapeFix::RemoveAxes{count} => axis_widgets.truncate(axis_widgets.len().saturating_sub(count)),
+        ShapeFix::SetFixedSize{axis_idx, extent} => {
+            if let Some(widget) = axis_widgets.get_mut(axis_idx){
+                set_fixed_size(widget, extent);
+            }
+        },
+        ShapeFix::Reorder{order} => {
+            let mut widgets: Vec<Option<W>> = axis_widgets.drain(..).map(Some).collect();
+            axis_widgets.extend(order.into_iter().filter_map(|axis_idx| widgets[axis_idx].take()));
+        },
+    }
+}
+
+#[test]
+fn test_find_matching_order(){
+    let fixed = |size: usize| AxisSizeKey::Fixed(NonZeroUsize::new(size).unwrap());
+    let parameterized = |min: usize, step: usize| AxisSizeKey::Parameterized{
+        min: NonZeroUsize::new(min).unwrap(), step: NonZeroUsize::new(step).unwrap()
+    };
+
+    assert_eq!(find_matching_order(&[3, 64, 64], &[fixed(3), fixed(64), fixed(64)]), Some(vec![0, 1, 2]));
+    // channels last instead of first
+    assert_eq!(find_matching_order(&[64, 128, 3], &[fixed(3), fixed(64), fixed(128)]), Some(vec![1, 2, 0]));
+    // the parameterized axis accepts 3 too, so the search has to backtrack to leave it for the 64
+    assert_eq!(
+        find_matching_order(&[3, 64], &[parameterized(1, 1), fixed(3)]),
+        Some(vec![1, 0]),
+    );
+    assert_eq!(
+        find_matching_order(&[1, 64, 3], &[fixed(3), parameterized(32, 16), AxisSizeKey::Unknown]),
+        Some(vec![2, 1, 0]),
+    );
+    assert_eq!(find_matching_order(&[64, 3], &[fixed(3), fixed(32)]), None);
+    assert_eq!(find_matching_order(&[], &[]), Some(vec![]));
+}
+
+#[test]
+fn test_shape_fixes(){
+    let fixed = |size: usize| AxisSizeKey::Fixed(NonZeroUsize::new(size).unwrap());
+
+    assert_eq!(shape_fixes(&[1, 64, 64], &[AxisSizeKey::Unknown, fixed(64), fixed(64)]), vec![]);
+    assert_eq!(shape_fixes(&[1, 64, 64], &[AxisSizeKey::Unknown]), vec![ShapeFix::AddAxes{extents: vec![64, 64]}]);
+    assert_eq!(shape_fixes(&[64], &[fixed(64), fixed(3)]), vec![ShapeFix::RemoveAxes{count: 1}]);
+    assert_eq!(
+        shape_fixes(&[64, 3], &[fixed(3), fixed(64)]),
+        vec![
+            ShapeFix::Reorder{order: vec![1, 0]},
+            ShapeFix::SetFixedSize{axis_idx: 0, extent: 64},
+            ShapeFix::SetFixedSize{axis_idx: 1, extent: 3},
+        ],
+    );
+    assert_eq!(shape_fixes(&[64, 5], &[fixed(64), fixed(3)]), vec![ShapeFix::SetFixedSize{axis_idx: 1, extent: 5}]);
+}
+
+#[test]
+fn test_apply_shape_fix(){
+    let mut axes = vec!["c", "x", "y"];
+    apply_shape_fix(ShapeFix::Reorder{order: vec![1, 2, 0]}, &mut axes, |_| "new", |_, _| ());
+    assert_eq!(axes, vec!["x", "y", "c"]);
+    apply_shape_fix(ShapeFix::RemoveAxes{count: 2}, &mut axes, |_| "new", |_, _| ());
+    assert_eq!(axes, vec!["x"]);
+    apply_shape_fix(ShapeFix::AddAxes{extents: vec![3, 4]}, &mut axes, |_| "new", |_, _| ());
+    assert_eq!(axes, vec!["x", "new", "new"]);
+}
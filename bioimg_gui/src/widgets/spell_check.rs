@@ -0,0 +1,235 @@
+//! Optional spell checking of prose, like the model documentation and description, using a hunspell
+//! dictionary found on the system. Misspelled words are underlined; if no dictionary can be found,
+//! nothing is checked.
+//!
+//! Markdown code (fenced blocks and inline spans), URLs and words that look like identifiers,
+//! acronyms or numbers are skipped, since those are expected to be missing from any dictionary.
+
+use std::ops::Range;
+use std::sync::OnceLock;
+
+use egui::text::{LayoutJob, LayoutSection};
+
+use super::util::FrameCache;
+
+/// Path to a hunspell `.dic` file (with its `.aff` file next to it) to use instead of the system's English one
+pub const DICTIONARY_ENV_VAR: &str = "BIOIMG_SPELLCHECK_DICT";
+
+/// Whether this build can check spelling at all. There are no system dictionaries to load in the browser
+pub const IS_SUPPORTED: bool = cfg!(all(feature="spell_check", not(target_arch="wasm32")));
+
+#[cfg(all(feature="spell_check", not(target_arch="wasm32")))]
+const DICTIONARY_DIRS: &[&str] = &["/usr/share/hunspell", "/usr/share/myspell", "/usr/share/myspell/dicts", "/Library/Spelling"];
+#[cfg(all(feature="spell_check", not(target_arch="wasm32")))]
+const DICTIONARY_NAMES: &[&str] = &["en_US", "en_GB", "en"];
+
+#[derive(thiserror::Error, Debug)]
+// never constructed in builds that can't check spelling
+#[cfg_attr(not(all(feature="spell_check", not(target_arch="wasm32"))), allow(dead_code))]
+pub enum SpellCheckError{
+    #[error("No English hunspell dictionary was found. Install one or point ${} to a .dic file", DICTIONARY_ENV_VAR)]
+    NoDictionary,
+    #[error("Could not read {}: {source}", .path.display())]
+    Io{path: std::path::PathBuf, source: std::io::Error},
+    #[cfg(all(feature="spell_check", not(target_arch="wasm32")))]
+    #[error("Could not parse the dictionary at {}: {reason}", .path.display())]
+    Parse{path: std::path::PathBuf, reason: spellbook::ParseDictionaryError},
+}
+
+static CHECKER: OnceLock<Result<SpellChecker, SpellCheckError>> = OnceLock::new();
+#[cfg(all(feature="spell_check", not(target_arch="wasm32")))]
+static LOADING: std::sync::Once = std::sync::Once::new();
+
+// never constructed in builds that can't check spelling
+#[cfg_attr(not(all(feature="spell_check", not(target_arch="wasm32"))), allow(dead_code))]
+pub struct SpellChecker{
+    #[cfg(all(feature="spell_check", not(target_arch="wasm32")))]
+    dictionary: spellbook::Dictionary,
+}
+
+impl SpellChecker{
+    #[cfg(all(feature="spell_check", not(target_arch="wasm32")))]
+    fn find_dictionary() -> Option<std::path::PathBuf>{
+        if let Some(path) = std::env::var_os(DICTIONARY_ENV_VAR){
+            return Some(path.into())
+        }
+        let home_dir = std::env::var_os("HOME").map(|home| std::path::PathBuf::from(home).join("Library/Spelling"));
+        DICTIONARY_DIRS.iter().map(std::path::PathBuf::from)
+            .chain(home_dir)
+            .flat_map(|dir| DICTIONARY_NAMES.iter().map(move |name| dir.join(format!("{name}.dic"))))
+            .find(|path| path.exists())
+    }
+
+    #[cfg(all(feature="spell_check", not(target_arch="wasm32")))]
+    fn load(dic_path: &std::path::Path) -> Result<Self, SpellCheckError>{
+        let read = |path: std::path::PathBuf| std::fs::read_to_string(&path)
+            .map_err(|source| SpellCheckError::Io{path, source});
+        let dic = read(dic_path.to_owned())?;
+        let aff = read(dic_path.with_extension("aff"))?;
+        let dictionary = spellbook::Dictionary::new(&aff, &dic)
+            .map_err(|reason| SpellCheckError::Parse{path: dic_path.to_owned(), reason})?;
+        Ok(Self{dictionary})
+    }
+
+    /// The spell checker, if a dictionary could be loaded. The first call starts loading it in the
+    /// background and repaints `ctx` once that's done
+    pub fn get(ctx: &egui::Context) -> Option<&'static SpellChecker>{
+        #[cfg(all(feature="spell_check", not(target_arch="wasm32")))]
+        LOADING.call_once(|| {
+            let ctx = ctx.clone();
+            std::thread::spawn(move || {
+                _ = CHECKER.set(
+                    Self::find_dictionary().ok_or(SpellCheckError::NoDictionary).and_then(|path| Self::load(&path))
+                );
+                ctx.request_repaint();
+            });
+        });
+        #[cfg(not(all(feature="spell_check", not(target_arch="wasm32"))))]
+        let _ = ctx;
+        CHECKER.get()?.as_ref().ok()
+    }
+
+    /// Why no dictionary could be loaded, if loading it was attempted and failed
+    pub fn load_error() -> Option<&'static SpellCheckError>{
+        CHECKER.get()?.as_ref().err()
+    }
+
+    fn is_known_word(&self, word: &str) -> bool{
+        #[cfg(all(feature="spell_check", not(target_arch="wasm32")))]
+        return self.dictionary.check(word);
+        #[cfg(not(all(feature="spell_check", not(target_arch="wasm32"))))]
+        return { let _ = word; true };
+    }
+
+    fn check_segment(&self, line: &str, segment: Range<usize>, misspelled: &mut Vec<Range<usize>>){
+        let text = &line[segment.clone()];
+        let is_word_char = |c: char| c.is_alphabetic() || c == '\'';
+        let mut chars = text.char_indices().peekable();
+        while let Some((start, c)) = chars.next(){
+            if !is_word_char(c){
+                continue
+            }
+            let mut end = start + c.len_utf8();
+            while let Some((idx, c)) = chars.peek().copied().filter(|(_, c)| is_word_char(*c)){
+                end = idx + c.len_utf8();
+                chars.next();
+            }
+            let before = text[..start].chars().next_back();
+            let after = text[end..].chars().next();
+            // e.g. `unet2d` or `model_name`
+            let is_part_of_identifier = [before, after].into_iter().flatten().any(|c| c.is_ascii_digit() || c == '_');
+            let word_start = start + (text[start..end].len() - text[start..end].trim_start_matches('\'').len());
+            let word = text[start..end].trim_matches('\'');
+            // acronyms and CamelCase names
+            let has_inner_uppercase = word.chars().skip(1).any(char::is_uppercase);
+            if is_part_of_identifier || has_inner_uppercase || word.chars().count() < 2 || self.is_known_word(word){
+                continue
+            }
+            let offset = segment.start + word_start;
+            misspelled.push(offset..offset + word.len());
+        }
+    }
+
+    /// Byte ranges of the misspelled words in the markdown `text`
+    pub fn misspelled_ranges(&self, text: &str) -> Vec<Range<usize>>{
+        let mut misspelled = vec![];
+        let mut in_fenced_block = false;
+        let mut line_offset = 0;
+        for line in text.split_inclusive('\n'){
+            let line_start = line_offset;
+            line_offset += line.len();
+            if line.trim_start().starts_with("```"){
+                in_fenced_block = !in_fenced_block;
+                continue
+            }
+            if in_fenced_block{
+                continue
+            }
+            let mut line_misspelled = vec![];
+            let mut in_code_span = false;
+            let mut token_start = None;
+            for (idx, c) in line.char_indices().chain(std::iter::once((line.len(), ' '))){
+                match (c.is_whitespace(), token_start){
+                    (false, None) => token_start = Some(idx),
+                    (true, Some(start)) => {
+                        token_start = None;
+                        let token = &line[start..idx];
+                        let mut segment_start = start;
+                        for segment in token.split('`'){
+                            let segment_end = segment_start + segment.len();
+                            let is_url = segment.contains("://") || segment.contains('@') || segment.starts_with("www.");
+                            if !in_code_span && !is_url{
+                                self.check_segment(line, segment_start..segment_end, &mut line_misspelled);
+                            }
+                            if segment_end < start + token.len(){
+                                in_code_span = !in_code_span;
+                            }
+                            segment_start = segment_end + 1;
+                        }
+                    },
+                    _ => (),
+                }
+            }
+            misspelled.extend(line_misspelled.into_iter().map(|range| range.start + line_start..range.end + line_start));
+        }
+        misspelled
+    }
+}
+
+#[derive(Default)]
+struct MisspellingFinder;
+
+impl egui::cache::ComputerMut<&str, Vec<Range<usize>>> for MisspellingFinder{
+    fn compute(&mut self, text: &str) -> Vec<Range<usize>> {
+        match CHECKER.get(){
+            Some(Some(checker)) => checker.misspelled_ranges(text),
+            _ => vec![],
+        }
+    }
+}
+
+type MisspellingCache = FrameCache<Vec<Range<usize>>, MisspellingFinder>;
+
+/// Underlines `ranges` of the text laid out by `job`, splitting its sections where needed
+fn underline_ranges(job: &mut LayoutJob, ranges: &[Range<usize>], stroke: egui::Stroke){
+    let mut sections = Vec::with_capacity(job.sections.len() + ranges.len() * 2);
+    for section in job.sections.drain(..){
+        let section_range = section.byte_range.clone();
+        let mut pieces: Vec<(Range<usize>, bool)> = vec![];
+        let mut pos = section_range.start;
+        for range in ranges.iter().filter(|range| range.start < section_range.end && range.end > section_range.start){
+            let start = range.start.max(pos);
+            let end = range.end.min(section_range.end);
+            if start > pos{
+                pieces.push((pos..start, false));
+            }
+            pieces.push((start..end, true));
+            pos = end;
+        }
+        if pieces.is_empty(){
+            sections.push(section);
+            continue
+        }
+        if pos < section_range.end{
+            pieces.push((pos..section_range.end, false));
+        }
+        for (piece_idx, (byte_range, underlined)) in pieces.into_iter().enumerate(){
+            let mut format = section.format.clone();
+            if underlined{
+                format.underline = stroke;
+            }
+            let leading_space = if piece_idx == 0 { section.leading_space } else { 0.0 };
+            sections.push(LayoutSection{leading_space, byte_range, format});
+        }
+    }
+    job.sections = sections;
+}
+
+/// Underlines the misspelled words in the text of `job`, if a dictionary is available
+pub fn underline_misspellings(ui: &egui::Ui, job: &mut LayoutJob){
+    if !IS_SUPPORTED || SpellChecker::get(ui.ctx()).is_none(){
+        return
+    }
+    let misspelled = ui.memory_mut(|mem| mem.caches.cache::<MisspellingCache>().get(job.text.as_str()));
+    underline_ranges(job, &misspelled, egui::Stroke::new(1.0, ui.visuals().error_fg_color));
+}
@@ -1,14 +1,76 @@
+use std::borrow::Cow;
+use std::ops::RangeInclusive;
 use std::{error::Error, fmt::Display, str::FromStr};
 use std::fmt::Write;
 
+use bioimg_spec::rdf::model::preprocessing::PreprocessingEpsilon;
+use bioimg_spec::rdf::model::preprocessing::zero_mean_unit_variance::ZmuvStdDeviation;
+
 use crate::result::{GuiError, Result};
 use super::Restore;
 use super::{error_display::show_if_error, StatefulWidget, ValueWidget};
 
+/// Accepts a comma as the decimal separator (e.g. `0,5`), as typed in many locales, as long as it
+/// can't be a thousands separator, i.e. it is the only separator and isn't followed by exactly three
+/// digits, like in `1,000`
+pub fn normalize_decimal_separator(raw: &str) -> Cow<'_, str>{
+    let raw = raw.trim();
+    let Some((_, fraction)) = raw.split_once(',') else {
+        return Cow::Borrowed(raw)
+    };
+    let num_fraction_digits = fraction.chars().take_while(|c| c.is_ascii_digit()).count();
+    if fraction.contains(',') || raw.contains('.') || num_fraction_digits == 3{
+        return Cow::Borrowed(raw)
+    }
+    Cow::Owned(raw.replace(',', "."))
+}
+
+/// Parses a decimal number, accepting comma decimals via [normalize_decimal_separator]
+pub fn parse_decimal<T>(raw: &str) -> Result<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Error,
+{
+    T::from_str(&normalize_decimal_separator(raw)).map_err(|err|{
+        if raw.contains(','){
+            GuiError::new(format!("{err}. Use '.' as the decimal separator, e.g. 0.5"))
+        } else {
+            GuiError::from(err)
+        }
+    })
+}
+
+/// How a [StagingFloat] of a given type is dragged around
+pub trait DragBounds{
+    /// The values the number can be dragged to
+    const RANGE: RangeInclusive<f64> = f64::NEG_INFINITY..=f64::INFINITY;
+    /// How much the number changes per pixel dragged
+    const SPEED: f64 = 0.01;
+    /// What is shown while there is no valid number yet
+    const INITIAL: f64 = 0.0;
+}
+
+impl DragBounds for f32{}
+
+impl DragBounds for PreprocessingEpsilon{
+    const RANGE: RangeInclusive<f64> = 1e-12..=0.1;
+    const SPEED: f64 = 1e-7;
+    const INITIAL: f64 = 1e-6;
+}
+
+impl DragBounds for ZmuvStdDeviation{
+    const RANGE: RangeInclusive<f64> = 1e-6..=f64::INFINITY;
+    const INITIAL: f64 = 1.0;
+}
+
+/// A decimal number, edited via a [egui::DragValue]. The number is kept as text so that it round trips
+/// exactly through saved drafts
 pub struct StagingFloat<T>{
     pub min_size: f32,
     pub raw: String,
     pub parsed: Result<T>,
+    pub range: RangeInclusive<f64>,
+    pub speed: f64,
 }
 
 impl<T> Restore for StagingFloat<T>{
@@ -21,29 +83,41 @@ impl<T> Restore for StagingFloat<T>{
     }
 }
 
-impl<T> Default for StagingFloat<T>{
+impl<T: DragBounds> Default for StagingFloat<T>{
     fn default() -> Self {
         Self{
             min_size: 50.0,
             raw: String::new(),
-            parsed: Err(GuiError::new("empty".to_owned()))
+            parsed: Err(GuiError::new("empty".to_owned())),
+            range: T::RANGE,
+            speed: T::SPEED,
         }
     }
 }
 
 impl<T> StatefulWidget for StagingFloat<T>
 where
-    T: FromStr + Copy,
+    T: FromStr + Copy + DragBounds,
     <T as FromStr>::Err: Error,
 {
     type Value<'p> = Result<T> where T: 'p;
 
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, _id: egui::Id) {
         ui.horizontal(|ui|{
-            ui.add(
-                egui::TextEdit::singleline(&mut self.raw).desired_width(50.0)
-            );
-            self.parsed = T::from_str(&self.raw).map_err(|err| GuiError::from(err));
+            // text that isn't a number in range is kept (and reported) until the user drags or types a new value
+            let mut value = parse_decimal::<f64>(&self.raw).unwrap_or(T::INITIAL);
+            let response = ui.add(
+                egui::DragValue::new(&mut value)
+                    .range(self.range.clone())
+                    .clamp_existing_to_range(false)
+                    .speed(self.speed)
+                    .custom_parser(|text| parse_decimal::<f64>(text).ok())
+                    .max_decimals(12)
+            ).on_hover_text("Drag to change or click to type a value");
+            if response.changed(){
+                self.raw = value.to_string();
+            }
+            self.parsed = self.parse();
             show_if_error(ui, &self.parsed);
         });
     }
@@ -65,12 +139,60 @@ impl<T: Display> ValueWidget for StagingFloat<T>{
 
 impl<T> StagingFloat<T>
 where
-    T: FromStr,
+    T: FromStr + DragBounds,
     <T as FromStr>::Err: Error
 {
     pub fn new_with_raw(value: f32) -> Self{
         let raw = value.to_string();
-        let parsed = T::from_str(&raw).map_err(|err| GuiError::from(err));
+        let parsed = parse_decimal(&raw);
         Self{raw, parsed, ..Default::default()}
     }
 }
+
+impl<T> StagingFloat<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Error
+{
+    /// Parses [Self::raw], rejecting numbers outside of [Self::range]
+    fn parse(&self) -> Result<T>{
+        let value: f64 = parse_decimal(&self.raw)
+            .map_err(|err| GuiError::new(format!("'{}' is not a number: {err}", self.raw.trim())))?;
+        let (start, end) = (*self.range.start(), *self.range.end());
+        if !self.range.contains(&value){
+            return Err(GuiError::new(match (start.is_finite(), end.is_finite()){
+                (true, true) => format!("Must be between {start} and {end}"),
+                (true, false) => format!("Must be at least {start}"),
+                (false, true) => format!("Must be at most {end}"),
+                (false, false) => "Must be a finite number".to_owned(),
+            }))
+        }
+        parse_decimal(&self.raw)
+    }
+}
+
+impl<T> StagingFloat<T>{
+    /// Restricts the values the number can be dragged to, e.g. `0.0..=100.0` for percentiles
+    pub fn with_range(mut self, range: RangeInclusive<f64>) -> Self{
+        self.range = range;
+        self
+    }
+}
+
+#[test]
+fn test_normalize_decimal_separator(){
+    assert_eq!(normalize_decimal_separator("0,5"), "0.5");
+    assert_eq!(normalize_decimal_separator(" -1,25 "), "-1.25");
+    assert_eq!(normalize_decimal_separator("1,0005"), "1.0005");
+    assert_eq!(normalize_decimal_separator("1,5e3"), "1.5e3");
+    assert_eq!(normalize_decimal_separator("0.5"), "0.5");
+
+    // could be thousands separators, so they are left for parsing to reject
+    assert_eq!(normalize_decimal_separator("1,000"), "1,000");
+    assert_eq!(normalize_decimal_separator("1,000,000"), "1,000,000");
+    assert_eq!(normalize_decimal_separator("1,000.5"), "1,000.5");
+    assert_eq!(normalize_decimal_separator("1,5,2"), "1,5,2");
+
+    assert_eq!(parse_decimal::<f32>("0,5").unwrap(), 0.5);
+    assert!(parse_decimal::<f32>("1,000").is_err());
+}
@@ -2,12 +2,14 @@ use std::{borrow::Borrow, error::Error, fmt::Display, str::FromStr};
 
 use crate::result::{GuiError, Result};
 
-use super::{error_display::show_error, Restore, StatefulWidget, ValueWidget};
+use super::{error_display::show_error, spell_check::underline_misspellings, Restore, StatefulWidget, ValueWidget};
 
 #[derive(Clone, Debug)]
 pub enum InputLines {
     SingleLine,
     Multiline,
+    /// Multiline text in natural language, with misspelled words underlined
+    Prose,
 }
 
 /// A text field that tries to parse the user's input into a T
@@ -118,6 +120,19 @@ where
                 InputLines::Multiline => {
                     ui.text_edit_multiline(&mut self.raw).rect
                 }
+                InputLines::Prose => {
+                    let mut layouter = |ui: &egui::Ui, text: &str, wrap_width: f32| {
+                        let mut layout_job = egui::text::LayoutJob::simple(
+                            text.to_owned(),
+                            egui::TextStyle::Body.resolve(ui.style()),
+                            ui.visuals().text_color(),
+                            wrap_width,
+                        );
+                        underline_misspellings(ui, &mut layout_job);
+                        ui.fonts(|f| f.layout_job(layout_job))
+                    };
+                    ui.add(egui::TextEdit::multiline(&mut self.raw).layouter(&mut layouter)).rect
+                }
             };
             self.update();
             if let Err(e) = &mut self.parsed{
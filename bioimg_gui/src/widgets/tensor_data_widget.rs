@@ -0,0 +1,268 @@
+use bioimg_spec::rdf::model as modelrdf;
+use bioimg_spec::rdf::model::tensor_data_descr::{
+    IntervalOrRatioDataDescr, NominalOrOrdinalDataDescr, TVs, TensorDataDescr, TensorDataUnit
+};
+use bioimg_spec::rdf::non_empty_list::NonEmptyList;
+use bioimg_spec::rdf::si_units::SiUnit;
+
+use crate::project_data::TensorDataModeSavedData;
+use crate::result::{GuiError, Result};
+use super::collapsible_widget::SummarizableWidget;
+use super::data_range_widget::DataRangeWidget;
+use super::error_display::show_error;
+use super::labels::SpecLinkExt;
+use super::search_and_pick_widget::SearchAndPickWidget;
+use super::dtype_support::DtypeSupport;
+use super::staging_float::StagingFloat;
+use super::staging_opt::StagingOpt;
+use super::staging_string::StagingString;
+use super::staging_vec::{ItemWidgetConf, StagingVec};
+use super::{Restore, StatefulWidget, ValueWidget};
+use super::field_finder_widget::findable_label;
+
+#[derive(Clone, strum::VariantArray, Default, strum::Display)]
+pub enum TensorDataMode{
+    #[default]
+    #[strum(to_string="Interval or Ratio")]
+    IntervalOrRatio,
+    #[strum(to_string="Nominal or Ordinal")]
+    NominalOrOrdinal,
+}
+
+impl Restore for TensorDataMode{
+    type SavedData = TensorDataModeSavedData;
+    fn dump(&self) -> Self::SavedData {
+        match self{
+            Self::IntervalOrRatio => Self::SavedData::IntervalOrRatio,
+            Self::NominalOrOrdinal => Self::SavedData::NominalOrOrdinal,
+        }
+    }
+    fn restore(&mut self, saved_data: Self::SavedData) {
+        *self = match saved_data{
+            Self::SavedData::IntervalOrRatio => Self::IntervalOrRatio,
+            Self::SavedData::NominalOrOrdinal => Self::NominalOrOrdinal,
+        }
+    }
+}
+
+pub struct NominalValueItemConf;
+impl ItemWidgetConf for NominalValueItemConf{
+    const ITEM_NAME: &'static str = "Value";
+    const INLINE_ITEM: bool = true;
+    const MIN_NUM_ITEMS: usize = 1;
+}
+
+/// Interprets the raw nominal values as the narrowest kind of value that all of them can be parsed into
+fn parse_nominal_values(raw_values: &[&str]) -> Result<TVs>{
+    fn parse_all<T: std::str::FromStr>(raw_values: &[&str]) -> Option<Vec<T>>{
+        raw_values.iter().map(|raw| raw.trim().parse()).collect::<Result<Vec<_>, _>>().ok()
+    }
+    fn non_empty<T>(values: Vec<T>) -> Result<NonEmptyList<T>>{
+        NonEmptyList::try_from(values).map_err(|_| GuiError::new("At least one value is required"))
+    }
+    let values = if let Some(ints) = parse_all(raw_values){
+        TVs::Ints(non_empty(ints)?)
+    } else if let Some(bools) = parse_all(raw_values){
+        TVs::Bools(non_empty(bools)?)
+    } else if let Some(floats) = parse_all(raw_values){
+        TVs::Floats(non_empty(floats)?)
+    } else {
+        let strings: Vec<String> = raw_values.iter().map(|raw| raw.to_string()).collect();
+        TVs::Strings(non_empty(strings)?)
+    };
+    Ok(values)
+}
+
+fn nominal_values_to_strings(values: &TVs) -> Vec<String>{
+    match values{
+        TVs::Ints(values) => values.iter().map(|v| v.to_string()).collect(),
+        TVs::Floats(values) => values.iter().map(|v| v.to_string()).collect(),
+        TVs::Bools(values) => values.iter().map(|v| v.to_string()).collect(),
+        TVs::Strings(values) => values.iter().cloned().collect(),
+    }
+}
+
+/// Edits the description of the values in a tensor: either values on a continuous scale
+/// (e.g. intensities) or a set of categorical values (e.g. class labels)
+#[derive(Restore)]
+#[restore(saved_data=crate::project_data::TensorDataWidgetSavedData)]
+pub struct TensorDataWidget{
+    pub mode_widget: SearchAndPickWidget<TensorDataMode>,
+    pub data_type_widget: SearchAndPickWidget<modelrdf::DataType>,
+    pub range_widget: DataRangeWidget,
+    pub unit_widget: StagingString<String>,
+    pub scale_widget: StagingFloat<f32>,
+    pub offset_widget: StagingOpt<StagingFloat<f32>, false>,
+    pub values_widget: StagingVec<StagingString<String>, NominalValueItemConf>,
+}
+
+impl Default for TensorDataWidget{
+    fn default() -> Self {
+        let mut scale_widget = StagingFloat::default();
+        scale_widget.set_value(1.0);
+        Self{
+            mode_widget: Default::default(),
+            data_type_widget: SearchAndPickWidget::data_types(Default::default()),
+            range_widget: Default::default(),
+            unit_widget: Default::default(),
+            scale_widget,
+            offset_widget: Default::default(),
+            values_widget: Default::default(),
+        }
+    }
+}
+
+impl ItemWidgetConf for TensorDataWidget{
+    const ITEM_NAME: &'static str = "Data Description";
+    const MIN_NUM_ITEMS: usize = 1;
+}
+
+impl TensorDataWidget{
+    /// An empty unit means "arbitrary unit"
+    fn parse_unit(&self) -> Result<Option<SiUnit>>{
+        let raw = self.unit_widget.raw.trim();
+        if raw.is_empty() || raw == "arbitrary unit"{
+            return Ok(None)
+        }
+        raw.parse::<SiUnit>().map(Some).map_err(|err| GuiError::new(err.to_string()))
+    }
+
+    fn set_unit(&mut self, unit: Option<TensorDataUnit>){
+        self.unit_widget.set_value(match unit{
+            Some(TensorDataUnit::Si(unit)) => unit.to_string(),
+            _ => String::new(),
+        });
+    }
+}
+
+impl ValueWidget for TensorDataWidget{
+    type Value<'v> = TensorDataDescr;
+
+    fn set_value<'v>(&mut self, value: Self::Value<'v>) {
+        self.data_type_widget.set_value(value.data_type());
+        match value{
+            TensorDataDescr::IntervalOrRatio(descr) => {
+                self.mode_widget.set_value(TensorDataMode::IntervalOrRatio);
+                self.range_widget.set_value(descr.range);
+                self.set_unit(Some(descr.unit));
+                self.scale_widget.set_value(descr.scale);
+                self.offset_widget.set_value(descr.offset);
+            },
+            TensorDataDescr::NominalOrOrdinal(descr) => {
+                self.mode_widget.set_value(TensorDataMode::NominalOrOrdinal);
+                self.values_widget.staging = nominal_values_to_strings(&descr.values).into_iter()
+                    .map(StagingString::new_with_raw)
+                    .collect();
+                self.set_unit(descr.unit);
+            },
+        }
+    }
+}
+
+impl SummarizableWidget for TensorDataWidget{
+    fn summarize(&mut self, ui: &mut egui::Ui, _id: egui::Id) {
+        match self.state(){
+            Ok(TensorDataDescr::IntervalOrRatio(descr)) => {
+                ui.label(format!("{} in {} ({})", descr.data_type, descr.range, descr.unit));
+            },
+            Ok(TensorDataDescr::NominalOrOrdinal(descr)) => {
+                ui.label(format!("{}: {}", descr.data_type, nominal_values_to_strings(&descr.values).join(", ")));
+            },
+            Err(err) => {
+                show_error(ui, err.to_string());
+            }
+        }
+    }
+}
+
+impl StatefulWidget for TensorDataWidget{
+    type Value<'p> = Result<TensorDataDescr>;
+
+    fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
+        ui.vertical(|ui|{
+            ui.horizontal(|ui|{
+                findable_label(ui, "Kind: ").on_hover_text(
+                    "Whether tensor values lie on a continuous scale (e.g. intensities) or \
+                    are one of a set of categories (e.g. class labels)"
+                );
+                self.mode_widget.draw_and_parse(ui, id.with("mode".as_ptr()));
+            });
+            ui.horizontal(|ui|{
+                findable_label(ui, "Data Type: ").on_hover_text_with_spec(ui,
+                    "IntervalOrRatioDataDescr.type",
+                    "The data type of the values in this tensor"
+                );
+                DtypeSupport::draw_picker(&mut self.data_type_widget, ui, id.with("data_type".as_ptr()));
+            });
+            match self.mode_widget.value{
+                TensorDataMode::IntervalOrRatio => {
+                    ui.horizontal(|ui|{
+                        findable_label(ui, "Range: ").on_hover_text_with_spec(ui,
+                            "IntervalOrRatioDataDescr.range",
+                            "The interval of values the data in this tensor can take"
+                        );
+                        self.range_widget.draw_and_parse(ui, id.with("range".as_ptr()));
+                    });
+                    ui.horizontal(|ui|{
+                        findable_label(ui, "Scale: ").on_hover_text_with_spec(ui,
+                            "IntervalOrRatioDataDescr.scale",
+                            "Scale for data on an interval (or ratio) scale"
+                        );
+                        self.scale_widget.draw_and_parse(ui, id.with("scale".as_ptr()));
+                    });
+                    ui.horizontal(|ui|{
+                        findable_label(ui, "Offset: ").on_hover_text_with_spec(ui,
+                            "IntervalOrRatioDataDescr.offset",
+                            "Offset for data on a ratio scale"
+                        );
+                        self.offset_widget.draw_and_parse(ui, id.with("offset".as_ptr()));
+                    });
+                },
+                TensorDataMode::NominalOrOrdinal => {
+                    ui.horizontal(|ui|{
+                        findable_label(ui, "Values: ").on_hover_text_with_spec(ui,
+                            "NominalOrOrdinalDataDescr.values",
+                            "A fixed set of nominal or an ascending sequence of ordinal values. \
+                            Text values are interpreted as labels for the tensor values 0, ..., N"
+                        );
+                        self.values_widget.draw_and_parse(ui, id.with("values".as_ptr()));
+                    });
+                },
+            }
+            ui.horizontal(|ui|{
+                findable_label(ui, "Unit: ").on_hover_text_with_spec(ui,
+                    "IntervalOrRatioDataDescr.unit",
+                    "An SI unit like 'm' or 'nm^2'. Leave empty for an arbitrary unit"
+                );
+                self.unit_widget.draw_and_parse(ui, id.with("unit".as_ptr()));
+                if let Err(err) = self.parse_unit(){
+                    show_error(ui, err.to_string());
+                }
+            });
+        });
+    }
+
+    fn state<'p>(&'p self) -> Self::Value<'p> {
+        let data_type = self.data_type_widget.value;
+        let unit = self.parse_unit()?;
+        Ok(match self.mode_widget.value{
+            TensorDataMode::IntervalOrRatio => TensorDataDescr::IntervalOrRatio(IntervalOrRatioDataDescr{
+                data_type,
+                range: self.range_widget.state().clone()?,
+                unit: unit.map(TensorDataUnit::Si).unwrap_or_default(),
+                scale: self.scale_widget.state()?,
+                offset: self.offset_widget.state().transpose()?,
+            }),
+            TensorDataMode::NominalOrOrdinal => {
+                let raw_values: Vec<&str> = self.values_widget.staging.iter()
+                    .map(|widget| widget.raw.as_str())
+                    .collect();
+                TensorDataDescr::NominalOrOrdinal(NominalOrOrdinalDataDescr{
+                    values: parse_nominal_values(&raw_values)?,
+                    data_type,
+                    unit: unit.map(TensorDataUnit::Si),
+                })
+            },
+        })
+    }
+}
@@ -1,27 +1,86 @@
 use std::io::Cursor;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use bioimg_runtime::{npy_array::ArcNpyArray, NpyArray};
+#[cfg(not(target_arch="wasm32"))]
+use bioimg_runtime::{download_cache::DownloadCache, TransferProgress};
+#[cfg(not(target_arch="wasm32"))]
+use bioimg_spec::rdf::HttpUrl;
+use bioimg_spec::rdf::model::AxisId;
 
 use crate::{project_data::TestTensorWidgetSavedData, result::GuiError};
 
+use super::url_widget::StagingUrl;
 use super::util::{GenSync, Generation};
 use super::{error_display::show_error, Restore, StatefulWidget, ValueWidget};
 
+/// How much of a remote test tensor has been downloaded so far
+#[cfg(not(target_arch="wasm32"))]
+#[derive(Default)]
+pub struct DownloadProgress{
+    pub downloaded_bytes: AtomicU64,
+    /// Zero if the server did not report the size of the file
+    pub total_bytes: AtomicU64,
+}
+
+/// Identifies one load of a test tensor. Every load gets a new id, even when it reads the same file again,
+/// so values derived from the tensor can be cached by id instead of by its contents
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TestTensorId(u64);
+
+impl TestTensorId{
+    fn next() -> Self{
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
 
 #[derive(Default)]
 pub enum TestTensorWidgetState{
     #[default]
     Empty,
-    Loaded{path: Option<PathBuf>, data: ArcNpyArray},
+    #[cfg(not(target_arch="wasm32"))]
+    Downloading{url: Arc<HttpUrl>, progress: Arc<DownloadProgress>},
+    Loaded{id: TestTensorId, path: Option<PathBuf>, data: ArcNpyArray},
     Error{message: String}
 }
 
-/// A widget for selecting a "test tensor" for a Model input
+impl TestTensorWidgetState{
+    fn loaded(path: Option<PathBuf>, data: ArcNpyArray) -> Self{
+        Self::Loaded{id: TestTensorId::next(), path, data}
+    }
+}
+
+/// A loaded test tensor along with the ids of its axes, for widgets that can derive values from the test data
+#[derive(Clone, Copy)]
+pub struct TestTensorContext<'a>{
+    pub data: &'a NpyArray,
+    /// The id of each axis of `data`, or `None` if that axis' widget doesn't parse yet
+    pub axis_ids: &'a [Option<AxisId>],
+}
+
+impl TestTensorContext<'_>{
+    pub fn axis_index(&self, axis_id: &AxisId) -> Option<usize>{
+        self.axis_ids.iter().position(|id| id.as_ref() == Some(axis_id))
+    }
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum TestTensorSourceMode{
+    #[default]
+    LocalFile,
+    Url,
+}
+
+/// A widget for selecting a "test tensor" for a Model input, either from a local file or
+/// from a URL (e.g. for reference data living on a shared server)
 #[derive(Default)]
 pub struct TestTensorWidget{
     state: GenSync<TestTensorWidgetState>,
+    source_mode: TestTensorSourceMode,
+    url_widget: StagingUrl,
 }
 
 impl ValueWidget for TestTensorWidget{
@@ -29,7 +88,7 @@ impl ValueWidget for TestTensorWidget{
 
     fn set_value<'v>(&mut self, data: Self::Value<'v>) {
         self.state = GenSync::new(
-            TestTensorWidgetState::Loaded { path: None, data}
+            TestTensorWidgetState::loaded(None, data)
         );
     }
 }
@@ -40,8 +99,13 @@ impl Restore for TestTensorWidget{
     fn dump(&self) -> Self::SavedData {
         let guard = self.state.lock();
         match &guard.1 {
-            TestTensorWidgetState::Empty  | &TestTensorWidgetState::Error { .. }=> TestTensorWidgetSavedData::Empty,
-            TestTensorWidgetState::Loaded { path, data } => TestTensorWidgetSavedData::Loaded {
+            TestTensorWidgetState::Empty | TestTensorWidgetState::Error { .. } => match self.source_mode{
+                TestTensorSourceMode::LocalFile => TestTensorWidgetSavedData::Empty,
+                TestTensorSourceMode::Url => TestTensorWidgetSavedData::Url { url: self.url_widget.raw.clone() },
+            },
+            #[cfg(not(target_arch="wasm32"))]
+            TestTensorWidgetState::Downloading { url, .. } => TestTensorWidgetSavedData::Url { url: url.to_string() },
+            TestTensorWidgetState::Loaded { path, data, .. } => TestTensorWidgetSavedData::Loaded {
                 path: path.clone(),
                 data: {
                     let mut v = vec![];
@@ -53,11 +117,17 @@ impl Restore for TestTensorWidget{
     }
 
     fn restore(&mut self, saved_data: Self::SavedData) {
+        self.source_mode = TestTensorSourceMode::LocalFile;
         self.state = GenSync::new(match saved_data{
             TestTensorWidgetSavedData::Empty => TestTensorWidgetState::Empty,
+            TestTensorWidgetSavedData::Url { url } => {
+                self.source_mode = TestTensorSourceMode::Url;
+                self.url_widget = StagingUrl::new_with_raw(url);
+                TestTensorWidgetState::Empty
+            },
             TestTensorWidgetSavedData::Loaded { path, data } => {
                 let state = match NpyArray::try_load(Cursor::new(data)){
-                    Ok(data) => TestTensorWidgetState::Loaded { path, data: Arc::new(data) },
+                    Ok(data) => TestTensorWidgetState::loaded(path, Arc::new(data)),
                     Err(_e) => TestTensorWidgetState::Error { message: "Could not deserialize npy data".to_owned() }
                 };
                 state
@@ -80,6 +150,50 @@ impl TestTensorWidget{
     pub fn state(&self) -> std::sync::MutexGuard<'_, (Generation, TestTensorWidgetState)>{
         self.state.lock()
     }
+
+    /// The test tensor, if one has been loaded successfully
+    pub fn loaded_data(&self) -> Option<ArcNpyArray>{
+        self.loaded_tensor().map(|(_, data)| data)
+    }
+
+    /// The test tensor along with the id of its load, if one has been loaded successfully
+    pub fn loaded_tensor(&self) -> Option<(TestTensorId, ArcNpyArray)>{
+        match &self.state().1{
+            TestTensorWidgetState::Loaded{id, data, ..} => Some((*id, Arc::clone(data))),
+            _ => None,
+        }
+    }
+
+    #[cfg(not(target_arch="wasm32"))]
+    fn download(url: &HttpUrl, progress: &DownloadProgress) -> Result<(PathBuf, ArcNpyArray), GuiError>{
+        let mut on_progress = |transfer: TransferProgress|{
+            progress.downloaded_bytes.store(transfer.transferred_bytes, Ordering::Relaxed);
+            progress.total_bytes.store(transfer.total_bytes.unwrap_or(0), Ordering::Relaxed);
+        };
+        let cached_path = DownloadCache::global().fetch(url, Some(&mut on_progress))?;
+        let data = NpyArray::try_load(std::fs::File::open(&cached_path)?)?;
+        // cached files are named after their hashes, so report the name in the URL instead
+        let file_name = url.path().rsplit('/').next()
+            .filter(|name| !name.is_empty())
+            .unwrap_or("test_tensor.npy");
+        Ok((PathBuf::from(file_name), Arc::new(data)))
+    }
+
+    #[cfg(not(target_arch="wasm32"))]
+    fn launch_download(
+        request_generation: Generation,
+        url: Arc<HttpUrl>,
+        progress: Arc<DownloadProgress>,
+        state: GenSync<TestTensorWidgetState>,
+    ){
+        std::thread::spawn(move ||{
+            let new_state = match Self::download(&url, &progress){
+                Ok((path, data)) => TestTensorWidgetState::loaded(Some(path), data),
+                Err(e) => TestTensorWidgetState::Error { message: format!("Could not download {url}: {e}") },
+            };
+            state.lock_then_maybe_set(request_generation, new_state);
+        });
+    }
     pub fn launch_test_tensor_picker(
         request_generation: Generation,
         state: GenSync<TestTensorWidgetState>,
@@ -101,7 +215,7 @@ impl TestTensorWidget{
                 (result, Some(file_handle.path().to_owned()))
             };
             let new_state = match result {
-                Ok(data) => TestTensorWidgetState::Loaded { path, data },
+                Ok(data) => TestTensorWidgetState::loaded(path, data),
                 Err(e) => TestTensorWidgetState::Error { message: e.to_string() }
             };
             state.lock_then_maybe_set(request_generation, new_state);
@@ -117,16 +231,64 @@ impl TestTensorWidget{
 impl StatefulWidget for TestTensorWidget{
     type Value<'p> = Result<ArcNpyArray, GuiError>;
 
-    fn draw_and_parse(&mut self, ui: &mut egui::Ui, _id: egui::Id) {
+    fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.horizontal(|ui|{
-            let guard = self.state.lock();
-            if ui.button("Open...").clicked(){
-                Self::launch_test_tensor_picker(guard.0, self.state.clone());
+            ui.selectable_value(&mut self.source_mode, TestTensorSourceMode::LocalFile, "File")
+                .on_hover_text("Load the test tensor from a .npy file in this computer");
+            ui.selectable_value(&mut self.source_mode, TestTensorSourceMode::Url, "URL")
+                .on_hover_text("Download the test tensor from a .npy file in some server");
+
+            #[allow(unused_mut)]
+            let mut guard = self.state.lock();
+            match self.source_mode{
+                TestTensorSourceMode::LocalFile => {
+                    if ui.button("Open...").clicked(){
+                        Self::launch_test_tensor_picker(guard.0, self.state.clone());
+                    }
+                },
+                #[cfg(target_arch="wasm32")]
+                TestTensorSourceMode::Url => {
+                    let _ = id;
+                    ui.weak("Downloading test tensors is not available in the web version yet");
+                },
+                #[cfg(not(target_arch="wasm32"))]
+                TestTensorSourceMode::Url => {
+                    self.url_widget.draw_and_parse(ui, id.with("url".as_ptr()));
+                    let is_downloading = matches!(guard.1, TestTensorWidgetState::Downloading { .. });
+                    let url = self.url_widget.state();
+                    let download_clicked = ui.add_enabled_ui(url.is_ok() && !is_downloading, |ui|{
+                        ui.button("Download")
+                    }).inner.clicked();
+                    if let (true, Ok(url)) = (download_clicked, url){
+                        let progress = Arc::new(DownloadProgress::default());
+                        guard.0 = guard.0.incremented();
+                        guard.1 = TestTensorWidgetState::Downloading { url: url.clone(), progress: progress.clone() };
+                        Self::launch_download(guard.0, url, progress, self.state.clone());
+                    }
+                },
             }
-            
+
             match &guard.1{
                 TestTensorWidgetState::Empty => (),
-                TestTensorWidgetState::Loaded { path, data } => {
+                #[cfg(not(target_arch="wasm32"))]
+                TestTensorWidgetState::Downloading { progress, .. } => {
+                    let downloaded = progress.downloaded_bytes.load(Ordering::Relaxed);
+                    let total = progress.total_bytes.load(Ordering::Relaxed);
+                    let downloaded_mb = downloaded as f32 / 1_000_000.0;
+                    if total == 0 {
+                        ui.spinner();
+                        ui.weak(format!("Downloaded {downloaded_mb:.1} MB"));
+                    } else {
+                        let total_mb = total as f32 / 1_000_000.0;
+                        ui.add(
+                            egui::ProgressBar::new(downloaded as f32 / total as f32)
+                                .desired_width(150.0)
+                                .text(format!("{downloaded_mb:.1} / {total_mb:.1} MB"))
+                        );
+                    }
+                    ui.ctx().request_repaint();
+                },
+                TestTensorWidgetState::Loaded { path, data, .. } => {
                     let shape = data.shape();
                     let last_item_idx = shape.len() - 1;
                     let shape_str = shape
@@ -157,6 +319,8 @@ impl StatefulWidget for TestTensorWidget{
         let guard = self.state.lock();
         match &guard.1{
             TestTensorWidgetState::Empty => Err(GuiError::new("Empty")),
+            #[cfg(not(target_arch="wasm32"))]
+            TestTensorWidgetState::Downloading { .. } => Err(GuiError::new("Test tensor is still downloading")),
             TestTensorWidgetState::Error { message } => Err(GuiError::new(message)),
             TestTensorWidgetState::Loaded { data, .. } => Ok(Arc::clone(data)),
         }
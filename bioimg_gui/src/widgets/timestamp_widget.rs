@@ -0,0 +1,59 @@
+use iso8601_timestamp::Timestamp;
+
+use crate::project_data::TimestampWidgetSavedData;
+use super::{Restore, StatefulWidget, ValueWidget};
+
+/// Keeps the timestamp of an imported model so that re-exporting it doesn't rewrite its creation date.
+/// Models that were not imported (or whose author opted in to refreshing it) are stamped with the export time.
+#[derive(Default)]
+pub struct TimestampWidget{
+    pub imported: Option<Timestamp>,
+    pub refresh_on_export: bool,
+}
+
+impl Restore for TimestampWidget{
+    type SavedData = TimestampWidgetSavedData;
+    fn dump(&self) -> Self::SavedData {
+        TimestampWidgetSavedData{
+            imported: self.imported.map(|ts| ts.to_string()),
+            refresh_on_export: self.refresh_on_export,
+        }
+    }
+    fn restore(&mut self, saved_data: Self::SavedData) {
+        self.imported = saved_data.imported.and_then(|raw| Timestamp::parse(&raw));
+        self.refresh_on_export = saved_data.refresh_on_export;
+    }
+}
+
+impl ValueWidget for TimestampWidget{
+    type Value<'v> = Timestamp;
+
+    fn set_value<'v>(&mut self, value: Self::Value<'v>) {
+        self.imported = Some(value);
+        self.refresh_on_export = false;
+    }
+}
+
+impl StatefulWidget for TimestampWidget{
+    type Value<'p> = Timestamp;
+
+    fn draw_and_parse(&mut self, ui: &mut egui::Ui, _id: egui::Id) {
+        let Some(imported) = self.imported else {
+            ui.weak("Will be set to the time of export");
+            return
+        };
+        ui.horizontal(|ui|{
+            ui.label(imported.to_string());
+            ui.checkbox(&mut self.refresh_on_export, "Update on export").on_hover_text(
+                "Replace this timestamp with the time of export, e.g. when publishing a new version of this model"
+            );
+        });
+    }
+
+    fn state<'p>(&'p self) -> Self::Value<'p> {
+        match self.imported{
+            Some(imported) if !self.refresh_on_export => imported,
+            _ => Timestamp::now_utc(),
+        }
+    }
+}
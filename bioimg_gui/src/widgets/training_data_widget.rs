@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use bioimg_spec::rdf;
+use bioimg_spec::rdf::model::dataset_descr::{DatasetDescr, DatasetDescrEnum, LinkedDatasetDescr};
+
+use crate::project_data::TrainingDataModeSavedData;
+use crate::result::{GuiError, Result};
+use super::collapsible_widget::SummarizableWidget;
+use super::error_display::show_error;
+use super::search_and_pick_widget::SearchAndPickWidget;
+use super::staging_opt::StagingOpt;
+use super::staging_string::StagingString;
+use super::url_widget::StagingUrl;
+#[cfg(not(target_arch="wasm32"))]
+use super::zoo_widget::ZooResourcePickerWidget;
+use super::{Restore, StatefulWidget, ValueWidget};
+use super::field_finder_widget::findable_label;
+
+#[derive(Clone, strum::VariantArray, Default, strum::Display)]
+pub enum TrainingDataMode{
+    #[default]
+    #[strum(to_string="Zoo Dataset")]
+    Linked,
+    #[strum(to_string="External Source")]
+    Described,
+}
+
+impl Restore for TrainingDataMode{
+    type SavedData = TrainingDataModeSavedData;
+    fn dump(&self) -> Self::SavedData {
+        match self{
+            Self::Linked => Self::SavedData::Linked,
+            Self::Described => Self::SavedData::Described,
+        }
+    }
+    fn restore(&mut self, saved_data: Self::SavedData) {
+        *self = match saved_data{
+            Self::SavedData::Linked => Self::Linked,
+            Self::SavedData::Described => Self::Described,
+        }
+    }
+}
+
+#[derive(Default, Restore)]
+#[restore(saved_data=crate::project_data::TrainingDataWidgetSavedData)]
+pub struct TrainingDataWidget{
+    pub mode_widget: SearchAndPickWidget<TrainingDataMode>,
+    pub dataset_id_widget: StagingString<rdf::ResourceId>,
+    pub source_widget: StagingOpt<StagingUrl, false>,
+    #[cfg(not(target_arch="wasm32"))]
+    #[restore(default)]
+    pub dataset_picker_widget: ZooResourcePickerWidget,
+}
+
+impl ValueWidget for TrainingDataWidget{
+    type Value<'v> = DatasetDescrEnum;
+
+    fn set_value<'v>(&mut self, value: Self::Value<'v>) {
+        match value{
+            DatasetDescrEnum::LinkedDatasetDescr(linked) => {
+                self.mode_widget.value = TrainingDataMode::Linked;
+                self.dataset_id_widget.set_value(linked.id);
+            },
+            DatasetDescrEnum::DatasetDescr(descr) => {
+                self.mode_widget.value = TrainingDataMode::Described;
+                self.source_widget.set_value(descr.source.map(Arc::new));
+            },
+        }
+    }
+}
+
+impl SummarizableWidget for TrainingDataWidget{
+    fn summarize(&mut self, ui: &mut egui::Ui, _id: egui::Id) {
+        match self.state(){
+            Ok(DatasetDescrEnum::LinkedDatasetDescr(linked)) => {
+                ui.label(linked.id.to_string());
+            },
+            Ok(DatasetDescrEnum::DatasetDescr(descr)) => match descr.source{
+                Some(source) => { ui.label(source.to_string()); },
+                None => { ui.weak("Unknown source"); },
+            },
+            Err(err) => {
+                show_error(ui, err.to_string());
+            }
+        }
+    }
+}
+
+impl StatefulWidget for TrainingDataWidget{
+    type Value<'p> = Result<DatasetDescrEnum>;
+
+    fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
+        ui.vertical(|ui|{
+            ui.horizontal(|ui|{
+                findable_label(ui, "Kind: ").on_hover_text(
+                    "Whether the training data is a dataset in the zoo or some other, external dataset"
+                );
+                self.mode_widget.draw_and_parse(ui, id.with("mode".as_ptr()));
+            });
+            match self.mode_widget.value{
+                TrainingDataMode::Linked => {
+                    ui.horizontal(|ui|{
+                        findable_label(ui, "Dataset Id: ").on_hover_text("The id of a dataset in the zoo collection");
+                        self.dataset_id_widget.draw_and_parse(ui, id.with("dataset_id".as_ptr()));
+                        #[cfg(not(target_arch="wasm32"))]
+                        if let Some(picked_id) = self.dataset_picker_widget.draw(ui, "dataset"){
+                            self.dataset_id_widget = StagingString::new_with_raw(picked_id);
+                        }
+                    });
+                },
+                TrainingDataMode::Described => {
+                    ui.horizontal(|ui|{
+                        findable_label(ui, "Source: ").on_hover_text("A URL where the dataset can be found");
+                        self.source_widget.draw_and_parse(ui, id.with("source".as_ptr()));
+                    });
+                },
+            }
+        });
+    }
+
+    fn state<'p>(&'p self) -> Self::Value<'p> {
+        Ok(match self.mode_widget.value{
+            TrainingDataMode::Linked => DatasetDescrEnum::LinkedDatasetDescr(LinkedDatasetDescr{
+                id: self.dataset_id_widget.state()
+                    .map_err(|_| GuiError::new("Invalid dataset id"))?
+                    .clone(),
+            }),
+            TrainingDataMode::Described => DatasetDescrEnum::DatasetDescr(DatasetDescr::new(
+                self.source_widget.state().transpose()?.map(|source| source.as_ref().clone())
+            )),
+        })
+    }
+}
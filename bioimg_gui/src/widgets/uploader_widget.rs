@@ -0,0 +1,40 @@
+use bioimg_spec::rdf::{self, bounded_string::BoundedString};
+
+use super::{staging_opt::StagingOpt, staging_string::StagingString, Restore, StatefulWidget, ValueWidget};
+use super::field_finder_widget::findable_label;
+use crate::result::{GuiError, Result};
+
+#[derive(Default, Restore, ValueWidget)]
+#[restore(saved_data=crate::project_data::UploaderWidgetSavedData)]
+#[value_widget(value=rdf::Uploader)]
+pub struct UploaderWidget {
+    pub email_widget: StagingString<BoundedString<1, 1024>>, //FIXME: validate email
+    pub name_widget: StagingOpt<StagingString<BoundedString<1, 1024>>, false>,
+}
+
+impl StatefulWidget for UploaderWidget {
+    type Value<'p> = Result<rdf::Uploader>;
+
+    fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
+        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+            findable_label(ui, "Email: ").on_hover_text("The email of the person uploading this model to the zoo");
+            self.email_widget.draw_and_parse(ui, id.with("email"));
+            ui.end_row();
+
+            findable_label(ui, "Name: ").on_hover_text("The name of the person uploading this model to the zoo");
+            self.name_widget.draw_and_parse(ui, id.with("name"));
+            ui.end_row();
+        });
+    }
+
+    fn state<'p>(&'p self) -> Self::Value<'p> {
+        Ok(rdf::Uploader {
+            email: self.email_widget.state()
+                .map_err(|_| GuiError::new("Invalid email"))?
+                .clone(),
+            name: self.name_widget.state().transpose()
+                .map_err(|_| GuiError::new("Invalid name"))?
+                .cloned(),
+        })
+    }
+}
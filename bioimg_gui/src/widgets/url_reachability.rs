@@ -0,0 +1,112 @@
+//! Background checks of whether URLs can be fetched (i.e. respond with an HTTP status below 400).
+//!
+//! Checks are opt-in via [set_enabled], since they send requests to whatever the user types. Outcomes
+//! are cached for all URL widgets, so that redrawing the form, or having the same URL in several
+//! fields, doesn't send a new request every frame.
+
+use std::collections::HashMap;
+use std::sync::{mpsc, Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use bioimg_spec::rdf::HttpUrl;
+
+/// How long a successful check is trusted before the URL is checked again
+const REACHABLE_TTL: Duration = Duration::from_secs(30 * 60);
+/// Failures are retried sooner, since they are often transient
+const UNREACHABLE_TTL: Duration = Duration::from_secs(2 * 60);
+/// How many checks can be running at the same time. Further ones wait in a queue
+const NUM_WORKERS: usize = 4;
+
+fn enabled_id() -> egui::Id{
+    egui::Id::new("url reachability checks enabled")
+}
+
+/// Whether URL widgets drawn in `ctx` should check their URLs. Off unless the user enabled it
+pub fn is_enabled(ctx: &egui::Context) -> bool{
+    ctx.data(|data| data.get_temp(enabled_id())).unwrap_or(false)
+}
+
+pub fn set_enabled(ctx: &egui::Context, enabled: bool){
+    ctx.data_mut(|data| data.insert_temp(enabled_id(), enabled));
+}
+
+#[derive(Clone, Debug)]
+pub enum Reachability{
+    Checking,
+    Reachable,
+    Unreachable(String),
+}
+
+pub struct UrlCheck{
+    pub reachability: Reachability,
+    finished_at: Option<Instant>,
+}
+
+impl UrlCheck{
+    /// Whether the outcome of this check is too old to be trusted
+    pub fn is_stale(&self) -> bool{
+        let ttl = match self.reachability{
+            Reachability::Checking => return false,
+            Reachability::Reachable => REACHABLE_TTL,
+            Reachability::Unreachable(_) => UNREACHABLE_TTL,
+        };
+        self.finished_at.is_some_and(|finished_at| finished_at.elapsed() > ttl)
+    }
+}
+
+pub type SharedUrlCheck = Arc<Mutex<UrlCheck>>;
+
+static CHECKS: LazyLock<Mutex<HashMap<String, SharedUrlCheck>>> = LazyLock::new(Default::default);
+
+struct PendingCheck{
+    url: String,
+    check: SharedUrlCheck,
+    ctx: egui::Context,
+}
+
+impl PendingCheck{
+    fn run(self){
+        let reachability = match crate::request::check_url_reachable(&self.url){
+            Ok(_) => Reachability::Reachable,
+            Err(ureq::Error::Status(status, _)) => Reachability::Unreachable(format!("HTTP status {status}")),
+            Err(err) => Reachability::Unreachable(err.to_string()),
+        };
+        *self.check.lock().unwrap() = UrlCheck{reachability, finished_at: Some(Instant::now())};
+        self.ctx.request_repaint();
+    }
+}
+
+/// Queue of the checks to run, consumed by [NUM_WORKERS] threads that are started on first use
+static QUEUE: LazyLock<mpsc::Sender<PendingCheck>> = LazyLock::new(|| {
+    let (sender, receiver) = mpsc::channel::<PendingCheck>();
+    let receiver = Arc::new(Mutex::new(receiver));
+    for _ in 0..NUM_WORKERS{
+        let receiver = Arc::clone(&receiver);
+        std::thread::spawn(move || loop {
+            let Ok(pending) = receiver.lock().unwrap().recv() else {
+                return
+            };
+            pending.run();
+        });
+    }
+    sender
+});
+
+/// Returns the ongoing or cached check of `url`, starting a new one in the background if there is none,
+/// if the cached outcome is stale or if `force` is set. `ctx` is repainted once the check finishes
+pub fn check_url(url: &HttpUrl, ctx: &egui::Context, force: bool) -> SharedUrlCheck{
+    let mut checks = CHECKS.lock().unwrap();
+    if let Some(existing) = checks.get(url.as_str()){
+        let existing_check = existing.lock().unwrap();
+        let is_checking = matches!(existing_check.reachability, Reachability::Checking);
+        if is_checking || (!force && !existing_check.is_stale()){
+            return Arc::clone(existing)
+        }
+    }
+    let check = Arc::new(Mutex::new(UrlCheck{reachability: Reachability::Checking, finished_at: None}));
+    checks.insert(url.as_str().to_owned(), Arc::clone(&check));
+
+    let pending = PendingCheck{url: url.as_str().to_owned(), check: Arc::clone(&check), ctx: ctx.clone()};
+    QUEUE.send(pending).expect("URL check workers never stop");
+    check
+}
@@ -3,11 +3,48 @@ use std::sync::Arc;
 use bioimg_spec::rdf::HttpUrl;
 
 use super::{error_display::show_if_error, Restore, StatefulWidget, ValueWidget};
+#[cfg(not(target_arch="wasm32"))]
+use super::url_reachability::{self, check_url, Reachability, SharedUrlCheck};
 use crate::result::{GuiError, Result};
 
+/// How long the URL must stay unchanged before it is checked for reachability, so that
+/// half-typed URLs don't get requested
+#[cfg(not(target_arch="wasm32"))]
+const REACHABILITY_CHECK_DELAY_SECS: f64 = 1.0;
+
+/// A corrected version of `raw` for common mistakes when typing URLs, like a missing or misspelled scheme
+/// or surrounding whitespace. `None` if there is nothing obvious to fix
+pub fn suggest_url_fix(raw: &str) -> Option<String>{
+    let trimmed = raw.trim();
+    let suggestion = if trimmed.starts_with("https://") || trimmed.starts_with("http://"){
+        trimmed.to_owned()
+    } else if let Some(rest) = ["https//", "https:/", "htps://", "htttps://", "https:", "http//", "http:/", "htp://", "http:"]
+        .iter()
+        .find_map(|typo| trimmed.strip_prefix(typo))
+    {
+        format!("https://{}", rest.trim_start_matches('/'))
+    } else {
+        let host = trimmed.split('/').next().unwrap_or_default();
+        if !host.contains('.') || trimmed.contains(char::is_whitespace){
+            return None
+        }
+        format!("https://{trimmed}")
+    };
+    if suggestion == raw || HttpUrl::try_from(suggestion.clone()).is_err(){
+        return None
+    }
+    Some(suggestion)
+}
+
 pub struct StagingUrl {
     pub raw: String,
     parsed: Result<Arc<HttpUrl>>,
+    /// The last URL that was checked for reachability, and the result of that check
+    #[cfg(not(target_arch="wasm32"))]
+    reachability: Option<(Arc<HttpUrl>, SharedUrlCheck)>,
+    /// `egui` time of the last edit of `raw`
+    #[cfg(not(target_arch="wasm32"))]
+    last_edit_time: f64,
 }
 
 impl ValueWidget for StagingUrl{
@@ -37,6 +74,53 @@ impl StagingUrl{
             parsed: HttpUrl::try_from(raw.clone())
                 .map(|val| Arc::new(val))
                 .map_err(|err| GuiError::new(err.to_string())),
+            #[cfg(not(target_arch="wasm32"))]
+            reachability: None,
+            #[cfg(not(target_arch="wasm32"))]
+            last_edit_time: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Draws whether the URL can actually be fetched, checking it in the background once it stops
+    /// being edited
+    #[cfg(not(target_arch="wasm32"))]
+    fn draw_reachability(&mut self, ui: &mut egui::Ui){
+        let Ok(url) = &self.parsed else {
+            return
+        };
+        if !url_reachability::is_enabled(ui.ctx()){
+            return
+        }
+        let idle_secs = ui.input(|i| i.time) - self.last_edit_time;
+        if idle_secs < REACHABILITY_CHECK_DELAY_SECS{
+            ui.ctx().request_repaint_after(std::time::Duration::from_secs_f64(REACHABILITY_CHECK_DELAY_SECS - idle_secs));
+            return
+        }
+        let needs_check = match &self.reachability{
+            Some((checked_url, check)) => checked_url != url || check.lock().unwrap().is_stale(),
+            None => true,
+        };
+        if needs_check{
+            self.reachability = Some((Arc::clone(url), check_url(url, ui.ctx(), false)));
+        }
+        let Some((_, check)) = &self.reachability else {
+            return
+        };
+        let reachability = check.lock().unwrap().reachability.clone();
+        match reachability{
+            Reachability::Checking => {
+                ui.spinner().on_hover_text("Checking if this URL is reachable");
+            },
+            Reachability::Reachable => {
+                ui.label(egui::RichText::new("✔").color(egui::Color32::GREEN)).on_hover_text("This URL is reachable");
+            },
+            Reachability::Unreachable(reason) => {
+                ui.label(egui::RichText::new("⚠").color(egui::Color32::YELLOW))
+                    .on_hover_text(format!("Could not reach this URL: {reason}"));
+                if ui.small_button("⟳").on_hover_text("Check again").clicked(){
+                    self.reachability = Some((Arc::clone(url), check_url(url, ui.ctx(), true)));
+                }
+            },
         }
     }
 }
@@ -65,8 +149,25 @@ impl StatefulWidget for StagingUrl {
 
     fn draw_and_parse<'p>(&'p mut self, ui: &mut egui::Ui, _id: egui::Id) {
         self.update(); //FIXME: move update out of the draw method
-        ui.add(egui::TextEdit::singleline(&mut self.raw).min_size(egui::Vec2 { x: 200.0, y: 10.0 }));
+        let response = ui.add(egui::TextEdit::singleline(&mut self.raw).min_size(egui::Vec2 { x: 200.0, y: 10.0 }));
+        #[cfg(not(target_arch="wasm32"))]
+        {
+            if response.changed(){
+                self.last_edit_time = ui.input(|i| i.time);
+            }
+            self.draw_reachability(ui);
+        }
+        #[cfg(target_arch="wasm32")]
+        let _ = response;
         show_if_error(ui, &self.parsed);
+        if self.parsed.is_err(){
+            if let Some(fixed) = suggest_url_fix(&self.raw){
+                if ui.button(format!("Use {fixed}")).on_hover_text("Did you mean this URL?").clicked(){
+                    self.raw = fixed;
+                    self.update();
+                }
+            }
+        }
     }
 
     fn state<'p>(&'p self) -> Self::Value<'p> {
@@ -1,9 +1,11 @@
-use std::{marker::PhantomData, ops::Sub, sync::{mpsc::{Receiver, Sender}, Arc, Mutex, MutexGuard}};
+use std::{collections::BTreeSet, marker::PhantomData, ops::Sub, sync::{mpsc::{Receiver, Sender}, Arc, Mutex, MutexGuard}};
 
 use egui::InnerResponse;
 use egui::PopupCloseBehavior::CloseOnClickOutside;
 
 use crate::widgets::error_display::show_error;
+use crate::widgets::expansion_state::ExpansionState;
+use crate::widgets::field_finder_widget::{draw_hidden_section, findable_section, is_open_requested};
 
 use super::ValueWidget;
 
@@ -117,6 +119,11 @@ impl<T> Default for TaskChannel<T>{
 
 
 
+/// Keeps the results of expensive computations that widgets would otherwise redo on every frame, keyed
+/// by a hash of their inputs. A result is only recomputed when its key changes, and is dropped once it
+/// goes unused for a frame, e.g. when the widget that shows it is closed.
+pub type FrameCache<Value, Computer> = egui::cache::FrameCache<Value, Computer>;
+
 #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Default)]
 pub struct Generation(pub i64);
 
@@ -260,6 +267,19 @@ where
     }
 }
 
+/// Whether the item header within `rect` was ctrl-clicked (cmd-clicked on macOS), which toggles its selection
+fn header_command_clicked(ui: &egui::Ui, rect: egui::Rect) -> bool{
+    ui.input(|i| {
+        i.modifiers.command && i.pointer.primary_clicked() && i.pointer.interact_pos().is_some_and(|pos| rect.contains(pos))
+    })
+}
+
+fn toggle_selection(selection: &mut BTreeSet<usize>, widget_idx: usize){
+    if !selection.remove(&widget_idx){
+        selection.insert(widget_idx);
+    }
+}
+
 impl<'a, Itm, RndLbl, RndItm, NewItm> egui::Widget for VecWidget<'a, Itm, RndLbl, RndItm, NewItm>
 where
     RndLbl: FnMut(&mut Itm, usize, &mut egui::Ui),
@@ -270,8 +290,10 @@ where
         enum Action{
             Nothing,
             Remove(usize),
+            RemoveSelected,
             MoveUp(usize),
             MoveDown(usize),
+            InsertAt(usize),
         }
 
         let Self{
@@ -284,11 +306,26 @@ where
         } = self;
 
         let current_num_items = items.len();
+        let can_insert = new_item.is_some();
 
-        let draw_controls = |ui: &mut egui::Ui, widget_idx: usize, action: &mut Action|{
+        // Items selected for bulk deletion, kept in egui's memory since this widget only lives for one frame
+        let selection_id = match &item_renderer{
+            VecItemRender::HeaderAndBody{collapsible_id_source: Some(id_source), ..} => id_source.with("selection"),
+            _ => ui.id().with(("vec widget selection", item_label)),
+        };
+        let mut selection: BTreeSet<usize> = ui.data(|data| data.get_temp(selection_id)).unwrap_or_default();
+        selection.retain(|idx| *idx < current_num_items);
+
+        let draw_controls = |ui: &mut egui::Ui, widget_idx: usize, action: &mut Action, selection: &mut BTreeSet<usize>|{
+            if !selection.is_empty(){
+                let mut is_selected = selection.contains(&widget_idx);
+                if ui.checkbox(&mut is_selected, "").on_hover_text("Select for deletion").changed(){
+                    toggle_selection(selection, widget_idx);
+                }
+            }
             // let first_deletable_idx = min_items;
             // ui.add_enabled_ui(widget_idx >= first_deletable_idx, |ui|{
-                if ui.small_button("❌").clicked(){
+                if ui.small_button("❌").on_hover_text(format!("Remove this {item_label}. Ctrl+click headers to select several")).clicked(){
                     *action = Action::Remove(widget_idx);
                 }
             // });
@@ -307,44 +344,107 @@ where
                     }
                 });
             }
+            if can_insert{
+                ui.menu_button("➕", |ui|{
+                    if ui.button(format!("Insert {item_label} above")).clicked(){
+                        *action = Action::InsertAt(widget_idx);
+                        ui.close_menu();
+                    }
+                    if ui.button(format!("Insert {item_label} below")).clicked(){
+                        *action = Action::InsertAt(widget_idx + 1);
+                        ui.close_menu();
+                    }
+                }).response.on_hover_text(format!("Insert a new {item_label} next to this one"));
+            }
         };
 
         let mut action: Action = Action::Nothing;
         let resp = ui.vertical(|ui| {
-            let header_frame = egui::Frame::new().inner_margin(egui::Margin::same(5)).fill(ui.visuals().faint_bg_color);
+            if !selection.is_empty(){
+                ui.horizontal(|ui|{
+                    let num_selected = selection.len();
+                    ui.label(match num_selected{
+                        1 => format!("1 {item_label} selected"),
+                        _ => format!("{num_selected} {item_label}s selected"),
+                    });
+                    let num_remaining = current_num_items - num_selected;
+                    let remove_button = ui.add_enabled(num_remaining >= min_items, egui::Button::new("🗑 Remove selected"))
+                        .on_disabled_hover_text(match min_items{
+                            1 => format!("At least 1 {item_label} is required"),
+                            _ => format!("At least {min_items} {item_label}s are required"),
+                        });
+                    if remove_button.clicked(){
+                        action = Action::RemoveSelected;
+                    }
+                    if ui.button("Select all").clicked(){
+                        selection.extend(0..current_num_items);
+                    }
+                    if ui.button("Clear selection").clicked(){
+                        selection.clear();
+                    }
+                });
+            }
+            let unselected_frame = egui::Frame::new().inner_margin(egui::Margin::same(5)).fill(ui.visuals().faint_bg_color);
+            let selected_frame = unselected_frame.fill(ui.visuals().selection.bg_fill.gamma_multiply(0.4));
             items.iter_mut().enumerate().for_each(|(widget_idx, widget)| {
-                match &mut item_renderer{
+                let header_frame = if selection.contains(&widget_idx) { selected_frame } else { unselected_frame };
+                let header_rect = match &mut item_renderer{
                     VecItemRender::HeaderOnly { render_header } => {
                         header_frame.show(ui, |ui|{
                             ui.horizontal(|ui|{
-                                draw_controls(ui, widget_idx, &mut action);
+                                draw_controls(ui, widget_idx, &mut action, &mut selection);
                                 render_header(widget, widget_idx, ui);
                                 ui.add_space(ui.available_width());
                             });
-                        });
+                        }).response.rect
                     },
                     VecItemRender::HeaderAndBody { render_header, render_body, collapsible_id_source, ..} => {
                         if let Some(id_source) = collapsible_id_source{
-                            let id = ui.make_persistent_id(id_source.with(widget_idx));
-                            egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, true)
-                                .show_header(ui, |ui| { header_frame.show(ui, |ui|{
-                                    draw_controls(ui, widget_idx, &mut action);
+                            let item_id = id_source.with(widget_idx);
+                            let id = ui.make_persistent_id(item_id);
+                            let mut collapsing_state = egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, true);
+                            if let Some(is_open) = ExpansionState::take_restored(ui.ctx(), item_id){
+                                collapsing_state.set_open(is_open);
+                            }
+                            if is_open_requested(ui.ctx(), id){
+                                collapsing_state.set_open(true);
+                            }
+                            ExpansionState::record(ui.ctx(), item_id, collapsing_state.is_open());
+                            if !collapsing_state.is_open(){
+                                draw_hidden_section(ui, id, |ui| render_body(widget, widget_idx, ui));
+                            }
+                            let was_open = collapsing_state.is_open();
+                            let mut header_rect = egui::Rect::NOTHING;
+                            let mut header = collapsing_state
+                                .show_header(ui, |ui| { header_rect = header_frame.show(ui, |ui|{
+                                    draw_controls(ui, widget_idx, &mut action, &mut selection);
                                     render_header(widget, widget_idx, ui);
                                     ui.add_space(ui.available_width());
-                                }) })
-                                .body(|ui| render_body(widget, widget_idx, ui));
+                                }).response.rect });
+                            // a ctrl-click on the collapse button selects the item, so it must not also collapse it
+                            if header.is_open() != was_open && ui.input(|i| i.modifiers.command){
+                                header.set_open(was_open);
+                            }
+                            let (toggle_response, _, _) = header
+                                .body(|ui| findable_section(ui, id, |ui| render_body(widget, widget_idx, ui)));
+                            ui.add_space(10.0);
+                            header_rect.union(toggle_response.rect)
                         } else {
-                            header_frame.show(ui, |ui|{
+                            let header_rect = header_frame.show(ui, |ui|{
                                 ui.horizontal(|ui|{
-                                    draw_controls(ui, widget_idx, &mut action);
+                                    draw_controls(ui, widget_idx, &mut action, &mut selection);
                                     render_header(widget, widget_idx, ui);
                                     ui.add_space(ui.available_width());
                                 });
-                            });
+                            }).response.rect;
                             render_body(widget, widget_idx, ui);
+                            ui.add_space(10.0);
+                            header_rect
                         }
-                        ui.add_space(10.0);
                     }
+                };
+                if header_command_clicked(ui, header_rect){
+                    toggle_selection(&mut selection, widget_idx);
                 }
             });
 
@@ -372,14 +472,31 @@ where
             }
         });
 
+        // indices change with any of the actions below, so the selection wouldn't point to the same items anymore
+        let selected = match action{
+            Action::Nothing => BTreeSet::new(),
+            _ => std::mem::take(&mut selection),
+        };
         match action{
             Action::Nothing => (),
             Action::Remove(idx) => {
                 items.remove(idx);
             },
+            Action::RemoveSelected => {
+                let mut idx = 0;
+                items.retain(|_| {
+                    let keep = !selected.contains(&idx);
+                    idx += 1;
+                    keep
+                });
+            },
             Action::MoveUp(idx) => items.swap(idx - 1, idx),
             Action::MoveDown(idx) => items.swap(idx, idx + 1),
+            Action::InsertAt(idx) => if let Some(new_item) = &mut new_item{
+                items.insert(idx, new_item());
+            },
         };
+        ui.data_mut(|data| data.insert_temp(selection_id, selection));
         resp.response
     }
 }
@@ -419,6 +536,66 @@ where
     }
 }
 
+/// Keyboard navigation in pick list popups: the arrow keys move a highlight through the visible entries,
+/// Enter picks the highlighted entry and Escape closes the popup
+pub struct PickListNavigation{
+    state_id: egui::Id,
+    /// Index of the highlighted entry among the visible ones
+    pub highlighted: usize,
+    /// Whether the highlight moved in this frame, so that the list should scroll to it
+    pub moved: bool,
+    pub confirmed: bool,
+    pub cancelled: bool,
+}
+
+impl PickListNavigation{
+    /// Consumes the navigation keys, so that they don't also move the cursor of the search field
+    pub fn read_keys(ui: &mut egui::Ui, popup_id: egui::Id) -> Self{
+        let state_id = popup_id.with("highlighted entry");
+        let (highlighted, num_visible): (usize, usize) = ui.data(|data| data.get_temp(state_id)).unwrap_or_default();
+        let (down, up, confirmed, cancelled) = ui.input_mut(|input| (
+            input.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+            input.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+            input.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+            input.consume_key(egui::Modifiers::NONE, egui::Key::Escape),
+        ));
+        let highlighted = if down {
+            (highlighted + 1).min(num_visible.saturating_sub(1))
+        } else if up {
+            highlighted.saturating_sub(1)
+        } else {
+            highlighted
+        };
+        Self{state_id, highlighted, moved: down || up, confirmed, cancelled}
+    }
+
+    /// Moves the highlight back to the first entry, e.g. because the search changed
+    pub fn reset(&mut self){
+        self.highlighted = 0;
+    }
+
+    /// Draws the visible entry number `idx`, marking it if it is highlighted
+    pub fn entry_button(&self, ui: &mut egui::Ui, idx: usize, text: impl Into<egui::WidgetText>) -> egui::Response{
+        let is_highlighted = idx == self.highlighted;
+        let response = ui.add(egui::Button::new(text).selected(is_highlighted));
+        if is_highlighted && self.moved{
+            response.scroll_to_me(None);
+        }
+        response
+    }
+
+    /// Whether the visible entry number `idx` was picked with the keyboard
+    pub fn is_confirmed(&self, idx: usize) -> bool{
+        self.confirmed && idx == self.highlighted
+    }
+
+    /// Remembers the highlight for the next frame, in which `num_visible` entries are expected
+    pub fn store(self, ui: &mut egui::Ui, num_visible: usize){
+        let highlighted = self.highlighted.min(num_visible.saturating_sub(1));
+        ui.data_mut(|data| data.insert_temp(self.state_id, (highlighted, num_visible)));
+    }
+}
+
 pub enum SearchVisibility{
     Show,
     #[allow(dead_code)]
@@ -465,6 +642,10 @@ where
         ui.set_min_width(200.0);
         // ui.set_min_height(vert_space_above_button.max(vert_space_under_button));
         // ui.set_max_height(vert_space_above_button.max(vert_space_under_button));
+        let mut navigation = PickListNavigation::read_keys(ui, popup_id);
+        if navigation.cancelled{
+            ui.memory_mut(|mem| mem.close_popup());
+        }
         ui.vertical(|ui|{
             let header_height = if matches!(search_visibility, SearchVisibility::Show){
                 let header_rect = ui.vertical(|ui|{
@@ -474,6 +655,9 @@ where
                         if button_response.clicked(){
                             search_resp.request_focus();
                         }
+                        if search_resp.changed(){
+                            navigation.reset();
+                        }
                     });
                     ui.add_space(10.0);
                 }).response.rect;
@@ -487,9 +671,8 @@ where
             let scroll_area = egui::ScrollArea::vertical()
                 .scroll_bar_visibility(egui::scroll_area::ScrollBarVisibility::VisibleWhenNeeded)
                 .max_height(vert_space_above_button.max(vert_space_under_button).sub(header_height).max(0.0));
-            let (num_visible_entries, candidate) = scroll_area.show(ui, |ui| {
-                let mut candidate: Option<T> = None;
-                let num_visible_entries = entries
+            let num_visible_entries = scroll_area.show(ui, |ui| {
+                entries
                     .filter(|entry| {
                         let widget_text = display(entry).into();
                         let entry_display = widget_text.text().to_lowercase();
@@ -500,26 +683,18 @@ where
                         }
                         return true
                     })
-                    .inspect(|entry| {
-                        candidate.replace(entry.clone());
+                    .enumerate()
+                    .inspect(|(idx, entry)| {
                         let widget_text = display(entry);
-                        if ui.button(widget_text).clicked() {
+                        if navigation.entry_button(ui, *idx, widget_text).clicked() || navigation.is_confirmed(*idx) {
                             current.replace(entry.clone());
                             ui.memory_mut(|mem| mem.toggle_popup(popup_id));
                             search.clear();
                         }
                     })
-                    .count();
-                (num_visible_entries, candidate)
+                    .count()
             }).inner;
-
-            if num_visible_entries == 1 && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
-                if let Some(candidate) = candidate {
-                    current.replace(candidate);
-                    ui.memory_mut(|mem| mem.toggle_popup(popup_id));
-                    search.clear();
-                }
-            }
+            navigation.store(ui, num_visible_entries);
         });
     });
 }
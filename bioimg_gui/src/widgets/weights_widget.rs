@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use bioimg_runtime as rt;
+use bioimg_spec::rdf::model as modelrdf;
 
 use crate::result::{GuiError, Result, VecResultExt};
 use super::{Restore, StatefulWidget, ValueWidget};
@@ -11,9 +12,12 @@ use super::staging_vec::StagingVec;
 use super::staging_opt::StagingOpt;
 use super::pytorch_statedict_weights_widget::PytorchStateDictWidget;
 use super::onnx_weights_widget::OnnxWeightsWidget;
+use super::search_and_pick_widget::SearchAndPickWidget;
 use super::file_source_widget::FileSourceWidget;
 use super::error_display::show_error;
+use super::labels::SpecLinkExt;
 use super::collapsible_widget::{CollapsibleWidget, SummarizableWidget};
+use super::field_finder_widget::findable_label;
 
 #[derive(Restore, Default)]
 #[restore(saved_data=crate::project_data::WeightsWidgetSavedData)]
@@ -38,19 +42,19 @@ impl  WeightsWidget{
     pub fn draw(&mut self, ui: &mut egui::Ui, id: egui::Id){
         ui.vertical(|ui|{
             ui.horizontal(|ui|{
-                ui.strong("Torchscript: ");
+                findable_label(ui, "Torchscript: ").on_hover_text_with_spec(ui, "WeightsDescr.torchscript", "Weights serialized as a TorchScript module");
                 self.torchscript_weights_widget.draw_and_parse(ui, id.with("tsweights".as_ptr()));
             });
             ui.horizontal(|ui|{
-                ui.strong("Pytorch state dict: ");
+                findable_label(ui, "Pytorch state dict: ").on_hover_text_with_spec(ui, "WeightsDescr.pytorch_state_dict", "Weights saved as a PyTorch state dict, plus the architecture needed to load them");
                 self.pytorch_state_dict_weights_widget.draw_and_parse(ui, id.with("pytorch".as_ptr()));
             });
             ui.horizontal(|ui|{
-                ui.strong("Keras: ");
+                findable_label(ui, "Keras: ").on_hover_text_with_spec(ui, "WeightsDescr.keras_hdf5", "Weights saved in the Keras HDF5 format");
                 self.keras_weights_widget.draw_and_parse(ui, id.with("keras".as_ptr()));
             });
             ui.horizontal(|ui|{
-                ui.strong("Onnx: ");
+                findable_label(ui, "Onnx: ").on_hover_text_with_spec(ui, "WeightsDescr.onnx", "Weights saved in the ONNX format");
                 self.onnx_weights_widget.draw_and_parse(ui, id.with("onnx".as_ptr()));
             });
         });
@@ -72,12 +76,13 @@ impl  WeightsWidget{
     }
 }
 
-#[derive(Default, Restore)]
+#[derive(Default, Restore, ValueWidget)]
 #[restore(saved_data=crate::project_data::WeightsDescrBaseWidgetSavedData)]
+#[value_widget(value=rt::WeightsBase)]
 pub struct WeightsDescrBaseWidget{
     pub source_widget: FileSourceWidget,
     pub authors_widget: StagingOpt<StagingVec<CollapsibleWidget<AuthorWidget>>>,
-    // pub parent_widget: Option<WeightsFormat>,
+    pub parent_widget: StagingOpt<SearchAndPickWidget<modelrdf::WeightsFormat>>,
 }
 
 impl SummarizableWidget for WeightsDescrBaseWidget{
@@ -93,30 +98,27 @@ impl SummarizableWidget for WeightsDescrBaseWidget{
     }
 }
 
-impl ValueWidget for WeightsDescrBaseWidget{
-    type Value<'v> = rt::WeightsBase;
-
-    fn set_value<'v>(&mut self, value: Self::Value<'v>) {
-        self.source_widget.set_value(value.source);
-        self.authors_widget.set_value(value.authors);
-    }
-}
-
 impl StatefulWidget for WeightsDescrBaseWidget{
     type Value<'p> = Result<rt::WeightsBase>;
 
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.vertical(|ui|{
             ui.horizontal(|ui|{
-                ui.strong("Source: ").on_hover_text("The file containing the serialized weights and biases");
+                findable_label(ui, "Source: ").on_hover_text_with_spec(ui, "WeightsEntryDescrBase.source", "The file containing the serialized weights and biases");
                 group_frame(ui, |ui|{
                     self.source_widget.draw_and_parse(ui, id.with("source"));
                 });
             });
             ui.horizontal(|ui|{
-                ui.strong("Authors: ").on_hover_text("The people who trained these weights and biases");
+                findable_label(ui, "Authors: ").on_hover_text_with_spec(ui, "WeightsEntryDescrBase.authors", "The people who trained these weights and biases");
                 self.authors_widget.draw_and_parse(ui, id.with("authors"));
             });
+            ui.horizontal(|ui|{
+                findable_label(ui, "Converted from: ").on_hover_text_with_spec(ui,
+                    "WeightsEntryDescrBase.parent", "The weights format these weights were converted from, if any"
+                );
+                self.parent_widget.draw_and_parse(ui, id.with("parent"));
+            });
         });
     }
 
@@ -125,15 +127,17 @@ impl StatefulWidget for WeightsDescrBaseWidget{
             authors.collect_result()
         }).transpose()?;
         let source = self.source_widget.state().map_err(|e| GuiError::new(format!("Model source error: {e}")))?;
-        Ok(rt::WeightsBase{authors, source})
+        Ok(rt::WeightsBase{authors, source, parent: self.parent_widget.state()})
     }
 }
 
 //////////////////////////////
 
-#[derive(Default, Restore)]
+#[derive(Default, Restore, ValueWidget)]
 #[restore(saved_data=crate::project_data::KerasHdf5WeightsWidgetSavedData)]
+#[value_widget(value=rt::KerasHdf5Weights)]
 pub struct KerasHdf5WeightsWidget{
+    #[value_widget(from=weights)]
     pub base_widget: WeightsDescrBaseWidget,
     pub tensorflow_version_widget: VersionWidget,
 }
@@ -154,15 +158,6 @@ impl SummarizableWidget for KerasHdf5WeightsWidget{
     }
 }
 
-impl ValueWidget for KerasHdf5WeightsWidget{
-    type Value<'v> = rt::KerasHdf5Weights;
-
-    fn set_value<'v>(&mut self, value: Self::Value<'v>) {
-        self.base_widget.set_value(value.weights);
-        self.tensorflow_version_widget.set_value(value.tensorflow_version);
-    }
-}
-
 impl StatefulWidget for KerasHdf5WeightsWidget{
     type Value<'p> = Result<rt::KerasHdf5Weights>;
 
@@ -170,7 +165,7 @@ impl StatefulWidget for KerasHdf5WeightsWidget{
         ui.vertical(|ui|{
             self.base_widget.draw_and_parse(ui, id.with("base"));
             ui.horizontal(|ui|{
-                ui.strong("Tensor Flow Version: ").on_hover_text(
+                findable_label(ui, "Tensor Flow Version: ").on_hover_text_with_spec(ui, "KerasHdf5WeightsDescr.tensorflow_version",
                     "Version of the tensor flow library used when training these weights and biases"
                 );
                 self.tensorflow_version_widget.draw_and_parse(ui, id.with("tfversion"));
@@ -188,9 +183,11 @@ impl StatefulWidget for KerasHdf5WeightsWidget{
 
 ////////////////////////////
 
-#[derive(Default, Restore)]
+#[derive(Default, Restore, ValueWidget)]
 #[restore(saved_data=crate::project_data::TorchscriptWeightsWidgetSavedData)]
+#[value_widget(value=rt::TorchscriptWeights)]
 pub struct TorchscriptWeightsWidget{
+    #[value_widget(from=weights)]
     pub base_widget: WeightsDescrBaseWidget,
     pub pytorch_version_widget: VersionWidget,
 }
@@ -211,15 +208,6 @@ impl SummarizableWidget for TorchscriptWeightsWidget{
     }
 }
 
-impl ValueWidget for TorchscriptWeightsWidget{
-    type Value<'v> = rt::TorchscriptWeights;
-
-    fn set_value<'v>(&mut self, value: Self::Value<'v>) {
-        self.base_widget.set_value(value.weights);
-        self.pytorch_version_widget.set_value(value.pytorch_version);
-    }
-}
-
 impl StatefulWidget for TorchscriptWeightsWidget{
     type Value<'p> = Result<rt::TorchscriptWeights>;
 
@@ -227,7 +215,7 @@ impl StatefulWidget for TorchscriptWeightsWidget{
         ui.vertical(|ui|{
             self.base_widget.draw_and_parse(ui, id.with("base"));
             ui.horizontal(|ui|{
-                ui.strong("Pytorch Version: ").on_hover_text("The pytorch library version used when training these weights and biases");
+                findable_label(ui, "Pytorch Version: ").on_hover_text_with_spec(ui, "TorchscriptWeightsDescr.pytorch_version", "The pytorch library version used when training these weights and biases");
                 self.pytorch_version_widget.draw_and_parse(ui, id.with("ptversion"));
             });
         });
@@ -0,0 +1,102 @@
+use crate::result::Result;
+
+use super::error_display::show_error;
+
+/// The sections that a first-time author is walked through, in order, when using the guided mode
+#[derive(Default, Copy, Clone, PartialEq, Eq, Debug, strum::VariantArray, strum::Display)]
+pub enum WizardStep{
+    #[default]
+    Metadata,
+    Inputs,
+    Outputs,
+    Weights,
+    Export,
+}
+
+impl WizardStep{
+    pub fn index(self) -> usize{
+        use strum::VariantArray;
+        Self::VARIANTS.iter().position(|step| *step == self).unwrap()
+    }
+    pub fn next(self) -> Option<Self>{
+        use strum::VariantArray;
+        Self::VARIANTS.get(self.index() + 1).copied()
+    }
+    pub fn previous(self) -> Option<Self>{
+        use strum::VariantArray;
+        Self::VARIANTS.get(self.index().checked_sub(1)?).copied()
+    }
+    pub fn tip(self) -> &'static str{
+        match self{
+            Self::Metadata => "Describe your model: name, authors, citations, license and documentation",
+            Self::Inputs => "Describe the tensors your model takes as inputs, and provide a test sample for each",
+            Self::Outputs => "Describe the tensors your model produces, and provide the expected test output for each",
+            Self::Weights => "Provide the files with the trained weights of your model, in at least one format",
+            Self::Export => "Review your model and export it to a .zip file",
+        }
+    }
+}
+
+pub enum WizardNavigation{
+    Stay,
+    GoTo(WizardStep),
+}
+
+/// Draws the step indicator at the top of the guided mode. Steps that come before the current one can be
+/// revisited freely; moving forward is done via the footer, which checks the current step for errors
+pub fn draw_wizard_header(ui: &mut egui::Ui, current_step: WizardStep) -> WizardNavigation{
+    use strum::VariantArray;
+
+    let mut navigation = WizardNavigation::Stay;
+    let num_steps = WizardStep::VARIANTS.len();
+    ui.horizontal(|ui|{
+        for (idx, step) in WizardStep::VARIANTS.iter().copied().enumerate(){
+            let label = format!("{}. {step}", idx + 1);
+            let text = if step == current_step{
+                egui::RichText::new(label).strong()
+            } else {
+                egui::RichText::new(label)
+            };
+            let enabled = step.index() <= current_step.index();
+            if ui.add_enabled(enabled, egui::Button::new(text).selected(step == current_step)).clicked(){
+                navigation = WizardNavigation::GoTo(step);
+            }
+            if idx + 1 < num_steps{
+                ui.weak("→");
+            }
+        }
+    });
+    let progress = (current_step.index() + 1) as f32 / num_steps as f32;
+    ui.add(egui::ProgressBar::new(progress).text(format!("Step {} of {num_steps}", current_step.index() + 1)));
+    ui.weak(current_step.tip());
+    ui.separator();
+    navigation
+}
+
+/// Draws the "Back" and "Next" buttons at the bottom of a guided mode step
+pub fn draw_wizard_footer(
+    ui: &mut egui::Ui, current_step: WizardStep, current_step_validation: &Result<()>
+) -> WizardNavigation{
+    let mut navigation = WizardNavigation::Stay;
+    ui.separator();
+    if let Err(err) = current_step_validation{
+        show_error(ui, err);
+    }
+    ui.horizontal(|ui|{
+        if let Some(previous) = current_step.previous(){
+            if ui.button(format!("⏴ Back to {previous}")).clicked(){
+                navigation = WizardNavigation::GoTo(previous);
+            }
+        }
+        if let Some(next) = current_step.next(){
+            let next_button = ui.add_enabled(
+                current_step_validation.is_ok(),
+                egui::Button::new(format!("Continue to {next} ⏵")),
+            );
+            if next_button.on_disabled_hover_text("Fix the errors in this step before continuing").clicked(){
+                navigation = WizardNavigation::GoTo(next);
+            }
+        }
+    });
+    navigation
+}
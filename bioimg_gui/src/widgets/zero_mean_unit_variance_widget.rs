@@ -7,6 +7,7 @@ use super::staging_float::StagingFloat;
 use super::util::{widget_vec_from_values, OptWidget, SomeRenderer, VecItemRender, VecWidget};
 use super::{Restore, ValueWidget};
 use super::{staging_string::StagingString, StatefulWidget};
+use super::field_finder_widget::findable_label;
 
 #[derive(Restore)]
 #[restore(saved_data=crate::project_data::ZeroMeanUnitVarianceWidgetSavedData)]
@@ -49,7 +50,7 @@ impl StatefulWidget for ZeroMeanUnitVarianceWidget{
     fn draw_and_parse(&mut self, ui: &mut egui::Ui, id: egui::Id) {
         ui.vertical(|ui|{
             ui.horizontal(|ui|{
-                ui.strong("Axes: ");
+                findable_label(ui, "Axes: ");
                 let opt_widget = OptWidget{
                     value: &mut self.axes_widget,
                     draw_frame: true,
@@ -72,7 +73,7 @@ impl StatefulWidget for ZeroMeanUnitVarianceWidget{
                 opt_widget.ui(ui);
             });
             ui.horizontal(|ui|{
-                ui.strong("Epsilon: ");
+                findable_label(ui, "Epsilon: ");
                 self.epsilon_widget.draw_and_parse(ui, id.with("epsilon".as_ptr()));
             });
         });
@@ -1,12 +1,12 @@
 use std::sync::Arc;
 
-use crate::request::{send_bytes, send_reader};
+use crate::request::{send_bytes, UreqTransport};
 use crate::result::Result;
 use bioimg_runtime::zoo_model::ZooModel;
 use bioimg_spec::rdf::HttpUrl;
 use bioimg_zoo::auth::{AuthInProgress, AuthStart, Seconds, UserToken};
-use bioimg_zoo::collection::{CollectionConfig, CollectionJson, ZooNickname, ZooNicknameGenerator};
-use bioimg_zoo::client::ClientMethod;
+use bioimg_zoo::collection::{CollectionJson, ResourceIdCheck, ZooNickname};
+use bioimg_zoo::client::PresignedUrl;
 
 use crate::result::GuiError;
 
@@ -179,63 +179,215 @@ impl StatefulWidget for ZooLoginWidget{
     }
 }
 
+#[derive(Default)]
+enum CollectionFetchState{
+    #[default]
+    NotStarted,
+    Fetching(std::thread::JoinHandle<Result<CollectionJson>>),
+    Ready(Arc<CollectionJson>),
+    Failed(GuiError),
+}
+
+impl CollectionFetchState{
+    fn fetch_collection() -> Result<CollectionJson>{
+        let resp = send_bytes(CollectionJson::request())?;
+        Ok(CollectionJson::parse_response(&resp)?)
+    }
+
+    fn start_if_needed(&mut self){
+        if let Self::NotStarted = self{
+            *self = Self::Fetching(std::thread::spawn(Self::fetch_collection));
+        }
+    }
+
+    fn update(&mut self){
+        *self = match std::mem::take(self){
+            Self::Fetching(task) => 'fetching: {
+                if !task.is_finished(){
+                    break 'fetching Self::Fetching(task)
+                }
+                match task.join(){
+                    Ok(Ok(collection)) => Self::Ready(Arc::new(collection)),
+                    Ok(Err(err)) => Self::Failed(err),
+                    Err(_) => Self::Failed(GuiError::new("Collection fetching task panicked")),
+                }
+            },
+            state => state,
+        }
+    }
+}
+
+/// Checks a model id against the zoo collection, warning if it is not there
+/// or if it belongs to something that is not a model
+#[derive(Default)]
+pub struct ModelIdCheckWidget{
+    state: CollectionFetchState,
+    last_check: Option<CachedIdCheck>,
+}
+
+/// An owned [ResourceIdCheck], so that the collection isn't searched again every frame
+#[derive(Clone)]
+enum IdCheckOutcome{
+    Found,
+    NotFound,
+    WrongResourceType{expected: &'static str, found: String},
+}
+
+impl From<ResourceIdCheck<'_>> for IdCheckOutcome{
+    fn from(check: ResourceIdCheck<'_>) -> Self{
+        match check{
+            ResourceIdCheck::Found(_) => Self::Found,
+            ResourceIdCheck::NotFound => Self::NotFound,
+            ResourceIdCheck::WrongResourceType { expected, found } => Self::WrongResourceType { expected, found },
+        }
+    }
+}
+
+struct CachedIdCheck{
+    model_id: String,
+    collection: Arc<CollectionJson>,
+    outcome: IdCheckOutcome,
+}
+
+impl ModelIdCheckWidget{
+    pub fn update(&mut self){
+        self.state.update()
+    }
+
+    /// The outcome of checking `model_id` against `collection`, reusing the last one if neither changed
+    fn check(&mut self, collection: Arc<CollectionJson>, model_id: &str) -> IdCheckOutcome{
+        match &self.last_check{
+            Some(last) if last.model_id == model_id && Arc::ptr_eq(&last.collection, &collection) => {
+                last.outcome.clone()
+            },
+            _ => {
+                let outcome = IdCheckOutcome::from(collection.check_resource_id(model_id, "model"));
+                self.last_check = Some(CachedIdCheck{model_id: model_id.to_owned(), collection, outcome: outcome.clone()});
+                outcome
+            },
+        }
+    }
+
+    pub fn draw(&mut self, ui: &mut egui::Ui, model_id: Option<&str>){
+        self.update();
+        let Some(model_id) = model_id else {
+            return
+        };
+        self.state.start_if_needed();
+        match &self.state{
+            CollectionFetchState::NotStarted => (),
+            CollectionFetchState::Fetching(_) => {
+                ui.weak("Checking model id against the zoo collection...");
+                ui.ctx().request_repaint();
+            },
+            CollectionFetchState::Failed(err) => {
+                let clicked = ui.horizontal(|ui|{
+                    ui.weak("Could not check model id against the zoo collection").on_hover_text(err.to_string());
+                    ui.small_button("Retry").clicked()
+                }).inner;
+                if clicked{
+                    self.state = CollectionFetchState::NotStarted;
+                }
+            },
+            CollectionFetchState::Ready(collection) => {
+                let collection = Arc::clone(collection);
+                match self.check(collection, model_id){
+                    IdCheckOutcome::Found => {
+                        ui.weak(format!("✔ '{model_id}' is a model in the zoo collection"));
+                    },
+                    IdCheckOutcome::NotFound => {
+                        super::error_display::show_warning(ui, format!(
+                            "'{model_id}' was not found in the zoo collection. Uploading with this id may create a new, \
+                            unrelated entry instead of a new version of an existing model"
+                        ));
+                    },
+                    IdCheckOutcome::WrongResourceType { expected, found } => {
+                        super::error_display::show_warning(ui, format!(
+                            "'{model_id}' belongs to a resource of type '{found}', not '{expected}'"
+                        ));
+                    },
+                }
+            },
+        }
+    }
+}
+
+/// A button that opens a searchable list of the resources of a given type (e.g. "model", "dataset")
+/// in the zoo collection, so that their ids don't have to be typed in by hand
+#[derive(Default)]
+pub struct ZooResourcePickerWidget{
+    state: CollectionFetchState,
+    filter: String,
+}
+
+impl ZooResourcePickerWidget{
+    /// Returns the id of the resource that was picked this frame, if any
+    pub fn draw(&mut self, ui: &mut egui::Ui, resource_type: &'static str) -> Option<String>{
+        self.state.update();
+        let mut picked = None;
+        ui.menu_button("🔍 Pick from zoo", |ui|{
+            self.state.start_if_needed();
+            match &self.state{
+                CollectionFetchState::NotStarted => (),
+                CollectionFetchState::Fetching(_) => {
+                    ui.horizontal(|ui|{
+                        ui.spinner();
+                        ui.weak("Fetching the zoo collection...");
+                    });
+                    ui.ctx().request_repaint();
+                },
+                CollectionFetchState::Failed(err) => {
+                    super::error_display::show_error(ui, format!("Could not fetch the zoo collection: {err}"));
+                    if ui.button("Retry").clicked(){
+                        self.state = CollectionFetchState::NotStarted;
+                    }
+                },
+                CollectionFetchState::Ready(collection) => {
+                    ui.horizontal(|ui|{
+                        ui.label("🔍");
+                        ui.text_edit_singleline(&mut self.filter);
+                    });
+                    let filter = self.filter.to_lowercase();
+                    egui::ScrollArea::vertical().max_height(300.0).show(ui, |ui|{
+                        let items = collection.items().iter()
+                            .filter(|item| item.resource_type() == Some(resource_type))
+                            .filter_map(|item| Some((item.id()?, item)))
+                            .filter(|(id, item)|{
+                                id.contains(&filter) ||
+                                item.name().is_some_and(|name| name.to_lowercase().contains(&filter))
+                            });
+                        for (id, item) in items{
+                            let resp = ui.selectable_label(false, id);
+                            let resp = match item.name(){
+                                Some(name) => resp.on_hover_text(name),
+                                None => resp,
+                            };
+                            if resp.clicked(){
+                                picked = Some(id.to_owned());
+                                ui.close_menu();
+                            }
+                        }
+                    });
+                },
+            }
+        });
+        picked
+    }
+}
+
+/// Packs `model` and uploads it to a temporary location of the zoo storage, without staging it, returning
+/// a URL the package can be downloaded from for a while, e.g. by the BioEngine for a remote test run
+pub fn upload_for_remote_test(user_token: UserToken, model: ZooModel) -> Result<PresignedUrl>{
+    let package = model.pack_into_tmp()?;
+    Ok(bioimg_zoo::upload::upload_for_remote_test(&UreqTransport, user_token, package)?)
+}
+
 pub fn upload_model(
     user_token: UserToken,
     model: ZooModel,
     on_progress: impl Fn(String),
 ) -> Result<ZooNickname>{
-    let mut file_to_upload = model.pack_into_tmp()?;
-
-    let collection_config: CollectionConfig = {
-        let req = CollectionConfig::request();
-        let collection_config_resp = send_bytes(req).unwrap();
-        CollectionConfig::parse_response(&collection_config_resp).unwrap()
-    };
-    let collection_json: CollectionJson = {
-        let req = CollectionJson::request();
-        let collection_json_resp = send_bytes(req).unwrap();
-        CollectionJson::parse_response(&collection_json_resp).unwrap()
-    };
-    let nickname_generator = ZooNicknameGenerator::new(collection_config, collection_json);
-    let nickname = (0..50)
-        .filter_map(|_| nickname_generator.generate_zoo_nickname())
-        .next().unwrap();
-    on_progress(format!("Chosen model nickname: {nickname}"));
-
-    let client = bioimg_zoo::client::Client::new(user_token);
-
-    let presigned_url = {
-        on_progress(format!("Requesting a storage URL..."));
-        let resp_signed_url = send_bytes(
-            client.presigned_url_request(&nickname, Seconds(3600), ClientMethod::PutObject)
-        )?;
-        let url = client.parse_presigned_url_resp(&resp_signed_url)?;
-        on_progress(format!("Got storage pressigned URL"));
-        eprintln!("==>> And this is the signed url for PUT: {url}. Now lets try putting something in it");
-        url
-    };
-
-    {
-        let put_req = client.write_to_bucket_request(&presigned_url, &mut file_to_upload);
-        on_progress(format!("Uploading model '{nickname}' to zoo"));
-        let resp = send_reader(put_req)?;
-        on_progress(format!("Model '{nickname}' successfully uploaded"));
-        let upload_resp_str = String::from_utf8(resp.into_body()).unwrap();
-        eprintln!("==>> And here's the response: {upload_resp_str}")
-    }
-
-    {
-        let resp_signed_url = send_bytes(client.presigned_url_request(&nickname, Seconds(3600), ClientMethod::GetObject)).unwrap();
-        let presigned_url = client.parse_presigned_url_resp(&resp_signed_url).unwrap();
-        eprintln!("==>> And this is the signed GET url: {presigned_url}");
-
-        eprintln!("Trying to stage it....");
-        let req = client.stage_model_request(&nickname, &presigned_url);
-        on_progress(format!("Staging model {nickname}"));
-        let resp = send_bytes(req)?;
-        on_progress(format!("Successfully staged model {nickname}. It will be available on the zoo once it's reviewed"));
-        let resp_str = String::from_utf8(resp.into_body()).unwrap();
-        eprintln!("==>> And here's the STAGING response: {resp_str}");
-    }
-    Ok(nickname)
+    on_progress("Packing model...".to_owned());
+    let package = model.pack_into_tmp()?;
+    Ok(bioimg_zoo::upload::upload_model(&UreqTransport, user_token, package, on_progress)?)
 }
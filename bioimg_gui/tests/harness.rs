@@ -0,0 +1,28 @@
+use bioimg_gui::harness::AppHarness;
+
+#[test]
+fn test_blank_form_cannot_be_exported(){
+    let mut harness = AppHarness::new();
+    assert!(harness.create_model().is_err());
+    harness.assert_notification("Could not create zoo model");
+}
+
+#[test]
+fn test_filling_metadata_from_rdf_yaml(){
+    let mut harness = AppHarness::new();
+    harness.fill_from_rdf_yaml(r#"
+format_version: 0.5.3
+type: model
+name: Harness Model
+description: A model whose metadata was filled in from yaml
+authors:
+  - name: John Doe
+cite:
+  - text: "He et al. Deep Residual Learning for Image Recognition"
+    doi: 10.1109/CVPR.2016.90
+license: MIT
+"#);
+    // all the metadata is there, so only the missing inputs and outputs are left to complain about
+    let err = harness.create_model().unwrap_err();
+    assert!(err.contains("model interface"), "unexpected error: {err}");
+}
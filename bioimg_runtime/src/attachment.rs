@@ -0,0 +1,66 @@
+//! Files attached to a model, along with an explanation of what they are.
+//!
+//! The spec has no field for attachment descriptions, so they are stored in the model's config under
+//! `config.bioimageio.attachment_descriptions`, as a map from the path of each attachment inside the
+//! package to its description.
+
+use bioimg_spec::rdf::file_description::Sha256;
+
+use crate::FileSource;
+
+const DESCRIPTIONS_KEY: &str = "attachment_descriptions";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attachment{
+    pub source: FileSource,
+    /// What this file is and why it is relevant to the model
+    pub description: Option<String>,
+    /// The hash of the contents of `source`. Only known for attachments that have been loaded from
+    /// a packaged model, since it is computed while packing
+    pub sha256: Option<Sha256>,
+}
+
+impl From<FileSource> for Attachment{
+    fn from(source: FileSource) -> Self {
+        Self{source, description: None, sha256: None}
+    }
+}
+
+/// Removes the attachment descriptions from `config`, so that they don't show up as custom
+/// configuration, returning them keyed by the path of their attachment
+pub fn take_descriptions(
+    config: &mut serde_json::Map<String, serde_json::Value>
+) -> serde_json::Map<String, serde_json::Value>{
+    let Some(serde_json::Value::Object(bioimageio)) = config.get_mut("bioimageio") else {
+        return Default::default()
+    };
+    let descriptions = match bioimageio.remove(DESCRIPTIONS_KEY){
+        Some(serde_json::Value::Object(descriptions)) => descriptions,
+        Some(other) => {
+            bioimageio.insert(DESCRIPTIONS_KEY.to_owned(), other);
+            return Default::default()
+        },
+        None => return Default::default(),
+    };
+    if bioimageio.is_empty(){
+        config.remove("bioimageio");
+    }
+    descriptions
+}
+
+/// Stores `descriptions` (keyed by attachment path) under `config.bioimageio.attachment_descriptions`.
+/// Leaves `config` untouched if there are no descriptions or if `config.bioimageio` is not an object
+pub fn record_descriptions(
+    config: &mut serde_json::Map<String, serde_json::Value>,
+    descriptions: serde_json::Map<String, serde_json::Value>,
+){
+    if descriptions.is_empty(){
+        return
+    }
+    let bioimageio = config
+        .entry("bioimageio")
+        .or_insert_with(|| serde_json::Value::Object(Default::default()));
+    if let serde_json::Value::Object(bioimageio) = bioimageio{
+        bioimageio.insert(DESCRIPTIONS_KEY.to_owned(), serde_json::Value::Object(descriptions));
+    }
+}
@@ -73,7 +73,7 @@ impl CondaEnv{
         &self,
         zip_file: &mut ModelZipWriter<impl Write + Seek>,
     ) -> Result<rdf::EnvironmentFileDescr, ModelPackingError> {
-        let zip_path = rdf::FsPath::unique_suffixed("_environment.yml");
+        let zip_path = zip_file.unique_path("_environment.yml")?;
         zip_file.write_file(&zip_path, |writer| {
             serde_yaml::to_writer(writer, &self.raw)
         })?;
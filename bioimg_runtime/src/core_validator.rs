@@ -0,0 +1,266 @@
+//! Runs the validation and test suite of the reference implementation, `bioimageio.core`, on a
+//! packaged model by shelling out to a Python interpreter that has it installed.
+//!
+//! The results are read back from the JSON dump of `bioimageio.core`'s `ValidationSummary`, so that
+//! they can be reported alongside this crate's own checks.
+
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use bioimg_spec::rdf::model::weights::WeightsFormat;
+
+use crate::model_weights::ModelWeights;
+use crate::test_results::TestRunRecord;
+use crate::zoo_model::{ModelPackingError, ZooModel};
+
+/// Environment variable pointing to the Python interpreter that has `bioimageio.core` installed
+pub const PYTHON_ENV_VAR: &str = "BIOIMAGEIO_PYTHON";
+
+/// Interpreters that are tried, in order, if [PYTHON_ENV_VAR] is not set
+const DEFAULT_PYTHONS: &[&str] = &["python3", "python"];
+
+const VERSION_SCRIPT: &str = "import bioimageio.core; print(bioimageio.core.__version__)";
+
+const TEST_SCRIPT: &str = "\
+import sys
+from bioimageio.core import test_description
+summary = test_description(sys.argv[1])
+sys.stdout.write(summary.model_dump_json())
+";
+
+/// Runs the test inputs of the model at `argv[1]` through the weights in `argv[2]` once to warm up,
+/// then prints how many seconds a second run took
+const TIMING_SCRIPT: &str = "\
+import sys, time
+from bioimageio.core import create_prediction_pipeline, load_description
+from bioimageio.core.digest_spec import get_test_inputs
+model = load_description(sys.argv[1])
+sample = get_test_inputs(model)
+with create_prediction_pipeline(model, weight_format=sys.argv[2]) as pipeline:
+    pipeline.predict_sample_without_blocking(sample)
+    start = time.perf_counter()
+    pipeline.predict_sample_without_blocking(sample)
+    sys.stdout.write(str(time.perf_counter() - start))
+";
+
+#[derive(thiserror::Error, Debug)]
+pub enum CoreValidationError {
+    #[error("Could not run {python}: {source}")]
+    CouldNotRun { python: PathBuf, source: std::io::Error },
+    #[error("bioimageio.core exited with {status}: {stderr}")]
+    Failed { status: std::process::ExitStatus, stderr: String },
+    #[error("Could not parse the validation summary of bioimageio.core: {0}")]
+    BadSummary(#[from] serde_json::Error),
+    #[error("Could not parse the timing reported by bioimageio.core: {0}")]
+    BadTiming(String),
+    #[error("Could not pack model for validation: {0}")]
+    PackingError(#[from] ModelPackingError),
+    #[error("{0}")]
+    IoError(#[from] std::io::Error),
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct CoreValidationEntry {
+    /// Path to the offending field in the rdf, e.g. `["inputs", 0, "axes"]`
+    #[serde(default)]
+    pub loc: Vec<serde_json::Value>,
+    pub msg: String,
+}
+
+impl CoreValidationEntry {
+    /// [Self::loc] as a dotted path, e.g. `inputs.0.axes`
+    pub fn location(&self) -> Option<String> {
+        if self.loc.is_empty() {
+            return None;
+        }
+        let parts: Vec<String> = self.loc.iter()
+            .map(|part| match part {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            })
+            .collect();
+        Some(parts.join("."))
+    }
+}
+
+impl Display for CoreValidationEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.location() {
+            Some(location) => write!(f, "{location}: {}", self.msg),
+            None => write!(f, "{}", self.msg),
+        }
+    }
+}
+
+/// The outcome of one of the checks `bioimageio.core` ran
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct CoreValidationDetail {
+    pub name: String,
+    pub status: String,
+    #[serde(default)]
+    pub errors: Vec<CoreValidationEntry>,
+    #[serde(default)]
+    pub warnings: Vec<CoreValidationEntry>,
+}
+
+impl CoreValidationDetail {
+    pub fn passed(&self) -> bool {
+        self.status == "passed"
+    }
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct CoreValidationSummary {
+    pub status: String,
+    #[serde(default)]
+    pub details: Vec<CoreValidationDetail>,
+}
+
+impl CoreValidationSummary {
+    pub fn passed(&self) -> bool {
+        self.status == "passed"
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &CoreValidationEntry> {
+        self.details.iter().flat_map(|detail| detail.errors.iter())
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &CoreValidationEntry> {
+        self.details.iter().flat_map(|detail| detail.warnings.iter())
+    }
+}
+
+/// The outcome of [CoreValidator::test_model]
+#[derive(Clone, Debug)]
+pub struct CoreTestRun {
+    pub summary: CoreValidationSummary,
+    /// Timing of the run, present only if the model passed
+    pub record: Option<TestRunRecord>,
+}
+
+impl Display for CoreValidationSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "bioimageio.core validation {}", self.status)?;
+        for detail in self.details.iter().filter(|detail| !detail.passed() || !detail.warnings.is_empty()) {
+            write!(f, "\n  {}: {}", detail.name, detail.status)?;
+            for error in &detail.errors {
+                write!(f, "\n    error: {error}")?;
+            }
+            for warning in &detail.warnings {
+                write!(f, "\n    warning: {warning}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A Python interpreter that can import `bioimageio.core`
+#[derive(Clone, Debug)]
+pub struct CoreValidator {
+    python: PathBuf,
+    pub core_version: String,
+}
+
+impl CoreValidator {
+    /// Checks whether `python` can import `bioimageio.core`
+    pub fn with_python(python: impl Into<PathBuf>) -> Option<Self> {
+        let python = python.into();
+        let output = Command::new(&python).args(["-c", VERSION_SCRIPT]).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        let core_version = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+        Some(Self { python, core_version })
+    }
+
+    /// Looks for an interpreter with `bioimageio.core` in [PYTHON_ENV_VAR], then in the `PATH`
+    pub fn discover() -> Option<Self> {
+        if let Some(python) = std::env::var_os(PYTHON_ENV_VAR) {
+            return Self::with_python(python);
+        }
+        DEFAULT_PYTHONS.iter().find_map(|python| Self::with_python(python))
+    }
+
+    pub fn python(&self) -> &Path {
+        &self.python
+    }
+
+    /// Runs `script` with `args` and returns its stdout
+    fn run_script(&self, script: &str, args: &[&std::ffi::OsStr]) -> Result<Vec<u8>, CoreValidationError> {
+        let output = Command::new(&self.python)
+            .args(["-c", script])
+            .args(args)
+            .output()
+            .map_err(|source| CoreValidationError::CouldNotRun { python: self.python.clone(), source })?;
+        if !output.status.success() {
+            return Err(CoreValidationError::Failed {
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_owned(),
+            });
+        }
+        Ok(output.stdout)
+    }
+
+    /// Runs the full validation and test suite of `bioimageio.core` on the model package at `package_path`
+    pub fn validate_package(&self, package_path: &Path) -> Result<CoreValidationSummary, CoreValidationError> {
+        let stdout = self.run_script(TEST_SCRIPT, &[package_path.as_os_str()])?;
+        Ok(serde_json::from_slice(&stdout)?)
+    }
+
+    /// Measures how long `bioimageio.core` takes to process the test inputs of the package at
+    /// `package_path` with the `backend` weights
+    pub fn time_package(&self, package_path: &Path, backend: WeightsFormat) -> Result<TestRunRecord, CoreValidationError> {
+        let backend_name = backend.to_string();
+        let stdout = self.run_script(TIMING_SCRIPT, &[package_path.as_os_str(), backend_name.as_ref()])?;
+        let stdout = String::from_utf8_lossy(&stdout);
+        let duration_per_tile = stdout.trim().parse::<f64>().ok()
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+            .ok_or_else(|| CoreValidationError::BadTiming(stdout.trim().to_owned()))?;
+        Ok(TestRunRecord {
+            backend,
+            duration_per_tile,
+            hardware: TestRunRecord::current_hardware(),
+            tool: format!("bioimageio.core {}", self.core_version),
+            timestamp: iso8601_timestamp::Timestamp::now_utc(),
+        })
+    }
+
+    /// Validates `model` like [Self::validate_model] and, if it passed, times it with its preferred weights
+    pub fn test_model(&self, model: ZooModel) -> Result<CoreTestRun, CoreValidationError> {
+        let backend = preferred_backend(&model.weights);
+        let tmp_file = tempfile::Builder::new().suffix(".zip").tempfile()?;
+        model.pack_into(tmp_file.as_file())?;
+        let summary = self.validate_package(tmp_file.path())?;
+        let record = match summary.passed() {
+            true => Some(self.time_package(tmp_file.path(), backend)?),
+            false => None,
+        };
+        Ok(CoreTestRun { summary, record })
+    }
+
+    /// Packs `model` into a temporary .zip and validates it via [Self::validate_package]
+    pub fn validate_model(&self, model: ZooModel) -> Result<CoreValidationSummary, CoreValidationError> {
+        let tmp_file = tempfile::Builder::new().suffix(".zip").tempfile()?;
+        model.pack_into(tmp_file.as_file())?;
+        self.validate_package(tmp_file.path())
+    }
+}
+
+/// The weights format that is timed by [CoreValidator::test_model], favoring the most commonly used ones
+pub fn preferred_backend(weights: &ModelWeights) -> WeightsFormat {
+    if weights.torchscript().is_some() {
+        WeightsFormat::Torchscript
+    } else if weights.pytorch_state_dict().is_some() {
+        WeightsFormat::PytorchStateDict
+    } else if weights.onnx().is_some() {
+        WeightsFormat::Onnx
+    } else if weights.tensorflow_saved_model_bundle().is_some() {
+        WeightsFormat::TensorflowSavedModelBundle
+    } else if weights.keras_hdf5().is_some() {
+        WeightsFormat::KerasHdf5
+    } else {
+        WeightsFormat::TensorflowJs
+    }
+}
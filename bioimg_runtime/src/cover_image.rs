@@ -24,7 +24,7 @@ impl CoverImage {
         &self,
         zip_file: &mut ModelZipWriter<impl Write + Seek>,
     ) -> Result< rdf::CoverImageSource, ModelPackingError> {
-        let test_tensor_zip_path = rdf::FsPath::unique_suffixed("_cover_image.png");
+        let test_tensor_zip_path = zip_file.unique_path("_cover_image.png")?;
         zip_file.write_file(&test_tensor_zip_path, |writer| -> Result<(), ModelPackingError> {
             let encoder = PngEncoder::new(writer);
             Ok(self.0.write_with_encoder(encoder)?)
@@ -0,0 +1,293 @@
+//! An on-disk cache for files fetched over HTTP (e.g. weights hosted on Zenodo or in the zoo), so that
+//! re-importing or re-packing a model doesn't download the same (potentially huge) files again.
+//!
+//! Files are stored by the sha256 of their contents under `blobs/`, and `urls/` maps the hash of each
+//! fetched URL to the hash of the contents it returned, so identical files from different URLs are
+//! only stored once. A URL's entry expires after [DownloadCache::max_age], since the contents
+//! behind a URL (e.g. one pointing at the latest version of a file) can change.
+
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::{Duration, SystemTime};
+
+use bioimg_spec::rdf::HttpUrl;
+use sha2::Digest;
+
+use crate::file_source::{ProgressCallback, TransferProgress};
+
+/// Environment variable that overrides the default location of the cache
+pub const CACHE_DIR_ENV_VAR: &str = "BIOIMG_CACHE_DIR";
+
+/// How long a downloaded URL is trusted to still point at the same contents, unless configured otherwise
+pub const DEFAULT_MAX_AGE: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+static CONFIGURED_CACHE_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+#[derive(thiserror::Error, Debug)]
+pub enum DownloadCacheError{
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error("Error downloading {url}: {reason}")]
+    HttpError{url: String, reason: String},
+    #[error("Unexpected status ({status}) when requesting {url}")]
+    UnexpectedHttpStatus{status: u16, url: HttpUrl},
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DownloadCache{
+    root: PathBuf,
+    max_age: Option<Duration>,
+}
+
+fn sha256_hex(bytes: impl AsRef<[u8]>) -> String{
+    hex_digest(sha2::Sha256::digest(bytes))
+}
+
+fn hex_digest(digest: impl AsRef<[u8]>) -> String{
+    digest.as_ref().iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn dir_size(dir: &Path) -> std::io::Result<u64>{
+    let mut size = 0;
+    for entry in std::fs::read_dir(dir)?{
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        size += if metadata.is_dir(){
+            dir_size(&entry.path())?
+        } else {
+            metadata.len()
+        };
+    }
+    Ok(size)
+}
+
+impl DownloadCache{
+    pub fn new(root: impl Into<PathBuf>) -> Self{
+        Self{root: root.into(), max_age: Some(DEFAULT_MAX_AGE)}
+    }
+
+    /// Sets how long after downloading a URL it gets downloaded again. `None` keeps entries until the cache is cleared
+    pub fn with_max_age(self, max_age: Option<Duration>) -> Self{
+        Self{max_age, ..self}
+    }
+
+    pub fn max_age(&self) -> Option<Duration>{
+        self.max_age
+    }
+
+    /// The cache at the directory set via [DownloadCache::configure], or else at [DownloadCache::default_dir]
+    pub fn global() -> Self{
+        let configured = CONFIGURED_CACHE_DIR.read().unwrap().clone();
+        Self::new(configured.unwrap_or_else(Self::default_dir))
+    }
+
+    /// Changes the directory used by [DownloadCache::global]. `None` resets it to the default
+    pub fn configure(root: Option<PathBuf>){
+        *CONFIGURED_CACHE_DIR.write().unwrap() = root;
+    }
+
+    /// `$BIOIMG_CACHE_DIR` if set, or else the platform's usual [cache location](crate::platform_dirs::cache_dir)
+    pub fn default_dir() -> PathBuf{
+        if let Some(dir) = std::env::var_os(CACHE_DIR_ENV_VAR){
+            return PathBuf::from(dir)
+        }
+        crate::platform_dirs::cache_dir().unwrap_or_else(|| std::env::temp_dir().join("bioimg"))
+    }
+
+    pub fn root(&self) -> &Path{
+        &self.root
+    }
+
+    fn blob_path(&self, content_hash: &str) -> PathBuf{
+        self.root.join("blobs").join(content_hash)
+    }
+
+    fn url_index_path(&self, url: &HttpUrl) -> PathBuf{
+        self.root.join("urls").join(sha256_hex(url.as_str()))
+    }
+
+    fn is_expired(&self, index_path: &Path) -> bool{
+        let Some(max_age) = self.max_age else {
+            return false
+        };
+        let Ok(modified) = std::fs::metadata(index_path).and_then(|metadata| metadata.modified()) else {
+            return true
+        };
+        // a modification time in the future (e.g. after the clock was changed) counts as fresh
+        SystemTime::now().duration_since(modified).is_ok_and(|age| age > max_age)
+    }
+
+    /// The path to the cached contents of `url`, if it has been fetched less than [Self::max_age] ago
+    pub fn lookup(&self, url: &HttpUrl) -> Option<PathBuf>{
+        let index_path = self.url_index_path(url);
+        if self.is_expired(&index_path){
+            return None
+        }
+        let content_hash = std::fs::read_to_string(index_path).ok()?;
+        let blob_path = self.blob_path(content_hash.trim());
+        blob_path.exists().then_some(blob_path)
+    }
+
+    /// Returns the path to a local copy of the contents of `url`, downloading them only if they're not cached yet
+    pub fn fetch(&self, url: &HttpUrl, mut on_progress: Option<ProgressCallback>) -> Result<PathBuf, DownloadCacheError>{
+        if let Some(cached) = self.lookup(url){
+            return Ok(cached)
+        }
+        let http_error = |reason: String| DownloadCacheError::HttpError { url: url.to_string(), reason };
+
+        let response = match ureq::get(url.as_str()).call(){
+            Ok(response) => response,
+            Err(ureq::Error::Status(status, _)) => {
+                return Err(DownloadCacheError::UnexpectedHttpStatus { status, url: url.clone() })
+            },
+            Err(err) => return Err(http_error(err.to_string())),
+        };
+        if response.status() / 100 != 2{
+            return Err(DownloadCacheError::UnexpectedHttpStatus { status: response.status(), url: url.clone() })
+        }
+        let total_bytes = response.header("Content-Length").and_then(|raw| raw.parse().ok());
+        let mut reader = response.into_reader();
+
+        let tmp_dir = self.root.join("tmp");
+        std::fs::create_dir_all(&tmp_dir)?;
+        let tmp_path = tmp_dir.join(uuid::Uuid::new_v4().to_string());
+        let mut tmp_file = std::fs::File::create(&tmp_path)?;
+
+        let mut hasher = sha2::Sha256::new();
+        let mut progress = TransferProgress { transferred_bytes: 0, total_bytes };
+        let mut chunk = vec![0u8; 256 * 1024];
+        let download_result = loop {
+            let num_read = match reader.read(&mut chunk){
+                Ok(0) => break Ok(()),
+                Ok(num_read) => num_read,
+                Err(err) => break Err(err),
+            };
+            hasher.update(&chunk[..num_read]);
+            if let Err(err) = tmp_file.write_all(&chunk[..num_read]){
+                break Err(err)
+            }
+            progress.transferred_bytes += num_read as u64;
+            if let Some(on_progress) = &mut on_progress{
+                on_progress(progress);
+            }
+        };
+        drop(tmp_file);
+        if let Err(err) = download_result{
+            _ = std::fs::remove_file(&tmp_path);
+            return Err(http_error(err.to_string()))
+        }
+
+        let content_hash = hex_digest(hasher.finalize());
+        let blob_path = self.blob_path(&content_hash);
+        std::fs::create_dir_all(blob_path.parent().unwrap())?;
+        if blob_path.exists(){
+            std::fs::remove_file(&tmp_path)?;
+        } else {
+            std::fs::rename(&tmp_path, &blob_path)?;
+        }
+        let index_path = self.url_index_path(url);
+        std::fs::create_dir_all(index_path.parent().unwrap())?;
+        std::fs::write(index_path, &content_hash)?;
+        Ok(blob_path)
+    }
+
+    /// Forgets what `url` was downloaded as, so that the next [Self::fetch] downloads it again, e.g.
+    /// because the cached contents turned out to be wrong. The contents themselves stay until [Self::clear]
+    pub fn invalidate(&self, url: &HttpUrl) -> std::io::Result<()>{
+        match std::fs::remove_file(self.url_index_path(url)){
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+
+    /// How many bytes the cache is currently taking on disk
+    pub fn size_bytes(&self) -> std::io::Result<u64>{
+        if !self.root.exists(){
+            return Ok(0)
+        }
+        dir_size(&self.root)
+    }
+
+    /// Deletes every cached file
+    pub fn clear(&self) -> std::io::Result<()>{
+        for subdir in ["blobs", "urls", "tmp"]{
+            let path = self.root.join(subdir);
+            if path.exists(){
+                std::fs::remove_dir_all(path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Serves `body` with `status` to every request on a local port, counting the requests
+#[cfg(test)]
+fn serve(status: &'static str, body: &'static [u8]) -> (HttpUrl, std::sync::Arc<std::sync::atomic::AtomicUsize>){
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = HttpUrl::try_from(format!("http://{}/weights.pt", listener.local_addr().unwrap())).unwrap();
+    let num_requests = std::sync::Arc::new(AtomicUsize::new(0));
+    let counter = std::sync::Arc::clone(&num_requests);
+    std::thread::spawn(move || for stream in listener.incoming(){
+        let mut stream = stream.unwrap();
+        let mut request = vec![];
+        let mut chunk = [0u8; 1024];
+        while !request.ends_with(b"\r\n\r\n"){
+            let num_read = stream.read(&mut chunk).unwrap();
+            if num_read == 0{
+                break
+            }
+            request.extend_from_slice(&chunk[..num_read]);
+        }
+        counter.fetch_add(1, Ordering::SeqCst);
+        write!(stream, "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", body.len()).unwrap();
+        stream.write_all(body).unwrap();
+    });
+    (url, num_requests)
+}
+
+#[test]
+fn test_cache_hits_and_misses(){
+    use std::sync::atomic::Ordering;
+
+    let (url, num_requests) = serve("200 OK", b"some weights");
+    let cache_dir = tempfile::tempdir().unwrap();
+    let cache = DownloadCache::new(cache_dir.path());
+    assert_eq!(cache.lookup(&url), None);
+
+    let path = cache.fetch(&url, None).unwrap();
+    assert_eq!(std::fs::read(&path).unwrap(), b"some weights");
+    assert_eq!(num_requests.load(Ordering::SeqCst), 1);
+
+    assert_eq!(cache.fetch(&url, None).unwrap(), path);
+    assert_eq!(num_requests.load(Ordering::SeqCst), 1, "second fetch should have been a cache hit");
+
+    cache.invalidate(&url).unwrap();
+    assert_eq!(cache.lookup(&url), None);
+    assert_eq!(cache.fetch(&url, None).unwrap(), path);
+    assert_eq!(num_requests.load(Ordering::SeqCst), 2, "invalidated entries should be downloaded again");
+
+    std::thread::sleep(Duration::from_millis(20));
+    let expiring_cache = DownloadCache::new(cache_dir.path()).with_max_age(Some(Duration::from_millis(10)));
+    assert_eq!(expiring_cache.lookup(&url), None);
+    expiring_cache.fetch(&url, None).unwrap();
+    assert_eq!(num_requests.load(Ordering::SeqCst), 3, "expired entries should be downloaded again");
+    assert_eq!(cache.lookup(&url), Some(path));
+
+    cache.clear().unwrap();
+    assert_eq!(cache.lookup(&url), None);
+}
+
+#[test]
+fn test_unexpected_status_is_not_cached(){
+    let (url, _) = serve("404 Not Found", b"nope");
+    let cache_dir = tempfile::tempdir().unwrap();
+    let cache = DownloadCache::new(cache_dir.path());
+    match cache.fetch(&url, None){
+        Err(DownloadCacheError::UnexpectedHttpStatus { status: 404, url: error_url }) => assert_eq!(error_url, url),
+        other => panic!("Expected an unexpected status error, found {other:?}"),
+    }
+    assert_eq!(cache.lookup(&url), None);
+}
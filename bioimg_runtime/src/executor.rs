@@ -0,0 +1,41 @@
+//! Helpers for futures that run on the app's executors: a `smol` executor on a thread of
+//! their own on native, and the browser's event loop on wasm
+
+/// Suspends the current task once, so that the executor can run something else.
+///
+/// In the browser this waits for a macrotask (`setTimeout`) rather than a microtask, so that the
+/// page also gets a chance to handle input and render a frame before the task resumes.
+pub async fn yield_now(){
+    #[cfg(target_arch = "wasm32")]
+    {
+        let Some(window) = web_sys::window() else {
+            return
+        };
+        let timeout = js_sys::Promise::new(&mut |resolve, _reject| {
+            if window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, 0).is_err(){
+                _ = resolve.call0(&wasm_bindgen::JsValue::NULL);
+            }
+        });
+        _ = wasm_bindgen_futures::JsFuture::from(timeout).await;
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    YieldNow{yielded: false}.await
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+struct YieldNow{
+    yielded: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl std::future::Future for YieldNow{
+    type Output = ();
+    fn poll(mut self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> std::task::Poll<Self::Output> {
+        if self.yielded{
+            return std::task::Poll::Ready(())
+        }
+        self.yielded = true;
+        cx.waker().wake_by_ref();
+        std::task::Poll::Pending
+    }
+}
@@ -23,7 +23,7 @@ fn rdf_file_dump(
     zip_path: rdf::FsPath,
 ) -> Result<rdf::FileReference, ModelPackingError> {
     let mut file = std::fs::File::open(file_path)?;
-    zip_file.write_file(&zip_path, |writer| -> Result<usize, std::io::Error> {
+    zip_file.write_sourced_file(&zip_path, Some(file_path.to_string_lossy().into_owned()), |writer| -> Result<usize, std::io::Error> {
         const READ_BUFFER_SIZE: usize = 16 * 1024 * 1024;
         let mut read_buffer: Vec<u8> = vec![0; READ_BUFFER_SIZE];
         let mut total_bytes_read: usize = 0;
@@ -47,13 +47,15 @@ impl FileExt for Path{
         zip_file: &mut ModelZipWriter<impl Write + Seek>,
         suffix: &str
     ) -> Result<rdf::FileReference, ModelPackingError> {
-        rdf_file_dump(self, zip_file, rdf::FsPath::unique_suffixed(suffix))
+        let zip_path = zip_file.unique_path(suffix)?;
+        rdf_file_dump(self, zip_file, zip_path)
     }
 
     fn rdf_dump(
         &self,
         zip_file: &mut ModelZipWriter<impl Write + Seek>,
     ) -> Result<rdf::FileReference, ModelPackingError> {
-        rdf_file_dump(self, zip_file, rdf::FsPath::unique())
+        let zip_path = zip_file.unique_path("")?;
+        rdf_file_dump(self, zip_file, zip_path)
     }
 }
@@ -4,7 +4,13 @@ use std::io::{Read, Seek, Write};
 use std::borrow::Borrow;
 
 use bioimg_spec::rdf::{self, FileReference, HttpUrl};
+use bioimg_spec::rdf::file_description::Sha256;
+use bioimg_spec::rdf::file_reference::FsPathComponent;
+use sha2::Digest;
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::download_cache::{DownloadCache, DownloadCacheError};
+use crate::size_report::format_size;
 use crate::{zip_archive_ext::SharedZipArchive, zip_writer_ext::ModelZipWriter, zoo_model::ModelPackingError};
 
 #[derive(thiserror::Error, Debug)]
@@ -17,6 +23,84 @@ pub enum FileSourceError{
     HttpError{reason: String}
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+impl From<DownloadCacheError> for ModelPackingError{
+    fn from(err: DownloadCacheError) -> Self {
+        match err{
+            DownloadCacheError::IoError(err) => Self::IoError(err),
+            DownloadCacheError::UnexpectedHttpStatus { status, url } => Self::UnexpectedHttpStatus { status, url },
+            err @ DownloadCacheError::HttpError { .. } => Self::HttpErro { reason: err.to_string() },
+        }
+    }
+}
+
+/// How much of a file has been read or copied so far
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransferProgress{
+    pub transferred_bytes: u64,
+    /// `None` if the size of the file isn't known up front, e.g. for some HTTP downloads
+    pub total_bytes: Option<u64>,
+}
+
+impl TransferProgress{
+    /// A number between 0.0 and 1.0, if the total size of the transfer is known
+    pub fn fraction(&self) -> Option<f32>{
+        match self.total_bytes{
+            Some(0) => Some(1.0),
+            Some(total) => Some(self.transferred_bytes as f32 / total as f32),
+            None => None,
+        }
+    }
+}
+
+/// Called every time more bytes of a file are read, so that long copies can report their progress
+pub type ProgressCallback<'a> = &'a mut dyn FnMut(TransferProgress);
+
+/// Lends `on_progress` out for one more transfer, so that a single callback can report on several files in a row
+pub(crate) fn reborrow_progress<'b>(on_progress: &'b mut Option<ProgressCallback<'_>>) -> Option<ProgressCallback<'b>>{
+    on_progress.as_mut().map(|on_progress| &mut **on_progress as ProgressCallback<'b>)
+}
+
+struct ProgressReader<'a, R>{
+    inner: R,
+    progress: TransferProgress,
+    on_progress: Option<ProgressCallback<'a>>,
+}
+
+impl<'a, R: Read> ProgressReader<'a, R>{
+    fn new(inner: R, total_bytes: Option<u64>, on_progress: Option<ProgressCallback<'a>>) -> Self{
+        Self{inner, progress: TransferProgress { transferred_bytes: 0, total_bytes }, on_progress}
+    }
+}
+
+impl<R: Read> Read for ProgressReader<'_, R>{
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let num_read = self.inner.read(buf)?;
+        self.progress.transferred_bytes += num_read as u64;
+        if let Some(on_progress) = &mut self.on_progress{
+            on_progress(self.progress);
+        }
+        Ok(num_read)
+    }
+}
+
+/// Computes the sha256 of everything that is written through it
+struct HashingWriter<'a>{
+    inner: &'a mut dyn Write,
+    hasher: sha2::Sha256,
+}
+
+impl Write for HashingWriter<'_>{
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let num_written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..num_written]);
+        Ok(num_written)
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum FileSource{
     Data{data: Arc<[u8]>, name: Option<String>},
@@ -50,6 +134,13 @@ impl PartialEq for FileSource{
 }
 impl Eq for FileSource{}
 
+/// The extension of the last segment of a '/'-separated path, so that e.g. the `.zip` in
+/// `some.zip/weights` isn't mistaken for the extension of `weights`
+fn extension_of_last_segment(path: &str) -> Option<String>{
+    let last_segment = path.rsplit('/').next()?;
+    std::path::Path::new(last_segment).extension().map(|ext| ext.to_string_lossy().into_owned())
+}
+
 impl Display for FileSource{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self{
@@ -57,7 +148,7 @@ impl Display for FileSource{
                 if let Some(name) = name {
                     write!(f, "{name} ")?;
                 }
-                write!(f, "{} bytes", data.len()) //FIXME: KB, MB, GB ?
+                write!(f, "{}", format_size(data.len() as u64))
             },
             #[cfg(not(target_arch="wasm32"))]
             Self::LocalFile { path } => write!(f, "{}", path.to_string_lossy()),
@@ -72,39 +163,42 @@ impl FileSource{
     fn rdf_dump(
         &self,
         zip_file: &mut ModelZipWriter<impl Write + Seek>,
-    ) -> Result<rdf::FsPath, ModelPackingError> {
+        on_progress: Option<ProgressCallback>,
+    ) -> Result<(rdf::FsPath, Option<Sha256>), ModelPackingError> {
         let extension = match self{
-            Self::Data{name, ..} => if let Some(name) = name {
-                name.split(".").last().map(|s| s.to_owned())
-            } else {
-                None
-            }
+            Self::Data{name, ..} => name.as_deref().and_then(extension_of_last_segment),
             #[cfg(not(target_arch="wasm32"))]
             Self::LocalFile { path } => path.extension().map(|ex| ex.to_string_lossy().to_string()),
-            Self::FileInZipArchive { inner_path, .. } => {
-                inner_path.split(".").last().map(|s| s.to_owned())
-            },
-            Self::HttpUrl(url) => {
-                url.path().split(".").last().map(|s| s.to_owned())
-            }
-        };
-        let output_inner_path = match extension{
-            Some(ext) => rdf::FsPath::unique_suffixed(&format!(".{ext}")),
-            None => rdf::FsPath::unique(),
+            Self::FileInZipArchive { inner_path, .. } => extension_of_last_segment(inner_path),
+            Self::HttpUrl(url) => extension_of_last_segment(url.path()),
         };
-        zip_file.write_file(&output_inner_path, |writer| -> Result<u64, ModelPackingError>{
-            let copied_bytes: u64 = match self{
+        // extensions that can't be part of a zip entry name (e.g. containing a backslash) are just dropped
+        let suffix = extension
+            .map(|ext| format!(".{ext}"))
+            .filter(|suffix| FsPathComponent::try_from(suffix.clone()).is_ok())
+            .unwrap_or_default();
+        let output_inner_path = zip_file.unique_path(&suffix)?;
+        if zip_file.placeholders_only(){
+            return Ok((output_inner_path, None))
+        }
+        let sha256 = zip_file.write_sourced_file(&output_inner_path, Some(self.to_string()), |writer| -> Result<Sha256, ModelPackingError>{
+            let writer = &mut HashingWriter{inner: writer, hasher: sha2::Sha256::new()};
+            match self{
                 Self::Data{ data, .. } => {
-                    let mut reader = std::io::Cursor::new(&data);
+                    let total_bytes = Some(data.len() as u64);
+                    let mut reader = ProgressReader::new(std::io::Cursor::new(&data), total_bytes, on_progress);
                     std::io::copy(&mut reader, writer)?
                 },
                 #[cfg(not(target_arch="wasm32"))]
                 Self::LocalFile { path } => {
-                    std::io::copy(&mut std::fs::File::open(path)?, writer)?
+                    let file = std::fs::File::open(path)?;
+                    let total_bytes = Some(file.metadata()?.len());
+                    std::io::copy(&mut ProgressReader::new(file, total_bytes, on_progress), writer)?
                 },
                 Self::FileInZipArchive { archive, inner_path } => {
                     archive.with_entry(&inner_path, |entry|{
-                        std::io::copy(entry, writer)
+                        let total_bytes = Some(entry.size());
+                        std::io::copy(&mut ProgressReader::new(entry, total_bytes, on_progress), writer)
                     })??
                 },
                 #[cfg(target_arch = "wasm32")]
@@ -113,38 +207,31 @@ impl FileSource{
                 }
                 #[cfg(not(target_arch = "wasm32"))]
                 Self::HttpUrl(http_url) => {
-                    let response = ureq::get(http_url.as_str()).call()
-                        .map_err(|e| ModelPackingError::HttpErro { reason: e.to_string()})?;
-                    eprintln!("Requesting {http_url} returned result {}", response.status());
-                    if response.status() / 100 != 2{
-                        return Err(ModelPackingError::UnexpectedHttpStatus {
-                            status: response.status(),
-                            url: http_url.as_ref().clone(),
-                        })
-                    }
-                    let mut response_reader = response.into_reader();
-                    std::io::copy(&mut response_reader, writer)? //FIXME!! limit size or whatever
+                    let cached_path = DownloadCache::global().fetch(http_url, on_progress)?;
+                    std::io::copy(&mut std::fs::File::open(cached_path)?, writer)?
                 }
             };
-            Ok(copied_bytes)
+            Ok(Sha256::from_digest(writer.hasher.finalize_reset().into()))
         })?;
-        Ok(output_inner_path)
+        Ok((output_inner_path, Some(sha256)))
     }
 
     pub fn rdf_dump_as_file_reference(
         &self,
         zip_file: &mut ModelZipWriter<impl Write + Seek>,
+        on_progress: Option<ProgressCallback>,
     ) -> Result<rdf::FileReference, ModelPackingError> {
-        let output_inner_path = self.rdf_dump(zip_file)?;
+        let (output_inner_path, _) = self.rdf_dump(zip_file, on_progress)?;
         Ok(rdf::FileReference::Path(output_inner_path))
     }
 
     pub fn dump_as_file_description(
         &self,
         zip_file: &mut ModelZipWriter<impl Write + Seek>,
+        on_progress: Option<ProgressCallback>,
     ) -> Result<rdf::FileDescription, ModelPackingError> {
-        let file_reference = self.rdf_dump_as_file_reference(zip_file)?;
-        Ok(rdf::FileDescription{source: file_reference, sha256: None})
+        let (output_inner_path, sha256) = self.rdf_dump(zip_file, on_progress)?;
+        Ok(rdf::FileDescription{source: rdf::FileReference::Path(output_inner_path), sha256})
     }
 }
 
@@ -174,16 +261,25 @@ impl FileSource{
         })
     }
 
-    pub fn read_to_end(&self, buf: &mut Vec<u8>) -> Result<usize, FileSourceError>{
+    pub fn read_to_end(&self, buf: &mut Vec<u8>, on_progress: Option<ProgressCallback>) -> Result<usize, FileSourceError>{
         match self{
             Self::Data { data, .. } => {
-                let mut reader = std::io::Cursor::new(data);
+                let total_bytes = Some(data.len() as u64);
+                let mut reader = ProgressReader::new(std::io::Cursor::new(data), total_bytes, on_progress);
                 Ok(reader.read_to_end(buf)?)
             }
             #[cfg(not(target_arch="wasm32"))]
-            Self::LocalFile { path } => Ok(std::fs::File::open(path)?.read_to_end(buf)?),
+            Self::LocalFile { path } => {
+                let file = std::fs::File::open(path)?;
+                let total_bytes = Some(file.metadata()?.len());
+                Ok(ProgressReader::new(file, total_bytes, on_progress).read_to_end(buf)?)
+            },
             Self::FileInZipArchive { archive, inner_path } => {
-                let bytes_read = archive.with_entry(&inner_path, |entry| entry.read_to_end(buf))
+                let bytes_read = archive
+                    .with_entry(&inner_path, |entry| {
+                        let total_bytes = Some(entry.size());
+                        ProgressReader::new(entry, total_bytes, on_progress).read_to_end(buf)
+                    })
                     .map_err(|inner| FileSourceError::ZipError { inner, path: inner_path.as_ref().to_owned()})??;
                 Ok(bytes_read)
             },
@@ -193,12 +289,22 @@ impl FileSource{
             },
             #[cfg(not(target_arch = "wasm32"))]
             Self::HttpUrl(http_url) => {
-                let mut response_reader = ureq::get(http_url.as_str())
-                .call()
-                .map_err(|e| FileSourceError::HttpError { reason: e.to_string()})?
-                .into_reader();
-                Ok(response_reader.read_to_end(buf)?)
+                let cached_path = DownloadCache::global().fetch(http_url, on_progress)
+                    .map_err(|e| FileSourceError::HttpError { reason: e.to_string()})?;
+                Ok(std::fs::File::open(cached_path)?.read_to_end(buf)?)
             }
         }
     }
+
+    /// Like [Self::read_to_end], but files in zip archives are decompressed a chunk at a time,
+    /// yielding to the executor in between, so that the web app doesn't freeze on large entries
+    pub async fn read_to_end_async(&self, buf: &mut Vec<u8>, on_progress: Option<ProgressCallback<'_>>) -> Result<usize, FileSourceError>{
+        match self{
+            Self::FileInZipArchive { archive, inner_path } => {
+                archive.read_entry_to_end_async(inner_path, buf, on_progress).await
+                    .map_err(|inner| FileSourceError::ZipError { inner, path: inner_path.as_ref().to_owned()})
+            },
+            _ => self.read_to_end(buf, on_progress),
+        }
+    }
 }
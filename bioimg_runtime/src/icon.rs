@@ -66,7 +66,7 @@ impl Icon{
             Self::Text(emoji) => return Ok(rdf::Icon::Emoji(emoji.clone())),
             Self::Image(icon_img) => icon_img,
         };
-        let test_tensor_zip_path = rdf::FsPath::unique_suffixed("_icon.png");
+        let test_tensor_zip_path = zip_file.unique_path("_icon.png")?;
         zip_file.write_file(&test_tensor_zip_path, |writer| -> Result<(), ModelPackingError> {
             let encoder = PngEncoder::new(writer);
             Ok(icon_img.0.write_with_encoder(encoder)?)
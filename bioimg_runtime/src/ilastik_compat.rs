@@ -0,0 +1,128 @@
+//! Checks whether a model can be used in ilastik's Neural Network workflow, which supports only a
+//! subset of what the spec allows.
+
+use std::borrow::Borrow;
+use std::fmt::Display;
+
+use bioimg_spec::rdf::model::{AxisType, preprocessing::PreprocessingDescr};
+
+use crate::{ModelInterface, ModelWeights, NpyArray};
+
+/// Spatial axes ilastik knows how to handle, by id
+const ILASTIK_SPATIAL_AXES: [&str; 3] = ["x", "y", "z"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IlastikIssueSeverity{
+    /// The model can't be loaded or run in ilastik
+    Error,
+    /// The model runs in ilastik, but may not behave like it does elsewhere
+    Warning,
+}
+
+#[derive(Clone, Debug)]
+pub struct IlastikIssue{
+    pub severity: IlastikIssueSeverity,
+    pub problem: String,
+    /// What the author can do about it
+    pub suggestion: String,
+}
+
+impl Display for IlastikIssue{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}. {}", self.problem, self.suggestion)
+    }
+}
+
+/// Lists everything about a model that stops it from working (well) in ilastik. An empty list
+/// means the model should work in ilastik
+pub fn check_ilastik_compatibility<DATA: Borrow<NpyArray>>(
+    interface: &ModelInterface<DATA>, weights: &ModelWeights
+) -> Vec<IlastikIssue>{
+    let mut issues = vec![];
+    let mut report = |severity, problem: String, suggestion: &str| {
+        issues.push(IlastikIssue{severity, problem, suggestion: suggestion.to_owned()})
+    };
+
+    if weights.pytorch_state_dict().is_none() && weights.torchscript().is_none() && weights.onnx().is_none(){
+        report(
+            IlastikIssueSeverity::Error,
+            "ilastik can only run pytorch_state_dict, torchscript or onnx weights".to_owned(),
+            "Add weights in one of those formats, e.g. by converting the existing ones",
+        );
+    }
+
+    if interface.inputs().len().get() != 1{
+        report(
+            IlastikIssueSeverity::Error,
+            format!("Model has {} inputs, but ilastik feeds a single image into models", interface.inputs().len().get()),
+            "Combine the inputs into a single tensor, e.g. as separate channels",
+        );
+    }
+    for input in interface.inputs().iter(){
+        let tensor_id = &input.tensor_meta.id;
+        let axes = input.tensor_meta.axes();
+        for axis in axes.iter(){
+            let axis_id = axis.id().to_string();
+            match axis.axis_type(){
+                AxisType::Time | AxisType::Index => report(
+                    IlastikIssueSeverity::Error,
+                    format!(
+                        "Input '{tensor_id}' has a {} axis '{axis_id}', which ilastik doesn't support",
+                        axis.axis_type().as_ref().to_lowercase(),
+                    ),
+                    "Only use batch, channel and space axes in the input",
+                ),
+                AxisType::Space if !ILASTIK_SPATIAL_AXES.contains(&axis_id.as_str()) => report(
+                    IlastikIssueSeverity::Error,
+                    format!("Input '{tensor_id}' has a space axis named '{axis_id}'"),
+                    "Name space axes 'x', 'y' and (for 3D data) 'z'",
+                ),
+                _ => (),
+            }
+        }
+        for required in ["x", "y"]{
+            if !axes.iter().any(|axis| axis.id().to_string() == required){
+                report(
+                    IlastikIssueSeverity::Error,
+                    format!("Input '{tensor_id}' has no '{required}' axis"),
+                    "ilastik processes 2D or 3D images, which need at least an 'x' and a 'y' space axis",
+                );
+            }
+        }
+        if axes.iter().filter(|axis| axis.axis_type() == AxisType::Channel).count() > 1{
+            report(
+                IlastikIssueSeverity::Error,
+                format!("Input '{tensor_id}' has more than one channel axis"),
+                "Merge the channel axes into one",
+            );
+        }
+        for step in input.tensor_meta.preprocessing(){
+            if matches!(step, PreprocessingDescr::ZeroMeanUnitVariance(_) | PreprocessingDescr::ScaleRange(_)){
+                report(
+                    IlastikIssueSeverity::Warning,
+                    format!("Input '{tensor_id}' is normalized with '{step}', whose statistics ilastik computes per tile"),
+                    "Results may differ between tiles; consider fixed_zero_mean_unit_variance with dataset-wide values",
+                );
+            }
+        }
+    }
+
+    if interface.outputs().len().get() > 1{
+        report(
+            IlastikIssueSeverity::Warning,
+            format!("Model has {} outputs, but ilastik only shows the first one", interface.outputs().len().get()),
+            "Put the most relevant output first",
+        );
+    }
+    for output in interface.outputs().iter(){
+        let axes = output.tensor_meta.axes();
+        if !["x", "y"].iter().all(|required| axes.iter().any(|axis| axis.id().to_string() == *required)){
+            report(
+                IlastikIssueSeverity::Error,
+                format!("Output '{}' lacks an 'x' or 'y' axis", output.tensor_meta.id),
+                "ilastik displays outputs as images, which need 'x' and 'y' space axes",
+            );
+        }
+    }
+    issues
+}
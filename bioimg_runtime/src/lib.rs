@@ -1,6 +1,11 @@
+pub mod attachment;
 pub mod axis_size_resolver;
 pub mod cover_image;
+#[cfg(not(target_arch="wasm32"))]
+pub mod core_validator;
 pub mod icon;
+pub mod ilastik_compat;
+pub mod lint;
 pub mod file_reference;
 pub mod model_interface;
 pub mod model_record;
@@ -8,15 +13,29 @@ pub mod npy_array;
 pub mod package_component;
 pub mod zip_writer_ext;
 pub mod zoo_model;
+pub mod zoo_model_builder;
 pub mod model_weights;
 pub mod conda_env;
 pub mod file_source;
+#[cfg(not(target_arch="wasm32"))]
+pub mod download_cache;
+#[cfg(not(target_arch="wasm32"))]
+pub mod platform_dirs;
 pub mod zip_archive_ext;
+pub mod executor;
+pub mod size_report;
+pub mod test_results;
+pub mod tensor_stats;
+#[cfg(not(target_arch="wasm32"))]
+pub mod weights_converter;
 
+pub use attachment::Attachment;
 pub use cover_image::{CoverImage, CoverImageParsingError};
 pub use icon::{Icon, IconImage};
 pub use model_interface::{ModelInterface, TensorValidationError};
 pub use npy_array::NpyArray;
 pub use model_weights::{WeightsBase, KerasHdf5Weights, TorchscriptWeights, ModelWeights};
 pub use conda_env::CondaEnv;
-pub use file_source::FileSource;
+pub use file_source::{FileSource, ProgressCallback, TransferProgress};
+pub use size_report::{SizeReport, SizeReportEntry, SizeReportEntryKind};
+pub use test_results::TestRunRecord;
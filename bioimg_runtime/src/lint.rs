@@ -0,0 +1,157 @@
+//! Best-practice checks for a [ZooModel] that go beyond what the spec requires, mirroring what the
+//! bioimage.io reviewers look for before accepting a model into the zoo.
+
+use std::fmt::Display;
+
+use crate::zoo_model::ZooModel;
+
+/// Fewer tags than this make a model hard to find on bioimage.io
+pub const MIN_RECOMMENDED_TAGS: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LintRule{
+    DocumentationValidationSection,
+    EnoughTags,
+    CoversPresent,
+    VersionSet,
+    MaintainersPresent,
+    MaintainerGithubHandles,
+}
+
+impl LintRule{
+    pub const ALL: [LintRule; 6] = [
+        Self::DocumentationValidationSection,
+        Self::EnoughTags,
+        Self::CoversPresent,
+        Self::VersionSet,
+        Self::MaintainersPresent,
+        Self::MaintainerGithubHandles,
+    ];
+
+    /// A short, stable identifier of this rule, e.g. for referring to it from the command line
+    pub fn code(&self) -> &'static str{
+        match self{
+            Self::DocumentationValidationSection => "documentation-validation-section",
+            Self::EnoughTags => "enough-tags",
+            Self::CoversPresent => "covers-present",
+            Self::VersionSet => "version-set",
+            Self::MaintainersPresent => "maintainers-present",
+            Self::MaintainerGithubHandles => "maintainer-github-handles",
+        }
+    }
+
+    /// What this rule expects of a model
+    pub fn description(&self) -> &'static str{
+        match self{
+            Self::DocumentationValidationSection => "The documentation has a 'Validation' section describing how the model was evaluated",
+            Self::EnoughTags => "The model has at least 3 tags",
+            Self::CoversPresent => "The model has at least one cover image",
+            Self::VersionSet => "The model has a version",
+            Self::MaintainersPresent => "The model has at least one maintainer",
+            Self::MaintainerGithubHandles => "All maintainers have valid GitHub user names",
+        }
+    }
+}
+
+impl Display for LintRule{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LintWarning{
+    pub rule: LintRule,
+    pub message: String,
+}
+
+impl Display for LintWarning{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.rule, self.message)
+    }
+}
+
+/// The parts of a model that the [LintRule]s look at. Can be filled in from a draft that is
+/// not a valid [ZooModel] yet, e.g. to show authors how far along they are
+#[derive(Clone, Debug, Default)]
+pub struct LintSubject<'a>{
+    pub documentation: &'a str,
+    pub num_tags: usize,
+    pub num_covers: usize,
+    pub has_version: bool,
+    pub maintainer_github_users: Vec<&'a str>,
+}
+
+impl<'a> From<&'a ZooModel> for LintSubject<'a>{
+    fn from(model: &'a ZooModel) -> Self {
+        Self{
+            documentation: &model.documentation,
+            num_tags: model.tags.len(),
+            num_covers: model.covers.len(),
+            has_version: model.version.is_some(),
+            maintainer_github_users: model.maintainers.iter()
+                .map(|maintainer| maintainer.github_user.as_str())
+                .collect(),
+        }
+    }
+}
+
+/// Whether `documentation` has a markdown heading mentioning validation, e.g. `## Validation`
+fn has_validation_section(documentation: &str) -> bool{
+    documentation.lines()
+        .map(|line| line.trim_start())
+        .filter(|line| line.starts_with('#'))
+        .any(|heading| heading.to_lowercase().contains("validation"))
+}
+
+/// GitHub user names are up to 39 alphanumeric characters or single hyphens, not starting or ending with a hyphen
+fn is_valid_github_user(user: &str) -> bool{
+    !user.is_empty()
+        && user.len() <= 39
+        && user.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        && !user.starts_with('-')
+        && !user.ends_with('-')
+        && !user.contains("--")
+}
+
+/// Runs all [LintRule]s on `model`
+pub fn lint(model: &ZooModel) -> Vec<LintWarning>{
+    lint_subject(&LintSubject::from(model))
+}
+
+/// Runs all [LintRule]s on the relevant parts of a model
+pub fn lint_subject(subject: &LintSubject<'_>) -> Vec<LintWarning>{
+    let mut warnings = vec![];
+    let mut warn = |rule: LintRule, message: String| warnings.push(LintWarning{rule, message});
+
+    if !has_validation_section(subject.documentation){
+        warn(
+            LintRule::DocumentationValidationSection,
+            "Documentation has no 'Validation' section describing how the model was evaluated".to_owned(),
+        );
+    }
+    if subject.num_tags < MIN_RECOMMENDED_TAGS{
+        warn(
+            LintRule::EnoughTags,
+            format!("Model has {} tags; at least {MIN_RECOMMENDED_TAGS} make it easier to find", subject.num_tags),
+        );
+    }
+    if subject.num_covers == 0{
+        warn(LintRule::CoversPresent, "Model has no cover images".to_owned());
+    }
+    if !subject.has_version{
+        warn(LintRule::VersionSet, "Model has no version".to_owned());
+    }
+    if subject.maintainer_github_users.is_empty(){
+        warn(LintRule::MaintainersPresent, "Model has no maintainers to contact about issues".to_owned());
+    }
+    for github_user in subject.maintainer_github_users.iter().copied(){
+        if !is_valid_github_user(github_user){
+            warn(
+                LintRule::MaintainerGithubHandles,
+                format!("Maintainer '{github_user}' does not look like a GitHub user name"),
+            );
+        }
+    }
+    warnings
+}
@@ -1,5 +1,5 @@
 use std::borrow::Borrow;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::io::{Seek, Write};
 use std::sync::Arc;
@@ -16,9 +16,12 @@ use crate::zip_archive_ext::SharedZipArchive;
 use crate::zip_writer_ext::ModelZipWriter;
 use crate::zoo_model::ModelPackingError;
 use crate::FileSource;
-use bioimg_spec::rdf::model::axis_size::QualifiedAxisId;
-use bioimg_spec::rdf::model::{AnyAxisSize, InputAxis, OutputAxis, PreprocessingDescr};
+use bioimg_spec::rdf::model::axis_size::{QualifiedAxisId, ResolvedAxisSize};
+use bioimg_spec::rdf::model::{AnyAxisSize, InputAxis, OutputAxis, ParameterizedAxisSize, PreprocessingDescr};
 use bioimg_spec::rdf::model::{self as modelrdf, TensorId};
+use bioimg_spec::rdf::model::data_type::DataType;
+use bioimg_spec::rdf::model::tensor_data_descr::TensorDataDescr;
+use bioimg_spec::util::SingleOrMultiple;
 
 use super::axis_size_resolver::AxisSizeResolutionError;
 
@@ -59,7 +62,7 @@ impl InputSlot<Arc<NpyArray>> {
         &self,
         zip_file: &mut ModelZipWriter<impl Write + Seek>,
     ) -> Result<modelrdf::InputTensorDescr, ModelPackingError> {
-        let test_tensor_zip_path = rdf::FsPath::unique_suffixed(&format!("_{}_test_tensor.npy", self.tensor_meta.id));
+        let test_tensor_zip_path = zip_file.unique_path(&format!("_{}_test_tensor.npy", self.tensor_meta.id))?;
         zip_file.write_file(&test_tensor_zip_path, |writer| self.test_tensor.write_npy(writer))?;
         Ok(modelrdf::input_tensor::InputTensorDescr{
             meta: self.tensor_meta.clone(),
@@ -75,7 +78,7 @@ impl InputSlot<Arc<NpyArray>> {
         rdf: modelrdf::InputTensorDescr, archive: SharedZipArchive
     ) -> Result<Self, ModelInterfaceLoadingError>{
         let mut test_tensor_raw_data = vec![];
-        FileSource::from_rdf_file_descr(archive, &rdf.test_tensor)?.read_to_end(&mut test_tensor_raw_data)?;
+        FileSource::from_rdf_file_descr(archive, &rdf.test_tensor)?.read_to_end(&mut test_tensor_raw_data, None)?;
         //FIXME: there's another copy inside try_load, i think
         let test_tensor = NpyArray::try_load(&mut test_tensor_raw_data.as_slice())?;
 
@@ -134,7 +137,7 @@ impl OutputSlot<Arc<NpyArray>> {
         &self,
         zip_file: &mut ModelZipWriter<impl Write + Seek>,
     ) -> Result<modelrdf::OutputTensorDescr, ModelPackingError> {
-        let test_tensor_zip_path = rdf::FsPath::unique_suffixed(&format!("_{}_test_tensor.npy", self.tensor_meta.id));
+        let test_tensor_zip_path = zip_file.unique_path(&format!("_{}_test_tensor.npy", self.tensor_meta.id))?;
         zip_file.write_file(&test_tensor_zip_path, |writer| self.test_tensor.write_npy(writer))?;
         Ok(modelrdf::OutputTensorDescr{
             metadata: self.tensor_meta.clone(),
@@ -150,7 +153,7 @@ impl OutputSlot<Arc<NpyArray>> {
         rdf: modelrdf::OutputTensorDescr, archive: SharedZipArchive,
     ) -> Result<Self, ModelInterfaceLoadingError>{
         let mut test_tensor_raw_data = vec![];
-        FileSource::from_rdf_file_descr(archive, &rdf.test_tensor)?.read_to_end(&mut test_tensor_raw_data)?;
+        FileSource::from_rdf_file_descr(archive, &rdf.test_tensor)?.read_to_end(&mut test_tensor_raw_data, None)?;
         //FIXME: there's another copy inside try_load, i think
         let test_tensor = NpyArray::try_load(&mut test_tensor_raw_data.as_slice())?;
         Ok(Self{
@@ -208,7 +211,62 @@ pub enum TensorValidationError {
     #[error("Empty model interface outputs")]
     EmptyOutputs,
     #[error("No tensor with ID {reference}")]
-    InvalidTensorReference{reference: TensorId}
+    InvalidTensorReference{reference: TensorId},
+    #[error("Tensor '{tensor_id}' is described as {declared}, but its test tensor is {test_tensor}")]
+    MismatchedDataType{
+        tensor_id: TensorId,
+        declared: DataType,
+        test_tensor: DataType,
+    },
+}
+
+/// Checks the element type of a test tensor against the data type(s) declared in its description.
+/// Tensors without a data description are accepted with any element type
+fn validate_data_type(
+    tensor_id: &TensorId,
+    data: Option<&SingleOrMultiple<TensorDataDescr>>,
+    test_tensor: &NpyArray,
+) -> Result<(), TensorValidationError>{
+    let declared_types: Vec<DataType> = match data{
+        None => return Ok(()),
+        Some(SingleOrMultiple::Single(descr)) => vec![descr.data_type()],
+        Some(SingleOrMultiple::Multiple(descrs)) => descrs.iter().map(|descr| descr.data_type()).collect(),
+    };
+    let test_tensor_type = test_tensor.data_type();
+    match declared_types.into_iter().find(|declared| *declared != test_tensor_type){
+        Some(declared) => Err(TensorValidationError::MismatchedDataType {
+            tensor_id: tensor_id.clone(),
+            declared,
+            test_tensor: test_tensor_type,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// The widest element type a tensor can have according to its data description. Tensors without one
+/// default to float32, as in the spec
+fn widest_data_type(data: Option<&SingleOrMultiple<TensorDataDescr>>) -> DataType{
+    match data{
+        None => DataType::Float32,
+        Some(SingleOrMultiple::Single(descr)) => descr.data_type(),
+        Some(SingleOrMultiple::Multiple(descrs)) => descrs.iter()
+            .map(|descr| descr.data_type())
+            .max_by_key(|data_type| data_type.size_in_bytes())
+            .unwrap_or(DataType::Float32),
+    }
+}
+
+/// Estimated memory taken by one tile of all inputs and of all outputs of a model
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TileFootprint{
+    pub input_bytes: u64,
+    pub output_bytes: u64,
+}
+
+impl TileFootprint{
+    pub fn total_bytes(&self) -> u64{
+        self.input_bytes + self.output_bytes
+    }
 }
 
 #[allow(dead_code)]
@@ -242,6 +300,62 @@ impl<DATA: Borrow<NpyArray>> ModelInterface<DATA> {
     pub fn outputs(&self) -> &rdf::NonEmptyList<OutputSlot<DATA>>{
         &self.outputs
     }
+    /// Ids of the input tensors, followed by those of the output tensors
+    pub fn tensor_ids(&self) -> Vec<&TensorId>{
+        self.inputs.iter().map(|inp| &inp.tensor_meta.id)
+            .chain(self.outputs.iter().map(|out| &out.tensor_meta.id))
+            .collect()
+    }
+    fn resolve_sizes(&self) -> Result<HashMap<QualifiedAxisId, ResolvedAxisSize>, AxisSizeResolutionError>{
+        let axis_sizes: Vec<(QualifiedAxisId, AnyAxisSize)> = self.inputs.qual_id_sizes()
+            .chain(self.outputs.qual_id_sizes())
+            .collect();
+        SlotResolver::new(axis_sizes)?.solve()
+    }
+
+    /// Whether any axis has a size of the form `min + n * step`
+    pub fn has_parameterized_axes(&self) -> bool{
+        self.resolve_sizes()
+            .map(|sizes| sizes.values().any(|size| matches!(size, ResolvedAxisSize::Parameterized(_))))
+            .unwrap_or(false)
+    }
+
+    /// Estimates the memory of one tile when every parameterized axis is `min + n * step` and batch
+    /// axes have size 1. Offsets and scales of axes that reference other axes are not taken into account
+    pub fn tile_footprint(&self, n: usize) -> Result<TileFootprint, AxisSizeResolutionError>{
+        let sizes = self.resolve_sizes()?;
+        let extent = |qual_id: QualifiedAxisId| -> u64 {
+            match sizes.get(&qual_id){
+                Some(ResolvedAxisSize::Fixed(size)) => usize::from(*size) as u64,
+                Some(ResolvedAxisSize::Parameterized(ParameterizedAxisSize{min, step})) => {
+                    (usize::from(*min) + n * usize::from(*step)) as u64
+                },
+                None => 1, // batch
+            }
+        };
+        let tensor_bytes = |qual_ids: Vec<QualifiedAxisId>, data: Option<&SingleOrMultiple<TensorDataDescr>>| -> u64 {
+            let num_elements: u64 = qual_ids.into_iter().map(&extent).product();
+            num_elements * widest_data_type(data).size_in_bytes() as u64
+        };
+        let input_bytes = self.inputs.iter()
+            .map(|slot|{
+                let qual_ids = slot.tensor_meta.axes().iter()
+                    .map(|axis| QualifiedAxisId{tensor_id: slot.tensor_meta.id.clone(), axis_id: axis.id()})
+                    .collect();
+                tensor_bytes(qual_ids, slot.tensor_meta.data.as_ref())
+            })
+            .sum();
+        let output_bytes = self.outputs.iter()
+            .map(|slot|{
+                let qual_ids = slot.tensor_meta.axes().iter()
+                    .map(|axis| QualifiedAxisId{tensor_id: slot.tensor_meta.id.clone(), axis_id: axis.id()})
+                    .collect();
+                tensor_bytes(qual_ids, slot.tensor_meta.data.as_ref())
+            })
+            .sum();
+        Ok(TileFootprint{input_bytes, output_bytes})
+    }
+
     pub fn try_build(inputs: Vec<InputSlot<DATA>>, outputs: Vec<OutputSlot<DATA>>) -> Result<Self, TensorValidationError> {
         let inputs = rdf::NonEmptyList::try_from(inputs).map_err(|_| TensorValidationError::EmptyInputs)?;
         let outputs = rdf::NonEmptyList::try_from(outputs).map_err(|_| TensorValidationError::EmptyOutputs)?;
@@ -269,6 +383,7 @@ impl<DATA: Borrow<NpyArray>> ModelInterface<DATA> {
 
         macro_rules! validate_resolution {( $slots:ident ) => {
             for slot in $slots.iter(){
+                validate_data_type(&slot.tensor_meta.id, slot.tensor_meta.data.as_ref(), slot.test_tensor.borrow())?;
                 let test_tensor_shape = slot.test_tensor.borrow().shape();
                 let mut test_tensor_dims = test_tensor_shape.iter().enumerate();
                 for axis in slot.tensor_meta.axes().iter(){
@@ -323,3 +438,34 @@ impl<DATA: Borrow<NpyArray>> ModelInterface<DATA> {
         Ok(Self{inputs, outputs})
     }
 }
+
+#[test]
+fn test_tile_footprint(){
+    use serde_json::json;
+
+    let input = InputSlot{
+        tensor_meta: serde_json::from_value(json!({
+            "id": "raw",
+            "description": "Raw input",
+            "axes": [{"type": "batch"}, {"type": "channel", "channel_names": ["r", "g"]}, {"type": "space", "id": "x", "size": {"min": 16, "step": 8}}],
+        })).unwrap(),
+        test_tensor: Arc::new(NpyArray::ArrayF32(ndarray::ArrayD::zeros(ndarray::IxDyn(&[1, 2, 16])))),
+    };
+    let output = OutputSlot{
+        tensor_meta: serde_json::from_value(json!({
+            "id": "mask",
+            "description": "Foreground mask",
+            "axes": [{"type": "batch"}, {"type": "space", "id": "x", "size": {"tensor_id": "raw", "axis_id": "x"}}],
+            "data": {"type": "uint8"},
+        })).unwrap(),
+        test_tensor: Arc::new(NpyArray::ArrayU8(ndarray::ArrayD::zeros(ndarray::IxDyn(&[1, 16])))),
+    };
+    let interface = ModelInterface::try_build(vec![input], vec![output]).unwrap();
+    assert!(interface.has_parameterized_axes());
+
+    // 2 channels of float32 in, a single uint8 channel out
+    assert_eq!(interface.tile_footprint(0).unwrap(), TileFootprint{input_bytes: 2 * 16 * 4, output_bytes: 16});
+    let footprint = interface.tile_footprint(2).unwrap();
+    assert_eq!(footprint, TileFootprint{input_bytes: 2 * 32 * 4, output_bytes: 32});
+    assert_eq!(footprint.total_bytes(), 288);
+}
@@ -5,6 +5,7 @@ use bioimg_spec::rdf::model as modelrdf;
 
 use crate::{conda_env::CondaEnvLoadingError, zip_archive_ext::{RdfFileReferenceReadError, SharedZipArchive}};
 use crate::{conda_env::CondaEnv, file_source::FileSourceError, zip_writer_ext::ModelZipWriter, zoo_model::ModelPackingError, FileSource};
+use crate::file_source::{reborrow_progress, ProgressCallback};
 
 #[derive(thiserror::Error, Debug)]
 pub enum ModelWeightsError{
@@ -71,26 +72,27 @@ impl ModelWeights{
         })
     }
 
+    /// Writes every weights file into `zip_file`, reporting how much of each was copied so far to `on_progress`
     pub fn rdf_dump(
-        &self, zip_file: &mut ModelZipWriter<impl Write + Seek>
+        &self, zip_file: &mut ModelZipWriter<impl Write + Seek>, mut on_progress: Option<ProgressCallback>
     ) -> Result<modelrdf::WeightsDescr, ModelPackingError> {
         let keras_hdf5 = self.keras_hdf5.as_ref().map(|weights|{
-            weights.rdf_dump(zip_file)
+            weights.rdf_dump(zip_file, reborrow_progress(&mut on_progress))
         }).transpose()?;
         let onnx = self.onnx.as_ref().map(|weights|{
-            weights.rdf_dump(zip_file)
+            weights.rdf_dump(zip_file, reborrow_progress(&mut on_progress))
         }).transpose()?;
         let pytorch_state_dict = self.pytorch_state_dict.as_ref().map(|weights|{
-            weights.rdf_dump(zip_file)
+            weights.rdf_dump(zip_file, reborrow_progress(&mut on_progress))
         }).transpose()?;
         let tensorflow_js = self.tensorflow_js.as_ref().map(|weights|{
-            weights.rdf_dump(zip_file)
+            weights.rdf_dump(zip_file, reborrow_progress(&mut on_progress))
         }).transpose()?;
         let tensorflow_saved_model_bundle = self.tensorflow_saved_model_bundle.as_ref().map(|weights|{
-            weights.rdf_dump(zip_file)
+            weights.rdf_dump(zip_file, reborrow_progress(&mut on_progress))
         }).transpose()?;
         let torchscript = self.torchscript.as_ref().map(|weights|{
-            weights.rdf_dump(zip_file)
+            weights.rdf_dump(zip_file, reborrow_progress(&mut on_progress))
         }).transpose()?;
         Ok(modelrdf::WeightsDescr::try_from(modelrdf::MaybeSomeWeightsDescr{
             keras_hdf5,
@@ -133,6 +135,8 @@ impl ModelWeights{
 pub struct WeightsBase{
     pub source: FileSource,
     pub authors: Option<Vec<rdf::Author2>>,
+    /// The weights these were converted from, if any
+    pub parent: Option<modelrdf::WeightsFormat>,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -149,11 +153,12 @@ impl WeightsBase{
     fn rdf_dump(
         &self,
         zip_file: &mut ModelZipWriter<impl Write + Seek>,
+        on_progress: Option<ProgressCallback>,
     ) -> Result<modelrdf::WeightsDescrBase, ModelPackingError> {
         Ok(modelrdf::WeightsDescrBase{
-            source: self.source.rdf_dump_as_file_reference(zip_file)?,
+            source: self.source.rdf_dump_as_file_reference(zip_file, on_progress)?,
             authors: self.authors.clone(),
-            parent: None, //FIXME
+            parent: self.parent.clone(),
             sha256: None, //FIXME
         })
     }
@@ -164,6 +169,7 @@ impl WeightsBase{
     ) -> Result<Self, ModelWeightsLoadingError>{
         Ok(Self{
             authors: rdf_weights_base.authors,
+            parent: rdf_weights_base.parent,
             source: FileSource::from_rdf_file_reference(archive, &rdf_weights_base.source)?
         })
     }
@@ -176,9 +182,9 @@ pub struct KerasHdf5Weights{
 }
 impl KerasHdf5Weights{
     fn rdf_dump(
-        &self, zip_file: &mut ModelZipWriter<impl Write + Seek>
+        &self, zip_file: &mut ModelZipWriter<impl Write + Seek>, on_progress: Option<ProgressCallback>
     ) -> Result<modelrdf::KerasHdf5WeightsDescr, ModelPackingError> {
-        let weights = self.weights.rdf_dump(zip_file)?;
+        let weights = self.weights.rdf_dump(zip_file, on_progress)?;
         Ok(modelrdf::KerasHdf5WeightsDescr{
             base: weights,
             tensorflow_version: self.tensorflow_version.clone(),
@@ -204,9 +210,9 @@ pub struct OnnxWeights{
 
 impl OnnxWeights{
     fn rdf_dump(
-        &self, zip_file: &mut ModelZipWriter<impl Write + Seek>
+        &self, zip_file: &mut ModelZipWriter<impl Write + Seek>, on_progress: Option<ProgressCallback>
     ) -> Result<modelrdf::OnnxWeightsDescr, ModelPackingError> {
-        let weights = self.weights.rdf_dump(zip_file)?;
+        let weights = self.weights.rdf_dump(zip_file, on_progress)?;
         Ok(modelrdf::OnnxWeightsDescr{
             base: weights,
             opset_version: self.opset_version.clone(),
@@ -230,7 +236,7 @@ pub enum PytorchArch{
     FromFile{
         file_source: FileSource,
         callable: rdf::Identifier,
-        kwargs: serde_json::Map<String, serde_json::Value>,
+        kwargs: modelrdf::weights::PythonKwargs,
     }
 }
 
@@ -241,7 +247,7 @@ impl PytorchArch{
     ) -> Result<modelrdf::PytorchArchitectureDescr, ModelPackingError> {
         match self{
             Self::FromFile { file_source: file_descr, callable, kwargs } => {
-                let file_descr = file_descr.dump_as_file_description(zip_file)?;
+                let file_descr = file_descr.dump_as_file_description(zip_file, None)?;
                 Ok(modelrdf::PytorchArchitectureDescr::FromFileDescr(
                     modelrdf::weights::PyTorchArchitectureFromFileDescr{
                         file_descr,
@@ -282,10 +288,10 @@ pub struct PytorchStateDictWeights{
 
 impl PytorchStateDictWeights{
     fn rdf_dump(
-        &self, zip_file: &mut ModelZipWriter<impl Write + Seek>
+        &self, zip_file: &mut ModelZipWriter<impl Write + Seek>, on_progress: Option<ProgressCallback>
     ) -> Result<modelrdf::PytorchStateDictWeightsDescr, ModelPackingError> {
         Ok(modelrdf::PytorchStateDictWeightsDescr{
-            base: self.weights.rdf_dump(zip_file)?,
+            base: self.weights.rdf_dump(zip_file, on_progress)?,
             architecture: self.architecture.rdf_dump(zip_file)?,
             pytorch_version: self.pytorch_version.clone(),
             dependencies: self.dependencies.as_ref().map(|env|{
@@ -320,10 +326,10 @@ pub struct TensorflowJsWeights{
 }
 impl TensorflowJsWeights{
     fn rdf_dump(
-        &self, zip_file: &mut ModelZipWriter<impl Write + Seek>
+        &self, zip_file: &mut ModelZipWriter<impl Write + Seek>, on_progress: Option<ProgressCallback>
     ) -> Result<modelrdf::TensorflowJsWeightsDescr, ModelPackingError> {
         Ok(modelrdf::TensorflowJsWeightsDescr{
-            base: self.weights.rdf_dump(zip_file)?,
+            base: self.weights.rdf_dump(zip_file, on_progress)?,
             tensorflow_version: self.tensorflow_version.clone(),
         })
     }
@@ -349,10 +355,10 @@ pub struct TensorflowSavedModelBundleWeights{
 
 impl TensorflowSavedModelBundleWeights{
     fn rdf_dump(
-        &self, zip_file: &mut ModelZipWriter<impl Write + Seek>
+        &self, zip_file: &mut ModelZipWriter<impl Write + Seek>, on_progress: Option<ProgressCallback>
     ) -> Result<modelrdf::TensorflowSavedModelBundleWeightsDescr, ModelPackingError> {
         Ok(modelrdf::TensorflowSavedModelBundleWeightsDescr{
-            base: self.weights.rdf_dump(zip_file)?,
+            base: self.weights.rdf_dump(zip_file, on_progress)?,
             tensorflow_version: self.tensorflow_version.clone(),
             dependencies: self.dependencies.as_ref().map(|env|{
                 env.rdf_dump(zip_file)
@@ -383,10 +389,10 @@ pub struct TorchscriptWeights {
 
 impl TorchscriptWeights {
     fn rdf_dump(
-        &self, zip_file: &mut ModelZipWriter<impl Write + Seek>
+        &self, zip_file: &mut ModelZipWriter<impl Write + Seek>, on_progress: Option<ProgressCallback>
     ) -> Result<modelrdf::TorchscriptWeightsDescr, ModelPackingError> {
         Ok(modelrdf::TorchscriptWeightsDescr{
-            base: self.weights.rdf_dump(zip_file)?,
+            base: self.weights.rdf_dump(zip_file, on_progress)?,
             pytorch_version: self.pytorch_version.clone(),
         })
     }
@@ -1,3 +1,5 @@
+use bioimg_spec::rdf::model::data_type::DataType;
+use ndarray::ShapeBuilder;
 use ndarray_npy::{ReadNpyError, WriteNpyExt, ReadNpyExt};
 use std::{
     io::{Read, Seek},
@@ -14,6 +16,148 @@ impl Display for UnsupportedNumpyElementType {
     }
 }
 
+#[derive(thiserror::Error, Debug)]
+pub enum NpyViewError {
+    #[error("Array has elements of type {found}, not {requested}")]
+    WrongElementType{requested: &'static str, found: &'static str},
+    #[error("Array with shape {shape:?} can't be viewed with the requested dimensionality: {source}")]
+    WrongDimensionality{shape: Vec<usize>, source: ndarray::ShapeError},
+}
+
+/// The element types an [NpyArray] can hold
+pub trait NpyElement: Clone + 'static {
+    /// The numpy name of this type, e.g. `float32`
+    fn dtype_name() -> &'static str;
+    fn view_of(array: &NpyArray) -> Option<ndarray::ArrayViewD<'_, Self>>;
+    fn into_npy_array(array: ndarray::ArrayD<Self>) -> NpyArray;
+}
+
+fn numpy_dtype_name(rust_type_name: &'static str) -> &'static str {
+    match rust_type_name {
+        "u8" => "uint8", "i8" => "int8", "u16" => "uint16", "i16" => "int16",
+        "u32" => "uint32", "i32" => "int32", "u64" => "uint64", "i64" => "int64",
+        "f32" => "float32", "f64" => "float64",
+        other => other,
+    }
+}
+
+/// Conversion of npy elements to f64, with `true`/`false` becoming 1.0/0.0
+trait NpyElementExt: Copy{
+    fn as_f64(self) -> f64;
+}
+
+macro_rules! impl_NpyElementExt_for_number {( $($element_type:ident),+ ) => {$(
+    impl NpyElementExt for $element_type{
+        fn as_f64(self) -> f64{
+            self as f64
+        }
+    }
+)+};}
+
+impl_NpyElementExt_for_number!(u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+
+impl NpyElementExt for bool{
+    fn as_f64(self) -> f64{
+        if self { 1.0 } else { 0.0 }
+    }
+}
+
+/// The parts of an `.npy` header needed to interpret the data that follows it
+struct NpyHeader{
+    /// numpy's type descriptor, e.g. `<f4`
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+    /// Where the array data starts, counting from the start of the file
+    data_offset: usize,
+}
+
+impl NpyHeader{
+    /// Parses the header at the start of `data`. Returns `None` for anything unusual (e.g. structured
+    /// dtypes), which is then left for `ndarray_npy` to deal with
+    fn parse(data: &[u8]) -> Option<Self>{
+        fn header_value<'h>(header: &'h str, key: &str) -> Option<&'h str>{
+            let key_pos = header.find(&format!("'{key}'")).or_else(|| header.find(&format!("\"{key}\"")))?;
+            let after_key = &header[key_pos + key.len() + 2..];
+            Some(after_key.trim_start().strip_prefix(':')?.trim_start())
+        }
+
+        let rest = data.strip_prefix(b"\x93NUMPY")?;
+        let (&major_version, rest) = rest.split_first()?;
+        let (_minor_version, rest) = rest.split_first()?;
+        let (header_len, rest) = match major_version{
+            1 => (u16::from_le_bytes(rest.get(..2)?.try_into().ok()?) as usize, rest.get(2..)?),
+            _ => (u32::from_le_bytes(rest.get(..4)?.try_into().ok()?) as usize, rest.get(4..)?),
+        };
+        let header = std::str::from_utf8(rest.get(..header_len)?).ok()?;
+        let data_offset = data.len() - rest.len() + header_len;
+
+        let raw_descr = header_value(header, "descr")?;
+        let quote = raw_descr.chars().next().filter(|c| *c == '\'' || *c == '"')?;
+        let raw_descr = &raw_descr[1..];
+        let descr = raw_descr[..raw_descr.find(quote)?].to_owned();
+
+        let fortran_order = header_value(header, "fortran_order")?.starts_with("True");
+
+        let raw_shape = header_value(header, "shape")?.strip_prefix('(')?;
+        let shape = raw_shape[..raw_shape.find(')')?]
+            .split(',')
+            .map(str::trim)
+            .filter(|dim| !dim.is_empty())
+            .map(|dim| dim.parse::<usize>().ok())
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self{descr, fortran_order, shape, data_offset})
+    }
+
+    fn is_big_endian(&self) -> bool{
+        self.descr.starts_with('>')
+    }
+
+    /// Builds an array out of the elements in `raw_data`, each taking `element_size` bytes
+    fn build_array<T>(
+        &self, raw_data: &[u8], element_size: usize, parse_element: impl Fn(&[u8]) -> T,
+    ) -> Result<ndarray::ArrayD<T>, ReadNpyError>{
+        let num_elements = self.shape.iter().try_fold(1usize, |acc, dim| acc.checked_mul(*dim))
+            .ok_or(ReadNpyError::LengthOverflow)?;
+        let expected_len = num_elements.checked_mul(element_size).ok_or(ReadNpyError::LengthOverflow)?;
+        if raw_data.len() < expected_len{
+            return Err(ReadNpyError::MissingData)
+        }
+        if raw_data.len() > expected_len{
+            return Err(ReadNpyError::ExtraBytes(raw_data.len() - expected_len))
+        }
+        let elements: Vec<T> = raw_data.chunks_exact(element_size).map(parse_element).collect();
+        let shape = ndarray::IxDyn(&self.shape);
+        let array = if self.fortran_order{
+            ndarray::ArrayD::from_shape_vec(shape.f(), elements)
+        } else {
+            ndarray::ArrayD::from_shape_vec(shape, elements)
+        };
+        array.map_err(|err| ReadNpyError::ParseData(Box::new(err)))
+    }
+}
+
+/// Reads half precision floats, which are widened to `f32` since the spec has no 16 bit float `DataType`
+fn read_f16_npy(data: &[u8], header: &NpyHeader) -> Result<ndarray::ArrayD<f32>, ReadNpyError>{
+    let big_endian = header.is_big_endian();
+    header.build_array(&data[header.data_offset..], 2, |bytes|{
+        let bytes = [bytes[0], bytes[1]];
+        let value = if big_endian { half::f16::from_be_bytes(bytes) } else { half::f16::from_le_bytes(bytes) };
+        value.to_f32()
+    })
+}
+
+/// Copies arrays that were stored with `fortran_order` (or are otherwise not contiguous) into C order.
+/// Indexing works either way, but this way the raw data of every loaded array is laid out the same
+/// and test tensors are always written back out in C order
+fn into_c_order<T: Clone>(array: ndarray::ArrayD<T>) -> ndarray::ArrayD<T>{
+    if array.is_standard_layout(){
+        return array
+    }
+    array.as_standard_layout().into_owned()
+}
+
 #[rustfmt::skip]
 macro_rules! impl_NpyArray_try_read {( $($element_type:ident),+ ) => { paste::paste! {
     #[derive(Clone)]
@@ -22,14 +166,21 @@ macro_rules! impl_NpyArray_try_read {( $($element_type:ident),+ ) => { paste::pa
     )*}
 
     impl NpyArray {
+        /// Reads an `.npy` file. Arrays stored in Fortran order keep their shape and get their
+        /// elements rearranged into C order
         pub fn try_load(mut reader: impl Read) -> Result<Self, ReadNpyError> {
             let mut data = vec![];
             reader.read_to_end(&mut data)?; //FIXME: what if too big?
+            if let Some(header) = NpyHeader::parse(&data){
+                if header.descr.ends_with("f2"){
+                    return Ok(Self::ArrayF32(into_c_order(read_f16_npy(&data, &header)?)))
+                }
+            }
             let mut cursor = std::io::Cursor::new(data);
             $(
                 cursor.rewind()?;
                 match ndarray::ArrayD::<$element_type>::read_npy(&mut cursor) {
-                    Ok(arr) => return Ok(Self::[<Array $element_type:upper>](arr)),
+                    Ok(arr) => return Ok(Self::[<Array $element_type:upper>](into_c_order(arr))),
                     Err(err) => match err {
                         ndarray_npy::ReadNpyError::WrongDescriptor(_) => (),
                         other_err => return Err(other_err),
@@ -50,9 +201,158 @@ macro_rules! impl_NpyArray_try_read {( $($element_type:ident),+ ) => { paste::pa
                 Self::[<Array $element_type:upper>](arr) => arr.shape(),
             )*}
         }
+
+        /// The numpy name of the element type, e.g. `float32`
+        pub fn dtype_name(&self) -> &'static str {
+            match self {$(
+                Self::[<Array $element_type:upper>](_) => numpy_dtype_name(stringify!($element_type)),
+            )*}
+        }
+
+        /// The spec's equivalent of the element type
+        pub fn data_type(&self) -> DataType {
+            // numpy's dtype names are the same ones used by the spec
+            self.dtype_name().parse().expect("every supported element type has a spec DataType")
+        }
+
+        /// All elements in logical order, converted to f64
+        pub fn iter_as_f64(&self) -> Box<dyn Iterator<Item=f64> + '_> {
+            match self {$(
+                Self::[<Array $element_type:upper>](arr) => Box::new(arr.iter().map(|v| v.as_f64())),
+            )*}
+        }
     }
+
+    $(
+        impl NpyElement for $element_type {
+            fn dtype_name() -> &'static str {
+                numpy_dtype_name(stringify!($element_type))
+            }
+            fn view_of(array: &NpyArray) -> Option<ndarray::ArrayViewD<'_, Self>> {
+                match array {
+                    NpyArray::[<Array $element_type:upper>](arr) => Some(arr.view()),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+            fn into_npy_array(array: ndarray::ArrayD<Self>) -> NpyArray {
+                NpyArray::[<Array $element_type:upper>](into_c_order(array))
+            }
+        }
+    )+
 }};}
 
-impl_NpyArray_try_read!(u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+impl_NpyArray_try_read!(bool, u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+
+impl NpyArray {
+    /// Typed access to the elements, e.g. `array.view::<f32, ndarray::Ix4>()`. Fails if the array
+    /// holds elements of a different type or has a different number of dimensions than `D`
+    pub fn view<T: NpyElement, D: ndarray::Dimension>(&self) -> Result<ndarray::ArrayView<'_, T, D>, NpyViewError> {
+        let view = T::view_of(self).ok_or_else(|| NpyViewError::WrongElementType {
+            requested: T::dtype_name(),
+            found: self.dtype_name(),
+        })?;
+        view.into_dimensionality::<D>().map_err(|source| NpyViewError::WrongDimensionality {
+            shape: self.shape().to_vec(),
+            source,
+        })
+    }
+}
+
+impl<T: NpyElement> From<ndarray::ArrayD<T>> for NpyArray {
+    fn from(array: ndarray::ArrayD<T>) -> Self {
+        T::into_npy_array(array)
+    }
+}
 
 pub type ArcNpyArray = Arc<NpyArray>;
+
+/// Builds a version 1.0 `.npy` file by hand, for headers that `ndarray_npy` can't write
+#[cfg(test)]
+fn raw_npy(descr: &str, fortran_order: bool, shape: &str, data: &[u8]) -> Vec<u8>{
+    let fortran_order = if fortran_order { "True" } else { "False" };
+    let mut header = format!("{{'descr': '{descr}', 'fortran_order': {fortran_order}, 'shape': {shape}, }}");
+    // the header, including the 10 bytes of magic string, version and length, ends on a 64 byte boundary
+    while (10 + header.len() + 1) % 64 != 0{
+        header.push(' ');
+    }
+    header.push('\n');
+    let mut npy = b"\x93NUMPY\x01\x00".to_vec();
+    npy.extend((header.len() as u16).to_le_bytes());
+    npy.extend(header.as_bytes());
+    npy.extend(data);
+    npy
+}
+
+#[test]
+fn test_npy_header_parse(){
+    let npy = raw_npy("<f4", false, "(2, 3)", &[0; 24]);
+    let header = NpyHeader::parse(&npy).unwrap();
+    assert_eq!(header.descr, "<f4");
+    assert!(!header.fortran_order);
+    assert_eq!(header.shape, vec![2, 3]);
+    assert_eq!(header.data_offset, npy.len() - 24);
+    assert_eq!(header.data_offset % 64, 0);
+    assert!(!header.is_big_endian());
+
+    assert_eq!(NpyHeader::parse(&raw_npy(">f2", true, "(5,)", &[])).unwrap().shape, vec![5]);
+    assert!(NpyHeader::parse(&raw_npy(">f2", true, "()", &[])).unwrap().shape.is_empty());
+    assert!(NpyHeader::parse(b"not an npy file").is_none());
+}
+
+#[test]
+fn test_loading_f16_and_bool(){
+    let values = [1.0f32, -2.5, 0.0];
+    let little_endian: Vec<u8> = values.iter().flat_map(|v| half::f16::from_f32(*v).to_le_bytes()).collect();
+    let big_endian: Vec<u8> = values.iter().flat_map(|v| half::f16::from_f32(*v).to_be_bytes()).collect();
+    for (descr, data) in [("<f2", little_endian), (">f2", big_endian)]{
+        let array = NpyArray::try_load(raw_npy(descr, false, "(3,)", &data).as_slice()).unwrap();
+        assert_eq!(array.dtype_name(), "float32");
+        assert_eq!(array.iter_as_f64().collect::<Vec<_>>(), vec![1.0, -2.5, 0.0], "{descr}");
+    }
+    let truncated = raw_npy("<f2", false, "(3,)", &[0; 4]);
+    assert!(matches!(NpyArray::try_load(truncated.as_slice()), Err(ReadNpyError::MissingData)));
+
+    let mask = ndarray::arr2(&[[true, false], [false, true]]).into_dyn();
+    let mut raw = vec![];
+    mask.write_npy(&mut raw).unwrap();
+    let array = NpyArray::try_load(raw.as_slice()).unwrap();
+    assert_eq!(array.dtype_name(), "bool");
+    assert_eq!(array.iter_as_f64().collect::<Vec<_>>(), vec![1.0, 0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn test_fortran_order_and_round_trip(){
+    // the elements of a 2x3 array in column-major order, i.e. [[0, 2, 4], [1, 3, 5]]
+    let data: Vec<u8> = (0..6i32).flat_map(|v| v.to_le_bytes()).collect();
+    let array = NpyArray::try_load(raw_npy("<i4", true, "(2, 3)", &data).as_slice()).unwrap();
+    let view = array.view::<i32, ndarray::Ix2>().unwrap();
+    assert_eq!(view, ndarray::arr2(&[[0, 2, 4], [1, 3, 5]]));
+    assert!(view.is_standard_layout());
+
+    let mut written = vec![];
+    array.write_npy(&mut written).unwrap();
+    assert!(!NpyHeader::parse(&written).unwrap().fortran_order);
+    let reloaded = NpyArray::try_load(written.as_slice()).unwrap();
+    assert_eq!(reloaded.view::<i32, ndarray::Ix2>().unwrap(), view);
+
+    let transposed = ndarray::arr2(&[[1.0f32, 2.0], [3.0, 4.0]]).reversed_axes().into_dyn();
+    let from_transposed = NpyArray::from(transposed.clone());
+    let NpyArray::ArrayF32(stored) = &from_transposed else {
+        panic!("Expected a float32 array, found {}", from_transposed.dtype_name());
+    };
+    assert!(stored.is_standard_layout());
+    assert_eq!(stored, &transposed);
+}
+
+#[test]
+fn test_typed_view(){
+    let array = NpyArray::from(ndarray::ArrayD::<f32>::zeros(ndarray::IxDyn(&[1, 2, 3])));
+    assert_eq!(array.view::<f32, ndarray::Ix3>().unwrap().shape(), &[1, 2, 3]);
+    assert_eq!(array.view::<f32, ndarray::IxDyn>().unwrap().ndim(), 3);
+    assert!(matches!(
+        array.view::<u8, ndarray::Ix3>(),
+        Err(NpyViewError::WrongElementType { requested: "uint8", found: "float32" })
+    ));
+    assert!(matches!(array.view::<f32, ndarray::Ix2>(), Err(NpyViewError::WrongDimensionality { .. })));
+}
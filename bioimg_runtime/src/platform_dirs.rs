@@ -0,0 +1,33 @@
+//! The platform's usual locations for per-user application files, following the XDG base directory
+//! spec on Linux and the OS conventions on Windows and macOS. Each function returns the directory
+//! for this application (i.e. with a `bioimg` subdirectory), or `None` if it can't be determined.
+
+use std::path::PathBuf;
+
+fn home_subdir(path: &str) -> Option<PathBuf>{
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(path))
+}
+
+/// Where configuration files like the address book are kept
+pub fn config_dir() -> Option<PathBuf>{
+    let dir = if cfg!(target_os = "windows") {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        home_subdir("Library/Application Support")
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME").map(PathBuf::from).or_else(|| home_subdir(".config"))
+    };
+    dir.map(|dir| dir.join("bioimg"))
+}
+
+/// Where files that can be recreated at any time, like downloads, are kept
+pub fn cache_dir() -> Option<PathBuf>{
+    let dir = if cfg!(target_os = "windows") {
+        std::env::var_os("LOCALAPPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        home_subdir("Library/Caches")
+    } else {
+        std::env::var_os("XDG_CACHE_HOME").map(PathBuf::from).or_else(|| home_subdir(".cache"))
+    };
+    dir.map(|dir| dir.join("bioimg"))
+}
@@ -0,0 +1,216 @@
+//! A breakdown of how much space each file of a [ZooModel] takes up once packed, so that model authors
+//! can see what is making their model big and what they could trim.
+
+use std::borrow::Borrow;
+use std::fmt::Display;
+use std::io::Write;
+use std::sync::Arc;
+
+use image::codecs::png::PngEncoder;
+
+use crate::model_weights::{PytorchArch, WeightsBase};
+use crate::zoo_model::ZooModel;
+use crate::{FileSource, Icon, ModelWeights};
+
+/// A [Write] sink that only keeps track of how many bytes were written to it
+#[derive(Default)]
+struct ByteCounter(u64);
+
+impl Write for ByteCounter{
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len() as u64;
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn png_size(image: &image::DynamicImage) -> Option<u64>{
+    let mut counter = ByteCounter::default();
+    image.write_with_encoder(PngEncoder::new(&mut counter)).ok()?;
+    Some(counter.0)
+}
+
+/// Formats a number of bytes with a human-friendly unit, e.g. `3.1 GB`
+pub fn format_size(size_bytes: u64) -> String{
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if size_bytes < 1000{
+        return format!("{size_bytes} B")
+    }
+    let mut size = size_bytes as f64;
+    let mut unit = "B";
+    for next_unit in UNITS{
+        if size < 1000.0{
+            break
+        }
+        size /= 1000.0;
+        unit = next_unit;
+    }
+    format!("{size:.1} {unit}")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SizeReportEntryKind{
+    Weights,
+    PytorchArchitecture,
+    TestTensor,
+    Cover,
+    Icon,
+    Attachment,
+    Documentation,
+}
+
+impl Display for SizeReportEntryKind{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self{
+            Self::Weights => "Weights",
+            Self::PytorchArchitecture => "Architecture",
+            Self::TestTensor => "Test Tensor",
+            Self::Cover => "Cover",
+            Self::Icon => "Icon",
+            Self::Attachment => "Attachment",
+            Self::Documentation => "Documentation",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SizeReportEntry{
+    pub kind: SizeReportEntryKind,
+    /// A name that identifies this entry among others of the same kind, e.g. the weights format or tensor id
+    pub name: String,
+    /// `None` if the size can't be determined without downloading the file
+    pub size_bytes: Option<u64>,
+}
+
+impl Display for SizeReportEntry{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}): ", self.kind, self.name)?;
+        match self.size_bytes{
+            Some(size) => write!(f, "{}", format_size(size)),
+            None => write!(f, "unknown size"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SizeReport{
+    pub entries: Vec<SizeReportEntry>,
+}
+
+impl SizeReport{
+    fn push(&mut self, kind: SizeReportEntryKind, name: impl Into<String>, size_bytes: Option<u64>){
+        self.entries.push(SizeReportEntry { kind, name: name.into(), size_bytes })
+    }
+
+    /// The sum of the sizes of all entries whose size is known
+    pub fn total_bytes(&self) -> u64{
+        self.entries.iter().filter_map(|entry| entry.size_bytes).sum()
+    }
+
+    /// Entries whose size couldn't be determined, e.g. files referenced by URLs that haven't been downloaded yet
+    pub fn unknown_entries(&self) -> impl Iterator<Item=&SizeReportEntry>{
+        self.entries.iter().filter(|entry| entry.size_bytes.is_none())
+    }
+
+    /// Sorts the entries from largest to smallest, with entries of unknown size at the end
+    pub fn sort_largest_first(&mut self){
+        self.entries.sort_by_key(|entry| std::cmp::Reverse(entry.size_bytes));
+    }
+
+    fn add_weights(&mut self, weights: &ModelWeights){
+        let mut add_base = |name: &str, base: &WeightsBase| {
+            self.push(SizeReportEntryKind::Weights, name, base.source.size_bytes());
+        };
+        if let Some(w) = weights.keras_hdf5(){
+            add_base("keras_hdf5", &w.weights);
+        }
+        if let Some(w) = weights.onnx(){
+            add_base("onnx", &w.weights);
+        }
+        if let Some(w) = weights.pytorch_state_dict(){
+            add_base("pytorch_state_dict", &w.weights);
+        }
+        if let Some(w) = weights.tensorflow_js(){
+            add_base("tensorflow_js", &w.weights);
+        }
+        if let Some(w) = weights.tensorflow_saved_model_bundle(){
+            add_base("tensorflow_saved_model_bundle", &w.weights);
+        }
+        if let Some(w) = weights.torchscript(){
+            add_base("torchscript", &w.weights);
+        }
+        if let Some(PytorchArch::FromFile { file_source, callable, .. }) = weights.pytorch_state_dict().map(|w| &w.architecture){
+            self.push(SizeReportEntryKind::PytorchArchitecture, callable.to_string(), file_source.size_bytes());
+        }
+    }
+}
+
+impl Display for SizeReport{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for entry in &self.entries{
+            writeln!(f, "{entry}")?;
+        }
+        write!(f, "Total: {}", format_size(self.total_bytes()))?;
+        let num_unknown = self.unknown_entries().count();
+        if num_unknown > 0{
+            write!(f, " (plus {num_unknown} entries of unknown size)")?;
+        }
+        Ok(())
+    }
+}
+
+impl ZooModel{
+    /// How many bytes each of the files in this model will take once packed, largest first.
+    ///
+    /// Sizes are uncompressed, and files referenced by URL only have a known size if they are already
+    /// in the [DownloadCache](crate::download_cache::DownloadCache)
+    pub fn size_report(&self) -> SizeReport{
+        let mut report = SizeReport::default();
+        report.add_weights(&self.weights);
+        for input in self.interface.inputs().iter(){
+            let mut counter = ByteCounter::default();
+            let size = input.test_tensor.write_npy(&mut counter).ok().map(|_| counter.0);
+            report.push(SizeReportEntryKind::TestTensor, input.tensor_meta.id.to_string(), size);
+        }
+        for output in self.interface.outputs().iter(){
+            let mut counter = ByteCounter::default();
+            let size = output.test_tensor.write_npy(&mut counter).ok().map(|_| counter.0);
+            report.push(SizeReportEntryKind::TestTensor, output.tensor_meta.id.to_string(), size);
+        }
+        for (idx, cover) in self.covers.iter().enumerate(){
+            report.push(SizeReportEntryKind::Cover, format!("#{}", idx + 1), png_size(cover));
+        }
+        if let Some(Icon::Image(icon_image)) = &self.icon{
+            let image: &Arc<image::DynamicImage> = icon_image.borrow();
+            report.push(SizeReportEntryKind::Icon, "image", png_size(image));
+        }
+        for attachment in &self.attachments{
+            report.push(SizeReportEntryKind::Attachment, attachment.source.to_string(), attachment.source.size_bytes());
+        }
+        report.push(SizeReportEntryKind::Documentation, "README.md", Some(self.documentation.len() as u64));
+        report.sort_largest_first();
+        report
+    }
+}
+
+impl FileSource{
+    /// The size of the contents of this file, if it can be determined without downloading it
+    pub fn size_bytes(&self) -> Option<u64>{
+        match self{
+            Self::Data { data, .. } => Some(data.len() as u64),
+            #[cfg(not(target_arch="wasm32"))]
+            Self::LocalFile { path } => std::fs::metadata(path).ok().map(|meta| meta.len()),
+            Self::FileInZipArchive { archive, inner_path } => archive.with_entry(inner_path, |entry| entry.size()).ok(),
+            #[cfg(target_arch="wasm32")]
+            Self::HttpUrl(_) => None,
+            #[cfg(not(target_arch="wasm32"))]
+            Self::HttpUrl(url) => {
+                let cached_path = crate::download_cache::DownloadCache::global().lookup(url)?;
+                std::fs::metadata(cached_path).ok().map(|meta| meta.len())
+            },
+        }
+    }
+}
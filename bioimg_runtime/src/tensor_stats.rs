@@ -0,0 +1,167 @@
+//! Summary statistics over the values of an [NpyArray], either over the whole tensor or for each
+//! index along one of its axes (e.g. per channel). NaNs are counted but otherwise ignored.
+
+use crate::npy_array::NpyArray;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TensorStats {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    /// Population standard deviation
+    pub std: f64,
+    /// Number of non-NaN values
+    pub count: usize,
+    pub nan_count: usize,
+}
+
+/// Running min/max/mean/variance. Uses Welford's algorithm, so that large tensors don't lose
+/// precision in a running sum of squares
+#[derive(Clone, Default)]
+struct StatsAccumulator {
+    count: usize,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+    nan_count: usize,
+}
+
+impl StatsAccumulator {
+    fn new() -> Self {
+        Self { min: f64::INFINITY, max: f64::NEG_INFINITY, ..Default::default() }
+    }
+
+    fn push(&mut self, value: f64) {
+        if value.is_nan() {
+            self.nan_count += 1;
+            return;
+        }
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    fn finish(self) -> TensorStats {
+        if self.count == 0 {
+            return TensorStats {
+                min: f64::NAN, max: f64::NAN, mean: f64::NAN, std: f64::NAN, count: 0, nan_count: self.nan_count,
+            };
+        }
+        TensorStats {
+            min: self.min,
+            max: self.max,
+            mean: self.mean,
+            std: (self.m2 / self.count as f64).sqrt(),
+            count: self.count,
+            nan_count: self.nan_count,
+        }
+    }
+}
+
+/// Maps the logical (C order) index of an element to its index along `axis_index`
+fn axis_position(shape: &[usize], axis_index: usize) -> impl Fn(usize) -> usize {
+    let axis_len = shape[axis_index];
+    let inner_len: usize = shape[axis_index + 1..].iter().product();
+    move |flat_index| (flat_index / inner_len.max(1)) % axis_len
+}
+
+impl TensorStats {
+    pub fn from_values(values: impl IntoIterator<Item = f64>) -> Self {
+        let mut acc = StatsAccumulator::new();
+        values.into_iter().for_each(|value| acc.push(value));
+        acc.finish()
+    }
+
+    /// Statistics over all values of `array`
+    pub fn compute(array: &NpyArray) -> Self {
+        Self::from_values(array.iter_as_f64())
+    }
+
+    /// Statistics for each index along the axis `axis_index` of `array`, e.g. one entry per channel.
+    /// Returns `None` if `array` doesn't have that many dimensions
+    pub fn along_axis(array: &NpyArray, axis_index: usize) -> Option<Vec<Self>> {
+        let shape = array.shape();
+        let axis_len = *shape.get(axis_index)?;
+        let position = axis_position(shape, axis_index);
+        let mut accumulators = vec![StatsAccumulator::new(); axis_len];
+        for (flat_index, value) in array.iter_as_f64().enumerate() {
+            accumulators[position(flat_index)].push(value);
+        }
+        Some(accumulators.into_iter().map(StatsAccumulator::finish).collect())
+    }
+}
+
+/// The `percents` (in the range 0..=100) percentiles of `values`, interpolating linearly between
+/// the closest ranks like numpy does by default. NaNs are ignored; if there are no other values,
+/// all percentiles are NaN
+pub fn percentiles_of(values: impl IntoIterator<Item = f64>, percents: &[f64]) -> Vec<f64> {
+    let mut sorted: Vec<f64> = values.into_iter().filter(|value| !value.is_nan()).collect();
+    sorted.sort_unstable_by(|a, b| a.total_cmp(b));
+    percents
+        .iter()
+        .map(|percent| {
+            if sorted.is_empty() {
+                return f64::NAN;
+            }
+            let rank = percent.clamp(0.0, 100.0) / 100.0 * (sorted.len() - 1) as f64;
+            let lower = rank.floor() as usize;
+            let upper = rank.ceil() as usize;
+            sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+        })
+        .collect()
+}
+
+/// The `percents` percentiles of all values of `array`. See [percentiles_of]
+pub fn percentiles(array: &NpyArray, percents: &[f64]) -> Vec<f64> {
+    percentiles_of(array.iter_as_f64(), percents)
+}
+
+/// The `percents` percentiles for each index along the axis `axis_index` of `array`, e.g. one
+/// `Vec` of percentiles per channel. Returns `None` if `array` doesn't have that many dimensions
+pub fn percentiles_along_axis(array: &NpyArray, axis_index: usize, percents: &[f64]) -> Option<Vec<Vec<f64>>> {
+    let shape = array.shape();
+    let axis_len = *shape.get(axis_index)?;
+    let position = axis_position(shape, axis_index);
+    let mut values_per_index = vec![Vec::<f64>::new(); axis_len];
+    for (flat_index, value) in array.iter_as_f64().enumerate() {
+        values_per_index[position(flat_index)].push(value);
+    }
+    Some(values_per_index.into_iter().map(|values| percentiles_of(values, percents)).collect())
+}
+
+#[test]
+fn test_tensor_stats() {
+    let array = NpyArray::from(ndarray::arr2(&[[1.0f32, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]).into_dyn());
+    let assert_close = |actual: f64, expected: f64| assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+
+    let stats = TensorStats::compute(&array);
+    assert_eq!((stats.min, stats.max, stats.count, stats.nan_count), (1.0, 8.0, 8, 0));
+    assert_close(stats.mean, 4.5);
+    assert_close(stats.std, 5.25f64.sqrt());
+
+    let per_row = TensorStats::along_axis(&array, 0).unwrap();
+    assert_eq!(per_row.len(), 2);
+    assert_close(per_row[0].mean, 2.5);
+    assert_close(per_row[0].std, 1.25f64.sqrt());
+    assert_eq!((per_row[1].min, per_row[1].max), (5.0, 8.0));
+
+    let per_column = TensorStats::along_axis(&array, 1).unwrap();
+    assert_eq!(per_column.len(), 4);
+    assert_eq!((per_column[0].min, per_column[0].max, per_column[0].count), (1.0, 5.0, 2));
+    assert_close(per_column[0].mean, 3.0);
+    assert_close(per_column[0].std, 2.0);
+    assert!(TensorStats::along_axis(&array, 2).is_none());
+
+    let with_nan = TensorStats::from_values([1.0, f64::NAN, 3.0]);
+    assert_eq!((with_nan.count, with_nan.nan_count, with_nan.mean), (2, 1, 2.0));
+    let only_nan = TensorStats::from_values([f64::NAN]);
+    assert!(only_nan.mean.is_nan() && only_nan.std.is_nan());
+    assert_eq!(only_nan.count, 0);
+
+    assert_eq!(percentiles(&array, &[0.0, 50.0, 100.0]), vec![1.0, 4.5, 8.0]);
+    assert_eq!(percentiles_along_axis(&array, 0, &[25.0]).unwrap(), vec![vec![1.75], vec![5.75]]);
+}
@@ -0,0 +1,116 @@
+//! Records of how a model performed when it was run locally, to be stored in its rdf under
+//! `config.bioimageio.test_results` so that consumers can see what performance to expect.
+
+use std::time::Duration;
+
+use bioimg_spec::rdf::model::weights::WeightsFormat;
+
+/// Timing and environment information of one successful test run of a model
+#[derive(Clone, Debug)]
+pub struct TestRunRecord {
+    /// The weights format the model was run with
+    pub backend: WeightsFormat,
+    /// How long it took to process a single tile of the test input
+    pub duration_per_tile: Duration,
+    /// A human readable description of the machine the model ran on
+    pub hardware: String,
+    /// Name and version of the tool that ran the model
+    pub tool: String,
+    pub timestamp: iso8601_timestamp::Timestamp,
+}
+
+impl TestRunRecord {
+    /// Describes the current machine, e.g. `linux x86_64, 16 threads`
+    pub fn current_hardware() -> String {
+        let mut hardware = format!("{} {}", std::env::consts::OS, std::env::consts::ARCH);
+        if let Ok(threads) = std::thread::available_parallelism() {
+            hardware += &format!(", {threads} threads");
+        }
+        hardware
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "backend": self.backend.to_string(),
+            "duration_per_tile_ms": self.duration_per_tile.as_secs_f64() * 1000.0,
+            "hardware": self.hardware,
+            "tool": self.tool,
+            "timestamp": self.timestamp,
+        })
+    }
+
+    /// Adds this record to `config.bioimageio.test_results`, replacing any previous record of the
+    /// same backend on the same hardware.
+    ///
+    /// Fails without touching `config` if `bioimageio` or `test_results` are present but are not an
+    /// object and a list respectively, so that unexpected configs are never clobbered.
+    pub fn record_into(&self, config: &mut serde_json::Map<String, serde_json::Value>) -> Result<(), TestResultsConfigError> {
+        let bioimageio = config
+            .entry("bioimageio")
+            .or_insert_with(|| serde_json::Value::Object(Default::default()));
+        let serde_json::Value::Object(bioimageio) = bioimageio else {
+            return Err(TestResultsConfigError::NotAnObject { key: "config.bioimageio" });
+        };
+        let test_results = bioimageio
+            .entry("test_results")
+            .or_insert_with(|| serde_json::Value::Array(vec![]));
+        let serde_json::Value::Array(test_results) = test_results else {
+            return Err(TestResultsConfigError::NotAList { key: "config.bioimageio.test_results" });
+        };
+
+        let record = self.to_json();
+        let is_same_setup = |existing: &serde_json::Value| {
+            existing.get("backend") == record.get("backend") && existing.get("hardware") == record.get("hardware")
+        };
+        match test_results.iter_mut().find(|existing| is_same_setup(existing)) {
+            Some(existing) => *existing = record,
+            None => test_results.push(record),
+        }
+        Ok(())
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum TestResultsConfigError {
+    #[error("Expected {key} to be an object")]
+    NotAnObject { key: &'static str },
+    #[error("Expected {key} to be a list")]
+    NotAList { key: &'static str },
+}
+
+#[test]
+fn test_recording_test_runs() {
+    let record = TestRunRecord {
+        backend: WeightsFormat::Onnx,
+        duration_per_tile: Duration::from_millis(250),
+        hardware: "linux x86_64, 8 threads".into(),
+        tool: "bioimageio.core 0.7.0".into(),
+        timestamp: iso8601_timestamp::Timestamp::parse("2024-01-01T00:00:00Z").unwrap(),
+    };
+    let json = record.to_json();
+    assert_eq!(json["backend"], "onnx");
+    assert_eq!(json["duration_per_tile_ms"], 250.0);
+    assert_eq!(json["hardware"], "linux x86_64, 8 threads");
+    assert_eq!(json["tool"], "bioimageio.core 0.7.0");
+
+    let mut config = serde_json::Map::new();
+    config.insert("other_tool".into(), serde_json::json!({"keep": "me"}));
+    record.record_into(&mut config).unwrap();
+    assert_eq!(config["bioimageio"]["test_results"], serde_json::json!([json]));
+
+    let faster = TestRunRecord { duration_per_tile: Duration::from_millis(100), ..record.clone() };
+    faster.record_into(&mut config).unwrap();
+    let on_other_hardware = TestRunRecord { hardware: "macos aarch64".into(), ..record.clone() };
+    on_other_hardware.record_into(&mut config).unwrap();
+    assert_eq!(
+        config["bioimageio"]["test_results"],
+        serde_json::json!([faster.to_json(), on_other_hardware.to_json()])
+    );
+    assert_eq!(config["other_tool"], serde_json::json!({"keep": "me"}));
+
+    let mut bad_config = serde_json::Map::new();
+    bad_config.insert("bioimageio".into(), serde_json::json!({"test_results": "not a list"}));
+    let untouched = bad_config.clone();
+    assert!(matches!(record.record_into(&mut bad_config), Err(TestResultsConfigError::NotAList { .. })));
+    assert_eq!(bad_config, untouched);
+}
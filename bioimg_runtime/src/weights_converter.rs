@@ -0,0 +1,209 @@
+//! Derives ONNX weights from the TorchScript weights of a model by running an external converter, so
+//! that models can offer more weight formats without their authors having to export them by hand.
+//!
+//! The converter is the command in [CONVERTER_ENV_VAR], if set. It is called with the path of a package
+//! holding just what the conversion reads (the rdf, the TorchScript weights and the test tensors they are
+//! traced with) and the path where the ONNX file should be written, and must print the opset version it
+//! used as the last line of its output. Otherwise, the converter of `bioimageio.core` is run with the
+//! same Python interpreter that [CoreValidator](crate::core_validator::CoreValidator) would use. That
+//! converter lives in `bioimageio.core.weight_converters`, which older versions of `bioimageio.core`
+//! don't have; the script then fails with a message asking to update it.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Arc;
+
+use bioimg_spec::rdf;
+use bioimg_spec::rdf::model as modelrdf;
+
+use crate::core_validator::CoreValidator;
+use crate::model_weights::OnnxWeights;
+use crate::zoo_model::{ModelPackingError, ZooModel};
+use crate::{FileSource, ModelWeights, TorchscriptWeights, WeightsBase};
+
+/// Environment variable with the command to run to convert TorchScript weights to ONNX
+pub const CONVERTER_ENV_VAR: &str = "BIOIMG_ONNX_CONVERTER";
+
+const CORE_CONVERSION_SCRIPT: &str = "\
+import sys
+from pathlib import Path
+try:
+    from bioimageio.core.weight_converters.torchscript_to_onnx import convert
+except ImportError as e:
+    sys.exit(f'This version of bioimageio.core cannot convert TorchScript weights to ONNX ({e}). Please update it')
+from bioimageio.spec import load_model_description
+onnx_descr = convert(load_model_description(sys.argv[1]), Path(sys.argv[2]))
+print(onnx_descr.opset_version)
+";
+
+#[derive(thiserror::Error, Debug)]
+pub enum WeightsConversionError {
+    #[error("Model has no TorchScript weights to convert")]
+    NoTorchscriptWeights,
+    #[error("Could not run {program}: {source}")]
+    CouldNotRun { program: PathBuf, source: std::io::Error },
+    #[error("Converter exited with {status}: {stderr}")]
+    Failed { status: std::process::ExitStatus, stderr: String },
+    #[error("Converter did not report a valid opset version, its output was: {output:?}")]
+    BadOpsetVersion { output: String },
+    #[error("Could not pack model for conversion: {0}")]
+    PackingError(#[from] ModelPackingError),
+    #[error("{0}")]
+    IoError(#[from] std::io::Error),
+}
+
+/// A command that can turn TorchScript weights into ONNX ones
+#[derive(Clone, Debug)]
+pub struct OnnxConverter {
+    program: PathBuf,
+    leading_args: Vec<OsString>,
+}
+
+impl OnnxConverter {
+    /// The converter in [CONVERTER_ENV_VAR], or else the one of `bioimageio.core`, if it is installed
+    pub fn discover() -> Option<Self> {
+        if let Some(program) = std::env::var_os(CONVERTER_ENV_VAR) {
+            return Some(Self { program: program.into(), leading_args: vec![] });
+        }
+        let validator = CoreValidator::discover()?;
+        Some(Self { program: validator.python().to_owned(), leading_args: vec!["-c".into(), CORE_CONVERSION_SCRIPT.into()] })
+    }
+
+    pub fn program(&self) -> &Path {
+        &self.program
+    }
+
+    /// Converts the TorchScript weights of the model package at `package_path` into an ONNX file at
+    /// `output_path`, returning the opset version of the result
+    pub fn convert_package(&self, package_path: &Path, output_path: &Path) -> Result<modelrdf::OnnxOpsetVersion, WeightsConversionError> {
+        let output = Command::new(&self.program)
+            .args(&self.leading_args)
+            .arg(package_path)
+            .arg(output_path)
+            .output()
+            .map_err(|source| WeightsConversionError::CouldNotRun { program: self.program.clone(), source })?;
+        if !output.status.success() {
+            return Err(WeightsConversionError::Failed {
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_owned(),
+            });
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        stdout.lines().last()
+            .and_then(|line| line.trim().parse::<u32>().ok())
+            .and_then(|version| modelrdf::OnnxOpsetVersion::try_from(version).ok())
+            .ok_or_else(|| WeightsConversionError::BadOpsetVersion { output: stdout.trim().to_owned() })
+    }
+
+    /// Converts the TorchScript weights of `model` into in-memory ONNX weights, marked as derived from
+    /// the TorchScript ones and credited to the same authors
+    pub fn convert_model(&self, model: ZooModel) -> Result<OnnxWeights, WeightsConversionError> {
+        let Some(torchscript) = model.weights.torchscript().cloned() else {
+            return Err(WeightsConversionError::NoTorchscriptWeights);
+        };
+        let authors = torchscript.weights.authors.clone();
+
+        let package_file = tempfile::Builder::new().suffix(".zip").tempfile()?;
+        conversion_input(model, torchscript).pack_into(package_file.as_file())?;
+        // the converter creates the file itself, and the directory (with the file) is deleted once it's read
+        let output_dir = tempfile::tempdir()?;
+        let onnx_path = output_dir.path().join("weights.onnx");
+        let opset_version = self.convert_package(package_file.path(), &onnx_path)?;
+        Ok(derived_onnx_weights(std::fs::read(&onnx_path)?, opset_version, authors))
+    }
+}
+
+/// The part of `model` that the converter needs: its rdf, the `torchscript` weights and the test tensors.
+/// Covers, attachments, the documentation and any other weights are left out so that they aren't copied
+/// into the package just to be ignored
+fn conversion_input(model: ZooModel, torchscript: TorchscriptWeights) -> ZooModel {
+    // the weights these were derived from are not in the package
+    let torchscript = TorchscriptWeights { weights: WeightsBase { parent: None, ..torchscript.weights }, ..torchscript };
+    ZooModel {
+        covers: vec![],
+        attachments: vec![],
+        icon: None,
+        documentation: String::new(),
+        weights: ModelWeights::new(None, None, None, None, None, Some(torchscript)).expect("There are TorchScript weights"),
+        ..model
+    }
+}
+
+/// The converted `onnx_bytes` as weights derived from the TorchScript ones
+fn derived_onnx_weights(
+    onnx_bytes: Vec<u8>, opset_version: modelrdf::OnnxOpsetVersion, authors: Option<Vec<rdf::Author2>>
+) -> OnnxWeights {
+    OnnxWeights {
+        weights: WeightsBase {
+            source: FileSource::Data { data: Arc::from(onnx_bytes), name: Some("weights.onnx".into()) },
+            authors,
+            parent: Some(modelrdf::WeightsFormat::Torchscript),
+        },
+        opset_version,
+    }
+}
+
+#[test]
+fn test_conversion_input_and_converted_weights(){
+    use serde_json::json;
+    use crate::model_interface::{InputSlot, OutputSlot};
+    use crate::zip_archive_ext::SharedZipArchive;
+    use crate::{Attachment, NpyArray};
+
+    let axes = json!([{"type": "batch"}, {"type": "space", "id": "x", "size": 4}]);
+    let test_tensor = || Arc::new(NpyArray::ArrayF32(ndarray::ArrayD::zeros(ndarray::IxDyn(&[1, 4]))));
+    let torchscript = TorchscriptWeights{
+        weights: WeightsBase{
+            source: FileSource::Data{data: Arc::from(b"fake weights".as_slice()), name: Some("weights.pt".into())},
+            authors: Some(vec![serde_json::from_value(json!({"name": "Jane Doe"})).unwrap()]),
+            parent: Some(modelrdf::WeightsFormat::PytorchStateDict),
+        },
+        pytorch_version: rdf::Version::major_minor_patch(2, 1, 0),
+    };
+    let model = ZooModel::builder()
+        .name(serde_json::from_value(json!("Convertible Model")).unwrap())
+        .description(serde_json::from_value(json!("A model with TorchScript weights")).unwrap())
+        .author(serde_json::from_value(json!({"name": "John Doe"})).unwrap())
+        .cite(serde_json::from_value(json!({"text": "Some paper", "doi": "10.1109/CVPR.2016.90"})).unwrap())
+        .documentation("# Convertible Model".into())
+        .input(InputSlot{
+            tensor_meta: serde_json::from_value(json!({"id": "in", "description": "Input", "axes": axes})).unwrap(),
+            test_tensor: test_tensor(),
+        })
+        .output(OutputSlot{
+            tensor_meta: serde_json::from_value(json!({"id": "out", "description": "Output", "axes": axes})).unwrap(),
+            test_tensor: test_tensor(),
+        })
+        .attachment(Attachment::from(FileSource::Data{data: Arc::from(b"a,b\n1,2\n".as_slice()), name: Some("table.csv".into())}))
+        .weights(ModelWeights::new(None, None, None, None, None, Some(torchscript.clone())).unwrap())
+        .build()
+        .unwrap();
+
+    let mut packed = std::io::Cursor::new(Vec::<u8>::new());
+    conversion_input(model, torchscript).pack_into(&mut packed).unwrap();
+    let archive = SharedZipArchive::from_raw_data(packed.into_inner(), "conversion input".to_owned());
+    let packed_names = archive.with_file_names(|names| names.map(|name| name.to_owned()).collect::<Vec<_>>());
+    assert!(packed_names.iter().all(|name| !name.ends_with(".csv")), "{packed_names:?}");
+    let conversion_input = ZooModel::try_load_archive(archive).unwrap();
+    assert!(conversion_input.attachments.is_empty());
+    assert_eq!(conversion_input.interface.inputs()[0].test_tensor.shape(), &[1, 4]);
+    let packed_torchscript = conversion_input.weights.torchscript().cloned().unwrap();
+    assert_eq!(packed_torchscript.weights.parent, None);
+
+    let opset_version = modelrdf::OnnxOpsetVersion::try_from(17).unwrap();
+    let onnx = derived_onnx_weights(b"onnx bytes".to_vec(), opset_version, packed_torchscript.weights.authors.clone());
+    let converted = ZooModel{
+        weights: ModelWeights::new(None, Some(onnx), None, None, None, Some(packed_torchscript)).unwrap(),
+        ..conversion_input
+    };
+    let mut packed = std::io::Cursor::new(Vec::<u8>::new());
+    converted.pack_into(&mut packed).unwrap();
+    let archive = SharedZipArchive::from_raw_data(packed.into_inner(), "converted model".to_owned());
+    let rdf: serde_json::Value = serde_yaml::from_slice(&archive.read_full_entry("rdf.yaml").unwrap()).unwrap();
+    assert_eq!(rdf.pointer("/weights/onnx/parent"), Some(&json!("torchscript")));
+    assert_eq!(rdf.pointer("/weights/onnx/opset_version"), Some(&json!(17)));
+    assert_eq!(rdf.pointer("/weights/onnx/authors/0/name"), Some(&json!("Jane Doe")));
+    let onnx_path = rdf.pointer("/weights/onnx/source").and_then(|source| source.as_str()).unwrap();
+    assert_eq!(archive.read_full_entry(onnx_path).unwrap(), b"onnx bytes");
+}
@@ -1,7 +1,13 @@
-use std::{fmt::{Debug, Display}, io::{Read, Seek}, path::{Path, PathBuf}, sync::{Arc, Mutex}};
+use std::{fmt::{Debug, Display}, io::{Read, Seek, Write}, path::{Path, PathBuf}, sync::{Arc, Mutex}};
 
 use bioimg_spec::rdf;
 
+use crate::executor::yield_now;
+use crate::file_source::{ProgressCallback, TransferProgress};
+
+/// How many decompressed bytes the async readers produce before yielding to the executor
+const ASYNC_READ_CHUNK_SIZE: u64 = 256 * 1024;
+
 pub trait SeekReadSend: Seek + Read + Send{}
 impl<T: Seek + Read + Send> SeekReadSend for T{}
 
@@ -83,6 +89,38 @@ impl SharedZipArchive{
             archive: Arc::new(Mutex::new(archive))
         })
     }
+    /// Packs the files under `dir` into an in-memory archive, so that an unpacked model can be loaded
+    /// just like a packaged one. Entries are stored uncompressed. Hidden files and directories, like
+    /// `.git`, are skipped
+    pub fn from_directory<P: AsRef<Path>>(dir: P) -> Result<Self, ZipArchiveOpenError>{
+        type MemZipWriter = zip::ZipWriter<std::io::Cursor<Vec<u8>>>;
+        fn add_dir(writer: &mut MemZipWriter, root: &Path, dir: &Path) -> Result<(), ZipArchiveOpenError>{
+            for entry in std::fs::read_dir(dir)?{
+                let entry = entry?;
+                if entry.file_name().to_string_lossy().starts_with('.'){
+                    continue
+                }
+                let path = entry.path();
+                if path.is_dir(){
+                    add_dir(writer, root, &path)?;
+                    continue
+                }
+                let inner_path = path.strip_prefix(root).expect("entries are under root").components()
+                    .map(|component| component.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                let file_options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+                writer.start_file(inner_path, file_options)?;
+                std::io::copy(&mut std::fs::File::open(&path)?, writer)?;
+            }
+            Ok(())
+        }
+        let dir = dir.as_ref();
+        let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::<u8>::new()));
+        add_dir(&mut writer, dir, dir)?;
+        let reader: BoxDynSeekReadSend = Box::new(writer.finish()?);
+        Ok(Self::new(ZipArchiveIdentifier::Path(dir.to_owned()), zip::ZipArchive::new(reader)?))
+    }
     pub fn new(identif: ZipArchiveIdentifier, archive: AnyZipArchive) -> Self{
         Self{identif, archive: Arc::new(Mutex::new(archive))}
     }
@@ -111,9 +149,51 @@ impl SharedZipArchive{
         _ = f.read_to_end(&mut data)?;
         Ok(data)
     }
+    /// Copies the still-compressed entry `name` into an archive of its own, so that it can be
+    /// decompressed without holding the lock on this archive
+    fn detach_entry(&self, name: &str) -> Result<AnyZipArchive, zip::result::ZipError>{
+        let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::<u8>::new()));
+        {
+            let mut archive_guard = self.archive.lock().unwrap();
+            let idx = archive_guard.index_for_name(name).ok_or(zip::result::ZipError::FileNotFound)?;
+            writer.raw_copy_file(archive_guard.by_index_raw(idx)?)?;
+        }
+        let reader: BoxDynSeekReadSend = Box::new(std::io::Cursor::new(writer.finish()?.into_inner()));
+        zip::ZipArchive::new(reader)
+    }
+    /// Decompresses the entry `name` into `buf` a chunk at a time, yielding to the executor in between
+    /// so that e.g. the web app can keep drawing frames while large entries are read
+    pub async fn read_entry_to_end_async(
+        &self, name: &str, buf: &mut Vec<u8>, mut on_progress: Option<ProgressCallback<'_>>,
+    ) -> Result<usize, zip::result::ZipError>{
+        let mut entry_archive = self.detach_entry(name)?;
+        let mut entry = entry_archive.by_index(0)?;
+        let mut progress = TransferProgress{transferred_bytes: 0, total_bytes: Some(entry.size())};
+        loop{
+            yield_now().await;
+            let num_read = (&mut entry).take(ASYNC_READ_CHUNK_SIZE).read_to_end(buf)?;
+            if num_read == 0{
+                break
+            }
+            progress.transferred_bytes += num_read as u64;
+            if let Some(on_progress) = &mut on_progress{
+                on_progress(progress);
+            }
+        }
+        Ok(progress.transferred_bytes as usize)
+    }
+    pub async fn read_full_entry_async(&self, entry_path: &str) -> Result<Vec<u8>, zip::result::ZipError>{
+        let mut data = Vec::<u8>::new();
+        self.read_entry_to_end_async(entry_path, &mut data, None).await?;
+        Ok(data)
+    }
     pub fn has_entry(&self, name: &str) -> bool{
         self.archive.lock().unwrap().by_name(name).is_ok()
     }
+    /// The name of the entry holding the resource description, if the archive has one
+    pub fn rdf_entry_name(&self) -> Option<&'static str>{
+        ["rdf.yaml", "bioimageio.yaml"].into_iter().find(|name| self.has_entry(name))
+    }
     pub fn with_file_names<F, Out>(&self, f: F) -> Out
     where
         F: for<'a> FnOnce(Box<dyn Iterator<Item=&'a str> + 'a>) -> Out,
@@ -123,6 +203,22 @@ impl SharedZipArchive{
         let file_names = Box::new(archive_guard.file_names());
         f(file_names)
     }
+    /// Copies the entries for which `filter` returns true into `writer` as they are, without
+    /// decompressing and recompressing them
+    pub fn raw_copy_entries_into<W, F>(&self, writer: &mut zip::ZipWriter<W>, mut filter: F) -> Result<(), zip::result::ZipError>
+    where
+        W: Write + Seek,
+        F: FnMut(&str) -> bool,
+    {
+        let mut archive_guard = self.archive.lock().unwrap();
+        for idx in 0..archive_guard.len(){
+            let entry = archive_guard.by_index_raw(idx)?;
+            if filter(entry.name()){
+                writer.raw_copy_file(entry)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -1,15 +1,109 @@
-use std::io::{Seek, Write};
+use std::io::{Seek, SeekFrom, Write};
 
 use bioimg_spec::rdf::FsPath;
+use bioimg_spec::rdf::file_reference::FsPathComponent;
 
 use crate::zoo_model::ModelPackingError;
 
+/// A file that was (or, in a dry run, would have been) written to a packed model
+#[derive(Clone, Debug)]
+pub struct PackedEntry{
+    pub path: FsPath,
+    /// Where the contents of the entry came from, or `None` if they were generated while packing,
+    /// like test tensors or the rdf itself
+    pub source: Option<String>,
+    pub size_bytes: u64,
+}
+
+/// A [Write] wrapper that keeps track of how many bytes went through it
+struct CountingWriter<'a>{
+    inner: &'a mut dyn Write,
+    count: u64,
+}
+
+impl Write for CountingWriter<'_>{
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.count += written as u64;
+        Ok(written)
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A sink that discards everything written to it but still supports seeking, so that a
+/// [ModelZipWriter] can run over it without producing any output
+#[derive(Default)]
+pub(crate) struct DiscardingSink{
+    position: u64,
+    len: u64,
+}
+
+impl Write for DiscardingSink{
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.position += buf.len() as u64;
+        self.len = self.len.max(self.position);
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for DiscardingSink{
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let new_position = match pos{
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        let Some(new_position) = new_position else {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "Seeking to a negative position"))
+        };
+        self.position = new_position;
+        Ok(new_position)
+    }
+}
+
 // Hides the ZipWriter to enforce correct usage
-pub struct ModelZipWriter<W: Write + Seek>(zip::ZipWriter<W>);
+pub struct ModelZipWriter<W: Write + Seek>{
+    inner: zip::ZipWriter<W>,
+    next_entry_idx: usize,
+    entries: Vec<PackedEntry>,
+    /// Whether files copied from a [crate::FileSource] are only given a path, without being read
+    placeholders_only: bool,
+}
+
+impl ModelZipWriter<DiscardingSink> {
+    /// A writer that assigns entry paths exactly like a real one, but doesn't read any external files,
+    /// e.g. to produce the rdf of a model without downloading its weights
+    pub(crate) fn placeholders() -> Self {
+        Self{placeholders_only: true, ..Self::new(DiscardingSink::default())}
+    }
+}
 
 impl<W: Write + Seek> ModelZipWriter<W> {
     pub fn new(zip_sink: W) -> Self {
-        Self(zip::ZipWriter::new(zip_sink))
+        Self{inner: zip::ZipWriter::new(zip_sink), next_entry_idx: 0, entries: vec![], placeholders_only: false}
+    }
+
+    pub(crate) fn placeholders_only(&self) -> bool {
+        self.placeholders_only
+    }
+
+    /// A new entry path ending in `suffix`. Paths are numbered in the order they are requested, so
+    /// packing the same model twice produces the same entry names
+    pub fn unique_path(&mut self, suffix: &str) -> Result<FsPath, ModelPackingError> {
+        let file_name = format!("{:03}{suffix}", self.next_entry_idx);
+        self.next_entry_idx += 1;
+        let component = FsPathComponent::try_from(file_name)?;
+        Ok(FsPath::from_components(vec![component])?)
+    }
+
+    /// The entries written so far, in the order they were written
+    pub fn entries(&self) -> &[PackedEntry] {
+        &self.entries
     }
 
     pub fn write_file<F, Out, E>(&mut self, path: &FsPath, f: F) -> Result<Out, ModelPackingError>
@@ -18,15 +112,30 @@ impl<W: Write + Seek> ModelZipWriter<W> {
         F: FnOnce(&mut dyn Write) -> Result<Out, E>,
         E: Into<ModelPackingError>,
     {
-        let file_options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
-        let path: String = path.clone().into();
-        self.0.start_file(path, file_options)?;
-        f(&mut self.0).map_err(|e| e.into())
+        self.write_sourced_file(path, None, f)
+    }
+
+    /// Like [Self::write_file], but records that the contents of the entry were copied from `source`
+    pub fn write_sourced_file<F, Out, E>(&mut self, path: &FsPath, source: Option<String>, f: F) -> Result<Out, ModelPackingError>
+    where
+        F: FnOnce(&mut dyn Write) -> Result<Out, E>,
+        E: Into<ModelPackingError>,
+    {
+        // a fixed modification time keeps the archive independent of when it was packed
+        let file_options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored)
+            .last_modified_time(zip::DateTime::default());
+        let raw_path: String = path.clone().into();
+        self.inner.start_file(raw_path, file_options)?;
+        let mut writer = CountingWriter{inner: &mut self.inner, count: 0};
+        let out = f(&mut writer).map_err(|e| e.into())?;
+        self.entries.push(PackedEntry{path: path.clone(), source, size_bytes: writer.count});
+        Ok(out)
     }
 
     //FIXME: can we enforce the calling of this function with something like must_use ?
     pub fn finish(self) -> Result<(), ModelPackingError> {
-        self.0.finish()?;
+        self.inner.finish()?;
         Ok(())
     }
 }
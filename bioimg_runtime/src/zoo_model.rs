@@ -12,22 +12,24 @@ use bioimg_spec::rdf::non_empty_list::NonEmptyList;
 use bioimg_spec::rdf::model::RdfTypeModel;
 use bioimg_spec::rdf::bounded_string::BoundedString;
 use bioimg_spec::rdf::model::unsupported::Version_0_4_X_OrEarlier;
-use bioimg_spec::rdf::model::ModelRdfV0_5;
+use bioimg_spec::rdf::model::{BestEffortParsingError, ModelRdfV0_5, ParsingWarning};
 use bioimg_spec::rdf::maintainer::Maintainer;
-use bioimg_spec::rdf::file_reference::FsPathComponent;
+use bioimg_spec::rdf::file_reference::{FsPathComponent, FsPathParsingError};
 use bioimg_spec::rdf::author::Author2;
 use bioimg_spec::rdf::model as  modelrdf;
 use image::ImageError;
 
-use crate::{FileSource, Icon, ModelInterface, NpyArray, TensorValidationError};
+use crate::{Attachment, FileSource, Icon, ModelInterface, NpyArray, ProgressCallback, TensorValidationError};
+use crate::file_source::reborrow_progress;
 use crate::zip_archive_ext::{SharedZipArchive, ZipArchiveOpenError};
 use crate::cover_image::CoverImageLoadingError;
 use crate::CoverImage;
-use crate::zip_writer_ext::ModelZipWriter;
+use crate::zip_writer_ext::{DiscardingSink, ModelZipWriter, PackedEntry};
 use crate::npy_array::ArcNpyArray;
 use crate::model_weights::{ModelWeights, ModelWeightsLoadingError};
 use crate::model_interface::{InputSlot, ModelInterfaceLoadingError, OutputSlot};
 use crate::icon::IconLoadingError;
+use crate::zoo_model_builder::ZooModelBuilder;
 
 #[derive(thiserror::Error, Debug)]
 pub enum ModelPackingError {
@@ -49,6 +51,8 @@ pub enum ModelPackingError {
     HttpErro{reason: String},
     #[error("Unexpected status ({status})when requesting {url}")]
     UnexpectedHttpStatus{status: u16, url: HttpUrl},
+    #[error("Bad zip entry name: {0}")]
+    BadEntryName(#[from] FsPathParsingError),
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -79,8 +83,12 @@ pub enum ModelLoadingError{
     TensorValidationError(#[from] TensorValidationError),
     #[error("Unsupported legacy model version: {version}. The earliest supported version is {earliest_supported}")]
     UnsupportedLegacyModel{version: Version_0_4_X_OrEarlier, earliest_supported: Version},
+    #[error("Could not upgrade legacy model (version {version}): {reason}")]
+    LegacyModelUpgrade{version: Version_0_4_X_OrEarlier, reason: modelrdf::ModelUpgradeError},
     #[error("Rdf version is too new for this application: {format_version}. The latest supported version is {latest_supported}")]
     FutureModel{format_version: Version_0_5_4_OrLater, latest_supported: Version},
+    #[error("Could not parse model rdf leniently: {0}")]
+    BestEffortParsingError(#[from] BestEffortParsingError),
     #[error("Bad rdf: {inner}")]
     BadModel{inner: serde_yaml::Error},
     #[error("Unrecognized rdf data (found version {format_version:?})")]
@@ -90,7 +98,7 @@ pub enum ModelLoadingError{
 pub struct ZooModel {
     pub description: rdf::ResourceTextDescription,
     pub covers: Vec<CoverImage>,
-    pub attachments: Vec<FileSource>,
+    pub attachments: Vec<Attachment>,
     pub cite: NonEmptyList<rdf::CiteEntry2>,
     pub config: serde_json::Map<String, serde_json::Value>,
     pub git_repo: Option<rdf::HttpUrl>,
@@ -100,63 +108,116 @@ pub struct ZooModel {
     pub tags: Vec<rdf::Tag>,
     pub version: Option<Version>,
     pub version_comment: Option<BoundedString<0, 512>>,
+    pub uploader: Option<rdf::Uploader>,
     pub authors: NonEmptyList<Author2>,
     pub documentation: String,
     pub license: LicenseId,
     pub name: ModelRdfName,
     pub id: Option<ResourceId>,
-    // training_data: DatasetDescrEnum, //FIXME
+    pub parent: Option<modelrdf::LinkedModel>,
+    pub run_mode: Option<modelrdf::RunMode>,
+    pub timestamp: iso8601_timestamp::Timestamp,
+    pub training_data: Option<modelrdf::dataset_descr::DatasetDescrEnum>,
     pub weights: ModelWeights,
     pub interface: ModelInterface<ArcNpyArray>,
 }
 
 impl ZooModel{
+    /// Starts assembling a model in code, see [ZooModelBuilder]
+    pub fn builder() -> ZooModelBuilder{
+        ZooModelBuilder::default()
+    }
+
     pub fn try_load(path: &Path) -> Result<Self, ModelLoadingError>{
         let archive = SharedZipArchive::open(path)?;
         Self::try_load_archive(archive)
     }
 
     pub fn try_load_archive(archive: SharedZipArchive) -> Result<Self, ModelLoadingError>{
-        let model_rdf_bytes: Vec<u8> = 'model_rdf: {
-            for file_name in ["rdf.yaml", "bioimageio.yaml"]{
-                match archive.read_full_entry(file_name) {
-                    Ok(bytes) => break 'model_rdf bytes,
-                    Err(zip_err) => match zip_err{
-                        zip::result::ZipError::FileNotFound => continue,
-                        err => return Err(ModelLoadingError::ZipError(err))
-                    }
-                };
-            }
-            return Err(ModelLoadingError::RdfYamlNotFound)
+        let model_rdf_bytes = Self::read_rdf_bytes(&archive)?;
+        let model_rdf = Self::parse_rdf(&model_rdf_bytes)?;
+        Self::try_from_rdf(model_rdf, archive)
+    }
+
+    /// Like [Self::try_load], but see [Self::try_load_archive_best_effort]
+    pub fn try_load_best_effort(path: &Path) -> Result<(Self, Vec<ParsingWarning>), ModelLoadingError>{
+        let archive = SharedZipArchive::open(path)?;
+        Self::try_load_archive_best_effort(archive)
+    }
+
+    /// Like [Self::try_load_archive], but also loads models with a newer 0.x format version, ignoring
+    /// any fields it doesn't know about. What got ignored is reported in the returned warnings
+    pub fn try_load_archive_best_effort(archive: SharedZipArchive) -> Result<(Self, Vec<ParsingWarning>), ModelLoadingError>{
+        let model_rdf_bytes = Self::read_rdf_bytes(&archive)?;
+        let (model_rdf, warnings) = match ModelRdfV0_5::parse_best_effort(&model_rdf_bytes){
+            Ok(parsed) => (parsed.model_rdf, parsed.warnings),
+            // the version was acceptable, so the regular parsing would only hide why the model itself is broken
+            Err(err @ BestEffortParsingError::BadModel(_)) => return Err(ModelLoadingError::BestEffortParsingError(err)),
+            // e.g. legacy models, which get upgraded (or better diagnostics) by the regular parsing
+            Err(_) => (Self::parse_rdf(&model_rdf_bytes)?, vec![]),
         };
-        let model_rdf = match serde_yaml::from_slice::<ModelRdfV0_5>(&model_rdf_bytes){
-            Ok(model_rdf) => model_rdf,
+        Ok((Self::try_from_rdf(model_rdf, archive)?, warnings))
+    }
+
+    fn read_rdf_bytes(archive: &SharedZipArchive) -> Result<Vec<u8>, ModelLoadingError>{
+        for file_name in ["rdf.yaml", "bioimageio.yaml"]{
+            match archive.read_full_entry(file_name) {
+                Ok(bytes) => return Ok(bytes),
+                Err(zip_err) => match zip_err{
+                    zip::result::ZipError::FileNotFound => continue,
+                    err => return Err(ModelLoadingError::ZipError(err))
+                }
+            };
+        }
+        Err(ModelLoadingError::RdfYamlNotFound)
+    }
+
+    fn parse_rdf(model_rdf_bytes: &[u8]) -> Result<ModelRdfV0_5, ModelLoadingError>{
+        match serde_yaml::from_slice::<ModelRdfV0_5>(model_rdf_bytes){
+            Ok(model_rdf) => Ok(model_rdf),
             Err(v5_err) => {
-                if let Ok(legacy_model) = serde_yaml::from_slice::<UnsupportedLegacyModel>(&model_rdf_bytes){
-                    return Err(ModelLoadingError::UnsupportedLegacyModel {
-                        version: legacy_model.format_version,
-                        earliest_supported: Version_0_5_x::earliest_supported_version(),
-                    })
+                if let Ok(legacy_model) = serde_yaml::from_slice::<UnsupportedLegacyModel>(model_rdf_bytes){
+                    let version = legacy_model.format_version;
+                    let earliest_upgradable = Version::major_minor_patch(0, 4, 0);
+                    if *version < earliest_upgradable{
+                        return Err(ModelLoadingError::UnsupportedLegacyModel { version, earliest_supported: earliest_upgradable })
+                    }
+                    let model_rdf_v0_4 = serde_yaml::from_slice::<modelrdf::ModelRdfV0_4>(model_rdf_bytes)
+                        .map_err(|inner| ModelLoadingError::BadModel { inner })?;
+                    return ModelRdfV0_5::try_from(model_rdf_v0_4)
+                        .map_err(|reason| ModelLoadingError::LegacyModelUpgrade { version, reason })
                 }
-                if let Ok(future_model) = serde_yaml::from_slice::<UnsupportedFutureModel>(&model_rdf_bytes){
+                if let Ok(future_model) = serde_yaml::from_slice::<UnsupportedFutureModel>(model_rdf_bytes){
                     return Err(ModelLoadingError::FutureModel{
                         format_version: future_model.format_version,
                         latest_supported: Version_0_5_x::latest_supported_version(),
                     })
                 }
-                return Err(ModelLoadingError::BadModel { inner: v5_err })
+                Err(ModelLoadingError::BadModel { inner: v5_err })
             }
-        };
+        }
+    }
 
+    fn try_from_rdf(model_rdf: ModelRdfV0_5, archive: SharedZipArchive) -> Result<Self, ModelLoadingError>{
         let covers: Vec<CoverImage> = model_rdf.covers.into_iter()
             .map(|rdf_cover| CoverImage::try_load(rdf_cover, &archive))
             .collect::<Result<_, _>>()?;
 
-        let attachments: Vec<FileSource> = model_rdf.attachments.into_iter()
+        let mut config = model_rdf.config;
+        let attachment_descriptions = crate::attachment::take_descriptions(&mut config);
+        let attachments: Vec<Attachment> = model_rdf.attachments.into_iter()
             .map(|att| match att.source{
                 rdf::FileReference::Url(_) => return Err(ModelLoadingError::UrlFileReferenceNotSupportedYet),
                 rdf::FileReference::Path(fs_path) => {
-                    Ok(FileSource::FileInZipArchive { archive: archive.clone(), inner_path: Arc::from(String::from(fs_path).as_str()) })
+                    let inner_path = String::from(fs_path);
+                    let description = attachment_descriptions.get(&inner_path)
+                        .and_then(|descr| descr.as_str())
+                        .map(|descr| descr.to_owned());
+                    Ok(Attachment{
+                        source: FileSource::FileInZipArchive { archive: archive.clone(), inner_path: Arc::from(inner_path.as_str()) },
+                        description,
+                        sha256: att.sha256,
+                    })
                 }
             })
             .collect::<Result<_, _>>()?;
@@ -188,7 +249,7 @@ impl ZooModel{
             covers,
             attachments,
             cite: model_rdf.cite,
-            config: model_rdf.config,
+            config,
             git_repo: model_rdf.git_repo,
             icon,
             links: model_rdf.links,
@@ -196,17 +257,46 @@ impl ZooModel{
             tags: model_rdf.tags,
             version: model_rdf.version,
             version_comment: model_rdf.version_comment,
+            uploader: model_rdf.uploader,
             authors: model_rdf.authors,
             documentation,
             license: model_rdf.license,
             name: model_rdf.name,
             id: model_rdf.id,
+            parent: model_rdf.parent,
+            run_mode: model_rdf.run_mode,
+            training_data: model_rdf.training_data,
+            timestamp: model_rdf.timestamp,
             weights,
             interface: model_interface,
         })
     }
 }
 
+/// Knobs for [ZooModel::pack_into_with]. Packing the same model with the same options always
+/// produces a byte-identical archive, so it can be verified by its hash
+#[derive(Clone, Debug, Default)]
+pub struct PackingOptions{
+    /// Written to the packed rdf.yaml instead of the model's own timestamp
+    pub timestamp: Option<iso8601_timestamp::Timestamp>,
+}
+
+/// The outcome of [ZooModel::pack_dry_run]
+#[derive(Debug)]
+pub struct PackingDryRun{
+    /// Every entry the archive would contain, in the order they would be written. If packing failed,
+    /// only the entries written before the failure
+    pub entries: Vec<PackedEntry>,
+    /// What would have stopped the model from being packed
+    pub error: Option<ModelPackingError>,
+}
+
+impl PackingDryRun{
+    pub fn total_bytes(&self) -> u64{
+        self.entries.iter().map(|entry| entry.size_bytes).sum()
+    }
+}
+
 impl ZooModel {
     #[cfg(not(target_arch="wasm32"))]
     pub fn pack_into_tmp(self) -> Result<std::fs::File, ModelPackingError>{
@@ -216,36 +306,84 @@ impl ZooModel {
         Ok(tmp_file)
     }
     pub fn pack_into<Sink: Write + Seek>(self, sink: Sink) -> Result<(), ModelPackingError> {
+        self.pack_into_with(sink, &PackingOptions::default())
+    }
+    pub fn pack_into_with<Sink: Write + Seek>(self, sink: Sink, options: &PackingOptions) -> Result<(), ModelPackingError> {
         let mut writer = ModelZipWriter::new(sink);
+        self.dump_into(&mut writer, options, None)?;
+        writer.finish()
+    }
+    /// Like [Self::pack_into_with], but calls `on_progress` while the weights and attachments, which can
+    /// take several GB, are copied. Progress starts over for each of those files
+    pub fn pack_into_with_progress<Sink: Write + Seek>(
+        self, sink: Sink, options: &PackingOptions, on_progress: ProgressCallback,
+    ) -> Result<(), ModelPackingError> {
+        let mut writer = ModelZipWriter::new(sink);
+        self.dump_into(&mut writer, options, Some(on_progress))?;
+        writer.finish()
+    }
+    /// Runs all of the packing logic (reading every file the model references and encoding images
+    /// and tensors) without writing the archive anywhere, to check what it would contain
+    pub fn pack_dry_run(self, options: &PackingOptions) -> PackingDryRun {
+        let mut writer = ModelZipWriter::new(DiscardingSink::default());
+        let error = self.dump_into(&mut writer, options, None).err();
+        PackingDryRun{entries: writer.entries().to_vec(), error}
+    }
+    /// The rdf of this model as it would be packed, but without reading any of the files it references
+    /// (like weights or downloads), so that it can be shown cheaply. Their paths are the same ones a
+    /// real [Self::pack_into] would use, but their sha256 is left out
+    pub fn rdf_with_placeholders(self) -> Result<ModelRdfV0_5, ModelPackingError> {
+        self.dump_rdf(&mut ModelZipWriter::placeholders(), &PackingOptions::default(), None)
+    }
+    fn dump_into<Sink: Write + Seek>(
+        self, writer: &mut ModelZipWriter<Sink>, options: &PackingOptions, on_progress: Option<ProgressCallback>,
+    ) -> Result<(), ModelPackingError> {
+        let model_rdf = self.dump_rdf(writer, options, on_progress)?;
+        let model_json_val = serde_json::to_value(&model_rdf).unwrap();
 
-        let (inputs, outputs) = self.interface.dump(&mut writer)?;
+        let rdf_file_name = FsPathComponent::try_from("rdf.yaml".to_owned()).unwrap();
+        let rdf_path = FsPath::from_components(vec![rdf_file_name]).unwrap();
+        writer.write_file(&rdf_path, |writer| serde_yaml::to_writer(writer, &model_json_val))?;
+        Ok(())
+    }
+    /// Writes every file of the model into `writer`, returning the rdf that references them
+    fn dump_rdf<Sink: Write + Seek>(
+        self, writer: &mut ModelZipWriter<Sink>, options: &PackingOptions, mut on_progress: Option<ProgressCallback>,
+    ) -> Result<ModelRdfV0_5, ModelPackingError> {
+        let (inputs, outputs) = self.interface.dump(writer)?;
         let covers = self.covers.iter().map(|cov| {
-            cov.dump(&mut writer)
-        }).collect::<Result<Vec<_>, _>>()?;
-        let attachments = self.attachments.iter().map(|file|{
-            file.dump_as_file_description(&mut writer)
+            cov.dump(writer)
         }).collect::<Result<Vec<_>, _>>()?;
+        let mut attachment_descriptions = serde_json::Map::new();
+        let attachments = self.attachments.iter().map(|attachment|{
+            let file_descr = attachment.source.dump_as_file_description(writer, reborrow_progress(&mut on_progress))?;
+            if let (Some(description), FileReference::Path(path)) = (&attachment.description, &file_descr.source){
+                attachment_descriptions.insert(String::from(path), serde_json::Value::String(description.clone()));
+            }
+            Ok(file_descr)
+        }).collect::<Result<Vec<_>, ModelPackingError>>()?;
+        let mut config = self.config;
+        crate::attachment::record_descriptions(&mut config, attachment_descriptions);
         let icon: Option<rdf::Icon> = match &self.icon{
-            Some(icon) => Some(icon.dump(&mut writer)?),
+            Some(icon) => Some(icon.dump(writer)?),
             None => None,
         };
         let documentation: FileReference = {
-            let documentation_path = FsPath::unique_suffixed("_README.md");
+            let documentation_path = writer.unique_path("_README.md")?;
             writer.write_file(&documentation_path, |writer| -> Result<FileReference, std::io::Error> {
                 writer.write_all(self.documentation.as_bytes())?;
                 Ok(FileReference::Path(documentation_path.clone()))
             })?
         };
-        let timestamp = iso8601_timestamp::Timestamp::now_utc();
-        let weights = self.weights.rdf_dump(&mut writer)?;
+        let weights = self.weights.rdf_dump(writer, on_progress)?;
 
-        let model_rdf = ModelRdfV0_5 {
+        Ok(ModelRdfV0_5 {
             description: self.description,
             covers,
             id: None,
             attachments,
             cite: self.cite,
-            config: self.config,
+            config,
             git_repo: self.git_repo,
             icon,
             links: self.links,
@@ -253,6 +391,7 @@ impl ZooModel {
             tags: self.tags,
             version: self.version,
             version_comment: self.version_comment,
+            uploader: self.uploader,
             format_version: Version_0_5_x::new(),
             rdf_type: RdfTypeModel,
             authors: self.authors,
@@ -261,18 +400,139 @@ impl ZooModel {
             license: self.license,
             name: self.name,
             outputs,
-            run_mode: None,
-            timestamp,
-            training_data: None, //FIXME
+            parent: self.parent,
+            run_mode: self.run_mode,
+            timestamp: options.timestamp.unwrap_or(self.timestamp),
+            training_data: self.training_data,
             weights,
+        })
+    }
+}
+
+/// A small but complete model whose files are all in memory, for tests that need something to pack
+#[cfg(test)]
+fn tiny_test_model() -> ZooModel{
+    use std::sync::Arc;
+    use serde_json::json;
+    use crate::{NpyArray, TorchscriptWeights, WeightsBase};
+
+    let test_tensor = || Arc::new(NpyArray::ArrayF32(ndarray::ArrayD::zeros(ndarray::IxDyn(&[1, 4]))));
+    let axes = json!([{"type": "batch"}, {"type": "space", "id": "x", "size": 4}]);
+    let input = InputSlot{
+        tensor_meta: serde_json::from_value(json!({"id": "raw", "description": "Raw input", "axes": axes})).unwrap(),
+        test_tensor: test_tensor(),
+    };
+    let output = OutputSlot{
+        tensor_meta: serde_json::from_value(json!({"id": "out", "description": "Prediction", "axes": axes})).unwrap(),
+        test_tensor: test_tensor(),
+    };
+    let torchscript = TorchscriptWeights{
+        weights: WeightsBase{
+            source: FileSource::Data{data: Arc::from(b"not really weights".as_slice()), name: Some("weights.pt".into())},
+            authors: None,
+            parent: None,
+        },
+        pytorch_version: Version::major_minor_patch(2, 0, 0),
+    };
+    ZooModel::builder()
+        .name(serde_json::from_value(json!("Tiny Model")).unwrap())
+        .description(serde_json::from_value(json!("A model for tests")).unwrap())
+        .author(serde_json::from_value(json!({"name": "John Doe"})).unwrap())
+        .cite(serde_json::from_value(json!({"text": "Some paper", "doi": "10.1109/CVPR.2016.90"})).unwrap())
+        .documentation("# Tiny Model".into())
+        .input(input)
+        .output(output)
+        .weights(ModelWeights::new(None, None, None, None, None, Some(torchscript)).unwrap())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_packing_is_reproducible(){
+    let options = PackingOptions{timestamp: iso8601_timestamp::Timestamp::parse("2024-01-01T00:00:00Z")};
+    let pack = || {
+        let mut packed = std::io::Cursor::new(Vec::<u8>::new());
+        tiny_test_model().pack_into_with(&mut packed, &options).unwrap();
+        packed.into_inner()
+    };
+    assert_eq!(pack(), pack());
+}
+
+#[test]
+fn test_placeholder_rdf_matches_packed_paths(){
+    let placeholder_rdf = serde_json::to_value(tiny_test_model().rdf_with_placeholders().unwrap()).unwrap();
+
+    let mut packed = std::io::Cursor::new(Vec::<u8>::new());
+    tiny_test_model().pack_into(&mut packed).unwrap();
+    let archive = SharedZipArchive::from_raw_data(packed.into_inner(), "tiny model".to_owned());
+    let packed_rdf: serde_json::Value = serde_yaml::from_slice(&archive.read_full_entry("rdf.yaml").unwrap()).unwrap();
+
+    for field in ["/documentation", "/weights/torchscript/source", "/inputs/0/test_tensor/source", "/outputs/0/test_tensor/source"]{
+        assert_eq!(placeholder_rdf.pointer(field), packed_rdf.pointer(field), "{field}");
+    }
+}
+
+#[test]
+fn test_dry_run_matches_packed_entries(){
+    use std::sync::Arc;
+    use serde_json::json;
+    use crate::{TorchscriptWeights, WeightsBase};
+
+    let model_with_attachment = |attachment: FileSource| {
+        let axes = json!([{"type": "batch"}, {"type": "space", "id": "x", "size": 3}]);
+        let test_tensor = || Arc::new(NpyArray::ArrayF32(ndarray::ArrayD::zeros(ndarray::IxDyn(&[1, 3]))));
+        let torchscript = TorchscriptWeights{
+            weights: WeightsBase{
+                source: FileSource::Data{data: Arc::from(b"fake weights".as_slice()), name: Some("weights.pt".into())},
+                authors: None,
+                parent: None,
+            },
+            pytorch_version: Version::major_minor_patch(2, 1, 0),
         };
-        let model_json_val = serde_json::to_value(&model_rdf).unwrap();
+        ZooModel::builder()
+            .name(serde_json::from_value(json!("Dry Run Model")).unwrap())
+            .description(serde_json::from_value(json!("A model with one packed file of each kind")).unwrap())
+            .author(serde_json::from_value(json!({"name": "John Doe"})).unwrap())
+            .cite(serde_json::from_value(json!({"text": "Some paper", "doi": "10.1109/CVPR.2016.90"})).unwrap())
+            .documentation("# Dry Run Model".into())
+            .input(InputSlot{
+                tensor_meta: serde_json::from_value(json!({"id": "in", "description": "Input", "axes": axes})).unwrap(),
+                test_tensor: test_tensor(),
+            })
+            .output(OutputSlot{
+                tensor_meta: serde_json::from_value(json!({"id": "out", "description": "Output", "axes": axes})).unwrap(),
+                test_tensor: test_tensor(),
+            })
+            .weights(ModelWeights::new(None, None, None, None, None, Some(torchscript)).unwrap())
+            .attachment(Attachment::from(attachment))
+            .build()
+            .unwrap()
+    };
+    let csv_attachment = || FileSource::Data{data: Arc::from(b"a,b\n1,2\n".as_slice()), name: Some("table.csv".into())};
 
-        let rdf_file_name = FsPathComponent::try_from("rdf.yaml".to_owned()).unwrap();
-        let rdf_path = FsPath::from_components(vec![rdf_file_name]).unwrap();
-        writer.write_file(&rdf_path, |writer| serde_yaml::to_writer(writer, &model_json_val))?;
+    let dry_run = model_with_attachment(csv_attachment()).pack_dry_run(&PackingOptions::default());
+    assert!(dry_run.error.is_none());
 
-        writer.finish()?;
-        Ok(())
+    let mut packed = std::io::Cursor::new(Vec::<u8>::new());
+    model_with_attachment(csv_attachment()).pack_into(&mut packed).unwrap();
+    let archive = SharedZipArchive::from_raw_data(packed.into_inner(), "dry run model".to_owned());
+    let mut packed_names = archive.with_file_names(|names| names.map(|name| name.to_owned()).collect::<Vec<_>>());
+    packed_names.sort();
+    let mut dry_run_names: Vec<String> = dry_run.entries.iter().map(|entry| String::from(&entry.path)).collect();
+    dry_run_names.sort();
+    assert_eq!(dry_run_names, packed_names);
+    assert!(dry_run_names.iter().any(|name| name.ends_with(".csv")));
+    for entry in &dry_run.entries{
+        let path = String::from(&entry.path);
+        assert_eq!(entry.size_bytes, archive.read_full_entry(&path).unwrap().len() as u64, "{path}");
     }
+
+    // attachments are written after the model interface, so its entries are still listed
+    let broken_dry_run = model_with_attachment(FileSource::LocalFile{
+        path: std::path::Path::new("/this/file/does/not/exist.txt").into(),
+    }).pack_dry_run(&PackingOptions::default());
+    assert!(broken_dry_run.error.is_some());
+    assert!(!broken_dry_run.entries.is_empty());
+    assert!(broken_dry_run.entries.iter().all(|entry| dry_run.entries.iter().any(|full| full.path == entry.path)));
+    assert!(!broken_dry_run.entries.iter().any(|entry| String::from(&entry.path) == "rdf.yaml"));
 }
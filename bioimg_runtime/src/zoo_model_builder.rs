@@ -0,0 +1,274 @@
+//! A fluent way of assembling a [ZooModel] in code, without having to build every `NonEmptyList`
+//! and optional field by hand.
+//!
+//! ```ignore
+//! // see the tests at the bottom of this module for a complete example
+//! let model = ZooModel::builder()
+//!     .name(name)
+//!     .description(description)
+//!     .author(author)
+//!     .cite(cite_entry)
+//!     .input(input_slot)
+//!     .output(output_slot)
+//!     .weights(weights)
+//!     .build()?;
+//! ```
+
+use bioimg_spec::rdf;
+use bioimg_spec::rdf::author::Author2;
+use bioimg_spec::rdf::bounded_string::BoundedString;
+use bioimg_spec::rdf::maintainer::Maintainer;
+use bioimg_spec::rdf::model::{self as modelrdf, ModelRdfName};
+use bioimg_spec::rdf::non_empty_list::NonEmptyList;
+use bioimg_spec::rdf::{LicenseId, ResourceId, Version};
+
+use crate::model_interface::{InputSlot, OutputSlot};
+use crate::npy_array::ArcNpyArray;
+use crate::zoo_model::ZooModel;
+use crate::{Attachment, CoverImage, Icon, ModelInterface, ModelWeights, TensorValidationError};
+
+#[derive(thiserror::Error, Debug)]
+pub enum ZooModelBuildError {
+    #[error("Missing required field '{0}'")]
+    MissingField(&'static str),
+    #[error("A model needs at least one entry in '{0}'")]
+    EmptyList(&'static str),
+    #[error("Invalid inputs/outputs: {0}")]
+    TensorValidationError(#[from] TensorValidationError),
+}
+
+/// Builds a [ZooModel], see [ZooModel::builder]. Optional fields start out empty, the license
+/// defaults to CC-BY-4.0 and the timestamp to the time of [Self::build]
+pub struct ZooModelBuilder {
+    name: Option<ModelRdfName>,
+    description: Option<rdf::ResourceTextDescription>,
+    authors: Vec<Author2>,
+    cite: Vec<rdf::CiteEntry2>,
+    inputs: Vec<InputSlot<ArcNpyArray>>,
+    outputs: Vec<OutputSlot<ArcNpyArray>>,
+    weights: Option<ModelWeights>,
+    license: LicenseId,
+    documentation: String,
+    covers: Vec<CoverImage>,
+    attachments: Vec<Attachment>,
+    config: serde_json::Map<String, serde_json::Value>,
+    git_repo: Option<rdf::HttpUrl>,
+    icon: Option<Icon>,
+    links: Vec<String>,
+    maintainers: Vec<Maintainer>,
+    tags: Vec<rdf::Tag>,
+    version: Option<Version>,
+    version_comment: Option<BoundedString<0, 512>>,
+    uploader: Option<rdf::Uploader>,
+    id: Option<ResourceId>,
+    parent: Option<modelrdf::LinkedModel>,
+    run_mode: Option<modelrdf::RunMode>,
+    timestamp: Option<iso8601_timestamp::Timestamp>,
+    training_data: Option<modelrdf::dataset_descr::DatasetDescrEnum>,
+}
+
+impl Default for ZooModelBuilder {
+    fn default() -> Self {
+        Self {
+            name: None,
+            description: None,
+            authors: vec![],
+            cite: vec![],
+            inputs: vec![],
+            outputs: vec![],
+            weights: None,
+            license: LicenseId::CC_BY_4_0,
+            documentation: String::new(),
+            covers: vec![],
+            attachments: vec![],
+            config: serde_json::Map::new(),
+            git_repo: None,
+            icon: None,
+            links: vec![],
+            maintainers: vec![],
+            tags: vec![],
+            version: None,
+            version_comment: None,
+            uploader: None,
+            id: None,
+            parent: None,
+            run_mode: None,
+            timestamp: None,
+            training_data: None,
+        }
+    }
+}
+
+macro_rules! setter {( $(#[$meta:meta])* $field:ident: $type:ty ) => {
+    $(#[$meta])*
+    pub fn $field(mut self, $field: $type) -> Self {
+        self.$field = $field.into();
+        self
+    }
+};}
+
+macro_rules! pusher {( $(#[$meta:meta])* $method:ident => $field:ident: $type:ty ) => {
+    $(#[$meta])*
+    pub fn $method(mut self, item: $type) -> Self {
+        self.$field.push(item);
+        self
+    }
+};}
+
+impl ZooModelBuilder {
+    setter!(name: ModelRdfName);
+    setter!(description: rdf::ResourceTextDescription);
+    setter!(weights: ModelWeights);
+    setter!(license: LicenseId);
+    setter!(
+        /// The markdown documentation of the model
+        documentation: String
+    );
+    setter!(git_repo: rdf::HttpUrl);
+    setter!(icon: Icon);
+    setter!(version: Version);
+    setter!(version_comment: BoundedString<0, 512>);
+    setter!(uploader: rdf::Uploader);
+    setter!(id: ResourceId);
+    setter!(parent: modelrdf::LinkedModel);
+    setter!(run_mode: modelrdf::RunMode);
+    setter!(timestamp: iso8601_timestamp::Timestamp);
+    setter!(training_data: modelrdf::dataset_descr::DatasetDescrEnum);
+    setter!(config: serde_json::Map<String, serde_json::Value>);
+
+    pusher!(author => authors: Author2);
+    pusher!(cite => cite: rdf::CiteEntry2);
+    pusher!(input => inputs: InputSlot<ArcNpyArray>);
+    pusher!(output => outputs: OutputSlot<ArcNpyArray>);
+    pusher!(cover => covers: CoverImage);
+    pusher!(attachment => attachments: Attachment);
+    pusher!(link => links: String);
+    pusher!(maintainer => maintainers: Maintainer);
+    pusher!(tag => tags: rdf::Tag);
+
+    /// Replaces the inputs and outputs with those of an already validated `interface`
+    pub fn interface(mut self, interface: ModelInterface<ArcNpyArray>) -> Self {
+        self.inputs = interface.inputs().iter().cloned().collect();
+        self.outputs = interface.outputs().iter().cloned().collect();
+        self
+    }
+
+    /// Checks that all required fields were set and that the inputs and outputs are consistent
+    /// with each other and with their test tensors
+    pub fn build(self) -> Result<ZooModel, ZooModelBuildError> {
+        let name = self.name.ok_or(ZooModelBuildError::MissingField("name"))?;
+        let description = self.description.ok_or(ZooModelBuildError::MissingField("description"))?;
+        let weights = self.weights.ok_or(ZooModelBuildError::MissingField("weights"))?;
+        let authors = NonEmptyList::try_from(self.authors).map_err(|_| ZooModelBuildError::EmptyList("authors"))?;
+        let cite = NonEmptyList::try_from(self.cite).map_err(|_| ZooModelBuildError::EmptyList("cite"))?;
+        let interface = ModelInterface::try_build(self.inputs, self.outputs)?;
+        Ok(ZooModel {
+            description,
+            covers: self.covers,
+            attachments: self.attachments,
+            cite,
+            config: self.config,
+            git_repo: self.git_repo,
+            icon: self.icon,
+            links: self.links,
+            maintainers: self.maintainers,
+            tags: self.tags,
+            version: self.version,
+            version_comment: self.version_comment,
+            uploader: self.uploader,
+            authors,
+            documentation: self.documentation,
+            license: self.license,
+            name,
+            id: self.id,
+            parent: self.parent,
+            run_mode: self.run_mode,
+            timestamp: self.timestamp.unwrap_or_else(iso8601_timestamp::Timestamp::now_utc),
+            training_data: self.training_data,
+            weights,
+            interface,
+        })
+    }
+}
+
+#[cfg(test)]
+fn torchscript_weights() -> ModelWeights {
+    use std::sync::Arc;
+    use crate::{FileSource, TorchscriptWeights, WeightsBase};
+
+    let torchscript = TorchscriptWeights {
+        weights: WeightsBase {
+            source: FileSource::Data { data: Arc::from(b"not really weights".as_slice()), name: Some("model.pt".into()) },
+            authors: None,
+            parent: None,
+        },
+        pytorch_version: Version::major_minor_patch(2, 0, 0),
+    };
+    ModelWeights::new(None, None, None, None, None, Some(torchscript)).unwrap()
+}
+
+#[test]
+fn test_build_pack_and_load() {
+    use std::sync::Arc;
+    use serde_json::json;
+    use crate::zip_archive_ext::SharedZipArchive;
+    use crate::NpyArray;
+
+    let axes = json!([{"type": "batch"}, {"type": "space", "id": "y", "size": 8}, {"type": "space", "id": "x", "size": 8}]);
+    let test_tensor = || Arc::new(NpyArray::ArrayF32(ndarray::ArrayD::zeros(ndarray::IxDyn(&[1, 8, 8]))));
+    let model = ZooModel::builder()
+        .name(serde_json::from_value(json!("Built Model")).unwrap())
+        .description(serde_json::from_value(json!("A model assembled by the builder")).unwrap())
+        .author(serde_json::from_value(json!({"name": "Jane Doe"})).unwrap())
+        .cite(serde_json::from_value(json!({"text": "Some paper", "doi": "10.1109/CVPR.2016.90"})).unwrap())
+        .documentation("# Built Model".into())
+        .license(LicenseId::MIT)
+        .tag(serde_json::from_value(json!("segmentation")).unwrap())
+        .version(Version::major_minor_patch(1, 2, 3))
+        .timestamp(iso8601_timestamp::Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+        .input(InputSlot {
+            tensor_meta: serde_json::from_value(json!({"id": "image", "description": "Image to segment", "axes": axes})).unwrap(),
+            test_tensor: test_tensor(),
+        })
+        .output(OutputSlot {
+            tensor_meta: serde_json::from_value(json!({"id": "mask", "description": "Foreground mask", "axes": axes})).unwrap(),
+            test_tensor: test_tensor(),
+        })
+        .weights(torchscript_weights())
+        .build()
+        .unwrap();
+
+    let mut packed = std::io::Cursor::new(Vec::<u8>::new());
+    model.pack_into(&mut packed).unwrap();
+    let archive = SharedZipArchive::from_raw_data(packed.into_inner(), "built model".to_owned());
+    let loaded = ZooModel::try_load_archive(archive).unwrap();
+
+    assert_eq!(json!(loaded.name), json!("Built Model"));
+    assert_eq!(loaded.documentation, "# Built Model");
+    assert_eq!(json!(loaded.license), json!(LicenseId::MIT));
+    assert_eq!(loaded.tags, model.tags);
+    assert_eq!(json!(loaded.version), json!("1.2.3"));
+    assert_eq!(json!(loaded.authors), json!(model.authors));
+    assert_eq!(json!(loaded.timestamp), json!(model.timestamp));
+    assert_eq!(loaded.interface.tensor_ids(), model.interface.tensor_ids());
+    assert_eq!(loaded.interface.inputs()[0].test_tensor.shape(), &[1, 8, 8]);
+    assert!(loaded.weights.torchscript().is_some());
+}
+
+#[test]
+fn test_build_reports_missing_fields() {
+    use serde_json::json;
+
+    let without_weights = ZooModel::builder()
+        .name(serde_json::from_value(json!("Built Model")).unwrap())
+        .description(serde_json::from_value(json!("A model assembled by the builder")).unwrap())
+        .build();
+    assert!(matches!(without_weights, Err(ZooModelBuildError::MissingField("weights"))));
+
+    let without_authors = ZooModel::builder()
+        .name(serde_json::from_value(json!("Built Model")).unwrap())
+        .description(serde_json::from_value(json!("A model assembled by the builder")).unwrap())
+        .weights(torchscript_weights())
+        .build();
+    assert!(matches!(without_authors, Err(ZooModelBuildError::EmptyList("authors"))));
+}
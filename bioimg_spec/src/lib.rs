@@ -2,3 +2,5 @@ extern crate self as bioimg_spec;
 
 pub mod rdf;
 pub mod util;
+#[cfg(feature = "python")]
+mod py;
@@ -1,6 +1,9 @@
+use numpy::{IntoPyArray, PyArrayDyn, PyReadonlyArrayDyn};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 use crate::rdf::Author2;
+use crate::rdf::model::{postprocessing::PostprocessingDescr, PreprocessingDescr};
 
 /// Formats the sum of two numbers as string.
 #[pyfunction]
@@ -8,10 +11,37 @@ fn sum_as_string(a: usize, b: usize) -> PyResult<String> {
     Ok((a + b).to_string())
 }
 
+/// Applies a preprocessing step, given as in an rdf.yaml (e.g. `{"id": "clip", "kwargs": {"min": 0, "max": 1}}`),
+/// to `tensor`. `axes` are the ids of the dimensions of `tensor`, e.g. `["batch", "channel", "y", "x"]`
+#[pyfunction]
+fn apply_preprocessing<'py>(
+    py: Python<'py>, step: &str, tensor: PyReadonlyArrayDyn<'py, f32>, axes: Vec<String>
+) -> PyResult<Bound<'py, PyArrayDyn<f32>>> {
+    let step: PreprocessingDescr = serde_yaml::from_str(step).map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let axis_ids: Vec<&str> = axes.iter().map(String::as_str).collect();
+    let processed = step.apply(&tensor.as_array().to_owned(), &axis_ids)
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    Ok(processed.into_pyarray(py))
+}
+
+/// Like `apply_preprocessing`, but for a postprocessing step
+#[pyfunction]
+fn apply_postprocessing<'py>(
+    py: Python<'py>, step: &str, tensor: PyReadonlyArrayDyn<'py, f32>, axes: Vec<String>
+) -> PyResult<Bound<'py, PyArrayDyn<f32>>> {
+    let step: PostprocessingDescr = serde_yaml::from_str(step).map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let axis_ids: Vec<&str> = axes.iter().map(String::as_str).collect();
+    let processed = step.apply(&tensor.as_array().to_owned(), &axis_ids)
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    Ok(processed.into_pyarray(py))
+}
+
 /// A Python module implemented in Rust.
 #[pymodule]
 fn bioimg_spec(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(sum_as_string, m)?)?;
+    m.add_function(wrap_pyfunction!(apply_preprocessing, m)?)?;
+    m.add_function(wrap_pyfunction!(apply_postprocessing, m)?)?;
     register_author_submodule(m)?;
     Ok(())
 }
@@ -35,3 +65,20 @@ impl Author2{
         })
     }
 }
+
+#[test]
+fn test_apply_preprocessing_to_numpy_array(){
+    use numpy::PyArrayMethods;
+
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py|{
+        let tensor = ndarray::array![[-1.0f32, 0.5], [2.0, 3.0]].into_dyn().into_pyarray(py);
+        let axes = vec!["channel".to_owned(), "x".to_owned()];
+        let step = r#"{"id": "clip", "kwargs": {"min": 0.0, "max": 2.0}}"#;
+        let clipped = apply_preprocessing(py, step, tensor.readonly(), axes.clone()).unwrap();
+        assert_eq!(clipped.readonly().as_array(), ndarray::array![[0.0f32, 0.5], [2.0, 2.0]].into_dyn());
+
+        let bad_step = r#"{"id": "scale_linear", "kwargs": {"axis": "channel", "gain": [1.0, 2.0, 3.0]}}"#;
+        assert!(apply_preprocessing(py, bad_step, tensor.readonly(), axes).is_err());
+    });
+}
@@ -0,0 +1,56 @@
+use aspartial::AsPartial;
+
+use crate::rdf::literal::{LitStr, StrMarker};
+use crate::rdf::resource_description::ResourceDescrBase;
+use crate::rdf::version::Version_0_3_x;
+use crate::rdf::FileReference;
+
+#[derive(Default, Clone, Copy, Debug)]
+pub struct Application;
+impl StrMarker for Application { const NAME: &'static str = "application"; }
+
+/// A bioimage.io application resource description file (application RDF), describing a tool or
+/// plugin that consumes bioimage.io resources, e.g. an ImageJ plugin or a web app
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[aspartial(name = PartialApplicationDescr)]
+pub struct ApplicationDescr{
+    /// Version of the bioimage.io resource description specification used
+    pub format_version: Version_0_3_x,
+
+    #[serde(rename = "type")]
+    /// Specialized resource type 'application'
+    pub rdf_type: LitStr<Application>,
+
+    #[serde(flatten)]
+    pub base: ResourceDescrBase,
+
+    /// URL or path to the source of the application
+    #[serde(default)]
+    pub source: Option<FileReference>,
+}
+
+#[test]
+fn test_application_badges(){
+    let raw = r#"
+format_version: 0.3.0
+type: application
+id: deepimagej
+name: deepImageJ
+description: A user-friendly plugin to run deep learning models in ImageJ
+badges:
+  - label: Open in Colab
+    icon: https://colab.research.google.com/assets/colab-badge.svg
+    url: https://colab.research.google.com/github/example/notebook.ipynb
+  - label: Docs
+    url: https://deepimagej.github.io
+source: https://github.com/deepimagej/deepimagej-plugin
+"#;
+    let application: ApplicationDescr = serde_yaml::from_str(raw).unwrap();
+    assert_eq!(application.base.id.as_ref().map(|id| id.to_string()).as_deref(), Some("deepimagej"));
+    assert_eq!(application.base.badges.len(), 2);
+    assert_eq!(application.base.badges[0].label.to_string(), "Open in Colab");
+    assert!(application.base.badges[0].icon.is_some());
+    assert!(application.base.badges[1].icon.is_none());
+    assert_eq!(application.base.badges[1].url.to_string(), "https://deepimagej.github.io");
+}
@@ -16,7 +16,10 @@ pub struct Author {
     pub orcid: Orcid,
 }
 
+/// An author of a resource, i.e. someone who contributed to its creation
 #[derive(Debug, Eq, PartialEq, Serialize, Deserialize, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "python", pyo3::pyclass)]
 #[aspartial(name = PartialAuthor2)]
 pub struct Author2 {
     pub name: BoundedString<1, 1024>,                // (Name→String) Full name.
@@ -35,6 +35,17 @@ impl<T: Borrow<str>> Borrow<str> for BasicCharsString<T>{
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a> + Borrow<str>> arbitrary::Arbitrary<'a> for BasicCharsString<T>{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let inner = T::arbitrary(u)?;
+        if inner.borrow().chars().any(|c| !Self::ALLOWED_CHARS.contains(&c)) {
+            return Err(arbitrary::Error::IncorrectFormat)
+        }
+        Ok(Self(inner))
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum BasicCharsStringError {
     #[error("{0}")]
@@ -63,6 +63,14 @@ impl<const MIN_CHARS: usize, const MAX_CHARS: usize> TryFrom<String> for Bounded
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, const MIN_CHARS: usize, const MAX_CHARS: usize> arbitrary::Arbitrary<'a> for BoundedString<MIN_CHARS, MAX_CHARS> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let raw = crate::util::arbitrary_values::string_from(u, crate::util::arbitrary_values::PLAIN_CHARS, MIN_CHARS, MAX_CHARS)?;
+        Self::try_from(raw).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl<const MIN_CHARS: usize, const MAX_CHARS: usize> Display for BoundedString<MIN_CHARS, MAX_CHARS> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -29,6 +29,18 @@ pub struct CiteEntry2 {
     url: Option<HttpUrl>,
 }
 
+// Entries without a DOI or URL get a DOI instead of being rejected
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CiteEntry2{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut msg: CiteEntry2Msg = u.arbitrary()?;
+        if msg.doi.is_none() && msg.url.is_none(){
+            msg.doi = Some(u.arbitrary()?);
+        }
+        Self::try_from(msg).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl AsPartial for CiteEntry2 {
     type Partial = PartialCiteEntry2Msg;
     fn to_partial(self) -> Self::Partial {
@@ -46,6 +58,7 @@ impl CiteEntry2{
 }
 
 #[derive(serde::Serialize, serde::Deserialize, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialCiteEntry2Msg)]
 pub struct CiteEntry2Msg{
     pub text: BoundedString<1, 1024>,        //(String) free text description
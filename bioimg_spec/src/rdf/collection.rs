@@ -0,0 +1,204 @@
+use aspartial::AsPartial;
+use serde_json::{Map, Value};
+
+use crate::rdf::generic::GenericResourceDescr;
+use crate::rdf::literal::{LitStr, StrMarker};
+use crate::rdf::model::ModelRdfV0_5;
+use crate::rdf::resource_description::ResourceDescrBase;
+use crate::rdf::version::Version_0_3_x;
+use crate::rdf::{ApplicationDescr, DatasetDescr, FileReference, NotebookDescr};
+
+#[derive(Default, Clone, Copy, Debug)]
+pub struct Collection;
+impl StrMarker for Collection { const NAME: &'static str = "collection"; }
+
+/// Fields of a collection that its entries inherit, unless they (or the rdf they point to) override them
+const INHERITED_FIELDS: &[&str] = &["authors", "cite", "license", "maintainers", "uploader", "git_repo", "tags", "links", "config"];
+
+/// Fields whose values are files relative to the rdf they are written in, at any depth (e.g. `weights.onnx.source`)
+const FILE_FIELDS: &[&str] = &["source", "documentation", "covers"];
+
+/// One of the resources in a collection. The entry's own fields override those of the rdf in
+/// `rdf_source`, which in turn override the ones inherited from the collection
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[aspartial(name = PartialCollectionEntry)]
+pub struct CollectionEntry{
+    /// The resource description file this entry is based on
+    #[serde(default)]
+    pub rdf_source: Option<FileReference>,
+
+    /// Fields of the resource description set (or overridden) by the entry itself
+    #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::util::arbitrary_values::json_object))]
+    pub overrides: Map<String, Value>,
+}
+
+/// A bioimage.io collection resource description file (collection RDF), grouping other resources
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[aspartial(name = PartialCollectionDescr)]
+pub struct CollectionDescr{
+    /// Version of the bioimage.io resource description specification used
+    pub format_version: Version_0_3_x,
+
+    #[serde(rename = "type")]
+    /// Specialized resource type 'collection'
+    pub rdf_type: LitStr<Collection>,
+
+    #[serde(flatten)]
+    pub base: ResourceDescrBase,
+
+    /// The resources in this collection
+    pub collection: Vec<CollectionEntry>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CollectionEntryError{
+    #[error("Could not load {rdf_source}: {reason}")]
+    LoadingError{rdf_source: FileReference, reason: String},
+    #[error("Entry is not a valid resource description: {0}")]
+    ParsingError(#[from] serde_json::Error),
+}
+
+/// The description of a collection entry, parsed according to its `type`
+#[derive(Debug, Clone)]
+pub enum ResolvedResourceDescr{
+    Model(Box<ModelRdfV0_5>),
+    Dataset(DatasetDescr),
+    Notebook(NotebookDescr),
+    Application(ApplicationDescr),
+    /// Any other type of resource, with only the fields common to all of them
+    Generic(GenericResourceDescr),
+}
+
+impl ResolvedResourceDescr{
+    fn from_raw(raw: Map<String, Value>) -> Result<Self, serde_json::Error>{
+        let rdf_type = raw.get("type").and_then(|rdf_type| rdf_type.as_str()).map(|rdf_type| rdf_type.to_owned());
+        let raw = Value::Object(raw);
+        Ok(match rdf_type.as_deref(){
+            Some("model") => Self::Model(Box::new(serde_json::from_value(raw)?)),
+            Some("dataset") => Self::Dataset(serde_json::from_value(raw)?),
+            Some("notebook") => Self::Notebook(serde_json::from_value(raw)?),
+            Some("application") => Self::Application(serde_json::from_value(raw)?),
+            _ => Self::Generic(serde_json::from_value(raw)?),
+        })
+    }
+}
+
+/// A collection entry with all overrides applied
+#[derive(Debug, Clone)]
+pub struct ResolvedCollectionEntry{
+    /// The merged fields, with files of the `rdf_source` made relative to the collection
+    pub raw: Map<String, Value>,
+    pub descr: ResolvedResourceDescr,
+}
+
+/// Prefixes the relative paths in the [FILE_FIELDS] of `value` with `base_dir`, so that files that were
+/// relative to an entry's `rdf_source` become relative to the collection
+fn rebase_file_fields(value: &mut Value, base_dir: &str){
+    fn rebase(value: &mut Value, base_dir: &str){
+        match value{
+            Value::String(path) if !path.contains("://") && !path.starts_with('/') => {
+                *path = format!("{base_dir}{}", path.trim_start_matches("./"));
+            },
+            Value::Array(items) => items.iter_mut().for_each(|item| rebase(item, base_dir)),
+            // e.g. attachments and test tensors, whose file is in their own `source`
+            Value::Object(_) => rebase_file_fields(value, base_dir),
+            _ => (),
+        }
+    }
+    match value{
+        Value::Object(fields) => for (key, field) in fields.iter_mut(){
+            if FILE_FIELDS.contains(&key.as_str()){
+                rebase(field, base_dir);
+            } else {
+                rebase_file_fields(field, base_dir);
+            }
+        },
+        Value::Array(items) => items.iter_mut().for_each(|item| rebase_file_fields(item, base_dir)),
+        _ => (),
+    }
+}
+
+impl CollectionDescr{
+    fn inherited_fields(&self) -> Map<String, Value>{
+        let Ok(Value::Object(base)) = serde_json::to_value(&self.base) else {
+            return Map::new()
+        };
+        base.into_iter()
+            .filter(|(key, value)| INHERITED_FIELDS.contains(&key.as_str()) && !value.is_null())
+            .collect()
+    }
+
+    /// Merges every entry with the rdf it points to and the fields it inherits from this collection.
+    ///
+    /// `load_rdf` is called with the `rdf_source` of each entry that has one, and should return its
+    /// parsed contents
+    pub fn resolve_entries<E: std::fmt::Display>(
+        &self,
+        mut load_rdf: impl FnMut(&FileReference) -> Result<Map<String, Value>, E>,
+    ) -> Vec<Result<ResolvedCollectionEntry, CollectionEntryError>>{
+        let inherited = self.inherited_fields();
+        self.collection.iter().map(|entry|{
+            let mut raw = inherited.clone();
+            if let Some(rdf_source) = &entry.rdf_source{
+                let source_rdf = load_rdf(rdf_source).map_err(|err| CollectionEntryError::LoadingError{
+                    rdf_source: rdf_source.clone(), reason: err.to_string()
+                })?;
+                let mut source_rdf = Value::Object(source_rdf);
+                let rdf_source = rdf_source.to_string();
+                if let Some(dir_end) = rdf_source.rfind('/'){
+                    rebase_file_fields(&mut source_rdf, &rdf_source[..=dir_end]);
+                }
+                if let Value::Object(source_rdf) = source_rdf{
+                    raw.extend(source_rdf);
+                }
+            }
+            raw.extend(entry.overrides.clone());
+            let descr = ResolvedResourceDescr::from_raw(raw.clone())?;
+            Ok(ResolvedCollectionEntry{raw, descr})
+        }).collect()
+    }
+}
+
+#[test]
+fn test_collection_entry_resolution(){
+    let raw = r#"
+format_version: 0.3.0
+type: collection
+name: Nucleus tools
+description: Models and datasets for nucleus segmentation
+authors:
+  - name: Jane Doe
+license: MIT
+collection:
+  - rdf_source: datasets/nuclei/rdf.yaml
+    name: Nuclei (curated)
+  - format_version: 0.3.0
+    type: application
+    name: Nucleus viewer
+    description: Looks at nuclei
+    license: Apache-2.0
+"#;
+    let collection: CollectionDescr = serde_yaml::from_str(raw).unwrap();
+    let resolved = collection.resolve_entries(|rdf_source| -> Result<_, String>{
+        assert_eq!(rdf_source.to_string(), "datasets/nuclei/rdf.yaml");
+        let dataset = "format_version: 0.3.0\ntype: dataset\nname: Nuclei\ndescription: Some nuclei\n\
+            documentation: ./README.md\ncovers: [cover.png, https://example.com/cover.png]\n";
+        serde_yaml::from_str(dataset).map_err(|err| err.to_string())
+    });
+    let ResolvedResourceDescr::Dataset(dataset) = &resolved[0].as_ref().unwrap().descr else {
+        panic!("Expected a dataset: {:?}", resolved[0]);
+    };
+    assert_eq!(dataset.base.name.to_string(), "Nuclei (curated)");
+    assert_eq!(dataset.base.authors.len(), 1);
+    assert_eq!(dataset.base.license, Some(crate::rdf::LicenseId::MIT));
+    assert_eq!(dataset.base.documentation.as_ref().map(|doc| doc.to_string()).as_deref(), Some("datasets/nuclei/README.md"));
+    let covers: Vec<String> = dataset.base.covers.iter().map(|cover| cover.to_string()).collect();
+    assert_eq!(covers, ["datasets/nuclei/cover.png", "https://example.com/cover.png"]);
+    let ResolvedResourceDescr::Application(application) = &resolved[1].as_ref().unwrap().descr else {
+        panic!("Expected an application: {:?}", resolved[1]);
+    };
+    assert_eq!(application.base.license, Some(crate::rdf::LicenseId::Apache_2_0));
+}
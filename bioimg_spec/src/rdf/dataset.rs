@@ -0,0 +1,59 @@
+use aspartial::AsPartial;
+
+use crate::rdf::HttpUrl;
+use crate::rdf::model::dataset_descr::DatasetDescrMarker;
+use crate::rdf::resource_description::ResourceDescrBase;
+use crate::rdf::version::Version_0_3_x;
+
+/// A bioimage.io dataset resource description file (dataset RDF), describing a dataset relevant to
+/// bioimage processing on its own rather than as the training data of a model
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[aspartial(name = PartialDatasetDescr)]
+pub struct DatasetDescr{
+    /// Version of the bioimage.io resource description specification used
+    pub format_version: Version_0_3_x,
+
+    #[serde(rename = "type")]
+    /// Specialized resource type 'dataset'
+    pub rdf_type: DatasetDescrMarker,
+
+    #[serde(flatten)]
+    pub base: ResourceDescrBase,
+
+    /// URL to the source of the dataset
+    #[serde(default)]
+    pub source: Option<HttpUrl>,
+}
+
+#[test]
+fn test_dataset_descr_round_trip(){
+    let raw = r#"
+format_version: 0.3.0
+type: dataset
+name: Nuclei of U2OS cells
+description: Fluorescence microscopy images of nuclei with instance segmentation masks
+authors:
+  - name: Jane Doe
+cite:
+  - text: Some paper
+    doi: 10.1038/s41592-019-0612-7
+license: CC-BY-4.0
+tags: [nuclei, fluorescence]
+id: u2os-nuclei
+parent: u2os-nuclei-raw
+source: https://zenodo.org/records/1234
+"#;
+    let dataset: DatasetDescr = serde_yaml::from_str(raw).unwrap();
+    assert_eq!(dataset.base.authors.len(), 1);
+    assert_eq!(dataset.base.parent.as_ref().map(|parent| parent.to_string()).as_deref(), Some("u2os-nuclei-raw"));
+    assert_eq!(dataset.source.as_ref().map(|url| url.as_str()), Some("https://zenodo.org/records/1234"));
+
+    // the fields of the base stay at the top level, next to the ones specific to datasets
+    let serialized = serde_json::to_value(&dataset).unwrap();
+    assert_eq!(serialized["type"], "dataset");
+    assert_eq!(serialized["id"], "u2os-nuclei");
+    assert_eq!(serialized["source"], "https://zenodo.org/records/1234");
+    let reparsed: DatasetDescr = serde_json::from_value(serialized).unwrap();
+    assert_eq!(reparsed.base.name.to_string(), dataset.base.name.to_string());
+}
@@ -5,6 +5,7 @@ use aspartial::AsPartial;
 use super::{lowercase::Lowercase, BoundedString, EnvironmentFile, FileReference};
 
 #[derive(Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialFileDescription)]
 pub struct FileDescription<R = FileReference>
 where
@@ -33,6 +34,31 @@ impl<R: Borrow<FileReference>> Display for FileDescription<R>{
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 pub struct Sha256(Lowercase<BoundedString<64, 64>>);
 
+impl Sha256 {
+    /// Builds the lowercase hex representation of a raw sha256 digest
+    pub fn from_digest(digest: [u8; 32]) -> Self {
+        use std::fmt::Write;
+        let mut hex = String::with_capacity(64);
+        for byte in digest {
+            write!(&mut hex, "{byte:02x}").unwrap();
+        }
+        Self(Lowercase::try_from(hex).expect("hex digests are 64 lowercase characters"))
+    }
+}
+
+impl Display for Sha256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Sha256 {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_digest(u.arbitrary::<[u8; 32]>()?))
+    }
+}
+
 impl AsPartial for Sha256 {
     type Partial = String;
     fn to_partial(self) -> Self::Partial {
@@ -23,6 +23,14 @@ pub enum FsPathParsingError{
 #[serde(into = "String")]
 pub struct FsPathComponent(String);
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FsPathComponent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use crate::util::arbitrary_values::{string_from, IDENTIFIER_CHARS};
+        Self::try_from(string_from(u, IDENTIFIER_CHARS, 1, 16)?).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl Deref for FsPathComponent{
     type Target = str;
     fn deref(&self) -> &Self::Target {
@@ -65,6 +73,17 @@ pub struct FsPath{
     components: Vec<FsPathComponent>
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FsPath {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut components: Vec<FsPathComponent> = vec![u.arbitrary()?];
+        for component in u.arbitrary_iter()?.take(4) {
+            components.push(component?);
+        }
+        Self::from_components(components).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl AsPartial for FsPath {
     type Partial = String;
     fn to_partial(self) -> Self::Partial {
@@ -160,6 +179,16 @@ pub enum UrlParsingError{
 #[serde(try_from = "String")]
 pub struct HttpUrl(url::Url);
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for HttpUrl {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let scheme = u.choose(&["http", "https"])?;
+        let host = u.choose(&["example.com", "bioimage.io", "zenodo.org", "127.0.0.1:8080"])?;
+        let path: FsPath = u.arbitrary()?;
+        Self::try_from(format!("{scheme}://{host}/{path}")).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl AsPartial for HttpUrl{
     type Partial = String;
     fn to_partial(self) -> Self::Partial {
@@ -221,6 +250,7 @@ pub enum FileReferenceParsingError{
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(untagged)]
 pub enum FileReference {
     Url(HttpUrl),
@@ -275,9 +305,11 @@ impl TryFrom<String> for FileReference{
 
 
 macro_rules! suffixed_file_ref {(
+    $(#[$attr:meta])*
     struct $name:ident suffixes=[ $($suffix:literal),+ ]
 ) => {
     #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+    $(#[$attr])*
     pub struct $name(FileReference);
 
     impl TryFrom<FileReference> for $name{
@@ -300,6 +332,17 @@ macro_rules! suffixed_file_ref {(
         }
     }
 
+    #[cfg(feature = "arbitrary")]
+    impl<'a> arbitrary::Arbitrary<'a> for $name{
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>{
+            let base: FileReference = u.arbitrary()?;
+            let suffix = u.choose(&[ $($suffix),+ ])?;
+            FileReference::try_from(format!("{base}{suffix}"))
+                .and_then(Self::try_from)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
     impl Deref for $name{
         type Target = FileReference;
         fn deref(&self) -> &Self::Target{
@@ -323,6 +366,10 @@ macro_rules! suffixed_file_ref {(
 
 suffixed_file_ref!(struct CoverImageSource suffixes=[".gif", ".jpeg", ".jpg", ".png"]);
 suffixed_file_ref!(struct EnvironmentFile suffixes=[".yaml", ".yml"]);
+suffixed_file_ref!(
+    #[serde(try_from = "FileReference")]
+    struct NotebookSource suffixes=[".ipynb"]
+);
 
 #[test]
 fn test_file_reference() {
@@ -0,0 +1,47 @@
+use aspartial::AsPartial;
+
+use crate::rdf::resource_description::ResourceDescrBase;
+use crate::rdf::{FileReference, Version};
+
+/// Any bioimage.io resource description, whatever its `type`. Only the metadata shared by all
+/// resources is interpreted, so that tools can list and show resources this crate has no
+/// specialized description for (e.g. `type: workflow`)
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[aspartial(name = PartialGenericResourceDescr)]
+pub struct GenericResourceDescr{
+    /// Version of the bioimage.io resource description specification used. Not checked, since
+    /// each resource type has its own versions
+    pub format_version: Version,
+
+    /// The kind of resource, e.g. 'dataset' or 'workflow'
+    #[serde(rename = "type")]
+    pub rdf_type: String,
+
+    #[serde(flatten)]
+    pub base: ResourceDescrBase,
+
+    /// URL or path to the source of the resource
+    #[serde(default)]
+    pub source: Option<FileReference>,
+}
+
+#[test]
+fn test_generic_resource_descr(){
+    let raw = r#"
+format_version: 0.2.4
+type: workflow
+name: Nucleus segmentation workflow
+description: Segments nuclei and measures their intensity
+authors:
+  - name: Jane Doe
+tags: [segmentation]
+"#;
+    let resource: GenericResourceDescr = serde_yaml::from_str(raw).unwrap();
+    assert_eq!(resource.rdf_type, "workflow");
+    assert_eq!(resource.base.tags.len(), 1);
+    // unknown types keep their own type and format version when written back
+    let serialized = serde_json::to_value(&resource).unwrap();
+    assert_eq!(serialized["type"], "workflow");
+    assert_eq!(serialized["format_version"], "0.2.4");
+}
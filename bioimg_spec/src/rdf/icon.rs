@@ -13,6 +13,7 @@ pub enum IconParsingError {
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(untagged)]
 pub enum Icon {
     Emoji(EmojiIcon),
@@ -40,6 +41,14 @@ pub enum EmojiParsingError {
 #[serde(into = "String")]
 pub struct EmojiIcon(String);
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for EmojiIcon{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let emoji = u.choose(&["🦀", "🔬", "🧫", "🦠", "🧬", "👩‍🔬", "🔬🦠"])?;
+        Self::from_str(emoji).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl Borrow<str> for EmojiIcon{
     fn borrow(&self) -> &str {
         self.0.borrow()
@@ -15,6 +15,19 @@ const PYTHON_KEYWORDS: [&'static str; 35] = [
 #[serde(try_from = "String")]
 pub struct Identifier(Arc<str>);
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Identifier{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use crate::util::arbitrary_values::{string_from, IDENTIFIER_CHARS};
+        let first_char = *u.choose(&['a', 'x', 'Z', '_'])?;
+        let mut raw = format!("{first_char}{}", string_from(u, IDENTIFIER_CHARS, 0, 15)?);
+        if PYTHON_KEYWORDS.contains(&raw.as_str()){
+            raw.push('_');
+        }
+        Self::try_from(raw).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl Deref for Identifier{
     type Target = str;
     fn deref(&self) -> &Self::Target {
@@ -99,3 +112,102 @@ impl From<Identifier> for String {
         return value.0.as_ref().to_owned();
     }
 }
+
+/// A dotted path of python identifiers, like a module path (e.g. `my_package.my_module`)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, AsPartial)]
+#[aspartial(newtype)]
+#[serde(try_from = "String")]
+pub struct DottedIdentifier(Arc<str>);
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DottedIdentifier{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut components = vec![String::from(u.arbitrary::<Identifier>()?)];
+        for component in u.arbitrary_iter::<Identifier>()?.take(3){
+            components.push(component?.into());
+        }
+        Self::try_from(components.join(".")).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+impl Deref for DottedIdentifier{
+    type Target = str;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DottedIdentifier{
+    pub fn components(&self) -> impl Iterator<Item=&str>{
+        self.0.split('.')
+    }
+}
+
+impl Borrow<str> for DottedIdentifier {
+    fn borrow(&self) -> &str {
+        return self.0.borrow();
+    }
+}
+
+impl Display for DottedIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum DottedIdentifierParsingError {
+    #[error("Empty string can't be a dotted identifier")]
+    EmptyString,
+    #[error("Bad component '{component}' in '{value}': {source}")]
+    BadComponent { value: String, component: String, source: IdentifierParsingError },
+}
+
+impl FromStr for DottedIdentifier{
+    type Err = DottedIdentifierParsingError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<&str> for DottedIdentifier{
+    type Error = DottedIdentifierParsingError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if value.is_empty(){
+            return Err(DottedIdentifierParsingError::EmptyString)
+        }
+        for component in value.split('.'){
+            if let Err(source) = Identifier::try_from(component){
+                return Err(DottedIdentifierParsingError::BadComponent {
+                    value: value.into(), component: component.into(), source
+                })
+            }
+        }
+        Ok(Self(Arc::from(value)))
+    }
+}
+
+impl TryFrom<String> for DottedIdentifier{
+    type Error = DottedIdentifierParsingError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<DottedIdentifier> for String {
+    fn from(value: DottedIdentifier) -> Self {
+        return value.0.as_ref().to_owned();
+    }
+}
+
+#[test]
+fn test_dotted_identifier_parsing(){
+    assert!(DottedIdentifier::try_from("my_package.my_module").is_ok());
+    assert!(DottedIdentifier::try_from("torch").is_ok());
+    assert!(DottedIdentifier::try_from("my_package..my_module").is_err());
+    assert!(DottedIdentifier::try_from("my_package.").is_err());
+    assert!(DottedIdentifier::try_from("my-package.models").is_err());
+    assert!(DottedIdentifier::try_from("my_package.import").is_err());
+}
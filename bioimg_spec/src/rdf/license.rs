@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 #[allow(non_camel_case_types)]
 #[derive(Default, Serialize, Deserialize, Eq, PartialEq, Debug, Copy, Clone)]
 #[derive(strum::EnumString, strum::VariantArray, strum::VariantNames, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum LicenseId {
     #[serde(rename = "0BSD")]
     ZERO_BSD,
@@ -1060,6 +1061,85 @@ pub enum LicenseId {
     ZPL_2_1,
 }
 
+impl LicenseId {
+    /// Licenses recommended for models uploaded to the bioimage.io zoo
+    pub const ZOO_RECOMMENDED: &[LicenseId] = &[
+        LicenseId::CC_BY_4_0,
+        LicenseId::CC0_1_0,
+        LicenseId::MIT,
+        LicenseId::BSD_2_Clause,
+        LicenseId::BSD_3_Clause,
+        LicenseId::Apache_2_0,
+    ];
+
+    /// The SPDX identifier of this license, e.g. `CC-BY-4.0`
+    pub fn spdx_id(&self) -> String {
+        match serde_json::to_value(self) {
+            Ok(serde_json::Value::String(id)) => id,
+            _ => self.to_string(),
+        }
+    }
+
+    /// The page describing this license in the SPDX license list
+    pub fn spdx_url(&self) -> String {
+        format!("https://spdx.org/licenses/{}.html", self.spdx_id())
+    }
+
+    /// The full name of this license, for the most commonly used ones
+    pub fn name(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::AGPL_3_0_only => "GNU Affero General Public License v3.0 only",
+            Self::AGPL_3_0_or_later => "GNU Affero General Public License v3.0 or later",
+            Self::Apache_1_1 => "Apache License 1.1",
+            Self::Apache_2_0 => "Apache License 2.0",
+            Self::Artistic_2_0 => "Artistic License 2.0",
+            Self::BSD_1_Clause => "BSD 1-Clause License",
+            Self::BSD_2_Clause => "BSD 2-Clause \"Simplified\" License",
+            Self::BSD_2_Clause_Patent => "BSD-2-Clause Plus Patent License",
+            Self::BSD_3_Clause => "BSD 3-Clause \"New\" or \"Revised\" License",
+            Self::BSD_3_Clause_Clear => "BSD 3-Clause Clear License",
+            Self::BSD_4_Clause => "BSD 4-Clause \"Original\" or \"Old\" License",
+            Self::BSL_1_0 => "Boost Software License 1.0",
+            Self::CC_BY_2_0 => "Creative Commons Attribution 2.0 Generic",
+            Self::CC_BY_3_0 => "Creative Commons Attribution 3.0 Unported",
+            Self::CC_BY_4_0 => "Creative Commons Attribution 4.0 International",
+            Self::CC_BY_NC_4_0 => "Creative Commons Attribution Non Commercial 4.0 International",
+            Self::CC_BY_NC_ND_4_0 => "Creative Commons Attribution Non Commercial No Derivatives 4.0 International",
+            Self::CC_BY_NC_SA_4_0 => "Creative Commons Attribution Non Commercial Share Alike 4.0 International",
+            Self::CC_BY_ND_4_0 => "Creative Commons Attribution No Derivatives 4.0 International",
+            Self::CC_BY_SA_3_0 => "Creative Commons Attribution Share Alike 3.0 Unported",
+            Self::CC_BY_SA_4_0 => "Creative Commons Attribution Share Alike 4.0 International",
+            Self::CC0_1_0 => "Creative Commons Zero v1.0 Universal",
+            Self::CECILL_2_1 => "CeCILL Free Software License Agreement v2.1",
+            Self::EPL_1_0 => "Eclipse Public License 1.0",
+            Self::EPL_2_0 => "Eclipse Public License 2.0",
+            Self::EUPL_1_2 => "European Union Public License 1.2",
+            Self::GPL_2_0_only => "GNU General Public License v2.0 only",
+            Self::GPL_2_0_or_later => "GNU General Public License v2.0 or later",
+            Self::GPL_3_0_only => "GNU General Public License v3.0 only",
+            Self::GPL_3_0_or_later => "GNU General Public License v3.0 or later",
+            Self::ISC => "ISC License",
+            Self::LGPL_2_1_only => "GNU Lesser General Public License v2.1 only",
+            Self::LGPL_2_1_or_later => "GNU Lesser General Public License v2.1 or later",
+            Self::LGPL_3_0_only => "GNU Lesser General Public License v3.0 only",
+            Self::LGPL_3_0_or_later => "GNU Lesser General Public License v3.0 or later",
+            Self::MIT => "MIT License",
+            Self::MIT_0 => "MIT No Attribution",
+            Self::MPL_2_0 => "Mozilla Public License 2.0",
+            Self::MS_PL => "Microsoft Public License",
+            Self::OFL_1_1 => "SIL Open Font License 1.1",
+            Self::PostgreSQL => "PostgreSQL License",
+            Self::PSF_2_0 => "Python Software Foundation License 2.0",
+            Self::Python_2_0 => "Python License 2.0",
+            Self::Unlicense => "The Unlicense",
+            Self::UPL_1_0 => "Universal Permissive License v1.0",
+            Self::WTFPL => "Do What The F*ck You Want To Public License",
+            Self::Zlib => "zlib License",
+            _ => return None,
+        })
+    }
+}
+
 impl AsPartial for LicenseId {
     type Partial = String;
     fn to_partial(self) -> Self::Partial {
@@ -9,6 +9,13 @@ use aspartial::AsPartial;
 #[serde(try_from = "usize")]
 pub struct LiteralInt<const VAL: usize>;
 
+#[cfg(feature = "arbitrary")]
+impl<'a, const VAL: usize> arbitrary::Arbitrary<'a> for LiteralInt<VAL> {
+    fn arbitrary(_u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self)
+    }
+}
+
 impl<const VAL: usize> AsPartial for LiteralInt<VAL>{
     type Partial = usize;
     fn to_partial(self) -> Self::Partial {
@@ -57,6 +64,13 @@ pub trait StrMarker: Copy + Clone + Default{
 #[serde(into = "String")]
 pub struct LitStr<M: StrMarker>(#[serde(bound = "M: StrMarker")]PhantomData<M>);
 
+#[cfg(feature = "arbitrary")]
+impl<'a, M: StrMarker> arbitrary::Arbitrary<'a> for LitStr<M> {
+    fn arbitrary(_u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::new())
+    }
+}
+
 impl<M: StrMarker> AsPartial for LitStr<M> {
     type Partial = String;
     fn to_partial(self) -> Self::Partial {
@@ -15,6 +15,19 @@ pub enum LowercaseParsingError {
 #[aspartial(newtype)]
 pub struct Lowercase<T>(T);
 
+/// Lowercases an arbitrary `T` rather than rejecting it if it has uppercase characters
+#[cfg(feature = "arbitrary")]
+impl<'a, T, E> arbitrary::Arbitrary<'a> for Lowercase<T>
+where
+    E: Error + 'static,
+    T: arbitrary::Arbitrary<'a> + Borrow<str> + for<'s> TryFrom<&'s str, Error = E>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let inner = T::arbitrary(u)?;
+        Self::try_from(inner.borrow().to_lowercase().as_str()).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl<T: Into<String>> From<Lowercase<T>> for String{
     fn from(value: Lowercase<T>) -> Self {
         value.0.into()
@@ -9,6 +9,7 @@ use super::{bounded_string::BoundedString, orcid::Orcid, slashless_string::Slash
 pub type MaintainerName = SlashlessString<BoundedString<1, 1024>>;
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialMaintainer)]
 pub struct Maintainer {
     pub affiliation: Option<BoundedString<1, 1024>>,
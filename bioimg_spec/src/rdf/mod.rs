@@ -1,11 +1,15 @@
+pub mod application;
 pub mod attachment;
 pub mod author;
 pub mod badge;
 pub mod bounded_string;
 pub mod cite_entry;
 pub mod clamped;
+pub mod collection;
+pub mod dataset;
 pub mod file_reference;
 pub mod file_description;
+pub mod generic;
 pub mod icon;
 pub mod identifier;
 pub mod license;
@@ -13,28 +17,38 @@ pub mod literal;
 pub mod lowercase;
 pub mod maintainer;
 pub mod model;
+pub mod notebook;
 pub mod non_empty_list;
 pub mod orcid;
+pub mod resource_description;
 pub mod si_units;
 pub mod slashless_string;
 pub mod basic_chars_string;
 pub mod version;
 pub mod tag;
+pub mod uploader;
 
 pub use bounded_string::BoundedString;
 pub use icon::{EmojiIcon, Icon, IconParsingError};
-pub use identifier::Identifier;
+pub use identifier::{Identifier, DottedIdentifier};
 pub use license::LicenseId;
 pub use literal::{LiteralInt, LitStr};
 pub use version::Version;
-pub use file_reference::{HttpUrl, FsPath, FileReference, CoverImageSource, EnvironmentFile};
+pub use file_reference::{HttpUrl, FsPath, FileReference, CoverImageSource, EnvironmentFile, NotebookSource};
 pub use author::Author2;
 pub use file_description::{FileDescription, EnvironmentFileDescr};
 pub use maintainer::{Maintainer, MaintainerName};
+pub use uploader::Uploader;
 pub use orcid::Orcid;
 pub use cite_entry::CiteEntry2;
 pub use tag::Tag;
 pub use non_empty_list::NonEmptyList;
+pub use application::ApplicationDescr;
+pub use collection::CollectionDescr;
+pub use dataset::DatasetDescr;
+pub use generic::GenericResourceDescr;
+pub use notebook::NotebookDescr;
+pub use resource_description::ResourceDescrBase;
 
 use self::{lowercase::Lowercase, slashless_string::SlashlessString};
 
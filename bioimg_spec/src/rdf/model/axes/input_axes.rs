@@ -11,6 +11,7 @@ use super::{
 };
 
 #[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialTimeInputAxis)]
 pub struct TimeInputAxis {
     #[serde(default = "_default_time_axis_id")]
@@ -31,6 +32,7 @@ impl Display for TimeInputAxis{
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialSpaceInputAxis)]
 pub struct SpaceInputAxis {
     #[serde(default = "_default_space_axis_id")]
@@ -51,6 +53,7 @@ impl Display for SpaceInputAxis{
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialInputAxis)]
 #[serde(tag = "type")]
 pub enum InputAxis {
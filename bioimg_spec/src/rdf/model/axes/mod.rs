@@ -29,6 +29,8 @@ pub enum AxisIdParsingError{
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub struct NonBatchAxisId(AxisId);
 
+crate::util::arbitrary_via_try_from!(NonBatchAxisId, AxisId);
+
 impl AsPartial for NonBatchAxisId {
     type Partial = String;
     fn to_partial(self) -> Self::Partial {
@@ -86,6 +88,13 @@ impl TryFrom<String> for NonBatchAxisId{
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy)]
 pub struct AxisScale(f32);
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AxisScale {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(crate::util::arbitrary_values::f32_in_range(u, 1e-3, 1e3)?))
+    }
+}
+
 impl AsPartial for AxisScale {
     type Partial = f32;
     fn to_partial(self) -> Self::Partial {
@@ -128,6 +137,13 @@ pub enum HaloParsingError {
 #[derive(Serialize, Deserialize, Debug, Clone, Copy)]
 pub struct Halo(u64);
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Halo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(u.int_in_range(1..=u64::MAX)?))
+    }
+}
+
 impl AsPartial for Halo {
     type Partial = u64;
     fn to_partial(self) -> Self::Partial {
@@ -177,6 +193,7 @@ impl StrMarker for Time { const NAME: &'static str = "time"; }
     serde::Serialize, serde::Deserialize, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default,
     strum::VariantArray, strum::AsRefStr, strum::Display,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum AxisType {
     #[serde(rename = "batch")]
     Batch,
@@ -211,6 +228,7 @@ impl TryFrom<f32> for AxisScale {
 // ///////////////////////
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialBatchAxis)]
 pub struct BatchAxis {
     #[serde(default)]
@@ -228,6 +246,7 @@ impl Display for BatchAxis{
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialChannelAxis)]
 pub struct ChannelAxis {
     #[serde(default)]
@@ -257,9 +276,46 @@ impl ChannelAxis {
         let len: usize = self.channel_names.len().into();
         len == extent
     }
+    /// The pattern the channel names follow, if any
+    pub fn channel_name_pattern(&self) -> Option<ChannelNamePattern> {
+        ChannelNamePattern::detect(self.channel_names.iter().map(|name| &**name))
+    }
+}
+
+/// Channel names made of a prefix, the 0-based index of the channel and a suffix, e.g. `c0`, `c1`, `c2`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChannelNamePattern {
+    pub prefix: String,
+    pub suffix: String,
+    pub count: usize,
+}
+
+impl ChannelNamePattern {
+    pub fn name(&self, channel_idx: usize) -> String {
+        format!("{}{channel_idx}{}", self.prefix, self.suffix)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = String> + '_ {
+        (0..self.count).map(|idx| self.name(idx))
+    }
+
+    /// Finds the prefix and suffix around the channel index in `names`, if they follow a pattern.
+    /// A single name is not considered a pattern, since any name with a `0` in it would match
+    pub fn detect<'n>(names: impl IntoIterator<Item = &'n str>) -> Option<Self> {
+        let names: Vec<&str> = names.into_iter().collect();
+        if names.len() < 2 {
+            return None;
+        }
+        let first = names[0];
+        first.match_indices('0').find_map(|(idx, _)| {
+            let pattern = Self { prefix: first[..idx].to_owned(), suffix: first[idx + 1..].to_owned(), count: names.len() };
+            names.iter().enumerate().all(|(channel_idx, name)| *name == pattern.name(channel_idx)).then_some(pattern)
+        })
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialIndexAxis)]
 pub struct IndexAxis {
     #[serde(default)]
@@ -318,6 +374,45 @@ macro_rules!  impl_axis_group{($inout:ident) => { paste::paste!{
             return Ok(Self(value));
         }
     }
+
+    // Axes that repeat a non-space axis type are dropped instead of rejecting the whole group
+    #[cfg(feature = "arbitrary")]
+    impl<'a> ::arbitrary::Arbitrary<'a> for [<$inout AxisGroup>] {
+        fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+            let mut axis_types = std::collections::HashSet::<AxisType>::with_capacity(5);
+            let mut axes: Vec< [<$inout Axis>] > = vec![];
+            for axis in u.arbitrary_iter::< [<$inout Axis>] >()? {
+                let axis = axis?;
+                if matches!(axis.axis_type(), AxisType::Space) || axis_types.insert(axis.axis_type()) {
+                    axes.push(axis);
+                }
+            }
+            if axes.is_empty() {
+                axes.push(u.arbitrary()?);
+            }
+            Ok(Self(axes))
+        }
+    }
 }};}
 
 pub(crate) use impl_axis_group;
+
+#[test]
+fn test_channel_name_pattern_detection() {
+    let detect = |names: &[&str]| ChannelNamePattern::detect(names.iter().copied());
+    let pattern = |prefix: &str, suffix: &str, count: usize| ChannelNamePattern {
+        prefix: prefix.to_owned(),
+        suffix: suffix.to_owned(),
+        count,
+    };
+
+    assert_eq!(detect(&["c0", "c1", "c2"]), Some(pattern("c", "", 3)));
+    assert_eq!(detect(&["ch_0_raw", "ch_1_raw"]), Some(pattern("ch_", "_raw", 2)));
+    assert_eq!(detect(&["x00", "x01"]), Some(pattern("x0", "", 2)));
+    assert_eq!(detect(&["c0", "c2"]), None);
+    assert_eq!(detect(&["red", "green", "blue"]), None);
+    assert_eq!(detect(&["c0"]), None);
+
+    let names: Vec<String> = pattern("c", "_raw", 12).names().collect();
+    assert_eq!(detect(&names.iter().map(String::as_str).collect::<Vec<_>>()), Some(pattern("c", "_raw", 12)));
+}
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use crate::rdf::model::axis_size::FixedOrRefAxisSize;
 use crate::rdf::model::AnyAxisSize;
 
+use super::input_axes::{InputAxis, SpaceInputAxis, TimeInputAxis};
 use super::{
     impl_axis_group, AxisDescription, AxisId, AxisScale, AxisType, BatchAxis, ChannelAxis,
     Halo, IndexAxis, _default_space_axis_id, _default_time_axis_id
@@ -14,6 +15,7 @@ use crate::rdf::model::time_unit::TimeUnit;
 use crate::rdf::model::space_unit::SpaceUnit;
 
 #[derive(Serialize, Deserialize, Clone, Debug, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialOutputSpacetimeSize)]
 #[serde(untagged)]
 pub enum OutputSpacetimeSize{
@@ -22,6 +24,7 @@ pub enum OutputSpacetimeSize{
 }
 
 #[derive(AsPartial, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialHaloedOutputSpacetimeSize)]
 pub struct HaloedOutputSpacetimeSize{
     pub size: FixedOrRefAxisSize,
@@ -29,6 +32,7 @@ pub struct HaloedOutputSpacetimeSize{
 }
 
 #[derive(AsPartial, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialStandardOutputSpacetimeSize)]
 pub struct StandardOutputSpacetimeSize{
     pub size: AnyAxisSize
@@ -50,6 +54,7 @@ impl OutputSpacetimeSize{
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialTimeOutputAxis)]
 pub struct TimeOutputAxis {
     #[serde(default = "_default_time_axis_id")]
@@ -71,6 +76,7 @@ impl Display for TimeOutputAxis{
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialSpaceOutputAxis)]
 pub struct SpaceOutputAxis {
     #[serde(default = "_default_space_axis_id")]
@@ -92,6 +98,7 @@ impl Display for SpaceOutputAxis{
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialOutputAxis)]
 #[serde(tag = "type")]
 pub enum OutputAxis {
@@ -145,6 +152,23 @@ impl From<SpaceOutputAxis> for OutputAxis{
     }
 }
 
+/// The output axis with the same id, unit, scale and size as an input axis
+impl From<InputAxis> for OutputAxis{
+    fn from(value: InputAxis) -> Self {
+        match value{
+            InputAxis::Batch(axis) => Self::Batch(axis),
+            InputAxis::Channel(axis) => Self::Channel(axis),
+            InputAxis::Index(axis) => Self::Index(axis),
+            InputAxis::Time(TimeInputAxis{id, description, unit, scale, size}) => Self::Time(
+                TimeOutputAxis{id, description, unit, scale, size: size.into()}
+            ),
+            InputAxis::Space(SpaceInputAxis{id, description, unit, scale, size}) => Self::Space(
+                SpaceOutputAxis{id, description, unit, scale, size: size.into()}
+            ),
+        }
+    }
+}
+
 impl OutputAxis{
     pub fn axis_type(&self) -> AxisType {
         match self {
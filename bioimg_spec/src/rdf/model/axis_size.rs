@@ -20,6 +20,7 @@ impl From<FixedAxisSize> for AnyAxisSize{
 }
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash, Clone, Debug, PartialOrd, Ord, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialQualifiedAxisId )]
 pub struct QualifiedAxisId {
     pub tensor_id: TensorId,
@@ -33,6 +34,7 @@ impl Display for QualifiedAxisId {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialAxisSizeReference )]
 pub struct AxisSizeReference {
     #[serde(flatten)]
@@ -59,13 +61,58 @@ impl Display for AxisSizeReference {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialParameterizedAxisSize)]
 pub struct ParameterizedAxisSize {
     pub min: NonZeroUsize,
     pub step: NonZeroUsize,
 }
 
+impl ParameterizedAxisSize {
+    /// Largest step that [Self::suggest_for] proposes
+    const MAX_SUGGESTED_STEP: usize = 16;
+
+    /// Whether `extent == min + n * step` for some `n`
+    pub fn accepts(&self, extent: usize) -> bool {
+        let (min, step) = (self.min.get(), self.step.get());
+        extent >= min && (extent - min) % step == 0
+    }
+
+    /// A parameterization that accepts `extent`, with a power-of-two step (as required by most
+    /// networks that downsample) no larger than 16
+    pub fn suggest_for(extent: NonZeroUsize) -> Self {
+        let step = 1usize << extent.trailing_zeros().min(Self::MAX_SUGGESTED_STEP.trailing_zeros());
+        let step = NonZeroUsize::new(step).unwrap();
+        Self { min: step, step }
+    }
+
+    /// The parameterizations closest to this one that accept `extent`: one with the same step and
+    /// a smaller min, and one with the same min and a smaller step
+    pub fn adjusted_to(&self, extent: NonZeroUsize) -> Vec<Self> {
+        if self.accepts(extent.get()) {
+            return vec![self.clone()];
+        }
+        let (min, step, extent) = (self.min.get(), self.step.get(), extent.get());
+        let mut adjusted = vec![];
+
+        let upper_min = min.min(extent);
+        let new_min = upper_min - (upper_min % step + step - extent % step) % step;
+        let new_min = if new_min == 0 { step } else { new_min };
+        adjusted.push(Self { min: NonZeroUsize::new(new_min).unwrap(), step: self.step });
+
+        if min < extent {
+            let distance = extent - min;
+            let new_step = (1..=step).rev().find(|candidate| distance % candidate == 0).unwrap_or(1);
+            let candidate = Self { min: self.min, step: NonZeroUsize::new(new_step).unwrap() };
+            if !adjusted.contains(&candidate) {
+                adjusted.push(candidate);
+            }
+        }
+        adjusted
+    }
+}
+
 impl From<ParameterizedAxisSize> for AnyAxisSize{
     fn from(value: ParameterizedAxisSize) -> Self {
         AnyAxisSize::Parameterized(value)
@@ -77,6 +124,7 @@ impl From<ParameterizedAxisSize> for AnyAxisSize{
 #[derive(AsPartial)]
 #[aspartial(name = PartialAnyAxisSize)]
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(untagged)]
 pub enum AnyAxisSize {
     Fixed(FixedAxisSize),
@@ -87,6 +135,7 @@ pub enum AnyAxisSize {
 ////////////////////////////////////////////
 
 #[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialResolvedAxisSize )]
 #[serde(untagged)]
 pub enum ResolvedAxisSize {
@@ -116,6 +165,7 @@ impl From<ResolvedAxisSize> for AnyAxisSize{
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialFixedOrRefAxisSize)]
 #[serde(untagged)]
 pub enum FixedOrRefAxisSize{
@@ -142,3 +192,28 @@ impl TryFrom<AnyAxisSize> for FixedOrRefAxisSize{
         }
     }
 }
+
+#[test]
+fn test_parameterized_size_adjustment() {
+    let size = |min: usize, step: usize| ParameterizedAxisSize {
+        min: NonZeroUsize::new(min).unwrap(),
+        step: NonZeroUsize::new(step).unwrap(),
+    };
+    let extent = |extent: usize| NonZeroUsize::new(extent).unwrap();
+
+    assert!(size(64, 16).accepts(256));
+    assert!(!size(64, 16).accepts(250));
+    assert!(!size(64, 16).accepts(48));
+
+    assert_eq!(ParameterizedAxisSize::suggest_for(extent(256)), size(16, 16));
+    assert_eq!(ParameterizedAxisSize::suggest_for(extent(100)), size(4, 4));
+    assert_eq!(ParameterizedAxisSize::suggest_for(extent(99)), size(1, 1));
+
+    assert_eq!(size(64, 16).adjusted_to(extent(256)), vec![size(64, 16)]);
+    assert_eq!(size(64, 16).adjusted_to(extent(250)), vec![size(58, 16), size(64, 6)]);
+    assert_eq!(size(64, 16).adjusted_to(extent(48)), vec![size(48, 16)]);
+    assert_eq!(size(64, 16).adjusted_to(extent(40)), vec![size(40, 16)]);
+    for adjusted in size(17, 8).adjusted_to(extent(100)) {
+        assert!(adjusted.accepts(100));
+    }
+}
@@ -0,0 +1,166 @@
+//! Lenient parsing of model descriptions that were written against a newer 0.x version of the spec.
+//!
+//! Newer versions usually only add optional fields, so such models are parsed as if they declared the
+//! latest supported version. Fields that this crate doesn't know about are dropped. Both cases are
+//! reported as [ParsingWarning]s, so that callers can decide whether to trust the result.
+
+use std::fmt::Display;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::rdf::version::Version_0_5_x;
+use crate::rdf::Version;
+use super::ModelRdfV0_5;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParsingWarning{
+    /// The model declared `found` as its `format_version`, but was parsed as `parsed_as`
+    NewerFormatVersion{found: Version, parsed_as: Version},
+    /// A field that is not part of the supported spec and got dropped, e.g. `inputs[0].axes[1].foo`
+    UnknownField{path: String},
+}
+
+impl Display for ParsingWarning{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self{
+            Self::NewerFormatVersion { found, parsed_as } => write!(
+                f, "Format version {found} is newer than supported; parsed as {parsed_as}"
+            ),
+            Self::UnknownField { path } => write!(f, "Unknown field '{path}' was ignored"),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum BestEffortParsingError{
+    #[error("Bad yaml: {0}")]
+    BadYaml(#[from] serde_yaml::Error),
+    #[error("Missing or malformed 'format_version'")]
+    BadFormatVersion,
+    #[error("Format version {found} can't be parsed as a 0.5 model")]
+    IncompatibleVersion{found: Version},
+    #[error("Bad rdf: {0}")]
+    BadModel(#[from] serde_json::Error),
+}
+
+pub struct BestEffortModelRdf{
+    pub model_rdf: ModelRdfV0_5,
+    pub warnings: Vec<ParsingWarning>,
+}
+
+impl ModelRdfV0_5{
+    /// Parses the yaml in `raw` like a regular model description, but also accepts any 0.x version from
+    /// 0.5 onwards and ignores unknown fields, reporting both in [BestEffortModelRdf::warnings]
+    pub fn parse_best_effort(raw: &[u8]) -> Result<BestEffortModelRdf, BestEffortParsingError>{
+        let mut raw: Value = serde_yaml::from_slice(raw)?;
+        let found: Version = raw.get("format_version")
+            .and_then(|version| serde_json::from_value(version.clone()).ok())
+            .ok_or(BestEffortParsingError::BadFormatVersion)?;
+        if found < Version_0_5_x::earliest_supported_version() || found >= Version::major_minor_patch(1, 0, 0){
+            return Err(BestEffortParsingError::IncompatibleVersion { found })
+        }
+
+        let mut warnings = vec![];
+        let latest_supported = Version_0_5_x::latest_supported_version();
+        if found > latest_supported{
+            raw["format_version"] = serde_json::to_value(&latest_supported)?;
+            warnings.push(ParsingWarning::NewerFormatVersion { found, parsed_as: latest_supported });
+        }
+        let model_rdf = ModelRdfV0_5::deserialize(&raw)?;
+        // unknown fields are silently skipped by serde, so they are the ones of the raw rdf that the parsed
+        // model doesn't have when written back out
+        let reserialized = serde_json::to_value(&model_rdf)?;
+        collect_unknown_fields(&raw, &reserialized, "", &mut warnings);
+        Ok(BestEffortModelRdf{model_rdf, warnings})
+    }
+}
+
+fn collect_unknown_fields(raw: &Value, parsed: &Value, path: &str, warnings: &mut Vec<ParsingWarning>){
+    match (raw, parsed){
+        (Value::Object(raw_fields), Value::Object(parsed_fields)) => for (key, raw_value) in raw_fields{
+            let field_path = if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+            match parsed_fields.get(key){
+                Some(parsed_value) => collect_unknown_fields(raw_value, parsed_value, &field_path, warnings),
+                // the only fields that are skipped when serializing are the unset optional ones, which the raw
+                // rdf can only have as explicit nulls
+                None if raw_value.is_null() => (),
+                None => warnings.push(ParsingWarning::UnknownField { path: field_path }),
+            }
+        },
+        (Value::Array(raw_items), Value::Array(parsed_items)) if raw_items.len() == parsed_items.len() => {
+            for (idx, (raw_item, parsed_item)) in raw_items.iter().zip(parsed_items).enumerate(){
+                collect_unknown_fields(raw_item, parsed_item, &format!("{path}[{idx}]"), warnings);
+            }
+        },
+        _ => (),
+    }
+}
+
+#[test]
+fn test_best_effort_parsing(){
+    let raw = r#"
+format_version: 0.5.5
+type: model
+name: Some Future Model
+description: A model using a newer version of the spec
+some_new_field: 123
+authors:
+  - name: John Doe
+cite:
+  - text: "He et al. Deep Residual Learning for Image Recognition"
+    doi: 10.1109/CVPR.2016.90
+license: MIT
+documentation: README.md
+inputs:
+  - id: raw
+    description: The raw image
+    axes:
+      - type: batch
+      - type: space
+        id: x
+        size: 64
+        some_new_axis_field: foo
+    test_tensor: {source: test_input.npy}
+outputs:
+  - id: out
+    description: The processed image
+    axes:
+      - type: batch
+      - type: space
+        id: x
+        size: 64
+    test_tensor: {source: test_output.npy}
+weights:
+  torchscript:
+    source: weights.pt
+    pytorch_version: 2.0.0
+"#;
+    let parsed = ModelRdfV0_5::parse_best_effort(raw.as_bytes()).unwrap();
+    assert_eq!(parsed.warnings.len(), 3);
+    assert_eq!(
+        parsed.warnings[0],
+        ParsingWarning::NewerFormatVersion {
+            found: Version::major_minor_patch(0, 5, 5),
+            parsed_as: Version_0_5_x::latest_supported_version(),
+        }
+    );
+    assert!(parsed.warnings.contains(&ParsingWarning::UnknownField { path: "some_new_field".into() }));
+    assert!(parsed.warnings.contains(&ParsingWarning::UnknownField {
+        path: "inputs[0].axes[1].some_new_axis_field".into()
+    }));
+
+    // fields that are skipped when serializing are not unknown, no matter if they were left unset or not
+    let with_data = raw
+        .replace("    test_tensor: {source: test_input.npy}", "    test_tensor: {source: test_input.npy}\n    data: {type: float32}")
+        .replace("    test_tensor: {source: test_output.npy}", "    test_tensor: {source: test_output.npy}\n    data: null");
+    let parsed_with_data = ModelRdfV0_5::parse_best_effort(with_data.as_bytes()).unwrap();
+    assert!(parsed_with_data.model_rdf.inputs[0].meta.data.is_some());
+    assert_eq!(parsed_with_data.warnings, parsed.warnings);
+
+    let raw_1_0 = raw.replace("format_version: 0.5.5", "format_version: 1.0.0");
+    assert!(matches!(
+        ModelRdfV0_5::parse_best_effort(raw_1_0.as_bytes()),
+        Err(BestEffortParsingError::IncompatibleVersion { .. })
+    ));
+}
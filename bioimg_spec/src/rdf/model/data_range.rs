@@ -1,4 +1,120 @@
+use std::fmt::Display;
+
+use aspartial::AsPartial;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
-pub struct DataRange(f64, f64);
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum DataRangeParsingError{
+    #[error("Max '{max}' not greater than min '{min}'")]
+    MaxNotGreaterThanMin{min: f32, max: f32},
+    #[error("Undefined float values not allowed in data range")]
+    UndefinedFloatValue,
+}
+
+/// The interval of values that the data in a tensor can take. A missing bound
+/// means that the interval is open-ended (i.e. -inf or inf) on that side
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(try_from = "DataRangeMessage")]
+#[serde(into = "DataRangeMessage")]
+pub struct DataRange{
+    min: Option<f32>,
+    max: Option<f32>,
+}
+
+/// The spec's representation of a data range: a `[min, max]` pair where `null` means unbounded
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DataRangeMessage(pub Option<f32>, pub Option<f32>);
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DataRange{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use crate::util::arbitrary_values::f32_in_range;
+        let min = if u.arbitrary()? { Some(f32_in_range(u, -1e6, 1e6)?) } else { None };
+        let max = if u.arbitrary()? { Some(min.unwrap_or(-1e6) + f32_in_range(u, 1.0, 1e6)?) } else { None };
+        Self::try_from_min_max(min, max).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+impl AsPartial for DataRangeMessage {
+    type Partial = Self;
+    fn to_partial(self) -> Self::Partial {
+        self
+    }
+}
+
+impl AsPartial for DataRange {
+    type Partial = DataRangeMessage;
+    fn to_partial(self) -> Self::Partial {
+        self.into()
+    }
+}
+
+impl DataRange {
+    /// Creates a new data range. Infinite bounds are treated the same as missing ones
+    pub fn try_from_min_max(min: Option<f32>, max: Option<f32>) -> Result<Self, DataRangeParsingError>{
+        let min = min.filter(|min| *min != f32::NEG_INFINITY);
+        let max = max.filter(|max| *max != f32::INFINITY);
+        if min.is_some_and(f32::is_nan) || max.is_some_and(f32::is_nan){
+            return Err(DataRangeParsingError::UndefinedFloatValue)
+        }
+        let (lower, upper) = (min.unwrap_or(f32::NEG_INFINITY), max.unwrap_or(f32::INFINITY));
+        if upper <= lower {
+            return Err(DataRangeParsingError::MaxNotGreaterThanMin { min: lower, max: upper })
+        }
+        Ok(Self{min, max})
+    }
+    pub fn unbounded() -> Self{
+        Self{min: None, max: None}
+    }
+    pub fn min(&self) -> Option<f32>{
+        self.min
+    }
+    pub fn max(&self) -> Option<f32>{
+        self.max
+    }
+    pub fn contains(&self, value: f32) -> bool{
+        self.min.is_none_or(|min| value >= min) && self.max.is_none_or(|max| value <= max)
+    }
+}
+
+impl TryFrom<DataRangeMessage> for DataRange{
+    type Error = DataRangeParsingError;
+    fn try_from(message: DataRangeMessage) -> Result<Self, Self::Error> {
+        Self::try_from_min_max(message.0, message.1)
+    }
+}
+
+impl From<DataRange> for DataRangeMessage{
+    fn from(value: DataRange) -> Self {
+        Self(value.min, value.max)
+    }
+}
+
+impl Display for DataRange{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.min{
+            Some(min) => write!(f, "[{min}, ")?,
+            None => write!(f, "(-∞, ")?,
+        }
+        match self.max{
+            Some(max) => write!(f, "{max}]"),
+            None => write!(f, "∞)"),
+        }
+    }
+}
+
+#[test]
+fn test_data_range_parsing(){
+    let range: DataRange = serde_json::from_str("[null, 1.0]").unwrap();
+    assert_eq!(range.min(), None);
+    assert_eq!(range.max(), Some(1.0));
+    assert_eq!(serde_json::to_string(&range).unwrap(), "[null,1.0]");
+
+    let range = DataRange::try_from_min_max(Some(f32::NEG_INFINITY), Some(f32::INFINITY)).unwrap();
+    assert_eq!(range, DataRange::unbounded());
+
+    assert!(serde_json::from_str::<DataRange>("[1.0, 1.0]").is_err());
+    assert!(DataRange::try_from_min_max(Some(2.0), Some(1.0)).is_err());
+    assert!(DataRange::try_from_min_max(Some(f32::INFINITY), None).is_err());
+}
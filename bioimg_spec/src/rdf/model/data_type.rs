@@ -4,6 +4,7 @@ use aspartial::AsPartial;
 
 #[derive(Default, Serialize, Deserialize, Eq, PartialEq, Debug, Copy, Clone)]
 #[derive(strum::EnumString, strum::VariantArray, strum::VariantNames, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum DataType {
     #[serde(rename = "bool")]
     #[strum(serialize = "bool")]
@@ -41,6 +42,18 @@ pub enum DataType {
     Int64,
 }
 
+impl DataType {
+    /// How many bytes one element of this type takes in memory
+    pub fn size_in_bytes(&self) -> usize {
+        match self {
+            Self::Bool | Self::Uint8 | Self::Int8 => 1,
+            Self::Uint16 | Self::Int16 => 2,
+            Self::Float32 | Self::Uint32 | Self::Int32 => 4,
+            Self::Float64 | Self::Uint64 | Self::Int64 => 8,
+        }
+    }
+}
+
 impl AsPartial for DataType {
     type Partial = String;
     fn to_partial(self) -> Self::Partial {
@@ -2,10 +2,10 @@ use aspartial::AsPartial;
 
 use crate::rdf::{HttpUrl, ResourceId};
 
-// A bioimage.io dataset resource description file (dataset RDF) describes a dataset relevant to bioimage
-// processing.
+// The datasets a model was trained on. See [crate::rdf::dataset::DatasetDescr] for standalone dataset RDFs.
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialDatasetDescrEnum)]
 #[serde(untagged)]
 pub enum DatasetDescrEnum{
@@ -18,6 +18,13 @@ pub enum DatasetDescrEnum{
 #[serde(try_from = "String")]
 pub struct DatasetDescrMarker;
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DatasetDescrMarker {
+    fn arbitrary(_u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self)
+    }
+}
+
 impl AsPartial for DatasetDescrMarker {
     type Partial = String;
     fn to_partial(self) -> Self::Partial {
@@ -43,21 +50,29 @@ impl TryFrom<String> for DatasetDescrMarker{
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialDatasetDescr)]
 pub struct DatasetDescr{
     #[serde(rename = "type")]
     marker: DatasetDescrMarker,
     /// URL to the source of the dataset
     #[serde(default)]
-    source: Option<HttpUrl>
+    pub source: Option<HttpUrl>
+}
+
+impl DatasetDescr{
+    pub fn new(source: Option<HttpUrl>) -> Self{
+        Self{marker: DatasetDescrMarker, source}
+    }
 }
 
 
 
 /// Reference to a bioimage.io dataset.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialLinkedDatasetDescr)]
 pub struct LinkedDatasetDescr{
     /// A valid dataset `id` from the bioimage.io collection.
-    id: ResourceId
+    pub id: ResourceId
 }
@@ -4,16 +4,20 @@ use aspartial::AsPartial;
 use serde::{Deserialize, Serialize};
 
 use crate::rdf::FileDescription;
-use super::{axes::input_axes::InputAxisGroup, preprocessing::{BinarizeDescr, PreprocessingDescr, ScaleLinearDescr, ScaleRangeDescr, Zmuv}, AxisId, TensorId, TensorTextDescription};
+use crate::util::SingleOrMultiple;
+use super::{axes::input_axes::InputAxisGroup, preprocessing::{PreprocessingDescr, ScaleRangeDescr, Zmuv}, tensor_data_descr::TensorDataDescr, AnyAxisSize, AxisId, TensorId, TensorTextDescription};
 
 #[derive(thiserror::Error, Debug)]
 pub enum InputTensorParsingError{
     #[error("{tensor_id}.preprocessing[{preproc_idx}] references non-existing axis '{reference}'")]
     PreprocessingReferencesNonExistingAxis{tensor_id: TensorId, preproc_idx: usize, reference: AxisId},
+    #[error("{tensor_id}.preprocessing[{preproc_idx}] has {num_values} values along axis '{axis}', which has size {axis_size}")]
+    PreprocessingLengthMismatch{tensor_id: TensorId, preproc_idx: usize, axis: AxisId, axis_size: usize, num_values: usize},
 }
 
 
 #[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialInputTensorDescr)]
 pub struct InputTensorDescr {
     #[serde(flatten)]
@@ -53,6 +57,11 @@ pub struct InputTensorMetadata {
     ///   changing the data type.
     preprocessing: Vec<PreprocessingDescr>,
     axes: InputAxisGroup,
+
+    /// Description of the tensor's data values, optionally per channel.
+    /// Consumers assume float32 intervals in arbitrary units if this is missing
+    #[serde(default)]
+    pub data: Option<SingleOrMultiple<TensorDataDescr>>,
 }
 
 impl InputTensorMetadata{
@@ -61,6 +70,7 @@ impl InputTensorMetadata{
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct InputTensorMetadataMsg {
     pub id: TensorId,
     #[serde(default)]
@@ -70,34 +80,59 @@ pub struct InputTensorMetadataMsg {
     #[serde(default)]
     pub description: TensorTextDescription,
     pub axes: InputAxisGroup,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<SingleOrMultiple<TensorDataDescr>>,
+}
+
+// Preprocessing steps that don't fit the generated axes are dropped instead of rejecting the whole tensor
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InputTensorMetadata{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut message: InputTensorMetadataMsg = u.arbitrary()?;
+        for step in std::mem::take(&mut message.preprocessing){
+            message.preprocessing.push(step);
+            if Self::try_from(message.clone()).is_err(){
+                message.preprocessing.pop();
+            }
+        }
+        Self::try_from(message).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
 }
 
 impl TryFrom<InputTensorMetadataMsg> for InputTensorMetadata{
     type Error = InputTensorParsingError;
     fn try_from(message: InputTensorMetadataMsg) -> Result<Self, Self::Error> {
 
-        fn ensure_axis_exists(message: &InputTensorMetadataMsg, preproc_idx: usize, preproc_axis_id: &AxisId) -> Result<(), InputTensorParsingError>{
+        /// Returns the size of the axis, if it is fixed
+        fn ensure_axis_exists(message: &InputTensorMetadataMsg, preproc_idx: usize, preproc_axis_id: &AxisId) -> Result<Option<usize>, InputTensorParsingError>{
             message.axes.iter()
-                .map(|ax| ax.id())
-                .find(|ax_id| {
-                    ax_id == preproc_axis_id
+                .find(|ax| {
+                    ax.id() == *preproc_axis_id
                 })
                 .ok_or(InputTensorParsingError::PreprocessingReferencesNonExistingAxis{
                     tensor_id: message.id.clone(),
                     preproc_idx,
                     reference: preproc_axis_id.clone()
                 })
-                .map(|_| ())
+                .map(|ax| match ax.size(){
+                    Some(AnyAxisSize::Fixed(size)) => Some(size.get()),
+                    _ => None,
+                })
         }
 
         for (preproc_idx, preproc) in message.preprocessing.iter().enumerate(){
+            if let Some((axis_id, num_values)) = preproc.values_along_axis(){
+                let axis_id: &AxisId = axis_id.borrow();
+                match ensure_axis_exists(&message, preproc_idx, axis_id)?{
+                    Some(axis_size) if axis_size != num_values => {
+                        return Err(InputTensorParsingError::PreprocessingLengthMismatch {
+                            tensor_id: message.id.clone(), preproc_idx, axis: axis_id.clone(), axis_size, num_values
+                        })
+                    },
+                    _ => (),
+                }
+            }
             match preproc{
-                PreprocessingDescr::Binarize(BinarizeDescr::AlongAxis(descr)) => {
-                    ensure_axis_exists(&message, preproc_idx, descr.axis.borrow())?;
-                },
-                PreprocessingDescr::ScaleLinear(ScaleLinearDescr::AlongAxis(descr)) => {
-                    ensure_axis_exists(&message, preproc_idx, descr.axis.borrow())?;
-                },
                 PreprocessingDescr::ZeroMeanUnitVariance(Zmuv{axes: Some(axes), ..}) => {
                     for preproc_axis_id in axes.iter(){
                         ensure_axis_exists(&message, preproc_idx, preproc_axis_id)?;
@@ -118,6 +153,7 @@ impl TryFrom<InputTensorMetadataMsg> for InputTensorMetadata{
             preprocessing: message.preprocessing,
             description: message.description,
             axes: message.axes,
+            data: message.data,
         })
     }
 }
@@ -130,7 +166,24 @@ impl From<InputTensorMetadata> for InputTensorMetadataMsg{
             preprocessing: value.preprocessing,
             description: value.description,
             axes: value.axes,
+            data: value.data,
         }
     }
 }
 
+
+#[test]
+fn test_values_along_axis_must_match_axis_size(){
+    let raw = |thresholds: &str| format!(r#"{{
+        "id": "raw",
+        "axes": [
+            {{"type": "channel", "channel_names": ["r", "g", "b"]}},
+            {{"type": "space", "id": "x", "size": 64}}
+        ],
+        "preprocessing": [
+            {{"id": "binarize", "kwargs": {{"axis": "channel", "threshold": {thresholds}}}}}
+        ]
+    }}"#);
+    assert!(serde_json::from_str::<InputTensorMetadata>(&raw("[0.5, 0.5, 0.5]")).is_ok());
+    assert!(serde_json::from_str::<InputTensorMetadata>(&raw("[0.5, 0.5]")).is_err());
+}
@@ -0,0 +1,25 @@
+use aspartial::AsPartial;
+
+use crate::rdf::ResourceId;
+
+/// Reference to a bioimage.io model, e.g. the model that another model was derived from
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq, Debug, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[aspartial(name = PartialLinkedModel)]
+pub struct LinkedModel{
+    /// A valid model `id` from the bioimage.io collection.
+    pub id: ResourceId,
+    /// The version number (n-th published version, not the semantic version) of the linked model
+    #[serde(default)]
+    pub version_number: Option<u32>,
+}
+
+impl std::fmt::Display for LinkedModel{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id)?;
+        if let Some(version_number) = self.version_number{
+            write!(f, " (version {version_number})")?;
+        }
+        Ok(())
+    }
+}
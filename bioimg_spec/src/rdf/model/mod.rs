@@ -17,14 +17,16 @@ pub mod tensor_id;
 pub mod time_unit;
 pub mod weights;
 pub mod run_mode;
+pub mod linked_model;
 pub mod dataset_descr;
 pub mod unsupported;
 pub mod model_rdf_0_5;
-// pub mod model_rdf_0_4;
+pub mod model_rdf_0_4;
+pub mod best_effort;
 
 pub use axes::{
     AxisType, AxisId, AxisScale,
-    BatchAxis, ChannelAxis, IndexAxis,
+    BatchAxis, ChannelAxis, ChannelNamePattern, IndexAxis,
     Batch, Index, Channel, Space, Time,
     Halo,
 };
@@ -48,16 +50,29 @@ pub use weights::{
     TensorflowJsWeightsDescr,
     TensorflowSavedModelBundleWeightsDescr,
     TorchscriptWeightsDescr,
+    WeightsFormat,
 };
 pub use preprocessing::PreprocessingDescr;
 pub use data_type::DataType;
 pub use model_rdf_0_5::ModelRdfV0_5;
+pub use model_rdf_0_4::{ModelRdfV0_4, ModelUpgradeError};
+pub use best_effort::{BestEffortModelRdf, BestEffortParsingError, ParsingWarning};
+pub use run_mode::RunMode;
+pub use data_range::DataRange;
+pub use linked_model::LinkedModel;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 #[serde(try_from = "String")]
 #[serde(into = "String")]
 pub struct RdfTypeModel;
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RdfTypeModel {
+    fn arbitrary(_u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self)
+    }
+}
+
 impl AsPartial for RdfTypeModel {
     type Partial = String;
     fn to_partial(self) -> Self::Partial {
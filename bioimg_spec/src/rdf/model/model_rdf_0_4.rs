@@ -0,0 +1,676 @@
+//! Version 0.4 of the model description. It is not used to describe models directly, but only to
+//! read legacy models so that they can be upgraded to a [ModelRdfV0_5].
+//!
+//! The upgrade works on the level of the serialized document, like the one in the python
+//! `bioimageio.spec` package: the 0.4 fields are mapped to their 0.5 counterparts, and the result
+//! is then parsed as any other 0.5 model, so that it goes through the exact same validation.
+
+use std::collections::BTreeMap;
+
+use serde_json::{json, Map, Value};
+
+use crate::rdf::{
+    Author2, CiteEntry2, CoverImageSource, FileReference, HttpUrl, Icon, LicenseId, Maintainer, Tag, Version,
+};
+use crate::rdf::version::{Version_0_4_x, Version_0_5_x};
+use super::dataset_descr::DatasetDescrEnum;
+use super::{ModelRdfV0_5, RdfTypeModel, RunMode};
+
+#[derive(thiserror::Error, Debug)]
+pub enum ModelUpgradeError{
+    #[error("Unknown axis '{axis}' in axes '{axes}' of tensor '{tensor}'")]
+    UnknownAxis{tensor: String, axes: String, axis: char},
+    #[error("Tensor '{tensor}' has axes '{axes}' but its shape has {num_sizes} entries")]
+    ShapeMismatch{tensor: String, axes: String, num_sizes: usize},
+    #[error("Output tensor '{tensor}' has a parameterized shape, which is only allowed for inputs")]
+    ParameterizedOutput{tensor: String},
+    #[error("Tensor '{tensor}' references tensor '{reference}', which is not an input with an axis '{axis}'")]
+    BadReference{tensor: String, reference: String, axis: char},
+    #[error("Tensor '{tensor}' has an offset of {offset} along axis '{axis}', which can't be expressed in version 0.5")]
+    BadOffset{tensor: String, axis: char, offset: f32},
+    #[error("Unknown processing step '{name}' in tensor '{tensor}'")]
+    UnknownProcessing{tensor: String, name: String},
+    #[error("Found {num_test_tensors} test tensors for {num_tensors} {kind}")]
+    TestTensorCountMismatch{kind: &'static str, num_tensors: usize, num_test_tensors: usize},
+    #[error("Unknown weights format '{0}'")]
+    UnknownWeightsFormat(String),
+    #[error("Architecture '{0}' should look like 'path/to/file.py:ClassName' or 'module.submodule:ClassName'")]
+    BadArchitecture(String),
+    #[error("Upgraded model is not valid: {0}")]
+    InvalidUpgrade(#[from] serde_json::Error),
+}
+
+/// The shape of a 0.4 tensor, with one entry per letter in its `axes`
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum ShapeV0_4{
+    Fixed(Vec<usize>),
+    Parameterized{min: Vec<usize>, step: Vec<usize>},
+    /// `shape = shape(reference_tensor) * scale + 2 * offset`
+    Implicit{reference_tensor: String, scale: Vec<f32>, offset: Vec<f32>},
+}
+
+/// A pre- or postprocessing step, e.g. `{name: scale_range, kwargs: {mode: per_sample, axes: xy}}`
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct ProcessingV0_4{
+    pub name: String,
+    #[serde(default)]
+    pub kwargs: Map<String, Value>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct TensorV0_4{
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// One letter per axis, e.g. `bcyx`
+    pub axes: String,
+    pub data_type: String,
+    #[serde(default)]
+    pub data_range: Option<Value>,
+    pub shape: ShapeV0_4,
+    #[serde(default)]
+    pub preprocessing: Vec<ProcessingV0_4>,
+    #[serde(default)]
+    pub postprocessing: Vec<ProcessingV0_4>,
+    /// Only meaningful for outputs
+    #[serde(default)]
+    pub halo: Option<Vec<usize>>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct WeightsEntryV0_4{
+    pub source: FileReference,
+    #[serde(default)]
+    pub sha256: Option<String>,
+    #[serde(default)]
+    pub authors: Option<Vec<Author2>>,
+    #[serde(default)]
+    pub parent: Option<String>,
+    /// e.g. `my_module/unet.py:UNet2d` or `my_module.unet:UNet2d`
+    #[serde(default)]
+    pub architecture: Option<String>,
+    #[serde(default)]
+    pub architecture_sha256: Option<String>,
+    #[serde(default)]
+    pub kwargs: Map<String, Value>,
+    #[serde(default)]
+    pub pytorch_version: Option<Version>,
+    #[serde(default)]
+    pub tensorflow_version: Option<Version>,
+    #[serde(default)]
+    pub opset_version: Option<u32>,
+    /// e.g. `conda:environment.yaml`
+    #[serde(default)]
+    pub dependencies: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct ModelRdfV0_4{
+    pub format_version: Version_0_4_x,
+    #[serde(rename = "type")]
+    pub rdf_type: RdfTypeModel,
+    pub name: String,
+    pub description: String,
+    pub authors: Vec<Author2>,
+    #[serde(default)]
+    pub maintainers: Vec<Maintainer>,
+    pub cite: Vec<CiteEntry2>,
+    #[serde(default)]
+    pub license: Option<LicenseId>,
+    pub documentation: FileReference,
+    #[serde(default)]
+    pub covers: Vec<CoverImageSource>,
+    /// e.g. `{files: [a.txt, b.png]}`
+    #[serde(default)]
+    pub attachments: Option<Map<String, Value>>,
+    #[serde(default)]
+    pub git_repo: Option<HttpUrl>,
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+    #[serde(default)]
+    pub links: Vec<String>,
+    #[serde(default)]
+    pub icon: Option<Icon>,
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub config: Map<String, Value>,
+    #[serde(default)]
+    pub version: Option<Version>,
+    pub timestamp: iso8601_timestamp::Timestamp,
+    /// e.g. `{id: affable-shark, sha256: ...}`
+    #[serde(default)]
+    pub parent: Option<Map<String, Value>>,
+    #[serde(default)]
+    pub run_mode: Option<RunMode>,
+    #[serde(default)]
+    pub training_data: Option<DatasetDescrEnum>,
+    pub inputs: Vec<TensorV0_4>,
+    pub outputs: Vec<TensorV0_4>,
+    pub test_inputs: Vec<FileReference>,
+    pub test_outputs: Vec<FileReference>,
+    #[serde(default)]
+    pub sample_inputs: Vec<FileReference>,
+    #[serde(default)]
+    pub sample_outputs: Vec<FileReference>,
+    pub weights: BTreeMap<String, WeightsEntryV0_4>,
+}
+
+/// The 0.5 id of the axis with the 0.4 letter `axis`
+fn axis_id(axis: char) -> Option<&'static str>{
+    Some(match axis{
+        'b' => "batch",
+        'c' => "channel",
+        'i' => "index",
+        't' => "time",
+        'x' => "x",
+        'y' => "y",
+        'z' => "z",
+        _ => return None,
+    })
+}
+
+fn axis_type(axis: char) -> &'static str{
+    match axis{
+        'b' => "batch",
+        'c' => "channel",
+        'i' => "index",
+        't' => "time",
+        _ => "space",
+    }
+}
+
+fn channel_names(num_channels: usize) -> Value{
+    Value::from((0..num_channels.max(1)).map(|idx| format!("channel{idx}")).collect::<Vec<_>>())
+}
+
+/// Converts the `axes` kwarg of a 0.4 processing step, e.g. `"xy"`, into a list of 0.5 axis ids
+fn processing_axes(tensor: &str, raw: &Value) -> Result<Option<Value>, ModelUpgradeError>{
+    let Value::String(letters) = raw else {
+        return Ok(None)
+    };
+    let ids = letters.chars()
+        .map(|axis| axis_id(axis).ok_or_else(|| ModelUpgradeError::UnknownAxis {
+            tensor: tensor.to_owned(), axes: letters.clone(), axis
+        }))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(if ids.is_empty() { None } else { Some(Value::from(ids)) })
+}
+
+impl ProcessingV0_4{
+    fn upgrade(&self, tensor: &str) -> Result<Value, ModelUpgradeError>{
+        let mut kwargs = Map::new();
+        let copy = |key: &str, kwargs: &mut Map<String, Value>|{
+            if let Some(value) = self.kwargs.get(key).filter(|value| !value.is_null()){
+                kwargs.insert(key.to_owned(), value.clone());
+            }
+        };
+        let mut axes = match self.kwargs.get("axes"){
+            Some(raw) => processing_axes(tensor, raw)?,
+            None => None,
+        };
+        // in 0.5, statistics are computed over the whole dataset by reducing along the batch axis, too
+        if let Some(Value::Array(ids)) = axes.as_mut().filter(|_| self.kwargs.get("mode").and_then(Value::as_str) == Some("per_dataset")){
+            if !ids.iter().any(|id| id == "batch"){
+                ids.insert(0, "batch".into());
+            }
+        }
+        let id = match self.name.as_str(){
+            "binarize" => {
+                copy("threshold", &mut kwargs);
+                "binarize"
+            },
+            "clip" => {
+                copy("min", &mut kwargs);
+                copy("max", &mut kwargs);
+                "clip"
+            },
+            "sigmoid" => return Ok(json!({"id": "sigmoid", "kwargs": null})),
+            "scale_linear" => {
+                copy("gain", &mut kwargs);
+                copy("offset", &mut kwargs);
+                // lists of gains and offsets were applied along the axes not listed in 'axes', i.e. per channel
+                if kwargs.values().any(Value::is_array){
+                    kwargs.insert("axis".into(), "channel".into());
+                }
+                "scale_linear"
+            },
+            "zero_mean_unit_variance" if self.kwargs.get("mode").and_then(Value::as_str) == Some("fixed") => {
+                copy("mean", &mut kwargs);
+                copy("std", &mut kwargs);
+                if kwargs.values().any(Value::is_array){
+                    kwargs.insert("axis".into(), "channel".into());
+                }
+                "fixed_zero_mean_unit_variance"
+            },
+            "zero_mean_unit_variance" => {
+                copy("eps", &mut kwargs);
+                "zero_mean_unit_variance"
+            },
+            "scale_range" => {
+                copy("min_percentile", &mut kwargs);
+                copy("max_percentile", &mut kwargs);
+                copy("eps", &mut kwargs);
+                copy("reference_tensor", &mut kwargs);
+                "scale_range"
+            },
+            "scale_mean_variance" => {
+                copy("reference_tensor", &mut kwargs);
+                copy("eps", &mut kwargs);
+                "scale_mean_variance"
+            },
+            _ => return Err(ModelUpgradeError::UnknownProcessing { tensor: tensor.to_owned(), name: self.name.clone() }),
+        };
+        if let Some(axes) = axes.filter(|_| matches!(id, "zero_mean_unit_variance" | "scale_range" | "scale_mean_variance")){
+            kwargs.insert("axes".into(), axes);
+        }
+        Ok(json!({"id": id, "kwargs": kwargs}))
+    }
+}
+
+impl TensorV0_4{
+    fn unknown_axis(&self) -> Option<char>{
+        self.axes.chars().find(|axis| axis_id(*axis).is_none())
+    }
+
+    fn check_num_sizes(&self, num_sizes: usize) -> Result<(), ModelUpgradeError>{
+        if let Some(axis) = self.unknown_axis(){
+            return Err(ModelUpgradeError::UnknownAxis { tensor: self.name.clone(), axes: self.axes.clone(), axis })
+        }
+        if num_sizes != self.axes.chars().count(){
+            return Err(ModelUpgradeError::ShapeMismatch { tensor: self.name.clone(), axes: self.axes.clone(), num_sizes })
+        }
+        Ok(())
+    }
+
+    /// The number of channels of this tensor, or of the smallest valid shape if it is parameterized
+    fn num_channels(&self) -> Option<usize>{
+        let channel_idx = self.axes.chars().position(|axis| axis == 'c')?;
+        match &self.shape{
+            ShapeV0_4::Fixed(sizes) => sizes.get(channel_idx).copied(),
+            ShapeV0_4::Parameterized { min, .. } => min.get(channel_idx).copied(),
+            ShapeV0_4::Implicit { .. } => None,
+        }
+    }
+
+    fn data(&self) -> Value{
+        let mut data = json!({"type": self.data_type});
+        if let Some(range) = self.data_range.clone(){
+            data["range"] = range;
+        }
+        data
+    }
+
+    fn input_axes(&self) -> Result<Vec<Value>, ModelUpgradeError>{
+        let sizes: Vec<Value> = match &self.shape{
+            ShapeV0_4::Fixed(sizes) => sizes.iter().map(|size| Value::from(*size)).collect(),
+            ShapeV0_4::Parameterized { min, step } => {
+                self.check_num_sizes(step.len())?;
+                min.iter().zip(step).map(|(min, step)| match step{
+                    0 => Value::from(*min),
+                    step => json!({"min": min, "step": step}),
+                }).collect()
+            },
+            ShapeV0_4::Implicit { reference_tensor, .. } => return Err(ModelUpgradeError::BadReference {
+                tensor: self.name.clone(), reference: reference_tensor.clone(), axis: self.axes.chars().next().unwrap_or('?'),
+            }),
+        };
+        self.check_num_sizes(sizes.len())?;
+        Ok(self.axes.chars().zip(sizes).map(|(axis, size)| match axis{
+            'b' => json!({"type": "batch"}),
+            'c' => json!({"type": "channel", "channel_names": channel_names(size.as_u64().or_else(|| size["min"].as_u64()).unwrap_or(1) as usize)}),
+            axis => json!({"type": axis_type(axis), "id": axis_id(axis), "size": size}),
+        }).collect())
+    }
+
+    fn output_axes(&self, inputs: &[TensorV0_4]) -> Result<Vec<Value>, ModelUpgradeError>{
+        let halo = self.halo.clone().unwrap_or_default();
+        let mut axes = match &self.shape{
+            ShapeV0_4::Fixed(sizes) => {
+                self.check_num_sizes(sizes.len())?;
+                self.axes.chars().zip(sizes).map(|(axis, size)| match axis{
+                    'b' => json!({"type": "batch"}),
+                    'c' => json!({"type": "channel", "channel_names": channel_names(*size)}),
+                    axis => json!({"type": axis_type(axis), "id": axis_id(axis), "size": size}),
+                }).collect::<Vec<_>>()
+            },
+            ShapeV0_4::Parameterized { .. } => return Err(ModelUpgradeError::ParameterizedOutput {
+                tensor: self.name.clone()
+            }),
+            ShapeV0_4::Implicit { reference_tensor, scale, offset } => {
+                self.check_num_sizes(scale.len())?;
+                self.check_num_sizes(offset.len())?;
+                let reference = inputs.iter().find(|input| input.name == *reference_tensor);
+                let bad_reference = |axis: char| ModelUpgradeError::BadReference {
+                    tensor: self.name.clone(), reference: reference_tensor.clone(), axis
+                };
+                self.axes.chars().zip(scale.iter().zip(offset)).map(|(axis, (scale, offset))|{
+                    let full_offset = 2.0 * offset;
+                    if full_offset < 0.0 || full_offset.fract() != 0.0{
+                        return Err(ModelUpgradeError::BadOffset { tensor: self.name.clone(), axis, offset: *offset })
+                    }
+                    let full_offset = full_offset as usize;
+                    Ok(match axis{
+                        'b' => json!({"type": "batch"}),
+                        'c' => {
+                            let ref_channels = reference.and_then(|reference| reference.num_channels()).unwrap_or(0);
+                            let num_channels = (ref_channels as f32 * scale).round() as usize + full_offset;
+                            json!({"type": "channel", "channel_names": channel_names(num_channels)})
+                        },
+                        axis if *scale == 0.0 => json!({"type": axis_type(axis), "id": axis_id(axis), "size": full_offset}),
+                        axis => {
+                            let reference = reference
+                                .filter(|reference| reference.axes.contains(axis))
+                                .ok_or_else(|| bad_reference(axis))?;
+                            let mut upgraded = json!({
+                                "type": axis_type(axis),
+                                "id": axis_id(axis),
+                                "size": {"tensor_id": reference.name, "axis_id": axis_id(axis), "offset": full_offset},
+                            });
+                            if *scale != 1.0{
+                                upgraded["scale"] = Value::from(1.0 / scale);
+                            }
+                            upgraded
+                        },
+                    })
+                }).collect::<Result<Vec<_>, _>>()?
+            },
+        };
+        for (upgraded, (axis, halo)) in axes.iter_mut().zip(self.axes.chars().zip(halo)){
+            if halo > 0 && matches!(axis_type(axis), "space" | "time"){
+                upgraded["halo"] = Value::from(halo);
+            }
+        }
+        Ok(axes)
+    }
+
+    fn upgrade(
+        &self,
+        axes: Vec<Value>,
+        processing: &[ProcessingV0_4],
+        processing_key: &str,
+        test_tensor: &FileReference,
+        sample_tensor: Option<&FileReference>,
+    ) -> Result<Value, ModelUpgradeError>{
+        let processing = processing.iter()
+            .map(|step| step.upgrade(&self.name))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut upgraded = json!({
+            "id": self.name,
+            "description": self.description.clone().unwrap_or_default(),
+            "axes": axes,
+            "data": self.data(),
+            "test_tensor": {"source": test_tensor},
+        });
+        upgraded[processing_key] = Value::from(processing);
+        if let Some(sample_tensor) = sample_tensor{
+            upgraded["sample_tensor"] = json!({"source": sample_tensor});
+        }
+        Ok(upgraded)
+    }
+}
+
+impl WeightsEntryV0_4{
+    fn upgrade(&self, format: &str) -> Result<Value, ModelUpgradeError>{
+        let mut upgraded = json!({"source": self.source});
+        if let Some(sha256) = &self.sha256{
+            upgraded["sha256"] = sha256.clone().into();
+        }
+        if let Some(authors) = &self.authors{
+            upgraded["authors"] = serde_json::to_value(authors)?;
+        }
+        if let Some(parent) = &self.parent{
+            upgraded["parent"] = if parent == "pytorch_script" { "torchscript".into() } else { parent.clone().into() };
+        }
+        let dependencies = self.dependencies.as_deref()
+            .and_then(|deps| deps.strip_prefix("conda:"))
+            .map(|env_file| json!({"source": env_file}));
+        let default_pytorch_version = || Version::major_minor_patch(1, 10, 0);
+        let default_tensorflow_version = || Version::major_minor_patch(1, 15, 0);
+        match format{
+            "pytorch_state_dict" => {
+                let raw_architecture = self.architecture.clone().unwrap_or_default();
+                let Some((location, callable)) = raw_architecture.rsplit_once(':') else {
+                    return Err(ModelUpgradeError::BadArchitecture(raw_architecture))
+                };
+                let mut architecture = if location.ends_with(".py") || location.contains('/'){
+                    json!({"source": location, "callable": callable, "kwargs": self.kwargs})
+                } else {
+                    json!({"import_from": location, "callable": callable, "kwargs": self.kwargs})
+                };
+                if let Some(sha256) = self.architecture_sha256.as_ref().filter(|_| architecture.get("source").is_some()){
+                    architecture["sha256"] = sha256.clone().into();
+                }
+                upgraded["architecture"] = architecture;
+                upgraded["pytorch_version"] = serde_json::to_value(self.pytorch_version.clone().unwrap_or_else(default_pytorch_version))?;
+                if let Some(dependencies) = dependencies{
+                    upgraded["dependencies"] = dependencies;
+                }
+            },
+            "torchscript" | "pytorch_script" => {
+                upgraded["pytorch_version"] = serde_json::to_value(self.pytorch_version.clone().unwrap_or_else(default_pytorch_version))?;
+            },
+            "onnx" => {
+                upgraded["opset_version"] = self.opset_version.unwrap_or(15).into();
+            },
+            "keras_hdf5" | "tensorflow_js" | "tensorflow_saved_model_bundle" => {
+                upgraded["tensorflow_version"] = serde_json::to_value(self.tensorflow_version.clone().unwrap_or_else(default_tensorflow_version))?;
+                if let Some(dependencies) = dependencies.filter(|_| format == "tensorflow_saved_model_bundle"){
+                    upgraded["dependencies"] = dependencies;
+                }
+            },
+            _ => return Err(ModelUpgradeError::UnknownWeightsFormat(format.to_owned())),
+        }
+        Ok(upgraded)
+    }
+}
+
+impl TryFrom<ModelRdfV0_4> for ModelRdfV0_5{
+    type Error = ModelUpgradeError;
+    fn try_from(model: ModelRdfV0_4) -> Result<Self, Self::Error> {
+        for (kind, num_tensors, num_test_tensors) in [
+            ("inputs", model.inputs.len(), model.test_inputs.len()),
+            ("outputs", model.outputs.len(), model.test_outputs.len()),
+        ]{
+            if num_tensors != num_test_tensors{
+                return Err(ModelUpgradeError::TestTensorCountMismatch { kind, num_tensors, num_test_tensors })
+            }
+        }
+        let inputs = model.inputs.iter().enumerate()
+            .map(|(idx, input)| input.upgrade(
+                input.input_axes()?,
+                &input.preprocessing,
+                "preprocessing",
+                &model.test_inputs[idx],
+                model.sample_inputs.get(idx),
+            ))
+            .collect::<Result<Vec<_>, _>>()?;
+        let outputs = model.outputs.iter().enumerate()
+            .map(|(idx, output)| output.upgrade(
+                output.output_axes(&model.inputs)?,
+                &output.postprocessing,
+                "postprocessing",
+                &model.test_outputs[idx],
+                model.sample_outputs.get(idx),
+            ))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut weights = Map::new();
+        for (format, entry) in &model.weights{
+            let format_name = if format == "pytorch_script" { "torchscript" } else { format.as_str() };
+            weights.insert(format_name.to_owned(), entry.upgrade(format)?);
+        }
+        let attachments: Vec<Value> = model.attachments.as_ref()
+            .and_then(|attachments| attachments.get("files"))
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .map(|file| json!({"source": file}))
+            .collect();
+
+        let mut upgraded = json!({
+            "format_version": Version_0_5_x::new(),
+            "type": model.rdf_type,
+            "name": model.name,
+            "description": model.description,
+            "authors": model.authors,
+            "maintainers": model.maintainers,
+            "cite": model.cite,
+            "documentation": model.documentation,
+            "covers": model.covers,
+            "attachments": attachments,
+            "tags": model.tags,
+            "links": model.links,
+            "config": model.config,
+            "timestamp": model.timestamp,
+            "inputs": inputs,
+            "outputs": outputs,
+            "weights": weights,
+        });
+        let optional_fields = [
+            ("license", serde_json::to_value(model.license)?),
+            ("git_repo", serde_json::to_value(model.git_repo)?),
+            ("icon", serde_json::to_value(model.icon)?),
+            ("version", serde_json::to_value(model.version)?),
+            ("run_mode", serde_json::to_value(model.run_mode)?),
+            ("training_data", serde_json::to_value(model.training_data)?),
+            // 0.4 ids were often zenodo DOIs, which are not valid 0.5 ids
+            ("id", model.id.filter(|id| !id.contains('/')).into()),
+            ("parent", model.parent.and_then(|parent| parent.get("id").cloned()).map(|id| json!({"id": id})).into()),
+        ];
+        for (key, value) in optional_fields{
+            if !value.is_null(){
+                upgraded[key] = value;
+            }
+        }
+        Ok(serde_json::from_value(upgraded)?)
+    }
+}
+
+#[test]
+fn test_upgrade_from_0_4(){
+    let raw = r#"
+format_version: 0.4.10
+type: model
+name: UNet 2D Nuclei Broad
+description: A 2d U-Net trained on the nuclei broad dataset.
+authors:
+  - name: Constantin Pape
+cite:
+  - text: "Ronneberger, Olaf et al. U-net: Convolutional networks for biomedical image segmentation."
+    doi: 10.1007/978-3-319-24574-4_28
+license: MIT
+documentation: README.md
+timestamp: 2019-12-11T12:22:32Z
+inputs:
+  - name: raw
+    axes: bcyx
+    data_type: float32
+    data_range: [-.inf, .inf]
+    shape:
+      min: [1, 1, 64, 64]
+      step: [0, 0, 16, 16]
+    preprocessing:
+      - name: zero_mean_unit_variance
+        kwargs: {mode: per_sample, axes: yx}
+outputs:
+  - name: probability
+    axes: bcyx
+    data_type: float32
+    data_range: [0.0, 1.0]
+    halo: [0, 0, 32, 32]
+    shape:
+      reference_tensor: raw
+      scale: [1.0, 1.0, 1.0, 1.0]
+      offset: [0, 0, 0, 0]
+    postprocessing:
+      - name: sigmoid
+test_inputs: [test_input.npy]
+test_outputs: [test_output.npy]
+weights:
+  pytorch_state_dict:
+    source: weights.pt
+    architecture: unet.py:UNet2d
+    kwargs: {in_channels: 1, out_channels: 1}
+    dependencies: conda:environment.yaml
+  torchscript:
+    source: weights-torchscript.pt
+    parent: pytorch_state_dict
+"#;
+    let legacy: ModelRdfV0_4 = serde_yaml::from_str(raw).unwrap();
+    let upgraded = ModelRdfV0_5::try_from(legacy).unwrap();
+    let reserialized = serde_json::to_value(&upgraded).unwrap();
+    assert_eq!(reserialized["inputs"][0]["axes"][2]["size"], json!({"min": 64, "step": 16}));
+    assert_eq!(reserialized["inputs"][0]["preprocessing"][0]["kwargs"]["axes"], json!(["y", "x"]));
+    assert_eq!(reserialized["outputs"][0]["axes"][3]["halo"], json!(32));
+    assert_eq!(reserialized["outputs"][0]["axes"][3]["size"]["tensor_id"], json!("raw"));
+    assert_eq!(reserialized["weights"]["pytorch_state_dict"]["architecture"]["callable"], json!("UNet2d"));
+    assert_eq!(reserialized["weights"]["pytorch_state_dict"]["dependencies"]["source"], json!("environment.yaml"));
+}
+
+#[test]
+fn test_upgrade_processing_and_shapes_from_0_4(){
+    fn upgrade(preprocessing: &str, output_shape: &str) -> Result<Value, ModelUpgradeError>{
+        let raw = format!(r#"
+format_version: 0.4.10
+type: model
+name: Two channel model
+description: A model with two input channels
+authors:
+  - name: Jane Doe
+cite:
+  - text: Some paper
+    doi: 10.1007/978-3-319-24574-4_28
+documentation: README.md
+timestamp: 2019-12-11T12:22:32Z
+inputs:
+  - name: raw
+    axes: bcyx
+    data_type: float32
+    shape: [1, 2, 64, 64]
+    preprocessing: [{preprocessing}]
+outputs:
+  - name: mask
+    axes: bcyx
+    data_type: float32
+    shape: {output_shape}
+test_inputs: [test_input.npy]
+test_outputs: [test_output.npy]
+weights:
+  onnx:
+    source: weights.onnx
+"#);
+        let legacy: ModelRdfV0_4 = serde_yaml::from_str(&raw).unwrap();
+        Ok(serde_json::to_value(ModelRdfV0_5::try_from(legacy)?).unwrap())
+    }
+    let same_as_input = "{reference_tensor: raw, scale: [1, 1, 1, 1], offset: [0, 0, 0, 0]}";
+
+    let scaled = upgrade("{name: scale_linear, kwargs: {gain: [1.0, 2.0], offset: [0.0, 0.5], axes: yx}}", same_as_input).unwrap();
+    assert_eq!(
+        scaled["inputs"][0]["preprocessing"][0],
+        json!({"id": "scale_linear", "kwargs": {"axis": "channel", "gain": [1.0, 2.0], "offset": [0.0, 0.5]}})
+    );
+
+    let per_dataset = upgrade("{name: zero_mean_unit_variance, kwargs: {mode: per_dataset, axes: yx}}", same_as_input).unwrap();
+    assert_eq!(per_dataset["inputs"][0]["preprocessing"][0]["kwargs"]["axes"], json!(["batch", "y", "x"]));
+
+    let fixed = upgrade("{name: zero_mean_unit_variance, kwargs: {mode: fixed, mean: 0.5, std: 2.0, axes: yx}}", same_as_input).unwrap();
+    assert_eq!(
+        fixed["inputs"][0]["preprocessing"][0],
+        json!({"id": "fixed_zero_mean_unit_variance", "kwargs": {"mean": 0.5, "std": 2.0}})
+    );
+
+    let halved = upgrade("", "{reference_tensor: raw, scale: [1, 1, 0.5, 0.5], offset: [0, 0, 1, 1]}").unwrap();
+    assert_eq!(halved["outputs"][0]["axes"][1]["channel_names"], json!(["channel0", "channel1"]));
+    assert_eq!(halved["outputs"][0]["axes"][2]["scale"], json!(2.0));
+    assert_eq!(halved["outputs"][0]["axes"][2]["size"], json!({"tensor_id": "raw", "axis_id": "y", "offset": 2}));
+
+    assert!(matches!(
+        upgrade("", "{min: [1, 2, 64, 64], step: [0, 0, 16, 16]}"),
+        Err(ModelUpgradeError::ParameterizedOutput { tensor }) if tensor == "mask"
+    ));
+    assert!(matches!(
+        upgrade("", "{reference_tensor: raw, scale: [1, 1, 1, 1], offset: [0, 0, 0.25, 0]}"),
+        Err(ModelUpgradeError::BadOffset { axis: 'y', .. })
+    ));
+}
@@ -1,16 +1,17 @@
 use crate::rdf::{
     self,
     version::Version_0_5_x, Author2, CiteEntry2, CoverImageSource, FileReference, HttpUrl, Icon, LicenseId,
-    Maintainer, NonEmptyList, ResourceId, ResourceTextDescription, Version
+    Maintainer, NonEmptyList, ResourceId, ResourceTextDescription, Uploader, Version
 };
 use crate::rdf::{BoundedString, FileDescription};
 use aspartial::AsPartial;
 use super::{WeightsDescr, _now};
 
 use super::dataset_descr::DatasetDescrEnum;
-use super::{run_mode::RunMode, InputTensorDescr, ModelRdfName, OutputTensorDescr, RdfTypeModel};
+use super::{linked_model::LinkedModel, run_mode::RunMode, InputTensorDescr, ModelRdfName, OutputTensorDescr, RdfTypeModel};
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialModelRdfV0_5 )]
 pub struct ModelRdfV0_5 {
     /// Version of the bioimage.io model description specification used.
@@ -57,6 +58,7 @@ pub struct ModelRdfV0_5 {
     /// (Packaging a resource means downloading/copying important linked files and creating a ZIP archive that contains
     /// an altered rdf.yaml file with local references to the downloaded files.)
     #[serde(default)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::util::arbitrary_values::json_object))]
     pub config: serde_json::Map<String, serde_json::Value>,
 
     /// A URL to the Git repository where the resource is being developed
@@ -96,6 +98,10 @@ pub struct ModelRdfV0_5 {
     #[serde(default)]
     pub version_comment: Option<BoundedString<0, 512>>,
 
+    /// The person who uploaded the model (e.g. to bioimage.io)
+    #[serde(default)]
+    pub uploader: Option<Uploader>,
+
 
 
     #[serde(rename = "type")]
@@ -130,6 +136,10 @@ pub struct ModelRdfV0_5 {
     // Describes the output tensors
     pub outputs: NonEmptyList<OutputTensorDescr>,
 
+    /// The model from which this model is derived, e.g. by fine-tuning the weights
+    #[serde(default)]
+    pub parent: Option<LinkedModel>,
+
     /// Custom run mode for this model: for more complex prediction procedures like test time
     /// data augmentation that currently cannot be expressed in the specification.
     /// No standard run modes are defined yet
@@ -140,6 +150,7 @@ pub struct ModelRdfV0_5 {
     /// with a few restrictions listed [here](https://docs.python.org/3/library/datetime.html#datetime.datetime.fromisoformat).
     /// (In Python a datetime object is valid, too).
     #[serde(default = "_now")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::util::arbitrary_values::timestamp))]
     pub timestamp: iso8601_timestamp::Timestamp,
 
     /// The dataset used to train this model
@@ -152,3 +163,35 @@ pub struct ModelRdfV0_5 {
     pub weights: WeightsDescr,
 }
 
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_arbitrary_model_rdf_round_trip(){
+    use arbitrary::{Arbitrary, Unstructured};
+
+    const NUM_ATTEMPTS: usize = 256;
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    let mut num_generated = 0;
+    for _ in 0..NUM_ATTEMPTS{
+        let bytes: Vec<u8> = (0..16 * 1024).map(|_|{
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        }).collect();
+        let Ok(model_rdf) = ModelRdfV0_5::arbitrary(&mut Unstructured::new(&bytes)) else {
+            continue
+        };
+        num_generated += 1;
+        let raw = serde_yaml::to_string(&model_rdf).unwrap();
+        let reparsed: ModelRdfV0_5 = serde_yaml::from_str(&raw)
+            .unwrap_or_else(|err| panic!("Could not parse back generated rdf: {err}\n{raw}"));
+        assert_eq!(raw, serde_yaml::to_string(&reparsed).unwrap());
+    }
+    // the spec types build valid values directly, so only rare corner cases (like an axis id that
+    // happens to be "batch") should make generation fail
+    assert!(
+        num_generated >= NUM_ATTEMPTS * 3 / 4,
+        "Only {num_generated} out of {NUM_ATTEMPTS} attempts generated a model rdf"
+    );
+}
@@ -3,19 +3,23 @@ use std::borrow::Borrow;
 use aspartial::AsPartial;
 use serde::{Deserialize, Serialize};
 
-use crate::rdf::{model::{postprocessing::ScaleMeanVarianceDescr, preprocessing::{BinarizeDescr, ScaleLinearDescr, ScaleRangeDescr, Zmuv}, AxisId}, FileDescription};
+use crate::rdf::{model::{postprocessing::ScaleMeanVarianceDescr, preprocessing::{ScaleRangeDescr, Zmuv}, AnyAxisSize, AxisId}, FileDescription};
 
-use super::{axes::output_axes::OutputAxisGroup, postprocessing::PostprocessingDescr, TensorId, TensorTextDescription};
+use crate::util::SingleOrMultiple;
+use super::{axes::output_axes::OutputAxisGroup, postprocessing::PostprocessingDescr, tensor_data_descr::TensorDataDescr, TensorId, TensorTextDescription};
 
 #[derive(thiserror::Error, Debug)]
 pub enum OutputTensorParsingError{
     #[error("{tensor_id}.postprocessing[{postproc_idx}] references non-existing axis '{reference}'")]
     PostprocessingReferencesNonExistingAxis{tensor_id: TensorId, postproc_idx: usize, reference: AxisId},
     #[error("Found a self-reference from/to {tensor_id}")]
-    SelfReference{tensor_id: TensorId}
+    SelfReference{tensor_id: TensorId},
+    #[error("{tensor_id}.postprocessing[{postproc_idx}] has {num_values} values along axis '{axis}', which has size {axis_size}")]
+    PostprocessingLengthMismatch{tensor_id: TensorId, postproc_idx: usize, axis: AxisId, axis_size: usize, num_values: usize},
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialOutputTensorDescr )]
 pub struct OutputTensorDescr {
     #[serde(flatten)]
@@ -39,6 +43,9 @@ pub struct OutputTensorMetadata{
     pub description: TensorTextDescription,
     axes: OutputAxisGroup,
     postprocessing: Vec<PostprocessingDescr>,
+    /// Description of the tensor's data values, optionally per channel
+    #[serde(default)]
+    pub data: Option<SingleOrMultiple<TensorDataDescr>>,
 }
 
 impl OutputTensorMetadata{
@@ -47,6 +54,7 @@ impl OutputTensorMetadata{
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct OutputTensorMetadataMsg{
     #[serde(default="_default_to_output")]
     pub id: TensorId,
@@ -55,31 +63,56 @@ pub struct OutputTensorMetadataMsg{
     #[serde(default)]
     pub description: TensorTextDescription,
     pub axes: OutputAxisGroup,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<SingleOrMultiple<TensorDataDescr>>,
+}
+
+// Postprocessing steps that don't fit the generated axes are dropped instead of rejecting the whole tensor
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for OutputTensorMetadata{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut message: OutputTensorMetadataMsg = u.arbitrary()?;
+        for step in std::mem::take(&mut message.postprocessing){
+            message.postprocessing.push(step);
+            if Self::try_from(message.clone()).is_err(){
+                message.postprocessing.pop();
+            }
+        }
+        Self::try_from(message).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
 }
 
 impl TryFrom<OutputTensorMetadataMsg> for OutputTensorMetadata{
     type Error = OutputTensorParsingError;
     fn try_from(message: OutputTensorMetadataMsg) -> Result<Self, Self::Error> {
-        fn ensure_axis_exists(message: &OutputTensorMetadataMsg, postproc_idx: usize, postproc_axis_id: &AxisId) -> Result<(), OutputTensorParsingError>{
+        /// Returns the size of the axis, if it is fixed
+        fn ensure_axis_exists(message: &OutputTensorMetadataMsg, postproc_idx: usize, postproc_axis_id: &AxisId) -> Result<Option<usize>, OutputTensorParsingError>{
             message.axes.iter()
-                .map(|ax| ax.id())
-                .find(|ax_id| ax_id == postproc_axis_id)
+                .find(|ax| ax.id() == *postproc_axis_id)
                 .ok_or(OutputTensorParsingError::PostprocessingReferencesNonExistingAxis{
                     tensor_id: message.id.clone(),
                     postproc_idx,
                     reference: postproc_axis_id.clone(),
                 })
-                .map(|_| ())
+                .map(|ax| match ax.size(){
+                    Some(AnyAxisSize::Fixed(size)) => Some(size.get()),
+                    _ => None,
+                })
         }
 
         for (postproc_idx, postproc) in message.postprocessing.iter().enumerate(){
+            if let Some((axis_id, num_values)) = postproc.values_along_axis(){
+                let axis_id: &AxisId = axis_id.borrow();
+                match ensure_axis_exists(&message, postproc_idx, axis_id)?{
+                    Some(axis_size) if axis_size != num_values => {
+                        return Err(OutputTensorParsingError::PostprocessingLengthMismatch {
+                            tensor_id: message.id.clone(), postproc_idx, axis: axis_id.clone(), axis_size, num_values
+                        })
+                    },
+                    _ => (),
+                }
+            }
             match postproc{
-                PostprocessingDescr::Binarize(BinarizeDescr::AlongAxis(descr)) => {
-                    ensure_axis_exists(&message, postproc_idx, descr.axis.borrow())?;
-                },
-                PostprocessingDescr::ScaleLinear(ScaleLinearDescr::AlongAxis(descr)) => {
-                    ensure_axis_exists(&message, postproc_idx, descr.axis.borrow())?;
-                },
                 PostprocessingDescr::ZeroMeanUnitVariance(Zmuv{axes: Some(axes), ..}) => {
                     for preproc_axis_id in axes.iter(){
                         ensure_axis_exists(&message, postproc_idx, preproc_axis_id)?;
@@ -106,6 +139,7 @@ impl TryFrom<OutputTensorMetadataMsg> for OutputTensorMetadata{
             postprocessing: message.postprocessing,
             description: message.description,
             axes: message.axes,
+            data: message.data,
         })
     }
 }
@@ -117,6 +151,7 @@ impl From<OutputTensorMetadata> for OutputTensorMetadataMsg{
             description: value.description,
             postprocessing: value.postprocessing,
             axes: value.axes,
+            data: value.data,
         }
     }
 }
@@ -4,13 +4,17 @@ use crate::rdf::NonEmptyList;
 use aspartial::AsPartial;
 
 use super::{AxisId, TensorId};
-use super::preprocessing::{BinarizeDescr, ClipDescr, EnsureDtype, FixedZmuv, PreprocessingEpsilon, ScaleLinearDescr, ScaleRangeDescr, Sigmoid, Zmuv};
+use super::axes::NonBatchAxisId;
+use super::preprocessing::{
+    impl_partial_processing_descr, BinarizeDescr, ClipDescr, EnsureDtype, FixedZmuv, PreprocessingEpsilon,
+    ProcessingStep, ScaleLinearDescr, ScaleRangeDescr, Sigmoid, Zmuv
+};
 
 
-// Note: be careful when editing this, as the partial version has to match
+// Note: be careful when editing this, as the partial version below has to match
 // precisely
-#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
-#[aspartial(name = PartialPostprocessingDescr )]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(tag = "id", content = "kwargs")]
 pub enum PostprocessingDescr {
     #[serde(rename = "binarize")]
@@ -33,6 +37,40 @@ pub enum PostprocessingDescr {
     ScaleMeanVarianceDescr(ScaleMeanVarianceDescr),
 }
 
+impl_partial_processing_descr!(PostprocessingDescr => PartialPostprocessingDescr {
+    Binarize(BinarizeDescr) as binarize = "binarize",
+    Clip(ClipDescr) as clip = "clip",
+    EnsureDtype(EnsureDtype) as ensure_dtype = "ensure_dtype",
+    ScaleLinear(ScaleLinearDescr) as scale_linear = "scale_linear",
+    Sigmoid(Sigmoid) as sigmoid = "sigmoid",
+    FixedZeroMeanUnitVariance(FixedZmuv) as fixed_zero_mean_unit_variance = "fixed_zero_mean_unit_variance",
+    ZeroMeanUnitVariance(Zmuv) as zero_mean_unit_variance = "zero_mean_unit_variance",
+    ScaleRange(ScaleRangeDescr) as scale_range = "scale_range",
+    ScaleMeanVarianceDescr(ScaleMeanVarianceDescr) as scale_mean_variance_descr = "scale_mean_variance",
+});
+
+impl PostprocessingDescr{
+    /// This step as one of the kinds that are shared with preprocessing, or the postprocessing-only step it is
+    pub(crate) fn as_step(&self) -> Result<ProcessingStep<'_>, &ScaleMeanVarianceDescr>{
+        Ok(match self{
+            Self::Binarize(descr) => ProcessingStep::Binarize(descr),
+            Self::Clip(descr) => ProcessingStep::Clip(descr),
+            Self::EnsureDtype(descr) => ProcessingStep::EnsureDtype(descr),
+            Self::ScaleLinear(descr) => ProcessingStep::ScaleLinear(descr),
+            Self::Sigmoid(descr) => ProcessingStep::Sigmoid(descr),
+            Self::FixedZeroMeanUnitVariance(descr) => ProcessingStep::FixedZeroMeanUnitVariance(descr),
+            Self::ZeroMeanUnitVariance(descr) => ProcessingStep::ZeroMeanUnitVariance(descr),
+            Self::ScaleRange(descr) => ProcessingStep::ScaleRange(descr),
+            Self::ScaleMeanVarianceDescr(descr) => return Err(descr),
+        })
+    }
+
+    /// The axis along which this step has one value per entry, and how many values it has
+    pub fn values_along_axis(&self) -> Option<(&NonBatchAxisId, usize)>{
+        self.as_step().ok()?.values_along_axis()
+    }
+}
+
 impl Display for PostprocessingDescr{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self{
@@ -51,6 +89,7 @@ impl Display for PostprocessingDescr{
 /// Scale a tensor's data distribution to match another tensor's mean/std.
 /// `out  = (tensor - mean) / (std + eps) * (ref_std + eps) + ref_mean.`
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialScaleMeanVarianceDescr)]
 pub struct ScaleMeanVarianceDescr{
     /// Name of tensor to match.
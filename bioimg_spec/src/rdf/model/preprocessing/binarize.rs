@@ -5,6 +5,7 @@ use ::aspartial::AsPartial;
 use crate::rdf::{model::axes::NonBatchAxisId, non_empty_list::NonEmptyList};
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialSimpleBinarizeDescr)]
 pub struct SimpleBinarizeDescr{
     pub threshold: f32,
@@ -17,6 +18,7 @@ impl Display for SimpleBinarizeDescr{
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialBinarizeAlongAxisDescr)]
 pub struct BinarizeAlongAxisDescr{
     pub threshold: NonEmptyList<f32>,
@@ -30,6 +32,7 @@ impl Display for BinarizeAlongAxisDescr{
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialBinarizeDescr)]
 #[serde(untagged)]
 pub enum BinarizeDescr{
@@ -17,6 +17,16 @@ pub struct ClipDescr {
     max: f32,
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ClipDescr{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use crate::util::arbitrary_values::f32_in_range;
+        let min = f32_in_range(u, -1e6, 1e6)?;
+        let max = min + f32_in_range(u, 1.0, 1e6)?;
+        Self::try_from_min_max(min, max).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl AsPartial for ClipDescr {
     type Partial = PartialClipDescrMessage;
     fn to_partial(self) -> Self::Partial {
@@ -57,6 +67,7 @@ impl TryFrom<ClipDescrMessage> for ClipDescr{
 }
 
 #[derive(serde::Serialize, serde::Deserialize, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialClipDescrMessage )]
 pub struct ClipDescrMessage {
     pub min: f32,
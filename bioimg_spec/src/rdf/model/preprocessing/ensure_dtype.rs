@@ -5,6 +5,7 @@ use ::aspartial::AsPartial;
 use crate::rdf::model::data_type::DataType;
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialEnsureDtype)]
 pub struct EnsureDtype{
     pub dtype: DataType
@@ -0,0 +1,237 @@
+//! Applies pre- and postprocessing steps to in-memory tensors, following the semantics of
+//! `bioimageio.core`. Tensors are always processed as `f32`, and `axis_ids` names each of their dimensions.
+
+use std::borrow::Borrow;
+
+use ndarray::{ArrayD, Axis, IxDyn};
+
+use crate::rdf::model::axes::NonBatchAxisId;
+use crate::rdf::model::postprocessing::PostprocessingDescr;
+use crate::rdf::model::{AxisId, DataType};
+use super::{BinarizeDescr, FixedZmuv, PreprocessingDescr, ProcessingStep, ScaleLinearDescr, ScaleRangeDescr, Zmuv};
+
+#[derive(thiserror::Error, Debug)]
+pub enum ProcessingError{
+    #[error("Tensor has {num_dims} dimensions but {num_axis_ids} axis ids were given")]
+    MismatchedAxisIds{num_dims: usize, num_axis_ids: usize},
+    #[error("Tensor has no axis '{0}'")]
+    MissingAxis(String),
+    #[error("Step has {num_values} values along axis '{axis}', but the tensor has size {size} along it")]
+    MismatchedNumValues{axis: String, num_values: usize, size: usize},
+    #[error("Steps that use a reference tensor ('{0}') can't be applied to a single tensor")]
+    ReferenceTensorNotSupported(String),
+}
+
+impl PreprocessingDescr{
+    pub fn apply(&self, tensor: &ArrayD<f32>, axis_ids: &[&str]) -> Result<ArrayD<f32>, ProcessingError>{
+        self.as_step().apply(tensor, axis_ids)
+    }
+}
+
+impl PostprocessingDescr{
+    pub fn apply(&self, tensor: &ArrayD<f32>, axis_ids: &[&str]) -> Result<ArrayD<f32>, ProcessingError>{
+        match self.as_step(){
+            Ok(step) => step.apply(tensor, axis_ids),
+            Err(descr) => Err(ProcessingError::ReferenceTensorNotSupported(descr.reference_tensor.to_string())),
+        }
+    }
+}
+
+impl ProcessingStep<'_>{
+    fn apply(self, tensor: &ArrayD<f32>, axis_ids: &[&str]) -> Result<ArrayD<f32>, ProcessingError>{
+        check_axis_ids(tensor, axis_ids)?;
+        match self{
+            Self::Binarize(descr) => binarize(descr, tensor, axis_ids),
+            Self::Clip(descr) => Ok(tensor.mapv(|x| x.clamp(descr.min(), descr.max()))),
+            Self::EnsureDtype(descr) => Ok(tensor.mapv(|x| ensure_dtype(x, descr.dtype))),
+            Self::ScaleLinear(descr) => scale_linear(descr, tensor, axis_ids),
+            Self::Sigmoid(_) => Ok(tensor.mapv(|x| 1.0 / (1.0 + (-x).exp()))),
+            Self::FixedZeroMeanUnitVariance(descr) => fixed_zmuv(descr, tensor, axis_ids),
+            Self::ZeroMeanUnitVariance(descr) => zmuv(descr, tensor, axis_ids),
+            Self::ScaleRange(descr) => scale_range(descr, tensor, axis_ids),
+        }
+    }
+}
+
+fn check_axis_ids(tensor: &ArrayD<f32>, axis_ids: &[&str]) -> Result<(), ProcessingError>{
+    if tensor.ndim() != axis_ids.len(){
+        return Err(ProcessingError::MismatchedAxisIds { num_dims: tensor.ndim(), num_axis_ids: axis_ids.len() })
+    }
+    Ok(())
+}
+
+fn axis_index(axis_ids: &[&str], axis: &str) -> Result<usize, ProcessingError>{
+    axis_ids.iter().position(|id| *id == axis).ok_or_else(|| ProcessingError::MissingAxis(axis.to_owned()))
+}
+
+/// The value `x` would have after being cast to `dtype`, e.g. when saved to disk
+fn ensure_dtype(x: f32, dtype: DataType) -> f32{
+    match dtype{
+        DataType::Float32 | DataType::Float64 => x,
+        DataType::Bool => if x != 0.0 { 1.0 } else { 0.0 },
+        DataType::Uint8 => x as u8 as f32,
+        DataType::Uint16 => x as u16 as f32,
+        DataType::Uint32 => x as u32 as f32,
+        DataType::Uint64 => x as u64 as f32,
+        DataType::Int8 => x as i8 as f32,
+        DataType::Int16 => x as i16 as f32,
+        DataType::Int32 => x as i32 as f32,
+        DataType::Int64 => x as i64 as f32,
+    }
+}
+
+/// Applies `f(value, param)` to every element, with `params[i]` used for the i-th entry along `axis`
+fn map_along_axis<P: Copy>(
+    tensor: &ArrayD<f32>,
+    axis_ids: &[&str],
+    axis: &NonBatchAxisId,
+    params: &[P],
+    f: impl Fn(f32, P) -> f32,
+) -> Result<ArrayD<f32>, ProcessingError>{
+    let axis: &str = axis.borrow();
+    let axis_idx = axis_index(axis_ids, axis)?;
+    let size = tensor.shape()[axis_idx];
+    if params.len() != size{
+        return Err(ProcessingError::MismatchedNumValues { axis: axis.to_owned(), num_values: params.len(), size })
+    }
+    let mut out = tensor.clone();
+    for (mut lane, param) in out.axis_iter_mut(Axis(axis_idx)).zip(params){
+        lane.mapv_inplace(|x| f(x, *param));
+    }
+    Ok(out)
+}
+
+/// Calls `normalize` once for every group of values that share the same index along all axes not in
+/// `reduced_axes`, i.e. the values that are normalized jointly. `None` means all axes are reduced
+fn normalize_groups(
+    tensor: &ArrayD<f32>,
+    axis_ids: &[&str],
+    reduced_axes: Option<&[AxisId]>,
+    normalize: impl Fn(&mut [f32]),
+) -> Result<ArrayD<f32>, ProcessingError>{
+    let reduced: Vec<usize> = match reduced_axes{
+        None => (0..axis_ids.len()).collect(),
+        Some(axes) => axes.iter()
+            .map(|axis| axis_index(axis_ids, axis.borrow()))
+            .collect::<Result<_, _>>()?,
+    };
+    let kept: Vec<usize> = (0..axis_ids.len()).filter(|idx| !reduced.contains(idx)).collect();
+    let permutation: Vec<usize> = kept.iter().chain(reduced.iter()).copied().collect();
+    let permuted = tensor.view().permuted_axes(IxDyn(&permutation));
+    let permuted_shape = permuted.shape().to_vec();
+    let group_size: usize = reduced.iter().map(|idx| tensor.shape()[*idx]).product();
+
+    let mut values: Vec<f32> = permuted.iter().copied().collect();
+    if group_size > 0{
+        values.chunks_mut(group_size).for_each(&normalize);
+    }
+    let mut inverse = vec![0; permutation.len()];
+    for (position, axis_idx) in permutation.iter().enumerate(){
+        inverse[*axis_idx] = position;
+    }
+    let normalized = ArrayD::from_shape_vec(IxDyn(&permuted_shape), values)
+        .expect("values were collected from an array of this shape");
+    Ok(normalized.permuted_axes(IxDyn(&inverse)).as_standard_layout().into_owned())
+}
+
+fn mean_and_std(values: &[f32]) -> (f32, f32){
+    let count = values.len() as f64;
+    let mean = values.iter().map(|x| *x as f64).sum::<f64>() / count;
+    let variance = values.iter().map(|x| (*x as f64 - mean).powi(2)).sum::<f64>() / count;
+    (mean as f32, variance.sqrt() as f32)
+}
+
+/// The `q`-th percentile of the sorted `values`, interpolated linearly like numpy's default
+fn percentile(sorted: &[f32], q: f32) -> f32{
+    let position = q / 100.0 * (sorted.len() - 1) as f32;
+    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f32)
+}
+
+fn binarize(descr: &BinarizeDescr, tensor: &ArrayD<f32>, axis_ids: &[&str]) -> Result<ArrayD<f32>, ProcessingError>{
+    let binarize = |x: f32, threshold: f32| if x > threshold { 1.0 } else { 0.0 };
+    match descr{
+        BinarizeDescr::Simple(descr) => Ok(tensor.mapv(|x| binarize(x, descr.threshold))),
+        BinarizeDescr::AlongAxis(descr) => map_along_axis(tensor, axis_ids, &descr.axis, &descr.threshold, binarize),
+    }
+}
+
+fn scale_linear(descr: &ScaleLinearDescr, tensor: &ArrayD<f32>, axis_ids: &[&str]) -> Result<ArrayD<f32>, ProcessingError>{
+    match descr{
+        ScaleLinearDescr::Simple(descr) => Ok(tensor.mapv(|x| x * descr.gain + descr.offset)),
+        ScaleLinearDescr::AlongAxis(descr) => map_along_axis(
+            tensor, axis_ids, &descr.axis, &descr.gain_offsets, |x, (gain, offset)| x * gain + offset
+        ),
+    }
+}
+
+fn fixed_zmuv(descr: &FixedZmuv, tensor: &ArrayD<f32>, axis_ids: &[&str]) -> Result<ArrayD<f32>, ProcessingError>{
+    match descr{
+        FixedZmuv::Simple(descr) => {
+            let (mean, std) = (descr.mean, f32::from(descr.std));
+            Ok(tensor.mapv(|x| (x - mean) / std))
+        },
+        FixedZmuv::AlongAxis(descr) => {
+            let params: Vec<(f32, f32)> = descr.mean_and_std.iter().map(|ms| (ms.mean, f32::from(ms.std))).collect();
+            map_along_axis(tensor, axis_ids, &descr.axis, &params, |x, (mean, std)| (x - mean) / std)
+        },
+    }
+}
+
+fn zmuv(descr: &Zmuv, tensor: &ArrayD<f32>, axis_ids: &[&str]) -> Result<ArrayD<f32>, ProcessingError>{
+    let eps = f32::from(descr.eps);
+    let axes = descr.axes.as_ref().map(|axes| &axes[..]);
+    normalize_groups(tensor, axis_ids, axes, |values|{
+        let (mean, std) = mean_and_std(values);
+        values.iter_mut().for_each(|x| *x = (*x - mean) / (std + eps));
+    })
+}
+
+fn scale_range(descr: &ScaleRangeDescr, tensor: &ArrayD<f32>, axis_ids: &[&str]) -> Result<ArrayD<f32>, ProcessingError>{
+    if let Some(reference_tensor) = &descr.reference_tensor{
+        return Err(ProcessingError::ReferenceTensorNotSupported(reference_tensor.to_string()))
+    }
+    let eps = f32::from(descr.eps);
+    let (min_percentile, max_percentile) = (descr.percentiles.min(), descr.percentiles.max());
+    normalize_groups(tensor, axis_ids, descr.axes.as_deref(), |values|{
+        let mut sorted = values.to_vec();
+        sorted.sort_by(f32::total_cmp);
+        let lower = percentile(&sorted, min_percentile);
+        let upper = percentile(&sorted, max_percentile);
+        values.iter_mut().for_each(|x| *x = (*x - lower) / (upper - lower + eps));
+    })
+}
+
+#[test]
+fn test_processing_execution(){
+    use ndarray::array;
+
+    let tensor = array![[0.0f32, 1.0, 2.0, 3.0], [10.0, 10.0, 20.0, 20.0]].into_dyn();
+    let axis_ids = ["channel", "x"];
+    let parse = |raw: &str| serde_json::from_str::<PreprocessingDescr>(raw).unwrap();
+
+    let clipped = parse(r#"{"id": "clip", "kwargs": {"min": 1.0, "max": 10.0}}"#).apply(&tensor, &axis_ids).unwrap();
+    assert_eq!(clipped, array![[1.0f32, 1.0, 2.0, 3.0], [10.0, 10.0, 10.0, 10.0]].into_dyn());
+
+    let per_channel = parse(r#"{"id": "scale_linear", "kwargs": {"axis": "channel", "gain": [2.0, 0.5], "offset": [1.0, 0.0]}}"#);
+    assert_eq!(
+        per_channel.apply(&tensor, &axis_ids).unwrap(),
+        array![[1.0f32, 3.0, 5.0, 7.0], [5.0, 5.0, 10.0, 10.0]].into_dyn()
+    );
+
+    // normalizing along 'x' only computes one mean and std per channel
+    let normalized = parse(r#"{"id": "zero_mean_unit_variance", "kwargs": {"axes": ["x"], "eps": 1e-6}}"#)
+        .apply(&tensor, &axis_ids).unwrap();
+    assert!((normalized[[1, 0]] + 1.0).abs() < 1e-4);
+    assert!((normalized[[1, 3]] - 1.0).abs() < 1e-4);
+    assert!(normalized.index_axis(Axis(0), 0).sum().abs() < 1e-4);
+
+    let scaled = parse(r#"{"id": "scale_range", "kwargs": {"axes": ["channel", "x"], "min_percentile": 0, "max_percentile": 100, "eps": 1e-6}}"#)
+        .apply(&tensor, &axis_ids).unwrap();
+    assert!((scaled[[0, 0]]).abs() < 1e-4 && (scaled[[1, 3]] - 1.0).abs() < 1e-4);
+
+    assert!(matches!(
+        per_channel.apply(&tensor.t().to_owned(), &axis_ids),
+        Err(ProcessingError::MismatchedNumValues { .. })
+    ));
+}
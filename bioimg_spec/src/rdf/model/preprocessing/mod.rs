@@ -5,6 +5,7 @@ pub mod sigmoid;
 pub mod zero_mean_unit_variance;
 pub mod scale_range;
 pub mod ensure_dtype;
+pub mod execution;
 
 use std::fmt::Display;
 use std::str::FromStr;
@@ -19,8 +20,10 @@ pub use self::scale_range::{ScaleRangeDescr, ScaleRangePercentile};
 pub use self::ensure_dtype::EnsureDtype;
 pub use self::zero_mean_unit_variance::Zmuv;
 pub use self::zero_mean_unit_variance::{SimpleFixedZmuv, FixedZmuvAlongAxis, FixedZmuv};
+pub use self::execution::ProcessingError;
 
 use crate::util::SingleOrMultiple;
+use super::axes::NonBatchAxisId;
 
 // //////////////
 
@@ -57,6 +60,14 @@ pub enum PreprocessingEpsilonParsingError{
 #[aspartial(newtype)]
 pub struct PreprocessingEpsilon(f32);
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for PreprocessingEpsilon{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Self::try_from(crate::util::arbitrary_values::f32_in_range(u, 1e-9, 0.1)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl Default for PreprocessingEpsilon{
     fn default() -> Self {
         Self(1e-6)
@@ -82,8 +93,86 @@ impl FromStr for PreprocessingEpsilon{
 }
 // //////////////////
 
-#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
-#[aspartial(name = PartialPreprocessingDescr)]
+/// Parses the kwargs of a partial processing step. Missing kwargs are treated like empty ones,
+/// so that steps without parameters (e.g. `sigmoid`) can still be recovered
+pub(crate) fn parse_partial_kwargs<T: serde::de::DeserializeOwned>(kwargs: &serde_json::Value) -> Option<T>{
+    if kwargs.is_null(){
+        if let Ok(parsed) = T::deserialize(&serde_json::Value::Object(Default::default())){
+            return Some(parsed)
+        }
+    }
+    T::deserialize(kwargs).ok()
+}
+
+/// Implements `AsPartial` for an `id`/`kwargs`-tagged processing step enum, with one optional field
+/// per kind of step in its partial counterpart.
+///
+/// The partial step is read into a `serde_json::Value` once and only the kwargs of the step named by
+/// `id` are parsed. A step with a missing or unknown `id` is tried against every kind of step.
+macro_rules! impl_partial_processing_descr {(
+    $descr:ident => $partial:ident { $( $variant:ident($inner:ty) as $field:ident = $id:literal ),+ $(,)? }
+) => {
+    #[derive(Clone, Debug, Default)]
+    pub struct $partial{
+        $( pub $field: Option<<$inner as ::aspartial::AsPartial>::Partial>, )+
+    }
+
+    impl ::aspartial::AsPartial for $partial{
+        type Partial = Self;
+        fn to_partial(self) -> Self::Partial {
+            self
+        }
+    }
+
+    impl ::aspartial::AsPartial for $descr{
+        type Partial = $partial;
+        fn to_partial(self) -> Self::Partial {
+            let mut partial = $partial::default();
+            match self{
+                $( Self::$variant(step) => partial.$field = Some(::aspartial::AsPartial::to_partial(step)), )+
+            }
+            partial
+        }
+    }
+
+    impl serde::Serialize for $partial{
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            use serde::ser::SerializeMap;
+            let mut map = serializer.serialize_map(None)?;
+            $(
+                if let Some(kwargs) = &self.$field{
+                    map.serialize_entry("id", $id)?;
+                    map.serialize_entry("kwargs", kwargs)?;
+                    return map.end()
+                }
+            )+
+            map.end()
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for $partial{
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            use crate::rdf::model::preprocessing::parse_partial_kwargs;
+            let serde_json::Value::Object(mut raw) = <serde_json::Value as serde::Deserialize>::deserialize(deserializer)? else {
+                return Err(serde::de::Error::custom("Expected a processing step with an 'id' and 'kwargs'"))
+            };
+            let kwargs = raw.remove("kwargs").unwrap_or(serde_json::Value::Null);
+            let mut partial = Self::default();
+            match raw.get("id").and_then(|id| id.as_str()){
+                $( Some($id) => partial.$field = parse_partial_kwargs(&kwargs), )+
+                _ => {
+                    $( partial.$field = parse_partial_kwargs(&kwargs); )+
+                }
+            }
+            Ok(partial)
+        }
+    }
+}}
+
+pub(crate) use impl_partial_processing_descr;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(tag = "id", content = "kwargs")]
 pub enum PreprocessingDescr {
     #[serde(rename = "binarize")]
@@ -104,6 +193,67 @@ pub enum PreprocessingDescr {
     ScaleRange(ScaleRangeDescr),
 }
 
+impl_partial_processing_descr!(PreprocessingDescr => PartialPreprocessingDescr {
+    Binarize(BinarizeDescr) as binarize = "binarize",
+    Clip(ClipDescr) as clip = "clip",
+    EnsureDtype(EnsureDtype) as ensure_dtype = "ensure_dtype",
+    ScaleLinear(ScaleLinearDescr) as scale_linear = "scale_linear",
+    Sigmoid(Sigmoid) as sigmoid = "sigmoid",
+    FixedZeroMeanUnitVariance(FixedZmuv) as fixed_zero_mean_unit_variance = "fixed_zero_mean_unit_variance",
+    ZeroMeanUnitVariance(Zmuv) as zero_mean_unit_variance = "zero_mean_unit_variance",
+    ScaleRange(ScaleRangeDescr) as scale_range = "scale_range",
+});
+
+impl PreprocessingDescr{
+    pub(crate) fn as_step(&self) -> ProcessingStep<'_>{
+        match self{
+            Self::Binarize(descr) => ProcessingStep::Binarize(descr),
+            Self::Clip(descr) => ProcessingStep::Clip(descr),
+            Self::EnsureDtype(descr) => ProcessingStep::EnsureDtype(descr),
+            Self::ScaleLinear(descr) => ProcessingStep::ScaleLinear(descr),
+            Self::Sigmoid(descr) => ProcessingStep::Sigmoid(descr),
+            Self::FixedZeroMeanUnitVariance(descr) => ProcessingStep::FixedZeroMeanUnitVariance(descr),
+            Self::ZeroMeanUnitVariance(descr) => ProcessingStep::ZeroMeanUnitVariance(descr),
+            Self::ScaleRange(descr) => ProcessingStep::ScaleRange(descr),
+        }
+    }
+
+    /// The axis along which this step has one value per entry, and how many values it has
+    pub fn values_along_axis(&self) -> Option<(&NonBatchAxisId, usize)>{
+        self.as_step().values_along_axis()
+    }
+}
+
+/// A borrowed step of any kind that is allowed both as pre- and as postprocessing
+#[derive(Clone, Copy)]
+pub(crate) enum ProcessingStep<'a>{
+    Binarize(&'a BinarizeDescr),
+    Clip(&'a ClipDescr),
+    EnsureDtype(&'a EnsureDtype),
+    ScaleLinear(&'a ScaleLinearDescr),
+    Sigmoid(&'a Sigmoid),
+    FixedZeroMeanUnitVariance(&'a FixedZmuv),
+    ZeroMeanUnitVariance(&'a Zmuv),
+    ScaleRange(&'a ScaleRangeDescr),
+}
+
+impl<'a> ProcessingStep<'a>{
+    pub(crate) fn values_along_axis(self) -> Option<(&'a NonBatchAxisId, usize)>{
+        match self{
+            Self::FixedZeroMeanUnitVariance(FixedZmuv::AlongAxis(descr)) => {
+                Some((&descr.axis, descr.mean_and_std.len().get()))
+            },
+            Self::Binarize(BinarizeDescr::AlongAxis(descr)) => {
+                Some((&descr.axis, descr.threshold.len().get()))
+            },
+            Self::ScaleLinear(ScaleLinearDescr::AlongAxis(descr)) => {
+                Some((&descr.axis, descr.gain_offsets.len().get()))
+            },
+            _ => None,
+        }
+    }
+}
+
 impl Display for PreprocessingDescr{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self{
@@ -118,3 +268,20 @@ impl Display for PreprocessingDescr{
         }
     }
 }
+
+#[test]
+fn test_partial_preprocessing_only_parses_named_step(){
+    use serde_json::json;
+
+    let partial: PartialPreprocessingDescr = serde_json::from_value(json!({
+        "id": "clip",
+        "kwargs": {"min": 0.0, "max": 1.0},
+    })).unwrap();
+    assert!(partial.clip.is_some());
+    assert!(partial.binarize.is_none());
+    assert!(partial.scale_range.is_none());
+
+    let partial: PartialPreprocessingDescr = serde_json::from_value(json!({"id": "sigmoid"})).unwrap();
+    assert!(partial.sigmoid.is_some());
+    assert!(partial.clip.is_none());
+}
@@ -6,6 +6,7 @@ use crate::{rdf::{model::axes::NonBatchAxisId, non_empty_list::NonEmptyList}, ut
 use super::{_default_to_1, _default_to_single_1, _default_to_single_0};
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialScaleLinearDescr)]
 #[serde(untagged)]
 pub enum ScaleLinearDescr{
@@ -23,6 +24,7 @@ impl Display for ScaleLinearDescr{
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialSimpleScaleLinearDescr)]
 pub struct SimpleScaleLinearDescr{
     /// multiplicative factor
@@ -42,6 +44,7 @@ impl Display for SimpleScaleLinearDescr{
 // //////////////////////
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(try_from="ScaleLinearAlongAxisDescrMessage")]
 #[serde(into="ScaleLinearAlongAxisDescrMessage")]
 pub struct ScaleLinearAlongAxisDescr{
@@ -64,7 +67,7 @@ impl Display for ScaleLinearAlongAxisDescr{
 
 #[derive(thiserror::Error, Debug)]
 pub enum ScaleLinearDescrParsingError{
-    #[error("Number of items in 'gains' and 'offsets' are incompatible")]
+    #[error("Number of items in 'gains' ({num_gains}) and 'offsets' ({num_offsets}) don't match")]
     MismatchedGainsAndOffsets{num_gains: usize, num_offsets: usize},
 }
 
@@ -42,6 +42,17 @@ pub struct ScaleRangePercentile{
     /// accepting percentiles specified in the range 0.0 to 1.0.
     max_percentile: f32,
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ScaleRangePercentile{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use crate::util::arbitrary_values::f32_in_range;
+        let min = f32_in_range(u, 0.0, 99.0)?;
+        let max = f32_in_range(u, min.max(1.0) + 0.5, 100.0)?;
+        Self::try_from_min_max(min, max).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl ScaleRangePercentile{
     pub fn min(&self) -> f32{
         self.min_percentile
@@ -82,6 +93,7 @@ impl From<ScaleRangePercentile> for ScaleRangePercentileMessage{
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 struct ScaleRangePercentileMessage{
     #[serde(default="_default_to_0f32")]
     pub min_percentile: f32,
@@ -90,6 +102,7 @@ struct ScaleRangePercentileMessage{
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name=PartialScaleRangeDescr)]
 pub struct ScaleRangeDescr{
     /// The subset of axes to normalize jointly, i.e. axes to reduce to compute the min/max percentile value.
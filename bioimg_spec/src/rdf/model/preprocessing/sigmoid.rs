@@ -3,6 +3,7 @@ use std::fmt::Display;
 use ::aspartial::AsPartial;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialSigmoid)]
 pub struct Sigmoid;
 
@@ -22,6 +22,7 @@ pub enum ZmuvParsingError{
 pub struct ZmuvStdDeviation(f32);
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialZmuv)]
 pub struct Zmuv {
     /// The subset of axes to normalize jointly, i.e. axes to reduce to compute mean/std.
@@ -47,6 +48,7 @@ impl Display for Zmuv{
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialFixedZmuv)]
 #[serde(untagged)]
 pub enum FixedZmuv{
@@ -73,6 +75,14 @@ impl TryFrom<f32> for ZmuvStdDeviation{
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ZmuvStdDeviation{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Self::try_from(crate::util::arbitrary_values::f32_in_range(u, 1e-6, 1e6)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl Display for ZmuvStdDeviation{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.0.fmt(f)
@@ -95,6 +105,7 @@ impl From<ZmuvStdDeviation> for f32{
 ///Normalize with fixed, precomputed values for mean and variance.
 ///See `zero_mean_unit_variance` for data dependent normalization.
 #[derive(Clone, serde::Serialize, serde::Deserialize, Debug, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialSimpleFixedZmuv)]
 pub struct SimpleFixedZmuv{
     ///The mean value to normalize with.
@@ -113,6 +124,7 @@ impl Display for SimpleFixedZmuv{
 // Normalize with fixed, precomputed values for mean and variance.
 // See `zero_mean_unit_variance` for data dependent normalization.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(try_from = "FixedZmuvAlongAxisMsg")]
 #[serde(into = "FixedZmuvAlongAxisMsg")]
 pub struct FixedZmuvAlongAxis{
@@ -1,16 +1,25 @@
 use aspartial::AsPartial;
 
+use crate::rdf::BoundedString;
 
-#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq, Debug, strum::Display)]
-pub enum RunMode{
-    #[serde(rename = "imagej")]
-    #[strum(serialize = "imagej")]
-    ImageJ
+/// Name of a run mode, e.g. "deepimagej". Consumers that don't recognize it will not run the model.
+pub type RunModeName = BoundedString<1, 1024>;
+
+/// Custom run mode for a model. Most models don't need this and should leave it unset
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq, Debug, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[aspartial(name = PartialRunMode)]
+pub struct RunMode{
+    /// Run mode name
+    pub name: RunModeName,
+    /// Run mode specific key word arguments
+    #[serde(default)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::util::arbitrary_values::json_object))]
+    pub kwargs: serde_json::Map<String, serde_json::Value>,
 }
 
-impl AsPartial for RunMode {
-    type Partial = String;
-    fn to_partial(self) -> Self::Partial {
-        self.to_string()
+impl std::fmt::Display for RunMode{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
     }
 }
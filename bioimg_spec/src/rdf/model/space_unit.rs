@@ -2,8 +2,11 @@ use serde::{Serialize, Deserialize};
 
 use aspartial::AsPartial;
 
+use crate::rdf::si_units::{parse_lenient, PhysicalUnit, UnitParsingError};
+
 #[derive(Default, Serialize, Deserialize, Eq, PartialEq, Debug, Copy, Clone)]
 #[derive(strum::VariantArray, strum::VariantNames, strum::EnumString, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum SpaceUnit{
     #[serde(rename = "attometer")]
     #[strum(to_string = "attometer")]
@@ -92,3 +95,61 @@ impl AsPartial for SpaceUnit {
         self.to_string()
     }
 }
+
+impl SpaceUnit {
+    /// Parses units the way they are often written in the wild (e.g. "um/pixel", "nanometres", "µm"),
+    /// as opposed to `FromStr`, which only accepts the exact spec-compliant names
+    pub fn parse_lenient(raw: &str) -> Result<Self, UnitParsingError> {
+        const ALIASES: &[(&str, SpaceUnit)] = &[
+            ("micron", SpaceUnit::Micrometer),
+            ("Å", SpaceUnit::Angstrom),
+            ("in", SpaceUnit::Inch),
+            ("inches", SpaceUnit::Inch),
+            ("ft", SpaceUnit::Foot),
+            ("feet", SpaceUnit::Foot),
+            ("yd", SpaceUnit::Yard),
+            ("mi", SpaceUnit::Mile),
+            ("pc", SpaceUnit::Parsec),
+        ];
+        parse_lenient(raw, "space", "m", ALIASES)
+    }
+
+    /// The factor by which a length expressed in `self` must be multiplied to be expressed in `other`
+    pub fn factor_to(self, other: SpaceUnit) -> f64 {
+        self.in_base_units() / other.in_base_units()
+    }
+}
+
+impl PhysicalUnit for SpaceUnit {
+    /// This unit, in meters
+    fn in_base_units(self) -> f64 {
+        match self {
+            Self::Attometer => 1e-18,
+            Self::Angstrom => 1e-10,
+            Self::Centimeter => 1e-2,
+            Self::Decimeter => 1e-1,
+            Self::Exameter => 1e18,
+            Self::Femtometer => 1e-15,
+            Self::Foot => 0.3048,
+            Self::Gigameter => 1e9,
+            Self::Hectometer => 1e2,
+            Self::Inch => 0.0254,
+            Self::Kilometer => 1e3,
+            Self::Megameter => 1e6,
+            Self::Meter => 1.0,
+            Self::Micrometer => 1e-6,
+            Self::Mile => 1609.344,
+            Self::Millimeter => 1e-3,
+            Self::Nanometer => 1e-9,
+            Self::Parsec => 3.085_677_581_491_367e16,
+            Self::Petameter => 1e15,
+            Self::Picometer => 1e-12,
+            Self::Terameter => 1e12,
+            Self::Yard => 0.9144,
+            Self::Yoctometer => 1e-24,
+            Self::Yottameter => 1e24,
+            Self::Zeptometer => 1e-21,
+            Self::Zettameter => 1e21,
+        }
+    }
+}
@@ -1,17 +1,38 @@
+use aspartial::AsPartial;
 use serde::{Deserialize, Serialize};
 
 use crate::rdf::{literal::StrMarker, non_empty_list::NonEmptyList, si_units::SiUnit, LitStr};
 
-use super::data_type::DataType;
+use super::{data_range::DataRange, data_type::DataType};
 
-#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[aspartial(name = PartialTensorDataDescr)]
 #[serde(untagged)]
 pub enum TensorDataDescr {
     NominalOrOrdinal(NominalOrOrdinalDataDescr),
     IntervalOrRatio(IntervalOrRatioDataDescr),
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+impl Default for TensorDataDescr{
+    fn default() -> Self {
+        Self::IntervalOrRatio(IntervalOrRatioDataDescr::default())
+    }
+}
+
+impl TensorDataDescr{
+    pub fn data_type(&self) -> DataType{
+        match self{
+            Self::NominalOrOrdinal(descr) => descr.data_type,
+            Self::IntervalOrRatio(descr) => descr.data_type,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[aspartial(name = PartialTVs)]
+#[serde(untagged)]
 pub enum TVs {
     Ints(NonEmptyList<i64>),
     Floats(NonEmptyList<f32>),
@@ -26,14 +47,33 @@ impl StrMarker for ArbitraryUnit{
     const NAME: &'static str = "arbitrary unit";
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[aspartial(name = PartialTensorDataUnit)]
 #[serde(untagged)]
 pub enum TensorDataUnit {
     ArbitraryUnit(LitStr<ArbitraryUnit>),
     Si(SiUnit),
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+impl Default for TensorDataUnit{
+    fn default() -> Self {
+        Self::ArbitraryUnit(LitStr::new())
+    }
+}
+
+impl std::fmt::Display for TensorDataUnit{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self{
+            Self::ArbitraryUnit(unit) => write!(f, "{}", unit.as_str()),
+            Self::Si(unit) => write!(f, "{unit}"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[aspartial(name = PartialNominalOrOrdinalDataDescr)]
 pub struct NominalOrOrdinalDataDescr {
     /// A fixed set of nominal or an ascending sequence of ordinal values.
     /// In this case `data_type` is required to be an unsigend integer type, e.g. 'uint8'.
@@ -52,16 +92,38 @@ fn _default_data_type() -> DataType {
     DataType::Uint8
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Describes tensor values that lie on a continuous scale, e.g. intensities
+#[derive(Serialize, Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[aspartial(name = PartialIntervalOrRatioDataDescr)]
 pub struct IntervalOrRatioDataDescr {
     #[serde(rename = "type")]
-    data_type: DataType,
-    range: (Option<f32>, Option<f32>),
-    unit: TensorDataUnit,
+    #[serde(default)]
+    pub data_type: DataType,
+    /// Tuple `(minimum, maximum)` specifying the allowed range of the data in this tensor.
+    /// `None` corresponds to min/max of what can be expressed by `data_type`.
+    #[serde(default)]
+    pub range: DataRange,
+    #[serde(default)]
+    pub unit: TensorDataUnit,
+    /// Scale for data on an interval (or ratio) scale.
     #[serde(default = "_default_scale")]
-    scale: f32,
+    pub scale: f32,
+    /// Offset for data on a ratio scale.
     #[serde(default)]
-    offset: Option<f32>,
+    pub offset: Option<f32>,
+}
+
+impl Default for IntervalOrRatioDataDescr{
+    fn default() -> Self {
+        Self{
+            data_type: DataType::default(),
+            range: DataRange::default(),
+            unit: TensorDataUnit::default(),
+            scale: _default_scale(),
+            offset: None,
+        }
+    }
 }
 
 fn _default_scale() -> f32 {
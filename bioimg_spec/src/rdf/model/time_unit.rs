@@ -2,8 +2,11 @@ use serde::{Serialize, Deserialize};
 
 use aspartial::AsPartial;
 
+use crate::rdf::si_units::{parse_lenient, PhysicalUnit, UnitParsingError};
+
 #[derive(Default, Serialize, Deserialize, Eq, PartialEq, Debug, Copy, Clone)]
 #[derive(strum::EnumString, strum::VariantArray, strum::VariantNames, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum TimeUnit{
     #[serde(rename = "attosecond")]
     #[strum(to_string = "attosecond")]
@@ -83,3 +86,53 @@ impl AsPartial for TimeUnit {
         self.to_string()
     }
 }
+
+impl TimeUnit {
+    /// Parses units the way they are often written in the wild (e.g. "ms", "minutes", "hr"),
+    /// as opposed to `FromStr`, which only accepts the exact spec-compliant names
+    pub fn parse_lenient(raw: &str) -> Result<Self, UnitParsingError> {
+        const ALIASES: &[(&str, TimeUnit)] = &[
+            ("min", TimeUnit::Minute),
+            ("h", TimeUnit::Hour),
+            ("hr", TimeUnit::Hour),
+            ("d", TimeUnit::Day),
+        ];
+        parse_lenient(raw, "time", "s", ALIASES)
+    }
+
+    /// The factor by which a duration expressed in `self` must be multiplied to be expressed in `other`
+    pub fn factor_to(self, other: TimeUnit) -> f64 {
+        self.in_base_units() / other.in_base_units()
+    }
+}
+
+impl PhysicalUnit for TimeUnit {
+    /// This unit, in seconds
+    fn in_base_units(self) -> f64 {
+        match self {
+            Self::Attosecond => 1e-18,
+            Self::Centisecond => 1e-2,
+            Self::Day => 86_400.0,
+            Self::Decisecond => 1e-1,
+            Self::Exasecond => 1e18,
+            Self::Femtosecond => 1e-15,
+            Self::Gigasecond => 1e9,
+            Self::Hectosecond => 1e2,
+            Self::Hour => 3_600.0,
+            Self::Kilosecond => 1e3,
+            Self::Megasecond => 1e6,
+            Self::Microsecond => 1e-6,
+            Self::Millisecond => 1e-3,
+            Self::Minute => 60.0,
+            Self::Nanosecond => 1e-9,
+            Self::Petasecond => 1e15,
+            Self::Picosecond => 1e-12,
+            Self::Second => 1.0,
+            Self::Terasecond => 1e12,
+            Self::Yoctosecond => 1e-24,
+            Self::Yottasecond => 1e24,
+            Self::Zeptosecond => 1e-21,
+            Self::Zettasecond => 1e21,
+        }
+    }
+}
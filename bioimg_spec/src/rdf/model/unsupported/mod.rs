@@ -8,7 +8,7 @@ pub enum LegacyVersionParsingError{
     VersionTooHigh{found: Version}
 }
 
-#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, derive_more::Deref)]
 #[allow(non_camel_case_types)]
 #[serde(try_from = "Version")]
 pub struct Version_0_4_X_OrEarlier(Version);
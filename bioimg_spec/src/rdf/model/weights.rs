@@ -2,7 +2,7 @@ use std::ops::Deref;
 
 use aspartial::AsPartial;
 
-use crate::rdf::{author::Author2, file_description::{FileDescription, Sha256}, file_reference::EnvironmentFile, FileReference, Identifier, Version};
+use crate::rdf::{author::Author2, file_description::{FileDescription, Sha256}, file_reference::EnvironmentFile, DottedIdentifier, FileReference, Identifier, Version};
 
 #[derive(thiserror::Error, Debug, Clone)]
 pub enum ModelWeightsParsingError{
@@ -11,10 +11,13 @@ pub enum ModelWeightsParsingError{
     #[error("No model weights found")]
     NoWeightsFound,
     #[error("Dependencies must be a .yml or .yaml file")]
-    DependenciesNotYaml{path: String}
+    DependenciesNotYaml{path: String},
+    #[error("Bad keyword argument name '{name}': {reason}")]
+    BadKwargName{name: String, reason: String},
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialMaybeSomeWeightsDescr)]
 pub struct MaybeSomeWeightsDescr{
     #[serde(default)]
@@ -37,6 +40,18 @@ pub struct MaybeSomeWeightsDescr{
 pub struct WeightsDescr(MaybeSomeWeightsDescr);
 
 
+// Torchscript weights are filled in if no format was generated, instead of rejecting the whole description
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for WeightsDescr{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut weights: MaybeSomeWeightsDescr = u.arbitrary()?;
+        if Self::try_from(weights.clone()).is_err(){
+            weights.torchscript = Some(u.arbitrary()?);
+        }
+        Self::try_from(weights).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl WeightsDescr{
     pub fn into_inner(self) -> MaybeSomeWeightsDescr{
         self.0
@@ -65,7 +80,8 @@ impl TryFrom<MaybeSomeWeightsDescr> for WeightsDescr{
     }
 }
 
-#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Debug, strum::Display)]
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Debug, Default, strum::Display, strum::VariantArray)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum WeightsFormat{
     #[serde(rename = "keras_hdf5")]
     #[strum(serialize = "keras_hdf5")]
@@ -84,6 +100,7 @@ pub enum WeightsFormat{
     TensorflowSavedModelBundle,
     #[serde(rename="torchscript")]
     #[strum(serialize="torchscript")]
+    #[default]
     Torchscript,
 }
 
@@ -95,6 +112,7 @@ impl AsPartial for WeightsFormat {
 }
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialModelWeights)]
 #[serde(tag = "type")]
 pub enum ModelWeightsEnum{
@@ -113,6 +131,7 @@ pub enum ModelWeightsEnum{
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name=PartialWeightsDescrBase)]
 pub struct WeightsDescrBase{
     pub source: FileReference,
@@ -125,6 +144,7 @@ pub struct WeightsDescrBase{
 
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialKerasHdf5WeightsDescr)]
 pub struct KerasHdf5WeightsDescr{
     #[serde(flatten)]
@@ -149,6 +169,13 @@ impl TryFrom<u32> for OnnxOpsetVersion{
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for OnnxOpsetVersion{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Self::try_from(u.int_in_range(7..=u32::MAX)?).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl From<OnnxOpsetVersion> for u32{
     fn from(value: OnnxOpsetVersion) -> Self {
         value.0
@@ -156,6 +183,7 @@ impl From<OnnxOpsetVersion> for u32{
 }
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialOnnxWeightsDescrBase)]
 pub struct OnnxWeightsDescr{
     #[serde(flatten)]
@@ -163,7 +191,50 @@ pub struct OnnxWeightsDescr{
     pub opset_version: OnnxOpsetVersion,
 }
 
+/// Keyword arguments to a python callable, whose keys must therefore be valid python identifiers
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq, Default, AsPartial)]
+#[aspartial(newtype)]
+#[serde(try_from = "serde_json::Map<String, serde_json::Value>")]
+pub struct PythonKwargs(serde_json::Map<String, serde_json::Value>);
+
+impl Deref for PythonKwargs{
+    type Target = serde_json::Map<String, serde_json::Value>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl TryFrom<serde_json::Map<String, serde_json::Value>> for PythonKwargs{
+    type Error = ModelWeightsParsingError;
+    fn try_from(value: serde_json::Map<String, serde_json::Value>) -> Result<Self, Self::Error> {
+        for name in value.keys(){
+            if let Err(err) = Identifier::try_from(name.as_str()){
+                return Err(ModelWeightsParsingError::BadKwargName { name: name.clone(), reason: err.to_string() })
+            }
+        }
+        Ok(Self(value))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for PythonKwargs{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut kwargs = serde_json::Map::new();
+        for name in u.arbitrary_iter::<Identifier>()?.take(8){
+            kwargs.insert(name?.into(), crate::util::arbitrary_values::json_value(u)?);
+        }
+        Self::try_from(kwargs).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+impl From<PythonKwargs> for serde_json::Map<String, serde_json::Value>{
+    fn from(value: PythonKwargs) -> Self {
+        value.0
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name=PartialPyTorchArchitectureFromFileDescr)]
 pub struct PyTorchArchitectureFromFileDescr{
     #[serde(flatten)]
@@ -172,23 +243,25 @@ pub struct PyTorchArchitectureFromFileDescr{
     /// examples: "MyNetworkClass", "get_my_model"
     pub callable: Identifier,
     /// key word arguments for the `callable`
-    pub kwargs: serde_json::Map<String, serde_json::Value>,
+    pub kwargs: PythonKwargs,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialPyTorchArchitectureFromLibraryDescr)]
 pub struct PyTorchArchitectureFromLibraryDescr{
     /// Identifier of the callable that returns a torch.nn.Module instance.
     /// examples: "MyNetworkClass", "get_my_model"
     pub callable: Identifier,
     /// key word arguments for the `callable`
-    pub kwargs: serde_json::Map<String, serde_json::Value>,
+    pub kwargs: PythonKwargs,
     /// Where to import the callable from, i.e. `from <import_from> import <callable>`
-    pub import_from: String
+    pub import_from: DottedIdentifier
 }
 
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq, Debug, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialPytorchArchitectureDescr)]
 #[serde(untagged)]
 pub enum PytorchArchitectureDescr{
@@ -209,6 +282,7 @@ impl From<PyTorchArchitectureFromFileDescr> for PytorchArchitectureDescr{
 }
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialPytorchStateDictWeightsDescr)]
 pub struct PytorchStateDictWeightsDescr{
     #[serde(flatten)]
@@ -227,6 +301,7 @@ pub struct PytorchStateDictWeightsDescr{
 }
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialTensorflowJsWeightsDescr)]
 pub struct TensorflowJsWeightsDescr{
     #[serde(flatten)]
@@ -236,6 +311,7 @@ pub struct TensorflowJsWeightsDescr{
 }
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name=PartialTensorflowSavedModelBundleWeightsDescr)]
 pub struct TensorflowSavedModelBundleWeightsDescr{
     #[serde(flatten)]
@@ -250,6 +326,7 @@ pub struct TensorflowSavedModelBundleWeightsDescr{
 }
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(name = PartialTorchscriptWeightsDescr)]
 pub struct TorchscriptWeightsDescr{
     #[serde(flatten)]
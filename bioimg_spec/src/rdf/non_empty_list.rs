@@ -12,6 +12,17 @@ use aspartial::AsPartial;
 pub struct NonEmptyList<T>(Vec<T>);
 
 
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for NonEmptyList<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut items = vec![T::arbitrary(u)?];
+        for item in u.arbitrary_iter::<T>()? {
+            items.push(item?);
+        }
+        Ok(Self(items))
+    }
+}
+
 impl<T: Display> Display for NonEmptyList<T>{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "[")?;
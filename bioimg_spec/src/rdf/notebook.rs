@@ -0,0 +1,48 @@
+use aspartial::AsPartial;
+
+use crate::rdf::literal::{LitStr, StrMarker};
+use crate::rdf::resource_description::ResourceDescrBase;
+use crate::rdf::version::Version_0_3_x;
+use crate::rdf::NotebookSource;
+
+#[derive(Default, Clone, Copy, Debug)]
+pub struct Notebook;
+impl StrMarker for Notebook { const NAME: &'static str = "notebook"; }
+
+/// A bioimage.io notebook resource description file (notebook RDF), describing a Jupyter notebook
+/// relevant to bioimage processing
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[aspartial(name = PartialNotebookDescr)]
+pub struct NotebookDescr{
+    /// Version of the bioimage.io resource description specification used
+    pub format_version: Version_0_3_x,
+
+    #[serde(rename = "type")]
+    /// Specialized resource type 'notebook'
+    pub rdf_type: LitStr<Notebook>,
+
+    #[serde(flatten)]
+    pub base: ResourceDescrBase,
+
+    /// The Jupyter notebook, as a URL or a path relative to the rdf. Must end in `.ipynb`
+    pub source: NotebookSource,
+}
+
+#[test]
+fn test_notebook_source(){
+    let raw = r#"
+format_version: 0.3.0
+type: notebook
+name: Stardist training
+description: Trains a Stardist model on your own data
+source: notebooks/stardist.ipynb
+"#;
+    let notebook: NotebookDescr = serde_yaml::from_str(raw).unwrap();
+    let crate::rdf::FileReference::Path(path) = &*notebook.source else {
+        panic!("Expected a relative path, found {}", notebook.source.to_string());
+    };
+    assert_eq!(path.file_name().to_string(), "stardist.ipynb");
+    let script = crate::rdf::FileReference::try_from("https://github.com/example/stardist.py".to_owned()).unwrap();
+    assert!(NotebookSource::try_from(script).is_err());
+}
@@ -111,6 +111,25 @@ impl TryFrom<String> for Orcid{
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Orcid{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut digits = String::with_capacity(19);
+        let mut checksum_total: u64 = 0;
+        for idx in 0..15{
+            if idx > 0 && idx % 4 == 0{
+                digits.push('-');
+            }
+            let digit = u.int_in_range(0..=9u64)?;
+            checksum_total = (checksum_total + digit) * 2;
+            digits.push(char::from_digit(digit as u32, 10).unwrap());
+        }
+        let checksum = (12 - checksum_total % 11) % 11;
+        digits.push(if checksum == 10 { 'X' } else { char::from_digit(checksum as u32, 10).unwrap() });
+        Self::try_from(digits).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 #[test]
 fn test_orcid_parsing(){
     let good_raw_orcid: String = "0000-0001-7051-1197".into();
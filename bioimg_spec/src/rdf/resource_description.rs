@@ -1,3 +1,97 @@
-pub struct ResourceDEscription{
+use aspartial::AsPartial;
 
-}
\ No newline at end of file
+use crate::rdf::badge::Badge;
+use crate::rdf::{
+    self, Author2, BoundedString, CiteEntry2, CoverImageSource, FileDescription, FileReference, HttpUrl, Icon,
+    LicenseId, Maintainer, ResourceId, ResourceTextDescription, Uploader, Version,
+};
+
+pub type ResourceName = BoundedString<1, 128>;
+
+/// The metadata shared by every kind of bioimage.io resource that isn't a model (datasets, notebooks,
+/// applications, etc). Resource descriptions embed it with `#[serde(flatten)]`, next to their `type`
+/// and `format_version` and whatever fields are specific to them
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[aspartial(name = PartialResourceDescrBase)]
+pub struct ResourceDescrBase{
+    /// A human-friendly name of the resource description
+    pub name: ResourceName,
+
+    /// A string containing a brief description.
+    pub description: ResourceTextDescription,
+
+    /// bioimage.io wide, unique identifier assigned by the
+    /// [bioimage.io collection](https://github.com/bioimage-io/collection-bioimage-io)
+    #[serde(default)]
+    pub id: Option<ResourceId>,
+
+    /// The description from which this one is derived
+    #[serde(default)]
+    pub parent: Option<ResourceId>,
+
+    /// Cover images. Please use an image smaller than 500KB and an aspect ratio width to height of 2:1 or 1:1
+    #[serde(default)]
+    pub covers: Vec<CoverImageSource>,
+
+    /// The authors are the creators of this resource description and the primary points of contact.
+    #[serde(default)]
+    pub authors: Vec<Author2>,
+
+    /// file attachments
+    #[serde(default)]
+    pub attachments: Vec<FileDescription>,
+
+    /// citations
+    #[serde(default)]
+    pub cite: Vec<CiteEntry2>,
+
+    /// A [SPDX license identifier](https://spdx.org/licenses/).
+    #[serde(default)]
+    pub license: Option<LicenseId>,
+
+    /// A URL to the Git repository where the resource is being developed
+    #[serde(default)]
+    pub git_repo: Option<HttpUrl>,
+
+    /// An icon for illustration, e.g. on bioimage.io
+    #[serde(default)]
+    pub icon: Option<Icon>,
+
+    /// IDs of other bioimage.io resources
+    #[serde(default)]
+    pub links: Vec<String>,
+
+    /// The person who uploaded the resource (e.g. to bioimage.io)
+    #[serde(default)]
+    pub uploader: Option<Uploader>,
+
+    /// Maintainers of this resource.
+    /// If not specified, `authors` are maintainers and at least some of them has to specify their `github_user` name
+    #[serde(default)]
+    pub maintainers: Vec<Maintainer>,
+
+    /// Associated tags
+    #[serde(default)]
+    pub tags: Vec<rdf::Tag>,
+
+    /// The version number of the resource, in `MAJOR.MINOR.PATCH` format
+    #[serde(default)]
+    pub version: Option<Version>,
+
+    #[serde(default)]
+    pub version_comment: Option<BoundedString<0, 512>>,
+
+    /// URL or relative path to a markdown file with additional documentation.
+    #[serde(default)]
+    pub documentation: Option<FileReference>,
+
+    /// Badges associated with this resource, e.g. an 'Open in Colab' link
+    #[serde(default)]
+    pub badges: Vec<Badge>,
+
+    /// A field for custom configuration that can contain any keys not present in the RDF spec.
+    #[serde(default)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::util::arbitrary_values::json_object))]
+    pub config: serde_json::Map<String, serde_json::Value>,
+}
@@ -1,14 +1,106 @@
+use aspartial::AsPartial;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// A single SI unit factor like "m", "nm" or "s^-1", as used to describe the unit of tensor values
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(try_from = "String")]
+#[serde(into = "String")]
 pub struct SiUnit {
     pub multiplier: Option<SiMultiplier>,
     pub measure: SiMesaure,
     pub exponent: i32,
 }
 
+impl AsPartial for SiUnit {
+    type Partial = String;
+    fn to_partial(self) -> Self::Partial {
+        self.to_string()
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum SiUnitParsingError{
+    #[error("Could not interpret '{raw}' as an SI unit")]
+    UnknownUnit{raw: String},
+    #[error("Bad exponent in SI unit '{raw}'")]
+    BadExponent{raw: String},
+}
+
+impl std::fmt::Display for SiUnit{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(multiplier) = &self.multiplier{
+            write!(f, "{multiplier}")?;
+        }
+        write!(f, "{}", self.measure)?;
+        if self.exponent != 1{
+            write!(f, "^{}", self.exponent)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for SiUnit{
+    type Err = SiUnitParsingError;
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let (symbol, exponent) = match raw.trim().split_once('^'){
+            None => (raw.trim(), 1),
+            Some((symbol, raw_exponent)) => {
+                let exponent: i32 = raw_exponent.trim_start_matches('+').parse()
+                    .map_err(|_| SiUnitParsingError::BadExponent { raw: raw.to_owned() })?;
+                if exponent == 0{
+                    return Err(SiUnitParsingError::BadExponent { raw: raw.to_owned() })
+                }
+                (symbol, exponent)
+            }
+        };
+        // a bare measure takes precedence, so that e.g. "T" is tesla and "mol" isn't milli-"ol"
+        if let Ok(measure) = symbol.parse::<SiMesaure>(){
+            return Ok(Self{multiplier: None, measure, exponent})
+        }
+        <SiMultiplier as strum::VariantArray>::VARIANTS.iter()
+            .filter_map(|multiplier|{
+                let measure = symbol.strip_prefix(multiplier.as_ref())?.parse::<SiMesaure>().ok()?;
+                Some(Self{multiplier: Some(multiplier.clone()), measure, exponent})
+            })
+            .next()
+            .ok_or_else(|| SiUnitParsingError::UnknownUnit { raw: raw.to_owned() })
+    }
+}
+
+impl TryFrom<String> for SiUnit{
+    type Error = SiUnitParsingError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Units are assembled from arbitrary parts and then kept only if they survive being written out and
+/// parsed back, since e.g. a multiplier followed by a measure might read as a different measure
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SiUnit{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let exponent = match u.int_in_range(-3..=3)?{
+            0 => 1,
+            exponent => exponent,
+        };
+        let unit = Self{multiplier: u.arbitrary()?, measure: u.arbitrary()?, exponent};
+        match unit.to_string().parse::<Self>(){
+            Ok(parsed) if parsed == unit => Ok(unit),
+            _ => Err(arbitrary::Error::IncorrectFormat),
+        }
+    }
+}
+
+impl From<SiUnit> for String{
+    fn from(value: SiUnit) -> Self {
+        value.to_string()
+    }
+}
+
 #[allow(non_camel_case_types)]
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(strum::EnumString, strum::Display, strum::AsRefStr, strum::VariantArray)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum SiMultiplier {
     Q,
     R,
@@ -26,6 +118,7 @@ pub enum SiMultiplier {
     c,
     m,
     #[serde(rename = "µ")]
+    #[strum(serialize = "µ")]
     micro,
     n,
     p,
@@ -38,7 +131,9 @@ pub enum SiMultiplier {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(strum::EnumString, strum::Display, strum::VariantArray)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum SiMesaure {
     m,
     g,
@@ -56,6 +151,7 @@ pub enum SiMesaure {
     V,
     F,
     #[serde(rename = "Ω")]
+    #[strum(serialize = "Ω")]
     Ohm,
     S,
     Wb,
@@ -70,3 +166,226 @@ pub enum SiMesaure {
     l,
     L,
 }
+
+/// A unit of some physical dimension (e.g. length, time) that is a known multiple of
+/// that dimension's SI base unit
+pub trait PhysicalUnit: Copy {
+    /// How many SI base units (e.g. meters, seconds) one of this unit amounts to
+    fn in_base_units(self) -> f64;
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum QuantityError{
+    #[error("Quantity value must be a finite number, found {value}")]
+    NotFinite{value: f64},
+}
+
+/// A physical amount, like "0.5 micrometer", whose value is always a finite number
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity<U>{
+    value: f64,
+    unit: U,
+}
+
+impl<U: PhysicalUnit> Quantity<U>{
+    pub fn try_new(value: f64, unit: U) -> Result<Self, QuantityError>{
+        if !value.is_finite(){
+            return Err(QuantityError::NotFinite { value })
+        }
+        Ok(Self{value, unit})
+    }
+    pub fn value(&self) -> f64{
+        self.value
+    }
+    pub fn unit(&self) -> U{
+        self.unit
+    }
+    /// The value of this quantity when expressed in SI base units (e.g. meters, seconds)
+    pub fn in_base_units(&self) -> f64{
+        self.value * self.unit.in_base_units()
+    }
+    /// Expresses this same quantity in another unit, failing if the result can't be represented
+    pub fn convert_to(self, unit: U) -> Result<Self, QuantityError>{
+        Self::try_new(self.in_base_units() / unit.in_base_units(), unit)
+    }
+}
+
+impl<U: std::fmt::Display> std::fmt::Display for Quantity<U>{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.value, self.unit)
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum UnitParsingError{
+    #[error("Empty unit")]
+    Empty,
+    #[error("Could not parse exponent in '{raw}'")]
+    BadExponent{raw: String},
+    #[error("Unit '{raw}' is divided by '{denominator}', but only per-pixel units (e.g. 'um/pixel') can be interpreted")]
+    UnsupportedDenominator{raw: String, denominator: String},
+    #[error("Unit '{raw}' has exponent {exponent}, but only plain {dimension} units (exponent 1) are allowed")]
+    UnsupportedExponent{raw: String, exponent: i32, dimension: &'static str},
+    #[error("'{symbol}' in '{raw}' is not a known {dimension} unit")]
+    UnknownUnit{raw: String, symbol: String, dimension: &'static str},
+}
+
+/// A unit expression as found in the wild, e.g. "um/pixel", "s^-1" or "nanometers",
+/// broken down into its parts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnitExpression<'a>{
+    /// The unit itself, without any exponent or denominator, e.g. "um"
+    pub symbol: &'a str,
+    pub exponent: i32,
+    /// Whether the unit was given per pixel/voxel (e.g. "um/pixel"), which is what axis scales mean anyway
+    pub per_pixel: bool,
+}
+
+const PIXEL_DENOMINATORS: &[&str] = &["pixel", "pixels", "px", "voxel", "voxels", "vx"];
+
+impl<'a> UnitExpression<'a>{
+    pub fn parse(raw: &'a str) -> Result<Self, UnitParsingError>{
+        let raw = raw.trim();
+        let (numerator, per_pixel) = match raw.split_once('/'){
+            None => (raw, false),
+            Some((numerator, denominator)) => {
+                let denominator = denominator.trim();
+                if !PIXEL_DENOMINATORS.contains(&denominator.to_lowercase().as_str()){
+                    return Err(UnitParsingError::UnsupportedDenominator {
+                        raw: raw.to_owned(), denominator: denominator.to_owned()
+                    })
+                }
+                (numerator.trim(), true)
+            }
+        };
+        let (symbol, exponent) = match numerator.split_once("^").or_else(|| numerator.split_once("**")){
+            None => (numerator, 1),
+            Some((symbol, raw_exponent)) => {
+                let raw_exponent = raw_exponent.trim().trim_start_matches('(').trim_end_matches(')');
+                let exponent = raw_exponent.parse::<i32>()
+                    .map_err(|_| UnitParsingError::BadExponent { raw: raw.to_owned() })?;
+                (symbol.trim(), exponent)
+            }
+        };
+        if symbol.is_empty(){
+            return Err(UnitParsingError::Empty)
+        }
+        Ok(Self{symbol, exponent, per_pixel})
+    }
+}
+
+fn si_prefix_factor(prefix: &str) -> Option<f64>{
+    Some(match prefix{
+        "" => 1.0,
+        "Y" => 1e24,
+        "Z" => 1e21,
+        "E" => 1e18,
+        "P" => 1e15,
+        "T" => 1e12,
+        "G" => 1e9,
+        "M" => 1e6,
+        "k" => 1e3,
+        "h" => 1e2,
+        "da" => 1e1,
+        "d" => 1e-1,
+        "c" => 1e-2,
+        "m" => 1e-3,
+        "u" | "µ" | "μ" => 1e-6,
+        "n" => 1e-9,
+        "p" => 1e-12,
+        "f" => 1e-15,
+        "a" => 1e-18,
+        "z" => 1e-21,
+        "y" => 1e-24,
+        _ => return None,
+    })
+}
+
+/// Leniently interprets unit expressions like "um/pixel", "nanometres" or "ms", mapping them to the
+/// spec-compliant unit with the same magnitude. `aliases` handles the symbols that are not an SI prefix
+/// followed by `base_symbol` (e.g. "in" or "min")
+pub(crate) fn parse_lenient<U>(
+    raw: &str,
+    dimension: &'static str,
+    base_symbol: &str,
+    aliases: &[(&str, U)],
+) -> Result<U, UnitParsingError>
+where
+    U: PhysicalUnit + std::str::FromStr + strum::VariantArray,
+{
+    let expression = UnitExpression::parse(raw)?;
+    if expression.exponent != 1{
+        return Err(UnitParsingError::UnsupportedExponent {
+            raw: raw.trim().to_owned(), exponent: expression.exponent, dimension
+        })
+    }
+    let symbol = expression.symbol;
+    if let Ok(unit) = symbol.parse::<U>(){
+        return Ok(unit)
+    }
+    let normalized = symbol.to_lowercase().replace("metre", "meter");
+    for candidate in [normalized.as_str(), normalized.trim_end_matches('s')]{
+        if let Ok(unit) = candidate.parse::<U>(){
+            return Ok(unit)
+        }
+        if let Some((_, unit)) = aliases.iter().find(|(alias, _)| *alias == candidate || *alias == symbol){
+            return Ok(*unit)
+        }
+    }
+    let factor = symbol.strip_suffix(base_symbol).and_then(si_prefix_factor);
+    if let Some(factor) = factor{
+        let found = U::VARIANTS.iter()
+            .find(|unit| ((unit.in_base_units() - factor) / factor).abs() < 1e-9);
+        if let Some(unit) = found{
+            return Ok(*unit)
+        }
+    }
+    Err(UnitParsingError::UnknownUnit { raw: raw.trim().to_owned(), symbol: symbol.to_owned(), dimension })
+}
+
+#[test]
+fn test_quantity_conversion(){
+    use crate::rdf::model::{SpaceUnit, TimeUnit};
+
+    assert!((SpaceUnit::Micrometer.factor_to(SpaceUnit::Nanometer) - 1000.0).abs() < 1e-9);
+    assert_eq!(TimeUnit::Hour.factor_to(TimeUnit::Minute), 60.0);
+
+    let quantity = Quantity::try_new(2.0, SpaceUnit::Millimeter).unwrap();
+    let converted = quantity.convert_to(SpaceUnit::Micrometer).unwrap();
+    assert_eq!(converted.unit(), SpaceUnit::Micrometer);
+    assert!((converted.value() - 2000.0).abs() < 1e-9);
+
+    assert!(Quantity::try_new(f64::NAN, TimeUnit::Second).is_err());
+    assert!(Quantity::try_new(1e300, SpaceUnit::Yottameter).unwrap().convert_to(SpaceUnit::Yoctometer).is_err());
+}
+
+#[test]
+fn test_lenient_unit_parsing(){
+    use crate::rdf::model::{SpaceUnit, TimeUnit};
+
+    assert_eq!(SpaceUnit::parse_lenient("um/pixel"), Ok(SpaceUnit::Micrometer));
+    assert_eq!(SpaceUnit::parse_lenient("nanometres"), Ok(SpaceUnit::Nanometer));
+    assert_eq!(SpaceUnit::parse_lenient("µm"), Ok(SpaceUnit::Micrometer));
+    assert_eq!(SpaceUnit::parse_lenient("micron"), Ok(SpaceUnit::Micrometer));
+    assert_eq!(TimeUnit::parse_lenient("ms"), Ok(TimeUnit::Millisecond));
+    assert_eq!(TimeUnit::parse_lenient("min"), Ok(TimeUnit::Minute));
+    assert!(matches!(
+        TimeUnit::parse_lenient("s^-1"),
+        Err(UnitParsingError::UnsupportedExponent { exponent: -1, .. })
+    ));
+    assert!(matches!(
+        SpaceUnit::parse_lenient("um/s"),
+        Err(UnitParsingError::UnsupportedDenominator { .. })
+    ));
+}
+
+#[test]
+fn test_si_unit_string_round_trip(){
+    for raw in ["m", "nm", "mol", "cd", "T", "kg", "µm^2", "s^-1", "Ω", "dam"]{
+        let unit: SiUnit = raw.parse().unwrap();
+        assert_eq!(unit.to_string(), raw);
+    }
+    assert_eq!("mm".parse::<SiUnit>().unwrap().multiplier, Some(SiMultiplier::m));
+    assert!("foo".parse::<SiUnit>().is_err());
+    assert!("m^0".parse::<SiUnit>().is_err());
+}
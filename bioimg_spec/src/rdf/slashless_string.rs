@@ -24,6 +24,17 @@ impl<T: Borrow<str>> Borrow<str> for SlashlessString<T>{
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a> + Borrow<str>> arbitrary::Arbitrary<'a> for SlashlessString<T>{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let inner = T::arbitrary(u)?;
+        if inner.borrow().contains(['/', '\\']) {
+            return Err(arbitrary::Error::IncorrectFormat)
+        }
+        Ok(Self(inner))
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum SlashlessStringError {
     #[error("{0}")]
@@ -6,6 +6,7 @@ use super::{bounded_string::BoundedStringParsingError, BoundedString};
 
 
 #[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq, Eq, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[aspartial(newtype)]
 pub struct Tag(BoundedString<1, 1024>);
 
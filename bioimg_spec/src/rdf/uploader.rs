@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use aspartial::AsPartial;
+
+use super::bounded_string::BoundedString;
+
+/// The person who uploaded the model (e.g. to bioimage.io)
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, AsPartial)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[aspartial(name = PartialUploader)]
+pub struct Uploader {
+    /// Email
+    pub email: BoundedString<1, 1024>, //FIXME: validate email
+    /// Name
+    pub name: Option<BoundedString<1, 1024>>,
+}
+
+impl Display for Uploader{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = &self.name{
+            write!(f, "{name} ")?;
+        };
+        write!(f, "📧{}", self.email)
+    }
+}
@@ -46,6 +46,16 @@ impl Version{
     pub fn version_0_5_0() -> Version{
         Self::major_minor_patch(0, 5, 0)
     }
+    pub fn version_0_3_0() -> Version{
+        Self::major_minor_patch(0, 3, 0)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Version{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::major_minor_patch(u.arbitrary::<u8>()?.into(), u.arbitrary::<u8>()?.into(), u.arbitrary::<u8>()?.into()))
+    }
 }
 
 impl TryFrom<String> for Version{
@@ -80,6 +90,13 @@ impl Version_0_5_x{
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Version_0_5_x{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(Version::major_minor_patch(0, 5, u.int_in_range(0..=3)?)))
+    }
+}
+
 impl TryFrom<Version> for Version_0_5_x {
     type Error = VersionParsingError;
     fn try_from(version: Version) -> Result<Self, Self::Error> {
@@ -92,3 +109,55 @@ impl TryFrom<Version> for Version_0_5_x {
         Ok(Self(version))
     }
 }
+
+/// The format version of the non-model resource descriptions (datasets, notebooks, applications, etc)
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, AsPartial)]
+#[aspartial(newtype)]
+#[serde(try_from="Version")]
+pub struct Version_0_3_x(Version);
+
+impl Version_0_3_x{
+    pub fn new() -> Self{
+        Self(Version::version_0_3_0())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Version_0_3_x{
+    fn arbitrary(_u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::new())
+    }
+}
+
+impl TryFrom<Version> for Version_0_3_x {
+    type Error = VersionParsingError;
+    fn try_from(version: Version) -> Result<Self, Self::Error> {
+        if  version < Version::version_0_3_0() {
+            return Err(VersionParsingError::TooLow { version })
+        }
+        if  version >= Version::major_minor_patch(0, 4, 0) {
+            return Err(VersionParsingError::TooHigh { version, max_supported: Version::version_0_3_0() })
+        }
+        Ok(Self(version))
+    }
+}
+
+/// The format version of legacy models, which can only be read to be upgraded to 0.5
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+#[serde(try_from="Version")]
+pub struct Version_0_4_x(Version);
+
+impl TryFrom<Version> for Version_0_4_x {
+    type Error = VersionParsingError;
+    fn try_from(version: Version) -> Result<Self, Self::Error> {
+        if  version < Version::major_minor_patch(0, 4, 0) {
+            return Err(VersionParsingError::TooLow { version })
+        }
+        if  version >= Version::version_0_5_0() {
+            return Err(VersionParsingError::TooHigh { version, max_supported: Version::major_minor_patch(0, 4, 10) })
+        }
+        Ok(Self(version))
+    }
+}
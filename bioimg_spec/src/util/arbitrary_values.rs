@@ -0,0 +1,76 @@
+//! Generators of arbitrary values for foreign types that don't implement `arbitrary::Arbitrary`,
+//! for use in `#[arbitrary(with = ...)]` field attributes
+
+use arbitrary::{Result, Unstructured};
+
+/// How deeply nested generated JSON arrays and objects can get
+const MAX_JSON_DEPTH: usize = 3;
+
+/// Characters that may appear in python identifiers, though not as their first character in the case of digits
+pub const IDENTIFIER_CHARS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789_";
+
+/// Characters that are accepted by every free text field of the spec, including the restrictive ones
+/// like model names
+pub const PLAIN_CHARS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789_- ()";
+
+/// A string of `min_len..=max_len` characters from the ASCII `alphabet`. Lengths are capped a few dozen
+/// characters above `min_len` to keep generated values readable
+pub fn string_from(u: &mut Unstructured<'_>, alphabet: &str, min_len: usize, max_len: usize) -> Result<String> {
+    let max_len = max_len.min(min_len + 32);
+    let len = u.int_in_range(min_len..=max_len)?;
+    (0..len).map(|_| u.choose(alphabet.as_bytes()).map(|c| char::from(*c))).collect()
+}
+
+/// A finite float in `min..=max`
+pub fn f32_in_range(u: &mut Unstructured<'_>, min: f32, max: f32) -> Result<f32> {
+    let fraction = f64::from(u.int_in_range(0..=u32::MAX)?) / f64::from(u32::MAX);
+    Ok((f64::from(min) + (f64::from(max) - f64::from(min)) * fraction) as f32)
+}
+
+fn json_value_with_depth(u: &mut Unstructured<'_>, depth: usize) -> Result<serde_json::Value> {
+    use serde_json::Value;
+    let max_kind = if depth < MAX_JSON_DEPTH { 5 } else { 3 };
+    Ok(match u.int_in_range(0..=max_kind)? {
+        0 => Value::Null,
+        1 => Value::Bool(u.arbitrary()?),
+        2 => Value::from(u.arbitrary::<i64>()?),
+        3 => Value::String(u.arbitrary()?),
+        4 => {
+            let len = u.arbitrary_len::<u64>()?;
+            let items = (0..len).map(|_| json_value_with_depth(u, depth + 1)).collect::<Result<Vec<_>>>()?;
+            Value::Array(items)
+        },
+        _ => Value::Object(json_object_with_depth(u, depth + 1)?),
+    })
+}
+
+fn json_object_with_depth(u: &mut Unstructured<'_>, depth: usize) -> Result<serde_json::Map<String, serde_json::Value>> {
+    let len = u.arbitrary_len::<(String, u64)>()?;
+    let mut object = serde_json::Map::new();
+    for _ in 0..len {
+        object.insert(u.arbitrary()?, json_value_with_depth(u, depth)?);
+    }
+    Ok(object)
+}
+
+pub fn json_value(u: &mut Unstructured<'_>) -> Result<serde_json::Value> {
+    json_value_with_depth(u, 0)
+}
+
+pub fn json_object(u: &mut Unstructured<'_>) -> Result<serde_json::Map<String, serde_json::Value>> {
+    json_object_with_depth(u, 0)
+}
+
+/// A timestamp with second precision between the years 1970 and 2100
+pub fn timestamp(u: &mut Unstructured<'_>) -> Result<iso8601_timestamp::Timestamp> {
+    let raw = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        u.int_in_range(1970..=2100u32)?,
+        u.int_in_range(1..=12u32)?,
+        u.int_in_range(1..=28u32)?,
+        u.int_in_range(0..=23u32)?,
+        u.int_in_range(0..=59u32)?,
+        u.int_in_range(0..=59u32)?,
+    );
+    iso8601_timestamp::Timestamp::parse(&raw).ok_or(arbitrary::Error::IncorrectFormat)
+}
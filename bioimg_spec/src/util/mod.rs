@@ -1,7 +1,25 @@
 use serde::{Deserialize, Serialize};
 use aspartial::AsPartial;
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary_values;
+
+/// Implements `arbitrary::Arbitrary` (behind the `arbitrary` feature) for a type that validates its
+/// contents when it is built, by generating an arbitrary `$raw` and rejecting it as
+/// `arbitrary::Error::IncorrectFormat` if it can't be converted into `$type`
+macro_rules! arbitrary_via_try_from {($type:ty, $raw:ty) => {
+    #[cfg(feature = "arbitrary")]
+    impl<'a> ::arbitrary::Arbitrary<'a> for $type {
+        fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+            let raw: $raw = u.arbitrary()?;
+            <$type>::try_from(raw).map_err(|_| ::arbitrary::Error::IncorrectFormat)
+        }
+    }
+};}
+pub(crate) use arbitrary_via_try_from;
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(untagged)]
 pub enum SingleOrMultiple<T> {
     Single(T),
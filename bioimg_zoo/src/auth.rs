@@ -78,6 +78,8 @@ impl TryFrom<String> for GithubUser{
 #[derive(serde::Deserialize, Debug, Clone)]
 pub struct Claims{
     sub: GithubUser,
+    #[serde(default, rename = "https://amun.ai/email")]
+    email: Option<String>,
 }
 
 #[derive(Debug, serde::Deserialize, Clone)]
@@ -91,6 +93,10 @@ impl UserToken{
     pub fn to_hypha_workspace_name(&self) -> String{
         self.claims.sub.to_hypha_workspace_name()
     }
+    /// The email of the logged-in user, if the identity provider shared it
+    pub fn email(&self) -> Option<&str>{
+        self.claims.email.as_deref()
+    }
 }
 
 impl TryFrom<String> for UserToken{
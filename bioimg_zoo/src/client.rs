@@ -41,10 +41,21 @@ impl Client{
     pub fn presigned_url_request(
         &self, nickname: &ZooNickname, expiration: Seconds, client_method: ClientMethod
     ) -> http::Request<Vec<u8>>{
+        self.presigned_url_request_at(&self.model_package_path(nickname), expiration, client_method)
+    }
+
+    /// Path of the zoo storage where the package of the model called `nickname` is uploaded to before staging
+    pub fn model_package_path(&self, nickname: &ZooNickname) -> camino::Utf8PathBuf{
         let mut path = camino::Utf8PathBuf::from("models");
         path.push(nickname.to_string());
-        println!("Generating presigned url with this path: {path}");
+        path
+    }
 
+    /// Requests a presigned url for an arbitrary `path` of the zoo storage, e.g. for packages that are
+    /// only uploaded temporarily and never staged
+    pub fn presigned_url_request_at(
+        &self, path: &camino::Utf8Path, expiration: Seconds, client_method: ClientMethod
+    ) -> http::Request<Vec<u8>>{
         let auth_header = self.user_token.as_header();
         http::Request::builder()
             .method(http::Method::POST)
@@ -53,7 +64,7 @@ impl Client{
             .header(auth_header.0, auth_header.1)
             .body(serde_json::to_vec(
                 &PresignedUrlPayload{
-                    path: &path,
+                    path,
                     client_method,
                     expiration,
                 }
@@ -69,6 +80,12 @@ impl Client{
         Ok(PresignedUrl { url })
     }
 
+    /// Path of the zoo storage where a package that is only meant to be tested remotely can be uploaded to
+    pub fn remote_test_package_path(&self, timestamp_millis: u128) -> camino::Utf8PathBuf{
+        let workspace = self.user_token.to_hypha_workspace_name();
+        camino::Utf8PathBuf::from(format!("remote-tests/{workspace}/{timestamp_millis}.zip"))
+    }
+
     pub fn write_to_bucket_request<R: Read>(&self, url: &PresignedUrl, reader: R) -> http::Request<R>{
         //bucket url is presigned (and from a different host) and needs no auth header
         http::Request::builder()
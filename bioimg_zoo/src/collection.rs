@@ -46,21 +46,89 @@ pub struct CollectionJson{
 
 #[derive(serde::Deserialize)]
 pub struct CollectionItem{
+    #[serde(default)]
+    id: Option<String>,
     #[serde(default)]
     nickname: Option<ZooNickname>,
+    #[serde(default, rename="type")]
+    resource_type: Option<String>,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    versions: Vec<String>,
+}
+
+const COLLECTION_BASE_URL: &str = "https://uk1s3.embassy.ebi.ac.uk/public-datasets/bioimage.io";
+const ARTIFACTS_BASE_URL: &str = "https://hypha.aicell.io/bioimage-io/artifacts";
+
+impl CollectionItem{
+    pub fn id(&self) -> Option<&str>{
+        self.id.as_deref()
+    }
+    pub fn nickname(&self) -> Option<&ZooNickname>{
+        self.nickname.as_ref()
+    }
+    pub fn resource_type(&self) -> Option<&str>{
+        self.resource_type.as_deref()
+    }
+    pub fn name(&self) -> Option<&str>{
+        self.name.as_deref()
+    }
+    /// The published versions of this resource, if the collection lists them
+    pub fn versions(&self) -> &[String]{
+        &self.versions
+    }
+    /// URL of the zip package of `version` of this resource, as packed by the zoo, or of its latest version if
+    /// `version` is `None`
+    pub fn package_url(&self, version: Option<&str>) -> Option<String>{
+        let id = self.id.as_deref()?;
+        Some(match version{
+            None => format!("{ARTIFACTS_BASE_URL}/{id}/create-zip-file"),
+            Some(version) => format!("{ARTIFACTS_BASE_URL}/{id}/create-zip-file?version={version}"),
+        })
+    }
+}
+
+/// The outcome of looking up a resource id in the zoo collection
+pub enum ResourceIdCheck<'c>{
+    Found(&'c CollectionItem),
+    NotFound,
+    WrongResourceType{expected: &'static str, found: String},
 }
 
 impl CollectionJson{
     pub fn request() -> http::Request<[u8; 0]>{
         http::Request::builder()
             .method(http::Method::GET)
-            .uri("https://uk1s3.embassy.ebi.ac.uk/public-datasets/bioimage.io/collection.json")
+            .uri(format!("{COLLECTION_BASE_URL}/collection.json"))
             .body([])
             .unwrap()
     }
     pub fn parse_response(response: &http::Response<Vec<u8>>) -> Result<Self, BadResponse>{
         read_json_response(&response)
     }
+    pub fn items(&self) -> &[CollectionItem]{
+        &self.collection
+    }
+    pub fn find_resource(&self, resource_id: &str) -> Option<&CollectionItem>{
+        self.collection.iter().find(|item|{
+            item.id.as_deref() == Some(resource_id) ||
+            item.nickname.as_ref().is_some_and(|nickname| nickname.to_string() == resource_id)
+        })
+    }
+    /// Checks that `resource_id` is in the collection and that it describes a resource of type `expected_type`
+    /// (e.g. "model", "dataset")
+    pub fn check_resource_id(&self, resource_id: &str, expected_type: &'static str) -> ResourceIdCheck<'_>{
+        let Some(item) = self.find_resource(resource_id) else {
+            return ResourceIdCheck::NotFound
+        };
+        match item.resource_type.as_deref(){
+            Some(found) if found != expected_type => ResourceIdCheck::WrongResourceType{
+                expected: expected_type, found: found.to_owned()
+            },
+            _ => ResourceIdCheck::Found(item),
+        }
+    }
 }
 ///////////////////////
 
@@ -0,0 +1,57 @@
+//! A small, curated list of public zoo models that are good references for how
+//! the different parts of a model description are usually filled in.
+
+use crate::BadResponse;
+
+pub struct ExampleModel{
+    pub id: &'static str,
+    pub title: &'static str,
+    /// What makes this model worth looking at
+    pub highlights: &'static str,
+}
+
+pub static EXAMPLE_MODELS: &[ExampleModel] = &[
+    ExampleModel{
+        id: "affable-shark",
+        title: "Nucleus segmentation (2D UNet)",
+        highlights: "Parameterized input sizes (min + step) and outputs whose size references the input",
+    },
+    ExampleModel{
+        id: "hiding-tiger",
+        title: "Cell boundary segmentation (2D UNet)",
+        highlights: "Multi-channel outputs and output halos",
+    },
+    ExampleModel{
+        id: "impartial-shrimp",
+        title: "Neuron segmentation in EM (3D UNet)",
+        highlights: "3D spatial axes with physical units and halos along every spatial axis",
+    },
+    ExampleModel{
+        id: "powerful-chipmunk",
+        title: "Cell segmentation in phase contrast (2D UNet)",
+        highlights: "Preprocessing with percentile-based range scaling",
+    },
+];
+
+impl ExampleModel{
+    pub fn rdf_url(&self) -> String{
+        format!("https://hypha.aicell.io/bioimage-io/artifacts/{}/files/rdf.yaml", self.id)
+    }
+
+    pub fn rdf_request(&self) -> http::Request<[u8; 0]>{
+        http::Request::builder()
+            .method(http::Method::GET)
+            .uri(self.rdf_url())
+            .body([])
+            .unwrap()
+    }
+
+    pub fn parse_rdf_response(response: &http::Response<Vec<u8>>) -> Result<String, BadResponse>{
+        if !response.status().is_success(){
+            return Err(BadResponse::FaiedResponse { status: response.status() })
+        }
+        String::from_utf8(response.body().clone()).map_err(|err| BadResponse::ParsingError {
+            expected_type_name: "utf8 text", source: Box::new(err)
+        })
+    }
+}
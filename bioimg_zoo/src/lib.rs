@@ -1,6 +1,9 @@
 pub mod auth;
 pub mod client;
 pub mod collection;
+pub mod examples;
+pub mod remote_test;
+pub mod upload;
 
 use std::error::Error;
 
@@ -21,7 +24,7 @@ pub enum BadResponse{
     #[error("Could not deserialize response: {inner}")]
     GarbledPayload{inner: serde_json::Error},
     #[error("Could not parse response as a {expected_type_name}")]
-    ParsingError{expected_type_name: &'static str, source: Box<dyn Error>}
+    ParsingError{expected_type_name: &'static str, source: Box<dyn Error + Send + Sync>}
 }
 
 fn read_json_response<T: DeserializeOwned>(response: &http::Response<Vec<u8>>) -> Result<T, BadResponse>{
@@ -0,0 +1,90 @@
+//! Requests to the BioEngine model runner, which runs the bioimage.io test suite on a model on remote
+//! hardware, for users whose own machines can't run the model
+
+use std::fmt::Display;
+
+use crate::{read_json_response, BadResponse, APPLICATION_JSON};
+
+/// The `test` function of the BioEngine model runner service, called over Hypha's HTTP endpoint for
+/// services (`https://<server>/<workspace>/services/<service_id>/<function>`), which takes the keyword
+/// arguments of the function as a JSON body
+pub const BIOENGINE_TEST_URL: &str = "https://hypha.aicell.io/bioimage-io/services/model-runner/test";
+
+/// Environment variable that overrides [BIOENGINE_TEST_URL], e.g. to use a self-hosted BioEngine
+pub const BIOENGINE_TEST_URL_ENV_VAR: &str = "BIOIMG_BIOENGINE_TEST_URL";
+
+fn bioengine_test_url() -> String{
+    std::env::var(BIOENGINE_TEST_URL_ENV_VAR).unwrap_or_else(|_| BIOENGINE_TEST_URL.to_owned())
+}
+
+/// Which model the BioEngine should test
+pub enum RemoteModelSource{
+    /// A model that is already in the zoo (or staged for review), by its id
+    ZooId(String),
+    /// A packaged model .zip that is publicly downloadable, e.g. a draft uploaded via
+    /// [crate::client::Client::remote_test_package_path]
+    PackageUrl(String),
+}
+
+impl Display for RemoteModelSource{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self{
+            Self::ZooId(model_id) => write!(f, "{model_id}"),
+            Self::PackageUrl(url) => write!(f, "{url}"),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct RemoteTestDetail{
+    pub name: String,
+    pub status: String,
+    #[serde(default)]
+    pub errors: Vec<serde_json::Value>,
+}
+
+/// The outcome of a remote test run, in the format of bioimageio.core's validation summary
+#[derive(serde::Deserialize, Debug)]
+pub struct RemoteTestSummary{
+    pub status: String,
+    #[serde(default)]
+    pub details: Vec<RemoteTestDetail>,
+}
+
+impl RemoteTestSummary{
+    pub fn request(source: &RemoteModelSource) -> http::Request<Vec<u8>>{
+        let payload = match source{
+            RemoteModelSource::ZooId(model_id) => serde_json::json!({"model_id": model_id}),
+            RemoteModelSource::PackageUrl(url) => serde_json::json!({"model_url": url}),
+        };
+        http::Request::builder()
+            .method(http::Method::POST)
+            .uri(bioengine_test_url())
+            .header(http::header::CONTENT_TYPE, APPLICATION_JSON)
+            .body(serde_json::to_vec(&payload).unwrap())
+            .unwrap()
+    }
+
+    pub fn parse_response(response: &http::Response<Vec<u8>>) -> Result<Self, BadResponse>{
+        read_json_response(response)
+    }
+
+    pub fn passed(&self) -> bool{
+        self.status == "passed"
+    }
+}
+
+impl Display for RemoteTestSummary{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Remote test {}", self.status)?;
+        for detail in self.details.iter().filter(|detail| detail.status != "passed"){
+            write!(f, "\n  {}: {}", detail.name, detail.status)?;
+            for error in &detail.errors{
+                let message = error.get("msg").and_then(|msg| msg.as_str()).map(|msg| msg.to_owned())
+                    .unwrap_or_else(|| error.to_string());
+                write!(f, "\n    {message}")?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+//! The steps of uploading a packaged model to the zoo, shared by every frontend. Like the rest of this crate
+//! this doesn't do any IO itself, and sends its requests through a [Transport] provided by the caller
+
+use std::error::Error;
+use std::io::Read;
+
+use crate::auth::{Seconds, UserToken};
+use crate::client::{Client, ClientMethod, PresignedUrl};
+use crate::collection::{CollectionConfig, CollectionJson, ZooNickname, ZooNicknameGenerator};
+use crate::BadResponse;
+
+/// How many times to try generating a nickname that is not taken yet
+const NICKNAME_ATTEMPTS: usize = 50;
+
+/// How long the presigned storage URLs used during an upload stay valid
+const PRESIGNED_URL_EXPIRATION: Seconds = Seconds(3600);
+
+/// Sends the requests of an upload, e.g. via an HTTP client
+pub trait Transport{
+    type Error: Into<Box<dyn Error + Send + Sync>>;
+
+    fn send_reader<R: Read>(&self, req: http::Request<R>) -> Result<http::Response<Vec<u8>>, Self::Error>;
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum UploadError{
+    #[error("{step}: {source}")]
+    RequestFailed{step: &'static str, source: Box<dyn Error + Send + Sync>},
+    #[error("{step}: {source}")]
+    BadResponse{step: &'static str, source: BadResponse},
+    #[error("Could not find a free nickname for the model")]
+    NoFreeNickname,
+}
+
+fn send<T: Transport, R: Read, Out>(
+    transport: &T,
+    step: &'static str,
+    req: http::Request<R>,
+    parse: impl FnOnce(&http::Response<Vec<u8>>) -> Result<Out, BadResponse>,
+) -> Result<Out, UploadError>{
+    let response = transport.send_reader(req)
+        .map_err(|err| UploadError::RequestFailed { step, source: err.into() })?;
+    parse(&response).map_err(|source| UploadError::BadResponse { step, source })
+}
+
+fn check_status(response: &http::Response<Vec<u8>>) -> Result<(), BadResponse>{
+    if !response.status().is_success(){
+        return Err(BadResponse::FaiedResponse { status: response.status() })
+    }
+    Ok(())
+}
+
+fn as_reader<T: AsRef<[u8]>>(req: http::Request<T>) -> http::Request<std::io::Cursor<T>>{
+    let (http_parts, body) = req.into_parts();
+    http::Request::from_parts(http_parts, std::io::Cursor::new(body))
+}
+
+/// Puts `package` at `path` of the zoo storage, returning a URL it can be downloaded from for a while
+fn put_package(
+    transport: &impl Transport, client: &Client, path: &camino::Utf8Path, package: impl Read
+) -> Result<PresignedUrl, UploadError>{
+    let put_url = send(
+        transport,
+        "Requesting a storage URL",
+        as_reader(client.presigned_url_request_at(path, PRESIGNED_URL_EXPIRATION, ClientMethod::PutObject)),
+        |resp| client.parse_presigned_url_resp(resp),
+    )?;
+    send(transport, "Uploading the model", client.write_to_bucket_request(&put_url, package), check_status)?;
+    send(
+        transport,
+        "Requesting a download URL",
+        as_reader(client.presigned_url_request_at(path, PRESIGNED_URL_EXPIRATION, ClientMethod::GetObject)),
+        |resp| client.parse_presigned_url_resp(resp),
+    )
+}
+
+/// Uploads the packaged model read from `package` under a new nickname and stages it for review,
+/// returning that nickname. Each step is reported to `on_progress` before it starts
+pub fn upload_model(
+    transport: &impl Transport,
+    user_token: UserToken,
+    package: impl Read,
+    on_progress: impl Fn(String),
+) -> Result<ZooNickname, UploadError>{
+    on_progress("Fetching the zoo collection...".to_owned());
+    let collection_config = send(
+        transport, "Fetching the collection config", as_reader(CollectionConfig::request()), CollectionConfig::parse_response
+    )?;
+    let collection_json = send(
+        transport, "Fetching the collection", as_reader(CollectionJson::request()), CollectionJson::parse_response
+    )?;
+    let nickname_generator = ZooNicknameGenerator::new(collection_config, collection_json);
+    let nickname = (0..NICKNAME_ATTEMPTS)
+        .filter_map(|_| nickname_generator.generate_zoo_nickname())
+        .next()
+        .ok_or(UploadError::NoFreeNickname)?;
+    on_progress(format!("Chosen model nickname: {nickname}"));
+
+    let client = Client::new(user_token);
+    on_progress(format!("Uploading model '{nickname}'..."));
+    let package_url = put_package(transport, &client, &client.model_package_path(&nickname), package)?;
+
+    on_progress(format!("Staging model '{nickname}'..."));
+    send(transport, "Staging the model", as_reader(client.stage_model_request(&nickname, &package_url)), check_status)?;
+    on_progress(format!("Model '{nickname}' staged. It will be available on the zoo once it's reviewed"));
+    Ok(nickname)
+}
+
+/// Uploads the packaged model read from `package` to a temporary location of the zoo storage, without
+/// staging it, returning a URL the package can be downloaded from for a while, e.g. by the BioEngine for a
+/// remote test run
+pub fn upload_for_remote_test(
+    transport: &impl Transport, user_token: UserToken, package: impl Read
+) -> Result<PresignedUrl, UploadError>{
+    let client = Client::new(user_token);
+    let timestamp_millis = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis())
+        .unwrap_or_default();
+    put_package(transport, &client, &client.remote_test_package_path(timestamp_millis), package)
+}
@@ -0,0 +1,407 @@
+//! Generates `bioimg_spec/bioimg_spec.pyi`, the type stubs of the Python module defined in
+//! `bioimg_spec/src/py`, so that Python users get completion and signatures for the bound types. maturin
+//! ships the `<module>.pyi` it finds next to Cargo.toml along with the compiled extension.
+//!
+//! The bindings are read with `syn` rather than introspected at runtime: functions marked `#[pyfunction]`,
+//! classes registered with `add_class` (in the submodule created by the enclosing function, if any) and
+//! their `#[pymethods]`. Docstrings are taken from the doc comments of the bindings and, for classes,
+//! from the doc comments of the bound RDF structs.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use syn::visit::Visit;
+
+const STUBS_FILE_NAME: &str = "bioimg_spec.pyi";
+
+struct PyFunction {
+    name: String,
+    params: Vec<(String, String)>,
+    returns: String,
+    docs: Vec<String>,
+    /// Whether this is a method taking `self`
+    is_method: bool,
+}
+
+#[derive(Default)]
+struct PyClass {
+    docs: Vec<String>,
+    methods: Vec<PyFunction>,
+}
+
+fn parse_file(path: &Path) -> syn::File {
+    let source = std::fs::read_to_string(path).unwrap_or_else(|err| panic!("Could not read {}: {err}", path.display()));
+    syn::parse_file(&source).unwrap_or_else(|err| panic!("Could not parse {}: {err}", path.display()))
+}
+
+fn has_attr(attrs: &[syn::Attribute], name: &str) -> bool {
+    attrs.iter().any(|attr| attr.path().segments.last().is_some_and(|seg| seg.ident == name))
+}
+
+fn doc_lines(attrs: &[syn::Attribute]) -> Vec<String> {
+    attrs.iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue{ value: syn::Expr::Lit(syn::ExprLit{ lit: syn::Lit::Str(s), .. }), .. }) => {
+                Some(s.value().trim().to_owned())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn type_name(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Path(type_path) => type_path.path.segments.last().map(|seg| seg.ident.to_string()),
+        syn::Type::Reference(reference) => type_name(&reference.elem),
+        _ => None,
+    }
+}
+
+fn generic_args(ty: &syn::Type) -> Vec<&syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return vec![]
+    };
+    let Some(syn::PathArguments::AngleBracketed(args)) = type_path.path.segments.last().map(|seg| &seg.arguments) else {
+        return vec![]
+    };
+    args.args.iter()
+        .filter_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The Python annotation of the Rust type `ty`, as converted by pyo3
+fn python_type(ty: &syn::Type, self_name: Option<&str>) -> String {
+    if let syn::Type::Tuple(tuple) = ty {
+        if tuple.elems.is_empty() {
+            return "None".into();
+        }
+        let elems: Vec<String> = tuple.elems.iter().map(|elem| python_type(elem, self_name)).collect();
+        return format!("typing.Tuple[{}]", elems.join(", "));
+    }
+    let Some(name) = type_name(ty) else {
+        return "typing.Any".into();
+    };
+    let args = generic_args(ty);
+    match (name.as_str(), args.as_slice()) {
+        ("PyResult" | "Result", [inner, ..]) => python_type(inner, self_name),
+        ("Option", [inner]) => format!("typing.Optional[{}]", python_type(inner, self_name)),
+        ("Vec", [inner]) => format!("typing.List[{}]", python_type(inner, self_name)),
+        ("HashMap" | "BTreeMap", [key, value]) => {
+            format!("typing.Dict[{}, {}]", python_type(key, self_name), python_type(value, self_name))
+        }
+        ("u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64" | "isize" | "NonZeroUsize", _) => "int".into(),
+        ("f32" | "f64", _) => "float".into(),
+        ("bool", _) => "bool".into(),
+        ("String" | "str" | "PathBuf" | "Path", _) => "str".into(),
+        ("Self", _) => self_name.map(|name| format!("\"{name}\"")).unwrap_or_else(|| "typing.Any".into()),
+        _ => "typing.Any".into(),
+    }
+}
+
+/// Whether `arg` is one of the arguments pyo3 fills in itself rather than taking it from the caller
+fn is_injected(ty: &syn::Type) -> bool {
+    matches!(type_name(ty).as_deref(), Some("Python" | "Bound" | "PyRef" | "PyRefMut"))
+}
+
+fn py_function(sig: &syn::Signature, attrs: &[syn::Attribute], self_name: Option<&str>) -> PyFunction {
+    let mut is_method = false;
+    let mut params = vec![];
+    for input in &sig.inputs {
+        match input {
+            syn::FnArg::Receiver(_) => is_method = true,
+            syn::FnArg::Typed(pat_type) => {
+                if is_injected(&pat_type.ty) {
+                    continue;
+                }
+                let syn::Pat::Ident(pat_ident) = pat_type.pat.as_ref() else {
+                    continue;
+                };
+                params.push((pat_ident.ident.to_string(), python_type(&pat_type.ty, self_name)));
+            }
+        }
+    }
+    let returns = match &sig.output {
+        syn::ReturnType::Default => "None".into(),
+        syn::ReturnType::Type(_, ty) => python_type(ty, self_name),
+    };
+    PyFunction { name: sig.ident.to_string(), params, returns, docs: doc_lines(attrs), is_method }
+}
+
+/// Collects the `add_class::<T>()` calls in a function body and the name passed to `PyModule::new`, if any
+#[derive(Default)]
+struct ClassRegistrations {
+    submodule: Option<String>,
+    classes: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for ClassRegistrations {
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        if call.method == "add_class" {
+            let class_name = call.turbofish.as_ref()
+                .and_then(|turbofish| turbofish.args.first())
+                .and_then(|arg| match arg {
+                    syn::GenericArgument::Type(ty) => type_name(ty),
+                    _ => None,
+                });
+            self.classes.extend(class_name);
+        }
+        syn::visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+        let is_module_constructor = match call.func.as_ref() {
+            syn::Expr::Path(func) => {
+                let segments: Vec<_> = func.path.segments.iter().map(|seg| seg.ident.to_string()).collect();
+                segments.ends_with(&["PyModule".to_owned(), "new".to_owned()])
+            }
+            _ => false,
+        };
+        if is_module_constructor {
+            let name = call.args.iter().find_map(|arg| match arg {
+                syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(name), .. }) => Some(name.value()),
+                _ => None,
+            });
+            self.submodule = self.submodule.take().or(name);
+        }
+        syn::visit::visit_expr_call(self, call);
+    }
+}
+
+/// Classes registered via `add_class::<T>()` in `item_fn`, and the name of the submodule it creates, if any
+fn registered_classes(item_fn: &syn::ItemFn) -> (Option<String>, Vec<String>) {
+    let mut registrations = ClassRegistrations::default();
+    registrations.visit_block(&item_fn.block);
+    (registrations.submodule, registrations.classes)
+}
+
+/// Finds the doc comments of the struct `name` anywhere under `dir`
+fn struct_docs(dir: &Path, name: &str) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return vec![]
+    };
+    let mut paths: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).collect();
+    paths.sort();
+    for path in paths {
+        if path.is_dir() {
+            let docs = struct_docs(&path, name);
+            if !docs.is_empty() {
+                return docs;
+            }
+            continue;
+        }
+        if path.extension().is_none_or(|ext| ext != "rs") {
+            continue;
+        }
+        let source = std::fs::read_to_string(&path).unwrap_or_default();
+        if !source.contains(&format!("struct {name}")) {
+            continue;
+        }
+        let Ok(file) = syn::parse_file(&source) else {
+            continue;
+        };
+        let docs = file.items.iter().find_map(|item| match item {
+            syn::Item::Struct(item_struct) if item_struct.ident == name => Some(doc_lines(&item_struct.attrs)),
+            _ => None,
+        });
+        if let Some(docs) = docs {
+            return docs;
+        }
+    }
+    vec![]
+}
+
+fn write_docstring(out: &mut String, indent: &str, docs: &[String]) {
+    match docs {
+        [] => (),
+        [line] => writeln!(out, "{indent}\"\"\"{line}\"\"\"").unwrap(),
+        lines => {
+            writeln!(out, "{indent}\"\"\"").unwrap();
+            for line in lines {
+                writeln!(out, "{indent}{line}").unwrap();
+            }
+            writeln!(out, "{indent}\"\"\"").unwrap();
+        }
+    }
+}
+
+fn write_function(out: &mut String, indent: &str, function: &PyFunction) {
+    let mut params: Vec<String> = function.params.iter().map(|(name, ty)| format!("{name}: {ty}")).collect();
+    if function.is_method {
+        params.insert(0, "self".into());
+    }
+    writeln!(out, "{indent}def {}({}) -> {}:", function.name, params.join(", "), function.returns).unwrap();
+    let body_indent = format!("{indent}    ");
+    write_docstring(out, &body_indent, &function.docs);
+    writeln!(out, "{body_indent}...").unwrap();
+}
+
+fn write_class(out: &mut String, indent: &str, name: &str, class: &PyClass) {
+    writeln!(out, "{indent}class {name}:").unwrap();
+    let body_indent = format!("{indent}    ");
+    write_docstring(out, &body_indent, &class.docs);
+    for method in &class.methods {
+        write_function(out, &body_indent, method);
+    }
+    if class.docs.is_empty() && class.methods.is_empty() {
+        writeln!(out, "{body_indent}...").unwrap();
+    }
+}
+
+fn render(py_module: &syn::File, rdf_dir: &Path) -> String {
+    let mut functions = vec![];
+    let mut classes: BTreeMap<String, PyClass> = BTreeMap::new();
+    let mut submodule_of_class: BTreeMap<String, String> = BTreeMap::new();
+
+    for item in &py_module.items {
+        match item {
+            syn::Item::Fn(item_fn) if has_attr(&item_fn.attrs, "pyfunction") => {
+                functions.push(py_function(&item_fn.sig, &item_fn.attrs, None));
+            }
+            syn::Item::Fn(item_fn) => {
+                let (submodule, class_names) = registered_classes(item_fn);
+                for class_name in class_names {
+                    classes.entry(class_name.clone()).or_default();
+                    if let Some(submodule) = &submodule {
+                        submodule_of_class.insert(class_name, submodule.clone());
+                    }
+                }
+            }
+            syn::Item::Impl(item_impl) if has_attr(&item_impl.attrs, "pymethods") => {
+                let Some(class_name) = type_name(&item_impl.self_ty) else {
+                    continue;
+                };
+                let class = classes.entry(class_name.clone()).or_default();
+                for impl_item in &item_impl.items {
+                    let syn::ImplItem::Fn(method) = impl_item else {
+                        continue;
+                    };
+                    let mut function = py_function(&method.sig, &method.attrs, Some(&class_name));
+                    if has_attr(&method.attrs, "new") {
+                        function.name = "__init__".into();
+                        function.returns = "None".into();
+                        function.is_method = true;
+                    }
+                    class.methods.push(function);
+                }
+            }
+            _ => (),
+        }
+    }
+    for (class_name, class) in classes.iter_mut() {
+        class.docs = struct_docs(rdf_dir, class_name);
+    }
+
+    let mut out = String::new();
+    writeln!(out, "# Generated by task__python_stubs from the bindings in src/py. Do not edit.").unwrap();
+    writeln!(out, "import typing").unwrap();
+    for function in &functions {
+        writeln!(out).unwrap();
+        write_function(&mut out, "", function);
+    }
+    for (class_name, class) in classes.iter().filter(|(name, _)| !submodule_of_class.contains_key(*name)) {
+        writeln!(out).unwrap();
+        write_class(&mut out, "", class_name, class);
+    }
+    let mut submodules: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (class_name, submodule) in &submodule_of_class {
+        submodules.entry(submodule).or_default().push(class_name);
+    }
+    for (submodule, class_names) in submodules {
+        writeln!(out).unwrap();
+        // submodules are modelled as namespaces so that everything fits in the single stub file maturin picks up
+        writeln!(out, "class {submodule}:").unwrap();
+        writeln!(out, "    \"\"\"The `bioimg_spec.{submodule}` submodule\"\"\"").unwrap();
+        for class_name in class_names {
+            write_class(&mut out, "    ", class_name, &classes[class_name]);
+        }
+    }
+    out
+}
+
+fn spec_crate_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).parent().unwrap().join("bioimg_spec")
+}
+
+fn main() {
+    let spec_dir = spec_crate_dir();
+    let py_module = parse_file(&spec_dir.join("src/py/mod.rs"));
+    let stubs = render(&py_module, &spec_dir.join("src/rdf"));
+
+    let stubs_path = spec_dir.join(STUBS_FILE_NAME);
+    std::fs::write(&stubs_path, stubs).unwrap_or_else(|err| panic!("Could not write {}: {err}", stubs_path.display()));
+    println!("Wrote {}", stubs_path.display());
+}
+
+#[test]
+fn test_rendering_stubs() {
+    let py_module: syn::File = syn::parse_quote! {
+        /// Adds two numbers
+        #[pyfunction]
+        fn add(py: Python<'_>, a: usize, b: Option<f32>) -> PyResult<Vec<String>> {
+            todo!()
+        }
+
+        fn helper() {}
+
+        #[pymodule]
+        fn some_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+            m.add_class::<TopLevel>()?;
+            register_submodule(m)
+        }
+
+        fn register_submodule(parent: &Bound<'_, PyModule>) -> PyResult<()> {
+            let module = PyModule::new(parent.py(), "nested")?;
+            module.add_class::<crate::rdf::Nested>()?;
+            parent.add_submodule(&module)
+        }
+
+        #[pymethods]
+        impl Nested {
+            #[new]
+            pub fn new(name: String) -> PyResult<Self> {
+                todo!()
+            }
+            /// The name
+            pub fn name(&self) -> String {
+                todo!()
+            }
+        }
+    };
+    let stubs = render(&py_module, Path::new("/nonexistent"));
+    let expected = "\
+# Generated by task__python_stubs from the bindings in src/py. Do not edit.
+import typing
+
+def add(a: int, b: typing.Optional[float]) -> typing.List[str]:
+    \"\"\"Adds two numbers\"\"\"
+    ...
+
+class TopLevel:
+    ...
+
+class nested:
+    \"\"\"The `bioimg_spec.nested` submodule\"\"\"
+    class Nested:
+        def __init__(self, name: str) -> None:
+            ...
+        def name(self) -> str:
+            \"\"\"The name\"\"\"
+            ...
+";
+    assert_eq!(stubs, expected);
+}
+
+#[test]
+fn test_stubs_of_the_spec_module() {
+    let spec_dir = spec_crate_dir();
+    let stubs = render(&parse_file(&spec_dir.join("src/py/mod.rs")), &spec_dir.join("src/rdf"));
+    assert!(stubs.contains("def apply_preprocessing(step: str, tensor: typing.Any, axes: typing.List[str]) -> typing.Any:"));
+    assert!(stubs.contains("class author:"));
+    // the docstring of the class comes from the RDF struct
+    assert!(stubs.contains("An author of a resource"));
+}